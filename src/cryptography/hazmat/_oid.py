@@ -38,20 +38,33 @@ class ExtensionOID:
     )
     PRECERT_POISON = ObjectIdentifier("1.3.6.1.4.1.11129.2.4.3")
     SIGNED_CERTIFICATE_TIMESTAMPS = ObjectIdentifier("1.3.6.1.4.1.11129.2.4.5")
+    MS_CERTIFICATE_TEMPLATE_NAME = ObjectIdentifier("1.3.6.1.4.1.311.20.2")
     MS_CERTIFICATE_TEMPLATE = ObjectIdentifier("1.3.6.1.4.1.311.21.7")
+    MS_APPLICATION_POLICIES = ObjectIdentifier("1.3.6.1.4.1.311.21.10")
+    ADMISSIONS = ObjectIdentifier("1.3.36.8.3.3")
+    NETSCAPE_CERT_TYPE = ObjectIdentifier("2.16.840.1.113730.1.1")
+    NETSCAPE_COMMENT = ObjectIdentifier("2.16.840.1.113730.1.13")
+    ENTRUST_VERSION_INFO = ObjectIdentifier("1.2.840.113533.7.65.0")
 
 
 class OCSPExtensionOID:
     NONCE = ObjectIdentifier("1.3.6.1.5.5.7.48.1.2")
     ACCEPTABLE_RESPONSES = ObjectIdentifier("1.3.6.1.5.5.7.48.1.4")
+    ARCHIVE_CUTOFF = ObjectIdentifier("1.3.6.1.5.5.7.48.1.6")
 
 
 class CRLEntryExtensionOID:
     CERTIFICATE_ISSUER = ObjectIdentifier("2.5.29.29")
     CRL_REASON = ObjectIdentifier("2.5.29.21")
     INVALIDITY_DATE = ObjectIdentifier("2.5.29.24")
+    HOLD_INSTRUCTION_CODE = ObjectIdentifier("2.5.29.23")
 
 
+class HoldInstructionOID:
+    NONE = ObjectIdentifier("1.2.840.10040.2.1")
+    CALL_ISSUER = ObjectIdentifier("1.2.840.10040.2.2")
+    REJECT = ObjectIdentifier("1.2.840.10040.2.3")
+
 class NameOID:
     COMMON_NAME = ObjectIdentifier("2.5.4.3")
     COUNTRY_NAME = ObjectIdentifier("2.5.4.6")
@@ -120,6 +133,7 @@ class SignatureAlgorithmOID:
     GOSTR3411_94_WITH_3410_2001 = ObjectIdentifier("1.2.643.2.2.3")
     GOSTR3410_2012_WITH_3411_2012_256 = ObjectIdentifier("1.2.643.7.1.1.3.2")
     GOSTR3410_2012_WITH_3411_2012_512 = ObjectIdentifier("1.2.643.7.1.1.3.3")
+    SM2_WITH_SM3 = ObjectIdentifier("1.2.156.10197.1.501")
 
 
 _SIG_OIDS_TO_HASH: dict[ObjectIdentifier, hashes.HashAlgorithm | None] = {
@@ -151,6 +165,7 @@ _SIG_OIDS_TO_HASH: dict[ObjectIdentifier, hashes.HashAlgorithm | None] = {
     SignatureAlgorithmOID.GOSTR3411_94_WITH_3410_2001: None,
     SignatureAlgorithmOID.GOSTR3410_2012_WITH_3411_2012_256: None,
     SignatureAlgorithmOID.GOSTR3410_2012_WITH_3411_2012_512: None,
+    SignatureAlgorithmOID.SM2_WITH_SM3: hashes.SM3(),
 }
 
 
@@ -158,11 +173,15 @@ class PublicKeyAlgorithmOID:
     DSA = ObjectIdentifier("1.2.840.10040.4.1")
     EC_PUBLIC_KEY = ObjectIdentifier("1.2.840.10045.2.1")
     RSAES_PKCS1_v1_5 = ObjectIdentifier("1.2.840.113549.1.1.1")
+    RSAES_OAEP = ObjectIdentifier("1.2.840.113549.1.1.7")
     RSASSA_PSS = ObjectIdentifier("1.2.840.113549.1.1.10")
     X25519 = ObjectIdentifier("1.3.101.110")
     X448 = ObjectIdentifier("1.3.101.111")
     ED25519 = ObjectIdentifier("1.3.101.112")
     ED448 = ObjectIdentifier("1.3.101.113")
+    GOSTR3410_2001 = ObjectIdentifier("1.2.643.2.2.19")
+    GOSTR3410_2012_256 = ObjectIdentifier("1.2.643.7.1.1.1.1")
+    GOSTR3410_2012_512 = ObjectIdentifier("1.2.643.7.1.1.1.2")
 
 
 class ExtendedKeyUsageOID:
@@ -186,6 +205,7 @@ class AuthorityInformationAccessOID:
 
 class SubjectInformationAccessOID:
     CA_REPOSITORY = ObjectIdentifier("1.3.6.1.5.5.7.48.5")
+    TIME_STAMPING = ObjectIdentifier("1.3.6.1.5.5.7.48.3")
 
 
 class CertificatePoliciesOID:
@@ -256,12 +276,17 @@ _OID_NAMES = {
     SignatureAlgorithmOID.GOSTR3410_2012_WITH_3411_2012_512: (
         "GOST R 34.10-2012 with GOST R 34.11-2012 (512 bit)"
     ),
+    SignatureAlgorithmOID.SM2_WITH_SM3: "SM2 with SM3",
     PublicKeyAlgorithmOID.DSA: "dsaEncryption",
     PublicKeyAlgorithmOID.EC_PUBLIC_KEY: "id-ecPublicKey",
     PublicKeyAlgorithmOID.RSAES_PKCS1_v1_5: "rsaEncryption",
+    PublicKeyAlgorithmOID.RSAES_OAEP: "rsaesOaep",
     PublicKeyAlgorithmOID.RSASSA_PSS: "rsassaPss",
     PublicKeyAlgorithmOID.X25519: "X25519",
     PublicKeyAlgorithmOID.X448: "X448",
+    PublicKeyAlgorithmOID.GOSTR3410_2001: "GOST R 34.10-2001",
+    PublicKeyAlgorithmOID.GOSTR3410_2012_256: "GOST R 34.10-2012 (256 bit)",
+    PublicKeyAlgorithmOID.GOSTR3410_2012_512: "GOST R 34.10-2012 (512 bit)",
     ExtendedKeyUsageOID.SERVER_AUTH: "serverAuth",
     ExtendedKeyUsageOID.CLIENT_AUTH: "clientAuth",
     ExtendedKeyUsageOID.CODE_SIGNING: "codeSigning",
@@ -283,10 +308,20 @@ _OID_NAMES = {
         "signedCertificateTimestampList"
     ),
     ExtensionOID.PRECERT_POISON: "ctPoison",
+    ExtensionOID.MS_CERTIFICATE_TEMPLATE_NAME: "msCertificateTemplateName",
     ExtensionOID.MS_CERTIFICATE_TEMPLATE: "msCertificateTemplate",
+    ExtensionOID.MS_APPLICATION_POLICIES: "msApplicationPolicies",
+    ExtensionOID.ADMISSIONS: "Admissions",
+    ExtensionOID.NETSCAPE_CERT_TYPE: "nsCertType",
+    ExtensionOID.NETSCAPE_COMMENT: "nsComment",
+    ExtensionOID.ENTRUST_VERSION_INFO: "entrustVersInfo",
     CRLEntryExtensionOID.CRL_REASON: "cRLReason",
     CRLEntryExtensionOID.INVALIDITY_DATE: "invalidityDate",
     CRLEntryExtensionOID.CERTIFICATE_ISSUER: "certificateIssuer",
+    CRLEntryExtensionOID.HOLD_INSTRUCTION_CODE: "holdInstructionCode",
+    HoldInstructionOID.NONE: "holdInstructionNone",
+    HoldInstructionOID.CALL_ISSUER: "holdInstructionCallIssuer",
+    HoldInstructionOID.REJECT: "holdInstructionReject",
     ExtensionOID.NAME_CONSTRAINTS: "nameConstraints",
     ExtensionOID.CRL_DISTRIBUTION_POINTS: "cRLDistributionPoints",
     ExtensionOID.CERTIFICATE_POLICIES: "certificatePolicies",
@@ -306,8 +341,32 @@ _OID_NAMES = {
     AuthorityInformationAccessOID.OCSP: "OCSP",
     AuthorityInformationAccessOID.CA_ISSUERS: "caIssuers",
     SubjectInformationAccessOID.CA_REPOSITORY: "caRepository",
+    SubjectInformationAccessOID.TIME_STAMPING: "timeStamping",
     CertificatePoliciesOID.CPS_QUALIFIER: "id-qt-cps",
     CertificatePoliciesOID.CPS_USER_NOTICE: "id-qt-unotice",
     OCSPExtensionOID.NONCE: "OCSPNonce",
+    OCSPExtensionOID.ARCHIVE_CUTOFF: "archiveCutoff",
     AttributeOID.CHALLENGE_PASSWORD: "challengePassword",
 }
+
+# Several OIDs share a name (e.g. the two SCT list extensions), iterate in
+# reverse so the first one listed in _OID_NAMES wins.
+_NAME_TO_OID = {
+    name: oid for oid, name in reversed(list(_OID_NAMES.items()))
+}
+
+
+def oid_to_name(oid: ObjectIdentifier | str) -> str | None:
+    if isinstance(oid, str):
+        oid = ObjectIdentifier(oid)
+    elif not isinstance(oid, ObjectIdentifier):
+        raise TypeError("oid must be an ObjectIdentifier or a dotted string")
+
+    return _OID_NAMES.get(oid)
+
+
+def name_to_oid(name: str) -> ObjectIdentifier | None:
+    if not isinstance(name, str):
+        raise TypeError("name must be a str")
+
+    return _NAME_TO_OID.get(name)
@@ -158,6 +158,9 @@ class Backend:
         if self._fips_enabled and isinstance(algorithm, hashes.SHA1):
             return True
 
+        if algorithm.block_size is None:
+            return False
+
         return self.hash_supported(algorithm)
 
     def cipher_supported(self, cipher: CipherAlgorithm, mode: Mode) -> bool:
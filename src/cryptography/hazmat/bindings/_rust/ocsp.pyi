@@ -4,9 +4,11 @@
 
 from cryptography.hazmat.primitives import hashes
 from cryptography.hazmat.primitives.asymmetric.types import PrivateKeyTypes
+from cryptography import x509
 from cryptography.x509 import ocsp
 
 class OCSPRequest: ...
+class OCSPCertID: ...
 class OCSPResponse: ...
 class OCSPSingleResponse: ...
 
@@ -15,6 +17,11 @@ def load_der_ocsp_response(data: bytes) -> ocsp.OCSPResponse: ...
 def create_ocsp_request(
     builder: ocsp.OCSPRequestBuilder,
 ) -> ocsp.OCSPRequest: ...
+def create_ocsp_cert_id(
+    cert: x509.Certificate,
+    issuer: x509.Certificate,
+    hash_algorithm: hashes.HashAlgorithm,
+) -> ocsp.OCSPCertID: ...
 def create_ocsp_response(
     status: ocsp.OCSPResponseStatus,
     builder: ocsp.OCSPResponseBuilder | None,
@@ -3,7 +3,7 @@
 # for complete details.
 
 class AESGCM:
-    def __init__(self, key: bytes) -> None: ...
+    def __init__(self, key: bytes, *, min_nonce_length: int = 8) -> None: ...
     @staticmethod
     def generate_key(key_size: int) -> bytes: ...
     def encrypt(
@@ -19,6 +19,21 @@ class AESGCM:
         associated_data: bytes | None,
     ) -> bytes: ...
 
+class AESGCMContext:
+    def __init__(self, key: bytes, *, min_nonce_length: int = 8) -> None: ...
+    def encrypt(
+        self,
+        nonce: bytes,
+        data: bytes,
+        associated_data: bytes | None,
+    ) -> bytes: ...
+    def decrypt(
+        self,
+        nonce: bytes,
+        data: bytes,
+        associated_data: bytes | None,
+    ) -> bytes: ...
+
 class ChaCha20Poly1305:
     def __init__(self, key: bytes) -> None: ...
     @staticmethod
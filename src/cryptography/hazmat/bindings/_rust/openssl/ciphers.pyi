@@ -17,7 +17,9 @@ def create_encryption_ctx(
 ) -> ciphers.CipherContext: ...
 @typing.overload
 def create_decryption_ctx(
-    algorithm: ciphers.CipherAlgorithm, mode: modes.ModeWithAuthenticationTag
+    algorithm: ciphers.CipherAlgorithm,
+    mode: modes.ModeWithAuthenticationTag,
+    buffered: bool = False,
 ) -> ciphers.AEADDecryptionContext: ...
 @typing.overload
 def create_decryption_ctx(
@@ -4,6 +4,7 @@
 
 import typing
 
+from cryptography.hazmat.primitives import serialization
 from cryptography.hazmat.primitives.asymmetric import ec
 
 class ECPrivateKey: ...
@@ -47,6 +48,9 @@ def from_public_numbers(
 def from_public_bytes(
     curve: ec.EllipticCurve, data: bytes
 ) -> ec.EllipticCurvePublicKey: ...
+def load_public_key_with_explicit_parameters(
+    data: bytes, encoding: serialization.Encoding
+) -> ec.EllipticCurvePublicKey: ...
 def derive_private_key(
     private_value: int, curve: ec.EllipticCurve
 ) -> ec.EllipticCurvePrivateKey: ...
@@ -2,6 +2,7 @@
 # 2.0, and the BSD License. See the LICENSE file in the root of this repository
 # for complete details.
 
+import os
 import typing
 
 from cryptography.hazmat.primitives import hashes
@@ -15,3 +16,8 @@ class Hash(hashes.HashContext):
     def update(self, data: bytes) -> None: ...
     def finalize(self) -> bytes: ...
     def copy(self) -> Hash: ...
+
+def hash_file(
+    algorithm: hashes.HashAlgorithm,
+    path: typing.Union[str, os.PathLike[str]],
+) -> bytes: ...
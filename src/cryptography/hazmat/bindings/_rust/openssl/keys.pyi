@@ -4,6 +4,9 @@
 
 import typing
 
+from cryptography.hazmat.primitives._serialization import (
+    KeySerializationEncryption,
+)
 from cryptography.hazmat.primitives.asymmetric.types import (
     PrivateKeyTypes,
     PublicKeyTypes,
@@ -15,6 +18,7 @@ def load_der_private_key(
     backend: typing.Any = None,
     *,
     unsafe_skip_rsa_key_validation: bool = False,
+    password_callback: typing.Callable[[], bytes | None] | None = None,
 ) -> PrivateKeyTypes: ...
 def load_pem_private_key(
     data: bytes,
@@ -22,7 +26,18 @@ def load_pem_private_key(
     backend: typing.Any = None,
     *,
     unsafe_skip_rsa_key_validation: bool = False,
+    password_callback: typing.Callable[[], bytes | None] | None = None,
 ) -> PrivateKeyTypes: ...
+def reencrypt_der_private_key(
+    data: bytes,
+    password: bytes | None,
+    encryption_algorithm: KeySerializationEncryption,
+) -> bytes: ...
+def reencrypt_pem_private_key(
+    data: bytes,
+    password: bytes | None,
+    encryption_algorithm: KeySerializationEncryption,
+) -> bytes: ...
 def load_der_public_key(
     data: bytes,
     backend: typing.Any = None,
@@ -5,10 +5,17 @@
 import typing
 
 from cryptography.hazmat.primitives.asymmetric import rsa
+from cryptography.hazmat.primitives.asymmetric.padding import (
+    AsymmetricPadding,
+)
+from cryptography.hazmat.primitives.asymmetric.utils import Prehashed
+from cryptography.hazmat.primitives.hashes import HashAlgorithm
 
 class RSAPrivateKey: ...
 class RSAPublicKey: ...
 
+class RSABlindingState: ...
+
 class RSAPrivateNumbers:
     def __init__(
         self,
@@ -53,3 +60,45 @@ def generate_private_key(
     public_exponent: int,
     key_size: int,
 ) -> rsa.RSAPrivateKey: ...
+def unsafe_raw_public_operation(
+    key: rsa.RSAPublicKey,
+    data: bytes,
+) -> bytes: ...
+def unsafe_raw_private_operation(
+    key: rsa.RSAPrivateKey,
+    data: bytes,
+) -> bytes: ...
+def blind(
+    message: bytes,
+    public_key: rsa.RSAPublicKey,
+    padding: AsymmetricPadding,
+    algorithm: Prehashed | HashAlgorithm,
+) -> tuple[bytes, RSABlindingState]: ...
+def _blind_with_fixed_randomness(
+    message: bytes,
+    public_key: rsa.RSAPublicKey,
+    padding: AsymmetricPadding,
+    algorithm: Prehashed | HashAlgorithm,
+    salt: bytes,
+    inv: bytes,
+) -> tuple[bytes, RSABlindingState]: ...
+def unblind(
+    blinded_signature: bytes,
+    state: RSABlindingState,
+) -> bytes: ...
+def decrypt_pkcs1v15_implicit_rejection(
+    key: rsa.RSAPrivateKey,
+    ciphertext: bytes,
+    synthetic_plaintext: bytes,
+) -> bytes: ...
+def rsa_kem_encapsulate(
+    key: rsa.RSAPublicKey,
+    algorithm: HashAlgorithm,
+    length: int,
+) -> tuple[bytes, bytes]: ...
+def rsa_kem_decapsulate(
+    key: rsa.RSAPrivateKey,
+    ciphertext: bytes,
+    algorithm: HashAlgorithm,
+    length: int,
+) -> bytes: ...
@@ -6,6 +6,7 @@ import typing
 
 from cryptography import x509
 from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric import rsa
 from cryptography.hazmat.primitives.serialization import pkcs7
 
 def serialize_certificates(
@@ -23,3 +24,63 @@ def load_pem_pkcs7_certificates(
 def load_der_pkcs7_certificates(
     data: bytes,
 ) -> list[x509.Certificate]: ...
+def pkcs7_content_type_der(data: bytes) -> x509.ObjectIdentifier: ...
+def pkcs7_content_type_pem(data: bytes) -> x509.ObjectIdentifier: ...
+def pkcs7_decrypt_der(
+    data: bytes,
+    certificate: x509.Certificate,
+    private_key: rsa.RSAPrivateKey,
+) -> bytes: ...
+def pkcs7_decrypt_pem(
+    data: bytes,
+    certificate: x509.Certificate,
+    private_key: rsa.RSAPrivateKey,
+) -> bytes: ...
+def pkcs7_verify_der(
+    data: bytes,
+    certificate: x509.Certificate,
+    *,
+    content: bytes | None = None,
+    content_digest: bytes | None = None,
+    expected_email: str | None = None,
+) -> None: ...
+def pkcs7_verify_pem(
+    data: bytes,
+    certificate: x509.Certificate,
+    *,
+    content: bytes | None = None,
+    content_digest: bytes | None = None,
+    expected_email: str | None = None,
+) -> None: ...
+def pkcs7_verify_signers_der(
+    data: bytes,
+    certificates: list[x509.Certificate],
+    *,
+    content: bytes | None = None,
+    content_digest: bytes | None = None,
+    require_all: bool = True,
+) -> list[x509.Certificate]: ...
+def pkcs7_verify_signers_pem(
+    data: bytes,
+    certificates: list[x509.Certificate],
+    *,
+    content: bytes | None = None,
+    content_digest: bytes | None = None,
+    require_all: bool = True,
+) -> list[x509.Certificate]: ...
+def pkcs7_verify_countersignature_der(
+    data: bytes,
+    certificate: x509.Certificate,
+    countersigner_certificate: x509.Certificate,
+) -> None: ...
+def pkcs7_verify_countersignature_pem(
+    data: bytes,
+    certificate: x509.Certificate,
+    countersigner_certificate: x509.Certificate,
+) -> None: ...
+def pkcs7_digest_algorithms_der(
+    data: bytes,
+) -> list[x509.ObjectIdentifier]: ...
+def pkcs7_digest_algorithms_pem(
+    data: bytes,
+) -> list[x509.ObjectIdentifier]: ...
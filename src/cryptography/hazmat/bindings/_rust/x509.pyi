@@ -8,7 +8,10 @@ import typing
 from cryptography import x509
 from cryptography.hazmat.primitives import hashes
 from cryptography.hazmat.primitives.asymmetric.padding import PSS, PKCS1v15
-from cryptography.hazmat.primitives.asymmetric.types import PrivateKeyTypes
+from cryptography.hazmat.primitives.asymmetric.types import (
+    PrivateKeyTypes,
+    PublicKeyTypes,
+)
 
 def load_pem_x509_certificate(
     data: bytes, backend: typing.Any = None
@@ -16,9 +19,13 @@ def load_pem_x509_certificate(
 def load_der_x509_certificate(
     data: bytes, backend: typing.Any = None
 ) -> x509.Certificate: ...
+def load_der_x509_certificate_view(data: bytes) -> CertificateView: ...
 def load_pem_x509_certificates(
     data: bytes,
 ) -> list[x509.Certificate]: ...
+def load_der_x509_certificates(
+    data: bytes,
+) -> list[x509.Certificate]: ...
 def load_pem_x509_crl(
     data: bytes, backend: typing.Any = None
 ) -> x509.CertificateRevocationList: ...
@@ -39,6 +46,16 @@ def create_x509_certificate(
     hash_algorithm: hashes.HashAlgorithm | None,
     rsa_padding: PKCS1v15 | PSS | None,
 ) -> x509.Certificate: ...
+def create_self_signed_x509_certificate(
+    subject_name: x509.Name,
+    private_key: PrivateKeyTypes,
+    serial_number: int,
+    not_valid_before: datetime.datetime,
+    not_valid_after: datetime.datetime,
+    extensions: list[x509.Extension[x509.ExtensionType]],
+    hash_algorithm: hashes.HashAlgorithm | None,
+    rsa_padding: PKCS1v15 | PSS | None,
+) -> x509.Certificate: ...
 def create_x509_csr(
     builder: x509.CertificateSigningRequestBuilder,
     private_key: PrivateKeyTypes,
@@ -52,8 +69,32 @@ def create_x509_crl(
     rsa_padding: PKCS1v15 | PSS | None,
 ) -> x509.CertificateRevocationList: ...
 
-class Sct: ...
+class Sct:
+    def verify(
+        self,
+        log_public_key: PublicKeyTypes,
+        certificate: x509.Certificate,
+        issuer: x509.Certificate,
+    ) -> None: ...
+
 class Certificate: ...
+
+class CertificateView:
+    @property
+    def serial_number(self) -> int: ...
+    @property
+    def issuer(self) -> x509.Name: ...
+    @property
+    def subject(self) -> x509.Name: ...
+    @property
+    def issuer_bytes(self) -> bytes: ...
+    @property
+    def subject_bytes(self) -> bytes: ...
+    @property
+    def not_valid_before_utc(self) -> datetime.datetime: ...
+    @property
+    def not_valid_after_utc(self) -> datetime.datetime: ...
+
 class RevokedCertificate: ...
 class CertificateRevocationList: ...
 class CertificateSigningRequest: ...
@@ -62,6 +103,22 @@ class PolicyBuilder:
     def time(self, new_time: datetime.datetime) -> PolicyBuilder: ...
     def store(self, new_store: Store) -> PolicyBuilder: ...
     def max_chain_depth(self, new_max_chain_depth: int) -> PolicyBuilder: ...
+    def signature_hash_algorithms(
+        self,
+        new_signature_hash_algorithms: list[hashes.HashAlgorithm],
+        *,
+        check_trust_anchor: bool = False,
+    ) -> PolicyBuilder: ...
+    def permit_precertificates(self) -> PolicyBuilder: ...
+    def require_key_establishment(self) -> PolicyBuilder: ...
+    def require_nested_validity(self) -> PolicyBuilder: ...
+    def handled_critical_extensions(
+        self, new_handled_critical_extensions: list[x509.ObjectIdentifier]
+    ) -> PolicyBuilder: ...
+    def ca_issuers_fetcher(
+        self, new_ca_issuers_fetcher: typing.Callable[[str], list[bytes]]
+    ) -> PolicyBuilder: ...
+    def max_aia_fetches(self, new_max_aia_fetches: int) -> PolicyBuilder: ...
     def build_client_verifier(self) -> ClientVerifier: ...
     def build_server_verifier(
         self, subject: x509.verification.Subject
@@ -72,6 +129,7 @@ class VerifiedClient:
     def subjects(self) -> list[x509.GeneralName]: ...
     @property
     def chain(self) -> list[x509.Certificate]: ...
+    def chain_pem(self, *, include_root: bool = True) -> bytes: ...
 
 class ClientVerifier:
     @property
@@ -85,6 +143,11 @@ class ClientVerifier:
         leaf: x509.Certificate,
         intermediates: list[x509.Certificate],
     ) -> VerifiedClient: ...
+    def satisfied_extended_key_usages(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate],
+    ) -> list[x509.ObjectIdentifier]: ...
 
 class ServerVerifier:
     @property
@@ -100,9 +163,34 @@ class ServerVerifier:
         leaf: x509.Certificate,
         intermediates: list[x509.Certificate],
     ) -> list[x509.Certificate]: ...
+    def satisfied_extended_key_usages(
+        self,
+        leaf: x509.Certificate,
+        intermediates: list[x509.Certificate],
+    ) -> list[x509.ObjectIdentifier]: ...
+
+class TrustAnchor:
+    def __init__(
+        self,
+        certificate: x509.Certificate,
+        purposes: list[x509.ObjectIdentifier],
+    ) -> None: ...
+    @property
+    def certificate(self) -> x509.Certificate: ...
+    @property
+    def purposes(self) -> list[x509.ObjectIdentifier]: ...
 
 class Store:
-    def __init__(self, certs: list[x509.Certificate]) -> None: ...
+    def __init__(
+        self, certs: list[x509.Certificate | TrustAnchor]
+    ) -> None: ...
 
 class VerificationError(Exception):
     pass
+
+def authority_key_identifier_matches(
+    aki: x509.AuthorityKeyIdentifier, issuer: x509.Certificate
+) -> bool: ...
+def chain_pem(
+    chain: list[x509.Certificate], *, include_root: bool = True
+) -> bytes: ...
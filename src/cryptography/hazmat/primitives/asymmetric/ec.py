@@ -23,6 +23,7 @@ class EllipticCurveOID:
     SECP384R1 = ObjectIdentifier("1.3.132.0.34")
     SECP521R1 = ObjectIdentifier("1.3.132.0.35")
     BRAINPOOLP256R1 = ObjectIdentifier("1.3.36.3.3.2.8.1.1.7")
+    BRAINPOOLP320R1 = ObjectIdentifier("1.3.36.3.3.2.8.1.1.9")
     BRAINPOOLP384R1 = ObjectIdentifier("1.3.36.3.3.2.8.1.1.11")
     BRAINPOOLP512R1 = ObjectIdentifier("1.3.36.3.3.2.8.1.1.13")
     SECT163K1 = ObjectIdentifier("1.3.132.0.1")
@@ -152,6 +153,8 @@ class EllipticCurvePublicKey(metaclass=abc.ABCMeta):
         self,
         encoding: _serialization.Encoding,
         format: _serialization.PublicFormat,
+        *,
+        explicit_parameters: bool = False,
     ) -> bytes:
         """
         Returns the key serialized as bytes.
@@ -281,6 +284,11 @@ class BrainpoolP256R1(EllipticCurve):
     key_size = 256
 
 
+class BrainpoolP320R1(EllipticCurve):
+    name = "brainpoolP320r1"
+    key_size = 320
+
+
 class BrainpoolP384R1(EllipticCurve):
     name = "brainpoolP384r1"
     key_size = 384
@@ -311,6 +319,7 @@ _CURVE_TYPES: dict[str, EllipticCurve] = {
     "sect409r1": SECT409R1(),
     "sect571r1": SECT571R1(),
     "brainpoolP256r1": BrainpoolP256R1(),
+    "brainpoolP320r1": BrainpoolP320R1(),
     "brainpoolP384r1": BrainpoolP384R1(),
     "brainpoolP512r1": BrainpoolP512R1(),
 }
@@ -321,6 +330,7 @@ class ECDSA(EllipticCurveSignatureAlgorithm):
         self,
         algorithm: asym_utils.Prehashed | hashes.HashAlgorithm,
         deterministic_signing: bool = False,
+        low_s: bool = False,
     ):
         from cryptography.hazmat.backends.openssl.backend import backend
 
@@ -335,6 +345,7 @@ class ECDSA(EllipticCurveSignatureAlgorithm):
             )
         self._algorithm = algorithm
         self._deterministic_signing = deterministic_signing
+        self._low_s = low_s
 
     @property
     def algorithm(
@@ -348,8 +359,17 @@ class ECDSA(EllipticCurveSignatureAlgorithm):
     ) -> bool:
         return self._deterministic_signing
 
+    @property
+    def low_s(
+        self,
+    ) -> bool:
+        return self._low_s
+
 
 generate_private_key = rust_openssl.ec.generate_private_key
+load_public_key_with_explicit_parameters = (
+    rust_openssl.ec.load_public_key_with_explicit_parameters
+)
 
 
 def derive_private_key(
@@ -378,6 +398,7 @@ _OID_TO_CURVE = {
     EllipticCurveOID.SECP384R1: SECP384R1,
     EllipticCurveOID.SECP521R1: SECP521R1,
     EllipticCurveOID.BRAINPOOLP256R1: BrainpoolP256R1,
+    EllipticCurveOID.BRAINPOOLP320R1: BrainpoolP320R1,
     EllipticCurveOID.BRAINPOOLP384R1: BrainpoolP384R1,
     EllipticCurveOID.BRAINPOOLP512R1: BrainpoolP512R1,
     EllipticCurveOID.SECT163K1: SECT163K1,
@@ -0,0 +1,65 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+from __future__ import annotations
+
+import typing
+
+from cryptography import utils
+from cryptography.hazmat.primitives.asymmetric import ec, rsa
+from cryptography.hazmat.primitives.asymmetric.types import (
+    PrivateKeyTypes,
+    PublicKeyTypes,
+)
+
+
+class KeyWeakness(utils.Enum):
+    SMALL_KEY = "Key size is below the minimum"
+    DEPRECATED_CURVE = "Elliptic curve is deprecated"
+    COMPROMISED_KEY = "Key is known to be compromised"
+
+
+# Prime curves smaller than 224 bits and all binary curves, which are
+# deprecated by NIST SP 800-186.
+_DEPRECATED_CURVES: tuple[type[ec.EllipticCurve], ...] = (
+    ec.SECP192R1,
+    ec.SECT163K1,
+    ec.SECT163R2,
+    ec.SECT233K1,
+    ec.SECT233R1,
+    ec.SECT283K1,
+    ec.SECT283R1,
+    ec.SECT409K1,
+    ec.SECT409R1,
+    ec.SECT571K1,
+    ec.SECT571R1,
+)
+
+
+def check_key_health(
+    key: PublicKeyTypes | PrivateKeyTypes,
+    *,
+    min_rsa_key_size: int = 2048,
+    compromised_moduli: typing.Container[int] | None = None,
+) -> list[KeyWeakness]:
+    if not isinstance(min_rsa_key_size, int):
+        raise TypeError("min_rsa_key_size must be an integer")
+
+    if isinstance(key, (rsa.RSAPrivateKey, ec.EllipticCurvePrivateKey)):
+        key = key.public_key()
+
+    weaknesses = []
+    if isinstance(key, rsa.RSAPublicKey):
+        if key.key_size < min_rsa_key_size:
+            weaknesses.append(KeyWeakness.SMALL_KEY)
+        if (
+            compromised_moduli is not None
+            and key.public_numbers().n in compromised_moduli
+        ):
+            weaknesses.append(KeyWeakness.COMPROMISED_KEY)
+    elif isinstance(key, ec.EllipticCurvePublicKey):
+        if isinstance(key.curve, _DEPRECATED_CURVES):
+            weaknesses.append(KeyWeakness.DEPRECATED_CURVE)
+
+    return weaknesses
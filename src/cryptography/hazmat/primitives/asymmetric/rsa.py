@@ -132,6 +132,7 @@ RSAPublicKey.register(rust_openssl.rsa.RSAPublicKey)
 
 RSAPrivateNumbers = rust_openssl.rsa.RSAPrivateNumbers
 RSAPublicNumbers = rust_openssl.rsa.RSAPublicNumbers
+RSABlindingState = rust_openssl.rsa.RSABlindingState
 
 
 def generate_private_key(
@@ -143,6 +144,77 @@ def generate_private_key(
     return rust_openssl.rsa.generate_private_key(public_exponent, key_size)
 
 
+def unsafe_raw_public_operation(
+    public_key: RSAPublicKey, data: bytes
+) -> bytes:
+    if not isinstance(public_key, RSAPublicKey):
+        raise TypeError("public_key must be an RSAPublicKey instance")
+
+    return rust_openssl.rsa.unsafe_raw_public_operation(public_key, data)
+
+
+def unsafe_raw_private_operation(
+    private_key: RSAPrivateKey, data: bytes
+) -> bytes:
+    if not isinstance(private_key, RSAPrivateKey):
+        raise TypeError("private_key must be an RSAPrivateKey instance")
+
+    return rust_openssl.rsa.unsafe_raw_private_operation(private_key, data)
+
+
+def blind(
+    message: bytes,
+    public_key: RSAPublicKey,
+    padding: AsymmetricPadding,
+    algorithm: asym_utils.Prehashed | hashes.HashAlgorithm,
+) -> tuple[bytes, RSABlindingState]:
+    if not isinstance(public_key, RSAPublicKey):
+        raise TypeError("public_key must be an RSAPublicKey instance")
+
+    return rust_openssl.rsa.blind(message, public_key, padding, algorithm)
+
+
+def unblind(blinded_signature: bytes, state: RSABlindingState) -> bytes:
+    if not isinstance(state, RSABlindingState):
+        raise TypeError("state must be an RSABlindingState instance")
+
+    return rust_openssl.rsa.unblind(blinded_signature, state)
+
+
+def decrypt_pkcs1v15_implicit_rejection(
+    private_key: RSAPrivateKey, ciphertext: bytes, synthetic_plaintext: bytes
+) -> bytes:
+    if not isinstance(private_key, RSAPrivateKey):
+        raise TypeError("private_key must be an RSAPrivateKey instance")
+
+    return rust_openssl.rsa.decrypt_pkcs1v15_implicit_rejection(
+        private_key, ciphertext, synthetic_plaintext
+    )
+
+
+def rsa_kem_encapsulate(
+    public_key: RSAPublicKey, algorithm: hashes.HashAlgorithm, length: int
+) -> tuple[bytes, bytes]:
+    if not isinstance(public_key, RSAPublicKey):
+        raise TypeError("public_key must be an RSAPublicKey instance")
+
+    return rust_openssl.rsa.rsa_kem_encapsulate(public_key, algorithm, length)
+
+
+def rsa_kem_decapsulate(
+    private_key: RSAPrivateKey,
+    ciphertext: bytes,
+    algorithm: hashes.HashAlgorithm,
+    length: int,
+) -> bytes:
+    if not isinstance(private_key, RSAPrivateKey):
+        raise TypeError("private_key must be an RSAPrivateKey instance")
+
+    return rust_openssl.rsa.rsa_kem_decapsulate(
+        private_key, ciphertext, algorithm, length
+    )
+
+
 def _verify_rsa_parameters(public_exponent: int, key_size: int) -> None:
     if public_exponent not in (3, 65537):
         raise ValueError(
@@ -8,7 +8,7 @@ import abc
 
 from cryptography.exceptions import UnsupportedAlgorithm, _Reasons
 from cryptography.hazmat.bindings._rust import openssl as rust_openssl
-from cryptography.hazmat.primitives import _serialization
+from cryptography.hazmat.primitives import _serialization, hashes
 
 
 class X25519PublicKey(metaclass=abc.ABCMeta):
@@ -105,5 +105,19 @@ class X25519PrivateKey(metaclass=abc.ABCMeta):
         Performs a key exchange operation using the provided peer's public key.
         """
 
+    @abc.abstractmethod
+    def exchange_hkdf(
+        self,
+        peer_public_key: X25519PublicKey,
+        algorithm: hashes.HashAlgorithm,
+        length: int,
+        salt: bytes | None,
+        info: bytes | None,
+    ) -> bytes:
+        """
+        Performs a key exchange operation using the provided peer's public key
+        and derives a key from the shared secret with HKDF.
+        """
+
 
 X25519PrivateKey.register(rust_openssl.x25519.X25519PrivateKey)
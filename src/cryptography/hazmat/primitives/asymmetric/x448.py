@@ -8,7 +8,7 @@ import abc
 
 from cryptography.exceptions import UnsupportedAlgorithm, _Reasons
 from cryptography.hazmat.bindings._rust import openssl as rust_openssl
-from cryptography.hazmat.primitives import _serialization
+from cryptography.hazmat.primitives import _serialization, hashes
 
 
 class X448PublicKey(metaclass=abc.ABCMeta):
@@ -107,6 +107,20 @@ class X448PrivateKey(metaclass=abc.ABCMeta):
         Performs a key exchange operation using the provided peer's public key.
         """
 
+    @abc.abstractmethod
+    def exchange_hkdf(
+        self,
+        peer_public_key: X448PublicKey,
+        algorithm: hashes.HashAlgorithm,
+        length: int,
+        salt: bytes | None,
+        info: bytes | None,
+    ) -> bytes:
+        """
+        Performs a key exchange operation using the provided peer's public key
+        and derives a key from the shared secret with HKDF.
+        """
+
 
 if hasattr(rust_openssl, "x448"):
     X448PrivateKey.register(rust_openssl.x448.X448PrivateKey)
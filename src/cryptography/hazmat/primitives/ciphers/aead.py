@@ -9,6 +9,7 @@ from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 __all__ = [
     "AESCCM",
     "AESGCM",
+    "AESGCMContext",
     "AESGCMSIV",
     "AESOCB3",
     "AESSIV",
@@ -16,6 +17,7 @@ __all__ = [
 ]
 
 AESGCM = rust_openssl.aead.AESGCM
+AESGCMContext = rust_openssl.aead.AESGCMContext
 ChaCha20Poly1305 = rust_openssl.aead.ChaCha20Poly1305
 AESCCM = rust_openssl.aead.AESCCM
 AESSIV = rust_openssl.aead.AESSIV
@@ -121,6 +121,32 @@ class Cipher(typing.Generic[Mode]):
             self.algorithm, self.mode
         )
 
+    def unsafe_streaming_decryptor(
+        self: Cipher[modes.ModeWithAuthenticationTag],
+    ) -> AEADDecryptionContext:
+        if not isinstance(self.mode, modes.ModeWithAuthenticationTag):
+            raise TypeError(
+                "Streaming AEAD decryption is only supported for modes with "
+                "an authentication tag."
+            )
+
+        return rust_openssl.ciphers.create_decryption_ctx(
+            self.algorithm, self.mode
+        )
+
+    def buffered_decryptor(
+        self: Cipher[modes.ModeWithAuthenticationTag],
+    ) -> AEADDecryptionContext:
+        if not isinstance(self.mode, modes.ModeWithAuthenticationTag):
+            raise TypeError(
+                "Buffered decryption is only supported for modes with an "
+                "authentication tag."
+            )
+
+        return rust_openssl.ciphers.create_decryption_ctx(
+            self.algorithm, self.mode, buffered=True
+        )
+
 
 _CIPHER_TYPE = Cipher[
     typing.Union[
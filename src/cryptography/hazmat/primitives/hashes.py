@@ -30,6 +30,7 @@ __all__ = [
     "Hash",
     "HashAlgorithm",
     "HashContext",
+    "hash_file",
 ]
 
 
@@ -87,6 +88,8 @@ class HashContext(metaclass=abc.ABCMeta):
 Hash = rust_openssl.hashes.Hash
 HashContext.register(Hash)
 
+hash_file = rust_openssl.hashes.hash_file
+
 
 class ExtendableOutputFunction(metaclass=abc.ABCMeta):
     """
@@ -139,25 +142,25 @@ class SHA512(HashAlgorithm):
 class SHA3_224(HashAlgorithm):  # noqa: N801
     name = "sha3-224"
     digest_size = 28
-    block_size = None
+    block_size = 144
 
 
 class SHA3_256(HashAlgorithm):  # noqa: N801
     name = "sha3-256"
     digest_size = 32
-    block_size = None
+    block_size = 136
 
 
 class SHA3_384(HashAlgorithm):  # noqa: N801
     name = "sha3-384"
     digest_size = 48
-    block_size = None
+    block_size = 104
 
 
 class SHA3_512(HashAlgorithm):  # noqa: N801
     name = "sha3-512"
     digest_size = 64
-    block_size = None
+    block_size = 72
 
 
 class SHAKE128(HashAlgorithm, ExtendableOutputFunction):
@@ -99,3 +99,72 @@ class HKDFExpand(KeyDerivationFunction):
     def verify(self, key_material: bytes, expected_key: bytes) -> None:
         if not constant_time.bytes_eq(self.derive(key_material), expected_key):
             raise InvalidKey
+
+
+def hkdf_expand_label(
+    algorithm: hashes.HashAlgorithm,
+    secret: bytes,
+    label: bytes,
+    context: bytes,
+    length: int,
+) -> bytes:
+    """
+    HKDF-Expand-Label as defined in RFC 8446, section 7.1.
+    """
+    utils._check_byteslike("secret", secret)
+    utils._check_bytes("label", label)
+    utils._check_bytes("context", context)
+
+    full_label = b"tls13 " + label
+    if len(full_label) > 255:
+        raise ValueError("label must be at most 249 bytes long.")
+    if len(context) > 255:
+        raise ValueError("context must be at most 255 bytes long.")
+    if not 0 < length <= 0xFFFF:
+        raise ValueError("length must be between 1 and 65535.")
+
+    hkdf_label = (
+        length.to_bytes(2, "big")
+        + len(full_label).to_bytes(1, "big")
+        + full_label
+        + len(context).to_bytes(1, "big")
+        + context
+    )
+    return HKDFExpand(algorithm, length, hkdf_label).derive(secret)
+
+
+def key_confirmation_tag(
+    algorithm: hashes.HashAlgorithm,
+    key_material: bytes,
+    salt: bytes | None,
+    mac_data: bytes,
+) -> bytes:
+    """
+    Computes a key confirmation MacTag as described in NIST SP 800-56C and
+    SP 800-56A, section 5.9, using HKDF to derive the MacKey and HMAC to
+    compute the tag.
+    """
+    utils._check_byteslike("key_material", key_material)
+    utils._check_bytes("mac_data", mac_data)
+
+    mac_key = HKDF(
+        algorithm, algorithm.digest_size, salt, b"key confirmation"
+    ).derive(key_material)
+    h = hmac.HMAC(mac_key, algorithm)
+    h.update(mac_data)
+    return h.finalize()
+
+
+def verify_key_confirmation_tag(
+    algorithm: hashes.HashAlgorithm,
+    key_material: bytes,
+    salt: bytes | None,
+    mac_data: bytes,
+    tag: bytes,
+) -> None:
+    utils._check_bytes("tag", tag)
+    expected_tag = key_confirmation_tag(
+        algorithm, key_material, salt, mac_data
+    )
+    if not constant_time.bytes_eq(expected_tag, tag):
+        raise InvalidKey
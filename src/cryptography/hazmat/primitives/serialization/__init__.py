@@ -15,12 +15,24 @@ from cryptography.hazmat.primitives._serialization import (
     _KeySerializationEncryption,
 )
 from cryptography.hazmat.primitives.serialization.base import (
+    der_to_pem,
     load_der_parameters,
     load_der_private_key,
     load_der_public_key,
     load_pem_parameters,
     load_pem_private_key,
     load_pem_public_key,
+    pem_to_der,
+    reencrypt_der_private_key,
+    reencrypt_pem_private_key,
+)
+from cryptography.hazmat.primitives.serialization.jwk import (
+    JWKPrivateKeyTypes,
+    JWKPublicKeyTypes,
+    load_jwk_private_key,
+    load_jwk_public_key,
+    serialize_jwk_private_key,
+    serialize_jwk_public_key,
 )
 from cryptography.hazmat.primitives.serialization.ssh import (
     SSHCertificate,
@@ -38,6 +50,8 @@ from cryptography.hazmat.primitives.serialization.ssh import (
 __all__ = [
     "BestAvailableEncryption",
     "Encoding",
+    "JWKPrivateKeyTypes",
+    "JWKPublicKeyTypes",
     "KeySerializationEncryption",
     "NoEncryption",
     "ParameterFormat",
@@ -51,13 +65,21 @@ __all__ = [
     "SSHPrivateKeyTypes",
     "SSHPublicKeyTypes",
     "_KeySerializationEncryption",
+    "der_to_pem",
     "load_der_parameters",
     "load_der_private_key",
     "load_der_public_key",
+    "load_jwk_private_key",
+    "load_jwk_public_key",
     "load_pem_parameters",
     "load_pem_private_key",
     "load_pem_public_key",
     "load_ssh_private_key",
     "load_ssh_public_identity",
     "load_ssh_public_key",
+    "pem_to_der",
+    "reencrypt_der_private_key",
+    "reencrypt_pem_private_key",
+    "serialize_jwk_private_key",
+    "serialize_jwk_public_key",
 ]
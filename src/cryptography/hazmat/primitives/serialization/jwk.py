@@ -0,0 +1,314 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+from __future__ import annotations
+
+import base64
+import string
+import typing
+
+from cryptography import utils
+from cryptography.exceptions import UnsupportedAlgorithm, _Reasons
+from cryptography.hazmat.primitives.asymmetric import (
+    ec,
+    ed448,
+    ed25519,
+    rsa,
+    x448,
+    x25519,
+)
+
+JWKPrivateKeyTypes = typing.Union[
+    rsa.RSAPrivateKey,
+    ec.EllipticCurvePrivateKey,
+    ed25519.Ed25519PrivateKey,
+    ed448.Ed448PrivateKey,
+    x25519.X25519PrivateKey,
+    x448.X448PrivateKey,
+]
+
+JWKPublicKeyTypes = typing.Union[
+    rsa.RSAPublicKey,
+    ec.EllipticCurvePublicKey,
+    ed25519.Ed25519PublicKey,
+    ed448.Ed448PublicKey,
+    x25519.X25519PublicKey,
+    x448.X448PublicKey,
+]
+
+# RFC 7518 section 6.2.1.1 and RFC 8812 section 3.1.
+_EC_CURVES: dict[str, type[ec.EllipticCurve]] = {
+    "P-256": ec.SECP256R1,
+    "P-384": ec.SECP384R1,
+    "P-521": ec.SECP521R1,
+    "secp256k1": ec.SECP256K1,
+}
+_EC_CURVE_NAMES = {curve.name: name for name, curve in _EC_CURVES.items()}
+
+# RFC 8037 section 2.
+_OKP_PUBLIC_TYPES: dict[str, typing.Any] = {
+    "Ed25519": ed25519.Ed25519PublicKey,
+    "Ed448": ed448.Ed448PublicKey,
+    "X25519": x25519.X25519PublicKey,
+    "X448": x448.X448PublicKey,
+}
+_OKP_PRIVATE_TYPES: dict[str, typing.Any] = {
+    "Ed25519": ed25519.Ed25519PrivateKey,
+    "Ed448": ed448.Ed448PrivateKey,
+    "X25519": x25519.X25519PrivateKey,
+    "X448": x448.X448PrivateKey,
+}
+
+
+_BASE64URL_ALPHABET = frozenset(string.ascii_letters + string.digits + "-_")
+
+
+def _b64_encode(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode("ascii")
+
+
+def _b64_decode(jwk: typing.Mapping[str, typing.Any], member: str) -> bytes:
+    value = jwk.get(member)
+    if not isinstance(value, str):
+        raise ValueError(f"JWK member {member!r} is missing or not a string")
+    # RFC 7515 section 2: base64url encoding with all trailing '='
+    # characters omitted.
+    if not _BASE64URL_ALPHABET.issuperset(value) or len(value) % 4 == 1:
+        raise ValueError(f"JWK member {member!r} is not valid base64url")
+    return base64.urlsafe_b64decode(value + "=" * (-len(value) % 4))
+
+
+def _encode_int(value: int) -> str:
+    # RFC 7518 section 2: integers use the minimum number of octets, with
+    # zero represented as a single zero octet.
+    return _b64_encode(utils.int_to_bytes(value))
+
+
+def _decode_int(jwk: typing.Mapping[str, typing.Any], member: str) -> int:
+    data = _b64_decode(jwk, member)
+    if not data:
+        raise ValueError(f"JWK member {member!r} is empty")
+    return int.from_bytes(data, "big")
+
+
+def _encode_fixed(value: int, length: int) -> str:
+    return _b64_encode(value.to_bytes(length, "big"))
+
+
+def _decode_fixed(
+    jwk: typing.Mapping[str, typing.Any], member: str, length: int
+) -> int:
+    # EC coordinates and private keys are not minimal, but exactly the
+    # full length of the curve's field (RFC 7518 section 6.2.1.2).
+    data = _b64_decode(jwk, member)
+    if len(data) != length:
+        raise ValueError(
+            f"JWK member {member!r} must be {length} bytes long for this "
+            f"curve"
+        )
+    return int.from_bytes(data, "big")
+
+
+def _get_kty(jwk: typing.Mapping[str, typing.Any]) -> str:
+    if not isinstance(jwk, typing.Mapping):
+        raise TypeError("jwk must be a mapping")
+    kty = jwk.get("kty")
+    if not isinstance(kty, str):
+        raise ValueError("JWK member 'kty' is missing or not a string")
+    if kty not in ("RSA", "EC", "OKP"):
+        raise UnsupportedAlgorithm(
+            f"Unsupported JWK key type: {kty}",
+            _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM,
+        )
+    return kty
+
+
+def _get_crv(jwk: typing.Mapping[str, typing.Any], curves) -> str:
+    crv = jwk.get("crv")
+    if not isinstance(crv, str):
+        raise ValueError("JWK member 'crv' is missing or not a string")
+    if crv not in curves:
+        raise UnsupportedAlgorithm(
+            f"Unsupported JWK curve: {crv}",
+            _Reasons.UNSUPPORTED_ELLIPTIC_CURVE,
+        )
+    return crv
+
+
+def _ec_curve(
+    jwk: typing.Mapping[str, typing.Any],
+) -> tuple[ec.EllipticCurve, int]:
+    curve = _EC_CURVES[_get_crv(jwk, _EC_CURVES)]()
+    return curve, (curve.key_size + 7) // 8
+
+
+def _load_ec_public_numbers(
+    jwk: typing.Mapping[str, typing.Any],
+) -> ec.EllipticCurvePublicNumbers:
+    curve, length = _ec_curve(jwk)
+    return ec.EllipticCurvePublicNumbers(
+        _decode_fixed(jwk, "x", length),
+        _decode_fixed(jwk, "y", length),
+        curve,
+    )
+
+
+def _load_rsa_public_numbers(
+    jwk: typing.Mapping[str, typing.Any],
+) -> rsa.RSAPublicNumbers:
+    return rsa.RSAPublicNumbers(_decode_int(jwk, "e"), _decode_int(jwk, "n"))
+
+
+def load_jwk_public_key(
+    jwk: typing.Mapping[str, typing.Any],
+) -> JWKPublicKeyTypes:
+    kty = _get_kty(jwk)
+    if kty == "RSA":
+        return _load_rsa_public_numbers(jwk).public_key()
+    elif kty == "EC":
+        return _load_ec_public_numbers(jwk).public_key()
+    else:
+        crv = _get_crv(jwk, _OKP_PUBLIC_TYPES)
+        return _OKP_PUBLIC_TYPES[crv].from_public_bytes(
+            _b64_decode(jwk, "x")
+        )
+
+
+def load_jwk_private_key(
+    jwk: typing.Mapping[str, typing.Any],
+) -> JWKPrivateKeyTypes:
+    kty = _get_kty(jwk)
+    if "d" not in jwk:
+        raise ValueError("JWK does not contain a private key")
+
+    if kty == "RSA":
+        if "oth" in jwk:
+            raise UnsupportedAlgorithm(
+                "RSA JWKs with more than two primes are not supported",
+                _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM,
+            )
+        public_numbers = _load_rsa_public_numbers(jwk)
+        d = _decode_int(jwk, "d")
+        # RFC 7518 section 6.3.2: the CRT parameters are either all
+        # present or all absent. Absent ones are recovered from d.
+        crt_members = ("p", "q", "dp", "dq", "qi")
+        present = [member in jwk for member in crt_members]
+        if all(present):
+            p, q, dmp1, dmq1, iqmp = (
+                _decode_int(jwk, member) for member in crt_members
+            )
+        elif not any(present):
+            p, q = rsa.rsa_recover_prime_factors(
+                public_numbers.n, public_numbers.e, d
+            )
+            dmp1 = rsa.rsa_crt_dmp1(d, p)
+            dmq1 = rsa.rsa_crt_dmq1(d, q)
+            iqmp = rsa.rsa_crt_iqmp(p, q)
+        else:
+            raise ValueError(
+                "RSA JWK must contain either all or none of the 'p', 'q', "
+                "'dp', 'dq' and 'qi' members"
+            )
+        return rsa.RSAPrivateNumbers(
+            p, q, d, dmp1, dmq1, iqmp, public_numbers
+        ).private_key()
+    elif kty == "EC":
+        ec_public_numbers = _load_ec_public_numbers(jwk)
+        _, length = _ec_curve(jwk)
+        # `private_key` checks that the private and public values match.
+        return ec.EllipticCurvePrivateNumbers(
+            _decode_fixed(jwk, "d", length), ec_public_numbers
+        ).private_key()
+    else:
+        crv = _get_crv(jwk, _OKP_PRIVATE_TYPES)
+        key = _OKP_PRIVATE_TYPES[crv].from_private_bytes(
+            _b64_decode(jwk, "d")
+        )
+        if key.public_key() != load_jwk_public_key(jwk):
+            raise ValueError("JWK private and public keys do not match")
+        return key
+
+
+def serialize_jwk_public_key(
+    public_key: JWKPublicKeyTypes,
+) -> dict[str, str]:
+    if isinstance(public_key, rsa.RSAPublicKey):
+        numbers = public_key.public_numbers()
+        return {
+            "kty": "RSA",
+            "n": _encode_int(numbers.n),
+            "e": _encode_int(numbers.e),
+        }
+    elif isinstance(public_key, ec.EllipticCurvePublicKey):
+        crv = _EC_CURVE_NAMES.get(public_key.curve.name)
+        if crv is None:
+            raise UnsupportedAlgorithm(
+                f"Unsupported JWK curve: {public_key.curve.name}",
+                _Reasons.UNSUPPORTED_ELLIPTIC_CURVE,
+            )
+        length = (public_key.curve.key_size + 7) // 8
+        ec_numbers = public_key.public_numbers()
+        return {
+            "kty": "EC",
+            "crv": crv,
+            "x": _encode_fixed(ec_numbers.x, length),
+            "y": _encode_fixed(ec_numbers.y, length),
+        }
+    elif isinstance(
+        public_key,
+        (
+            ed25519.Ed25519PublicKey,
+            ed448.Ed448PublicKey,
+            x25519.X25519PublicKey,
+            x448.X448PublicKey,
+        ),
+    ):
+        return {
+            "kty": "OKP",
+            "crv": next(
+                crv
+                for crv, cls in _OKP_PUBLIC_TYPES.items()
+                if isinstance(public_key, cls)
+            ),
+            "x": _b64_encode(public_key.public_bytes_raw()),
+        }
+    else:
+        raise TypeError("Unsupported key type")
+
+
+def serialize_jwk_private_key(
+    private_key: JWKPrivateKeyTypes,
+) -> dict[str, str]:
+    if not isinstance(
+        private_key,
+        (
+            rsa.RSAPrivateKey,
+            ec.EllipticCurvePrivateKey,
+            ed25519.Ed25519PrivateKey,
+            ed448.Ed448PrivateKey,
+            x25519.X25519PrivateKey,
+            x448.X448PrivateKey,
+        ),
+    ):
+        raise TypeError("Unsupported key type")
+
+    jwk = serialize_jwk_public_key(private_key.public_key())
+    if isinstance(private_key, rsa.RSAPrivateKey):
+        numbers = private_key.private_numbers()
+        jwk.update(
+            d=_encode_int(numbers.d),
+            p=_encode_int(numbers.p),
+            q=_encode_int(numbers.q),
+            dp=_encode_int(numbers.dmp1),
+            dq=_encode_int(numbers.dmq1),
+            qi=_encode_int(numbers.iqmp),
+        )
+    elif isinstance(private_key, ec.EllipticCurvePrivateKey):
+        length = (private_key.curve.key_size + 7) // 8
+        jwk["d"] = _encode_fixed(
+            private_key.private_numbers().private_value, length
+        )
+    else:
+        jwk["d"] = _b64_encode(private_key.private_bytes_raw())
+    return jwk
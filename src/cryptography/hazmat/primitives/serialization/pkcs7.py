@@ -23,6 +23,34 @@ load_der_pkcs7_certificates = rust_pkcs7.load_der_pkcs7_certificates
 
 serialize_certificates = rust_pkcs7.serialize_certificates
 
+pkcs7_content_type_der = rust_pkcs7.pkcs7_content_type_der
+
+pkcs7_content_type_pem = rust_pkcs7.pkcs7_content_type_pem
+
+pkcs7_decrypt_der = rust_pkcs7.pkcs7_decrypt_der
+
+pkcs7_decrypt_pem = rust_pkcs7.pkcs7_decrypt_pem
+
+pkcs7_verify_der = rust_pkcs7.pkcs7_verify_der
+
+pkcs7_verify_pem = rust_pkcs7.pkcs7_verify_pem
+
+pkcs7_verify_signers_der = rust_pkcs7.pkcs7_verify_signers_der
+
+pkcs7_verify_signers_pem = rust_pkcs7.pkcs7_verify_signers_pem
+
+pkcs7_verify_countersignature_der = (
+    rust_pkcs7.pkcs7_verify_countersignature_der
+)
+
+pkcs7_verify_countersignature_pem = (
+    rust_pkcs7.pkcs7_verify_countersignature_pem
+)
+
+pkcs7_digest_algorithms_der = rust_pkcs7.pkcs7_digest_algorithms_der
+
+pkcs7_digest_algorithms_pem = rust_pkcs7.pkcs7_digest_algorithms_pem
+
 PKCS7HashTypes = typing.Union[
     hashes.SHA224,
     hashes.SHA256,
@@ -35,6 +63,16 @@ PKCS7PrivateKeyTypes = typing.Union[
 ]
 
 
+class PKCS7ContentTypeOID:
+    DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.1")
+    SIGNED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.2")
+    ENVELOPED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.3")
+    SIGNED_AND_ENVELOPED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.4")
+    DIGESTED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.5")
+    ENCRYPTED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.7.6")
+    AUTH_ENVELOPED_DATA = x509.ObjectIdentifier("1.2.840.113549.1.9.16.1.23")
+
+
 class PKCS7Options(utils.Enum):
     Text = "Add text/plain MIME type"
     Binary = "Don't translate input data into canonical MIME format"
@@ -810,7 +810,9 @@ def _serialize_ssh_private_key(
     f_secrets.put_sshstr(key_type)
     kformat.encode_private(private_key, f_secrets)
     f_secrets.put_sshstr(comment)
-    f_secrets.put_raw(_PADDING[: blklen - (f_secrets.size() % blklen)])
+    # Like ssh-keygen, only pad up to the next block boundary, so that no
+    # padding is added if the secrets are already aligned.
+    f_secrets.put_raw(_PADDING[: -f_secrets.size() % blklen])
 
     # top-level structure
     f_main = _FragList()
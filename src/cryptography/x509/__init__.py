@@ -15,11 +15,16 @@ from cryptography.x509.base import (
     CertificateRevocationListBuilder,
     CertificateSigningRequest,
     CertificateSigningRequestBuilder,
+    CertificateValidityStatus,
+    CertificateView,
     InvalidVersion,
     RevokedCertificate,
     RevokedCertificateBuilder,
     Version,
+    create_self_signed_certificate,
     load_der_x509_certificate,
+    load_der_x509_certificate_view,
+    load_der_x509_certificates,
     load_der_x509_crl,
     load_der_x509_csr,
     load_pem_x509_certificate,
@@ -30,6 +35,8 @@ from cryptography.x509.base import (
 )
 from cryptography.x509.extensions import (
     AccessDescription,
+    Admission,
+    Admissions,
     AuthorityInformationAccess,
     AuthorityKeyIdentifier,
     BasicConstraints,
@@ -41,6 +48,7 @@ from cryptography.x509.extensions import (
     DeltaCRLIndicator,
     DistributionPoint,
     DuplicateExtension,
+    EntrustVersionInfo,
     ExtendedKeyUsage,
     Extension,
     ExtensionNotFound,
@@ -48,21 +56,31 @@ from cryptography.x509.extensions import (
     ExtensionType,
     FreshestCRL,
     GeneralNames,
+    HoldInstructionCode,
     InhibitAnyPolicy,
     InvalidityDate,
     IssuerAlternativeName,
     IssuingDistributionPoint,
     KeyUsage,
+    MSApplicationPolicies,
     MSCertificateTemplate,
+    MSCertificateTemplateName,
     NameConstraints,
+    NamingAuthority,
+    NetscapeCertType,
+    NetscapeComment,
     NoticeReference,
     OCSPAcceptableResponses,
+    OCSPArchiveCutoff,
     OCSPNoCheck,
     OCSPNonce,
     PolicyConstraints,
     PolicyInformation,
+    PolicyMapping,
+    PolicyMappings,
     PrecertificateSignedCertificateTimestamps,
     PrecertPoison,
+    ProfessionInfo,
     ReasonFlags,
     SignedCertificateTimestamps,
     SubjectAlternativeName,
@@ -83,6 +101,7 @@ from cryptography.x509.general_name import (
     RFC822Name,
     UniformResourceIdentifier,
     UnsupportedGeneralNameType,
+    X400Address,
 )
 from cryptography.x509.name import (
     Name,
@@ -174,6 +193,8 @@ __all__ = [
     "OID_CA_ISSUERS",
     "OID_OCSP",
     "AccessDescription",
+    "Admission",
+    "Admissions",
     "Attribute",
     "AttributeNotFound",
     "Attributes",
@@ -191,11 +212,14 @@ __all__ = [
     "CertificateRevocationListBuilder",
     "CertificateSigningRequest",
     "CertificateSigningRequestBuilder",
+    "CertificateValidityStatus",
+    "CertificateView",
     "DNSName",
     "DeltaCRLIndicator",
     "DirectoryName",
     "DistributionPoint",
     "DuplicateExtension",
+    "EntrustVersionInfo",
     "ExtendedKeyUsage",
     "Extension",
     "ExtensionNotFound",
@@ -204,6 +228,7 @@ __all__ = [
     "FreshestCRL",
     "GeneralName",
     "GeneralNames",
+    "HoldInstructionCode",
     "IPAddress",
     "InhibitAnyPolicy",
     "InvalidVersion",
@@ -211,21 +236,30 @@ __all__ = [
     "IssuerAlternativeName",
     "IssuingDistributionPoint",
     "KeyUsage",
+    "MSApplicationPolicies",
     "MSCertificateTemplate",
+    "MSCertificateTemplateName",
     "Name",
     "NameAttribute",
     "NameConstraints",
     "NameOID",
+    "NamingAuthority",
+    "NetscapeCertType",
+    "NetscapeComment",
     "NoticeReference",
     "OCSPAcceptableResponses",
+    "OCSPArchiveCutoff",
     "OCSPNoCheck",
     "OCSPNonce",
     "ObjectIdentifier",
     "OtherName",
     "PolicyConstraints",
     "PolicyInformation",
+    "PolicyMapping",
+    "PolicyMappings",
     "PrecertPoison",
     "PrecertificateSignedCertificateTimestamps",
+    "ProfessionInfo",
     "PublicKeyAlgorithmOID",
     "RFC822Name",
     "ReasonFlags",
@@ -245,8 +279,12 @@ __all__ = [
     "UnsupportedGeneralNameType",
     "UserNotice",
     "Version",
+    "X400Address",
     "certificate_transparency",
+    "create_self_signed_certificate",
     "load_der_x509_certificate",
+    "load_der_x509_certificates",
+    "load_der_x509_certificate_view",
     "load_der_x509_crl",
     "load_der_x509_csr",
     "load_pem_x509_certificate",
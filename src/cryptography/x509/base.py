@@ -29,11 +29,15 @@ from cryptography.hazmat.primitives.asymmetric.types import (
     CertificatePublicKeyTypes,
 )
 from cryptography.x509.extensions import (
+    AuthorityKeyIdentifier,
+    CertificateIssuer,
     Extension,
+    ExtensionNotFound,
     Extensions,
     ExtensionType,
     _make_sequence_methods,
 )
+from cryptography.x509.general_name import GeneralName
 from cryptography.x509.name import Name, _ASN1Type
 from cryptography.x509.oid import ObjectIdentifier
 
@@ -154,6 +158,12 @@ class Version(utils.Enum):
     v3 = 2
 
 
+class CertificateValidityStatus(utils.Enum):
+    VALID = "valid"
+    EXPIRED = "expired"
+    NOT_YET_VALID = "not_yet_valid"
+
+
 class InvalidVersion(Exception):
     def __init__(self, msg: str, parsed_version: int) -> None:
         super().__init__(msg)
@@ -174,6 +184,22 @@ class Certificate(metaclass=abc.ABCMeta):
         Returns certificate serial number
         """
 
+    @property
+    @abc.abstractmethod
+    def serial_number_raw(self) -> tuple[bytes, int]:
+        """
+        Returns the certificate serial number as both the contents of the DER
+        INTEGER and a Python integer.
+        """
+
+    @property
+    @abc.abstractmethod
+    def serial_number_is_negative(self) -> bool:
+        """
+        Returns True if the certificate's serial number is negative, which is
+        disallowed by RFC 5280.
+        """
+
     @property
     @abc.abstractmethod
     def version(self) -> Version:
@@ -194,6 +220,24 @@ class Certificate(metaclass=abc.ABCMeta):
         Returns the ObjectIdentifier of the public key.
         """
 
+    @property
+    @abc.abstractmethod
+    def public_key_algorithm_parameters(
+        self,
+    ) -> None | padding.PSS | padding.OAEP:
+        """
+        Returns the padding parameters the public key is restricted to.
+        """
+
+    @property
+    @abc.abstractmethod
+    def public_key_hash_algorithm(
+        self,
+    ) -> hashes.HashAlgorithm | None:
+        """
+        Returns the hash algorithm the public key is restricted to.
+        """
+
     @property
     @abc.abstractmethod
     def not_valid_before(self) -> datetime.datetime:
@@ -222,6 +266,16 @@ class Certificate(metaclass=abc.ABCMeta):
         Not after time (represented as a non-naive UTC datetime)
         """
 
+    @abc.abstractmethod
+    def validity_status(
+        self, time: datetime.datetime | None = None
+    ) -> tuple[CertificateValidityStatus, int]:
+        """
+        Returns whether the certificate is valid, expired, or not yet valid at
+        the given time (defaulting to now), and the number of seconds until
+        it expires.
+        """
+
     @property
     @abc.abstractmethod
     def issuer(self) -> Name:
@@ -269,6 +323,16 @@ class Certificate(metaclass=abc.ABCMeta):
         Returns an Extensions object.
         """
 
+    @property
+    @abc.abstractmethod
+    def raw_extensions(
+        self,
+    ) -> list[tuple[ObjectIdentifier, bool, bytes, bool]]:
+        """
+        Returns every extension, in order, as a tuple of its OID, criticality,
+        DER encoded value, and whether its OID appears more than once.
+        """
+
     @property
     @abc.abstractmethod
     def signature(self) -> bytes:
@@ -352,6 +416,15 @@ class RevokedCertificate(metaclass=abc.ABCMeta):
         Returns an Extensions object containing a list of Revoked extensions.
         """
 
+    @property
+    @abc.abstractmethod
+    def certificate_issuer(self) -> list[GeneralName] | None:
+        """
+        Returns the issuer of the revoked certificate as a list of general
+        names, accounting for certificateIssuer extensions on previous
+        entries of an indirect CRL.
+        """
+
 
 # Runtime isinstance checks need this since the rust class is not a subclass.
 RevokedCertificate.register(rust_x509.RevokedCertificate)
@@ -390,6 +463,16 @@ class _RawRevokedCertificate(RevokedCertificate):
     def extensions(self) -> Extensions:
         return self._extensions
 
+    @property
+    def certificate_issuer(self) -> list[GeneralName] | None:
+        # Without the containing CRL the issuer is only known if this entry
+        # carries its own certificateIssuer extension.
+        try:
+            ext = self._extensions.get_extension_for_class(CertificateIssuer)
+        except ExtensionNotFound:
+            return None
+        return list(ext.value)
+
 
 class CertificateRevocationList(metaclass=abc.ABCMeta):
     @abc.abstractmethod
@@ -476,6 +559,22 @@ class CertificateRevocationList(metaclass=abc.ABCMeta):
         datetime.
         """
 
+    @property
+    @abc.abstractmethod
+    def crl_number(self) -> int | None:
+        """
+        Returns the value of the CRL's CRLNumber extension, or None if it is
+        absent.
+        """
+
+    @property
+    @abc.abstractmethod
+    def authority_key_identifier(self) -> AuthorityKeyIdentifier | None:
+        """
+        Returns the CRL's AuthorityKeyIdentifier extension, or None if it is
+        absent.
+        """
+
     @property
     @abc.abstractmethod
     def extensions(self) -> Extensions:
@@ -537,6 +636,15 @@ class CertificateRevocationList(metaclass=abc.ABCMeta):
         Verifies signature of revocation list against given public key.
         """
 
+    @abc.abstractmethod
+    def verify_directly_issued_by(self, issuer: Certificate) -> None:
+        """
+        This method verifies that the revocation list issuer name matches
+        the issuer subject name, that the issuer is permitted to sign CRLs,
+        and that the revocation list is signed by the issuer's private key.
+        No other validation is performed.
+        """
+
 
 CertificateRevocationList.register(rust_x509.CertificateRevocationList)
 
@@ -560,6 +668,24 @@ class CertificateSigningRequest(metaclass=abc.ABCMeta):
         Returns the public key
         """
 
+    @property
+    @abc.abstractmethod
+    def public_key_algorithm_parameters(
+        self,
+    ) -> None | padding.PSS | padding.OAEP:
+        """
+        Returns the padding parameters the public key is restricted to.
+        """
+
+    @property
+    @abc.abstractmethod
+    def public_key_hash_algorithm(
+        self,
+    ) -> hashes.HashAlgorithm | None:
+        """
+        Returns the hash algorithm the public key is restricted to.
+        """
+
     @property
     @abc.abstractmethod
     def subject(self) -> Name:
@@ -635,6 +761,13 @@ class CertificateSigningRequest(metaclass=abc.ABCMeta):
         Verifies signature of signing request.
         """
 
+    @abc.abstractmethod
+    def verify_signature(self) -> None:
+        """
+        Verifies signature of signing request, raising InvalidSignature on
+        failure.
+        """
+
     @abc.abstractmethod
     def get_attribute_for_oid(self, oid: ObjectIdentifier) -> bytes:
         """
@@ -649,7 +782,11 @@ CertificateSigningRequest.register(rust_x509.CertificateSigningRequest)
 load_pem_x509_certificate = rust_x509.load_pem_x509_certificate
 load_der_x509_certificate = rust_x509.load_der_x509_certificate
 
+CertificateView = rust_x509.CertificateView
+load_der_x509_certificate_view = rust_x509.load_der_x509_certificate_view
+
 load_pem_x509_certificates = rust_x509.load_pem_x509_certificates
+load_der_x509_certificates = rust_x509.load_der_x509_certificates
 
 load_pem_x509_csr = rust_x509.load_pem_x509_csr
 load_der_x509_csr = rust_x509.load_der_x509_csr
@@ -1224,3 +1361,71 @@ class RevokedCertificateBuilder:
 
 def random_serial_number() -> int:
     return int.from_bytes(os.urandom(20), "big") >> 1
+
+
+def create_self_signed_certificate(
+    subject_name: Name,
+    private_key: CertificateIssuerPrivateKeyTypes,
+    not_valid_before: datetime.datetime,
+    not_valid_after: datetime.datetime,
+    algorithm: _AllowedHashTypes | None,
+    *,
+    extensions: typing.Iterable[Extension[ExtensionType]] = (),
+    serial_number: int | None = None,
+    rsa_padding: padding.PSS | padding.PKCS1v15 | None = None,
+) -> Certificate:
+    """
+    Creates a certificate for private_key's public key, signed by
+    private_key, whose issuer is subject_name.
+    """
+    if not isinstance(subject_name, Name):
+        raise TypeError("Expecting x509.Name object.")
+    if not isinstance(not_valid_before, datetime.datetime):
+        raise TypeError("Expecting datetime object.")
+    if not isinstance(not_valid_after, datetime.datetime):
+        raise TypeError("Expecting datetime object.")
+    not_valid_before = _convert_to_naive_utc_time(not_valid_before)
+    not_valid_after = _convert_to_naive_utc_time(not_valid_after)
+    if not_valid_before < _EARLIEST_UTC_TIME:
+        raise ValueError(
+            "The not valid before date must be on or after 1950 January 1."
+        )
+    if not_valid_after < not_valid_before:
+        raise ValueError(
+            "The not valid after date must be after the not valid before "
+            "date."
+        )
+
+    if serial_number is None:
+        serial_number = random_serial_number()
+    if not isinstance(serial_number, int):
+        raise TypeError("Serial number must be of integral type.")
+    if serial_number <= 0:
+        raise ValueError("The serial number should be positive.")
+    if serial_number.bit_length() >= 160:  # As defined in RFC 5280
+        raise ValueError("The serial number should not be more than 159 bits.")
+
+    extensions = list(extensions)
+    seen: list[Extension[ExtensionType]] = []
+    for extension in extensions:
+        if not isinstance(extension, Extension):
+            raise TypeError("Every extension must be an x509.Extension")
+        _reject_duplicate_extension(extension, seen)
+        seen.append(extension)
+
+    if rsa_padding is not None:
+        if not isinstance(rsa_padding, (padding.PSS, padding.PKCS1v15)):
+            raise TypeError("Padding must be PSS or PKCS1v15")
+        if not isinstance(private_key, rsa.RSAPrivateKey):
+            raise TypeError("Padding is only supported for RSA keys")
+
+    return rust_x509.create_self_signed_x509_certificate(
+        subject_name,
+        private_key,
+        serial_number,
+        not_valid_before,
+        not_valid_after,
+        extensions,
+        algorithm,
+        rsa_padding,
+    )
@@ -6,11 +6,16 @@ from __future__ import annotations
 
 import abc
 import datetime
+import typing
 
 from cryptography import utils
 from cryptography.hazmat.bindings._rust import x509 as rust_x509
+from cryptography.hazmat.primitives.asymmetric.types import PublicKeyTypes
 from cryptography.hazmat.primitives.hashes import HashAlgorithm
 
+if typing.TYPE_CHECKING:
+    from cryptography.x509.base import Certificate
+
 
 class LogEntryType(utils.Enum):
     X509_CERTIFICATE = 0
@@ -93,5 +98,16 @@ class SignedCertificateTimestamp(metaclass=abc.ABCMeta):
         Returns the raw bytes of any extensions for this SCT.
         """
 
+    @abc.abstractmethod
+    def verify(
+        self,
+        log_public_key: PublicKeyTypes,
+        certificate: Certificate,
+        issuer: Certificate,
+    ) -> None:
+        """
+        Verifies the SCT's signature using the log's public key.
+        """
+
 
 SignedCertificateTimestamp.register(rust_x509.Sct)
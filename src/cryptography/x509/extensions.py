@@ -36,6 +36,7 @@ from cryptography.x509.general_name import (
 )
 from cryptography.x509.name import Name, RelativeDistinguishedName
 from cryptography.x509.oid import (
+    CertificatePoliciesOID,
     CRLEntryExtensionOID,
     ExtensionOID,
     ObjectIdentifier,
@@ -994,6 +995,89 @@ class NoticeReference:
         return self._notice_numbers
 
 
+class PolicyMappings(ExtensionType):
+    oid = ExtensionOID.POLICY_MAPPINGS
+
+    def __init__(self, mappings: typing.Iterable[PolicyMapping]) -> None:
+        mappings = list(mappings)
+        if not all(isinstance(x, PolicyMapping) for x in mappings):
+            raise TypeError(
+                "Every item in the mappings list must be a PolicyMapping"
+            )
+
+        self._mappings = mappings
+
+    __len__, __iter__, __getitem__ = _make_sequence_methods("_mappings")
+
+    def __repr__(self) -> str:
+        return f"<PolicyMappings({self._mappings})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, PolicyMappings):
+            return NotImplemented
+
+        return self._mappings == other._mappings
+
+    def __hash__(self) -> int:
+        return hash(tuple(self._mappings))
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class PolicyMapping:
+    def __init__(
+        self,
+        issuer_domain_policy: ObjectIdentifier,
+        subject_domain_policy: ObjectIdentifier,
+    ) -> None:
+        if not isinstance(issuer_domain_policy, ObjectIdentifier) or (
+            not isinstance(subject_domain_policy, ObjectIdentifier)
+        ):
+            raise TypeError(
+                "issuer_domain_policy and subject_domain_policy must be "
+                "ObjectIdentifiers"
+            )
+
+        # RFC 5280 section 4.2.1.5: policies MUST NOT be mapped either to
+        # or from anyPolicy.
+        if CertificatePoliciesOID.ANY_POLICY in (
+            issuer_domain_policy,
+            subject_domain_policy,
+        ):
+            raise ValueError("anyPolicy can't be mapped to or from")
+
+        self._issuer_domain_policy = issuer_domain_policy
+        self._subject_domain_policy = subject_domain_policy
+
+    def __repr__(self) -> str:
+        return (
+            f"<PolicyMapping(issuer_domain_policy="
+            f"{self.issuer_domain_policy}, subject_domain_policy="
+            f"{self.subject_domain_policy})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, PolicyMapping):
+            return NotImplemented
+
+        return (
+            self.issuer_domain_policy == other.issuer_domain_policy
+            and self.subject_domain_policy == other.subject_domain_policy
+        )
+
+    def __hash__(self) -> int:
+        return hash((self.issuer_domain_policy, self.subject_domain_policy))
+
+    @property
+    def issuer_domain_policy(self) -> ObjectIdentifier:
+        return self._issuer_domain_policy
+
+    @property
+    def subject_domain_policy(self) -> ObjectIdentifier:
+        return self._subject_domain_policy
+
+
 class ExtendedKeyUsage(ExtensionType):
     oid = ExtensionOID.EXTENDED_KEY_USAGE
 
@@ -1799,6 +1883,38 @@ class InvalidityDate(ExtensionType):
         return rust_x509.encode_extension_value(self)
 
 
+class HoldInstructionCode(ExtensionType):
+    oid = CRLEntryExtensionOID.HOLD_INSTRUCTION_CODE
+
+    def __init__(self, hold_instruction: ObjectIdentifier) -> None:
+        if not isinstance(hold_instruction, ObjectIdentifier):
+            raise TypeError("hold_instruction must be an ObjectIdentifier")
+
+        self._hold_instruction = hold_instruction
+
+    def __repr__(self) -> str:
+        return (
+            f"<HoldInstructionCode(hold_instruction="
+            f"{self._hold_instruction})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, HoldInstructionCode):
+            return NotImplemented
+
+        return self.hold_instruction == other.hold_instruction
+
+    def __hash__(self) -> int:
+        return hash(self.hold_instruction)
+
+    @property
+    def hold_instruction(self) -> ObjectIdentifier:
+        return self._hold_instruction
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
 class PrecertificateSignedCertificateTimestamps(ExtensionType):
     oid = ExtensionOID.PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS
 
@@ -1943,6 +2059,42 @@ class OCSPAcceptableResponses(ExtensionType):
         return rust_x509.encode_extension_value(self)
 
 
+class OCSPArchiveCutoff(ExtensionType):
+    oid = OCSPExtensionOID.ARCHIVE_CUTOFF
+
+    def __init__(self, archive_cutoff: datetime.datetime) -> None:
+        if not isinstance(archive_cutoff, datetime.datetime):
+            raise TypeError("archive_cutoff must be a datetime.datetime")
+
+        self._archive_cutoff = archive_cutoff
+
+    def __repr__(self) -> str:
+        return f"<OCSPArchiveCutoff(archive_cutoff={self._archive_cutoff})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, OCSPArchiveCutoff):
+            return NotImplemented
+
+        return self.archive_cutoff == other.archive_cutoff
+
+    def __hash__(self) -> int:
+        return hash(self.archive_cutoff)
+
+    @property
+    def archive_cutoff(self) -> datetime.datetime:
+        return self._archive_cutoff
+
+    @property
+    def archive_cutoff_utc(self) -> datetime.datetime:
+        if self._archive_cutoff.tzinfo is None:
+            return self._archive_cutoff.replace(tzinfo=datetime.timezone.utc)
+        else:
+            return self._archive_cutoff.astimezone(tz=datetime.timezone.utc)
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
 class IssuingDistributionPoint(ExtensionType):
     oid = ExtensionOID.ISSUING_DISTRIBUTION_POINT
 
@@ -2162,6 +2314,523 @@ class MSCertificateTemplate(ExtensionType):
         return rust_x509.encode_extension_value(self)
 
 
+class MSCertificateTemplateName(ExtensionType):
+    oid = ExtensionOID.MS_CERTIFICATE_TEMPLATE_NAME
+
+    def __init__(self, name: str) -> None:
+        if not isinstance(name, str):
+            raise TypeError("name must be a str")
+        self._name = name
+
+    @property
+    def name(self) -> str:
+        return self._name
+
+    def __repr__(self) -> str:
+        return f"<MSCertificateTemplateName(name={self.name!r})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, MSCertificateTemplateName):
+            return NotImplemented
+
+        return self.name == other.name
+
+    def __hash__(self) -> int:
+        return hash(self.name)
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class MSApplicationPolicies(ExtensionType):
+    oid = ExtensionOID.MS_APPLICATION_POLICIES
+
+    def __init__(self, policies: typing.Iterable[PolicyInformation]) -> None:
+        policies = list(policies)
+        if not all(isinstance(x, PolicyInformation) for x in policies):
+            raise TypeError(
+                "Every item in the policies list must be a "
+                "PolicyInformation"
+            )
+
+        self._policies = policies
+
+    __len__, __iter__, __getitem__ = _make_sequence_methods("_policies")
+
+    def __repr__(self) -> str:
+        return f"<MSApplicationPolicies({self._policies})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, MSApplicationPolicies):
+            return NotImplemented
+
+        return self._policies == other._policies
+
+    def __hash__(self) -> int:
+        return hash(tuple(self._policies))
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class NamingAuthority:
+    def __init__(
+        self,
+        id: ObjectIdentifier | None,
+        url: str | None,
+        text: str | None,
+    ) -> None:
+        if id is not None and not isinstance(id, ObjectIdentifier):
+            raise TypeError("id must be an ObjectIdentifier or None")
+
+        if url is not None and not isinstance(url, str):
+            raise TypeError("url must be a str or None")
+
+        if text is not None and not isinstance(text, str):
+            raise TypeError("text must be a str or None")
+
+        self._id = id
+        self._url = url
+        self._text = text
+
+    def __repr__(self) -> str:
+        return (
+            f"<NamingAuthority(id={self.id}, url={self.url!r}, "
+            f"text={self.text!r})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, NamingAuthority):
+            return NotImplemented
+
+        return (
+            self.id == other.id
+            and self.url == other.url
+            and self.text == other.text
+        )
+
+    def __hash__(self) -> int:
+        return hash((self.id, self.url, self.text))
+
+    @property
+    def id(self) -> ObjectIdentifier | None:
+        return self._id
+
+    @property
+    def url(self) -> str | None:
+        return self._url
+
+    @property
+    def text(self) -> str | None:
+        return self._text
+
+
+class ProfessionInfo:
+    def __init__(
+        self,
+        naming_authority: NamingAuthority | None,
+        profession_items: typing.Iterable[str],
+        profession_oids: typing.Iterable[ObjectIdentifier] | None,
+        registration_number: str | None,
+        add_profession_info: bytes | None,
+    ) -> None:
+        if naming_authority is not None and not isinstance(
+            naming_authority, NamingAuthority
+        ):
+            raise TypeError("naming_authority must be a NamingAuthority")
+
+        profession_items = list(profession_items)
+        if not all(isinstance(item, str) for item in profession_items):
+            raise TypeError(
+                "Every item in the profession_items list must be a str"
+            )
+
+        if profession_oids is not None:
+            profession_oids = list(profession_oids)
+            if not all(
+                isinstance(oid, ObjectIdentifier) for oid in profession_oids
+            ):
+                raise TypeError(
+                    "Every item in the profession_oids list must be an "
+                    "ObjectIdentifier"
+                )
+
+        if registration_number is not None and not isinstance(
+            registration_number, str
+        ):
+            raise TypeError("registration_number must be a str or None")
+
+        if add_profession_info is not None and not isinstance(
+            add_profession_info, bytes
+        ):
+            raise TypeError("add_profession_info must be bytes or None")
+
+        self._naming_authority = naming_authority
+        self._profession_items = profession_items
+        self._profession_oids = profession_oids
+        self._registration_number = registration_number
+        self._add_profession_info = add_profession_info
+
+    def __repr__(self) -> str:
+        return (
+            f"<ProfessionInfo(naming_authority={self.naming_authority}, "
+            f"profession_items={self.profession_items}, "
+            f"profession_oids={self.profession_oids}, "
+            f"registration_number={self.registration_number!r}, "
+            f"add_profession_info={self.add_profession_info!r})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, ProfessionInfo):
+            return NotImplemented
+
+        return (
+            self.naming_authority == other.naming_authority
+            and self.profession_items == other.profession_items
+            and self.profession_oids == other.profession_oids
+            and self.registration_number == other.registration_number
+            and self.add_profession_info == other.add_profession_info
+        )
+
+    def __hash__(self) -> int:
+        if self.profession_oids is not None:
+            profession_oids: tuple[ObjectIdentifier, ...] | None = tuple(
+                self.profession_oids
+            )
+        else:
+            profession_oids = None
+        return hash(
+            (
+                self.naming_authority,
+                tuple(self.profession_items),
+                profession_oids,
+                self.registration_number,
+                self.add_profession_info,
+            )
+        )
+
+    @property
+    def naming_authority(self) -> NamingAuthority | None:
+        return self._naming_authority
+
+    @property
+    def profession_items(self) -> list[str]:
+        return self._profession_items
+
+    @property
+    def profession_oids(self) -> list[ObjectIdentifier] | None:
+        return self._profession_oids
+
+    @property
+    def registration_number(self) -> str | None:
+        return self._registration_number
+
+    @property
+    def add_profession_info(self) -> bytes | None:
+        return self._add_profession_info
+
+
+class Admission:
+    def __init__(
+        self,
+        admission_authority: GeneralName | None,
+        naming_authority: NamingAuthority | None,
+        profession_infos: typing.Iterable[ProfessionInfo],
+    ) -> None:
+        if admission_authority is not None and not isinstance(
+            admission_authority, GeneralName
+        ):
+            raise TypeError("admission_authority must be a GeneralName")
+
+        if naming_authority is not None and not isinstance(
+            naming_authority, NamingAuthority
+        ):
+            raise TypeError("naming_authority must be a NamingAuthority")
+
+        profession_infos = list(profession_infos)
+        if not all(
+            isinstance(info, ProfessionInfo) for info in profession_infos
+        ):
+            raise TypeError(
+                "Every item in the profession_infos list must be a "
+                "ProfessionInfo"
+            )
+
+        self._admission_authority = admission_authority
+        self._naming_authority = naming_authority
+        self._profession_infos = profession_infos
+
+    def __repr__(self) -> str:
+        return (
+            f"<Admission(admission_authority={self.admission_authority}, "
+            f"naming_authority={self.naming_authority}, "
+            f"profession_infos={self.profession_infos})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, Admission):
+            return NotImplemented
+
+        return (
+            self.admission_authority == other.admission_authority
+            and self.naming_authority == other.naming_authority
+            and self.profession_infos == other.profession_infos
+        )
+
+    def __hash__(self) -> int:
+        return hash(
+            (
+                self.admission_authority,
+                self.naming_authority,
+                tuple(self.profession_infos),
+            )
+        )
+
+    @property
+    def admission_authority(self) -> GeneralName | None:
+        return self._admission_authority
+
+    @property
+    def naming_authority(self) -> NamingAuthority | None:
+        return self._naming_authority
+
+    @property
+    def profession_infos(self) -> list[ProfessionInfo]:
+        return self._profession_infos
+
+
+class Admissions(ExtensionType):
+    oid = ExtensionOID.ADMISSIONS
+
+    def __init__(
+        self,
+        authority: GeneralName | None,
+        admissions: typing.Iterable[Admission],
+    ) -> None:
+        if authority is not None and not isinstance(authority, GeneralName):
+            raise TypeError("authority must be a GeneralName")
+
+        admissions = list(admissions)
+        if not all(isinstance(x, Admission) for x in admissions):
+            raise TypeError(
+                "Every item in the admissions list must be an Admission"
+            )
+
+        self._authority = authority
+        self._admissions = admissions
+
+    __len__, __iter__, __getitem__ = _make_sequence_methods("_admissions")
+
+    def __repr__(self) -> str:
+        return (
+            f"<Admissions(authority={self._authority}, "
+            f"admissions={self._admissions})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, Admissions):
+            return NotImplemented
+
+        return (
+            self.authority == other.authority
+            and self._admissions == other._admissions
+        )
+
+    def __hash__(self) -> int:
+        return hash((self.authority, tuple(self._admissions)))
+
+    @property
+    def authority(self) -> GeneralName | None:
+        return self._authority
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class NetscapeCertType(ExtensionType):
+    oid = ExtensionOID.NETSCAPE_CERT_TYPE
+
+    def __init__(
+        self,
+        ssl_client: bool,
+        ssl_server: bool,
+        smime: bool,
+        object_signing: bool,
+        ssl_ca: bool,
+        smime_ca: bool,
+        object_signing_ca: bool,
+    ) -> None:
+        self._ssl_client = ssl_client
+        self._ssl_server = ssl_server
+        self._smime = smime
+        self._object_signing = object_signing
+        self._ssl_ca = ssl_ca
+        self._smime_ca = smime_ca
+        self._object_signing_ca = object_signing_ca
+
+    @property
+    def ssl_client(self) -> bool:
+        return self._ssl_client
+
+    @property
+    def ssl_server(self) -> bool:
+        return self._ssl_server
+
+    @property
+    def smime(self) -> bool:
+        return self._smime
+
+    @property
+    def object_signing(self) -> bool:
+        return self._object_signing
+
+    @property
+    def ssl_ca(self) -> bool:
+        return self._ssl_ca
+
+    @property
+    def smime_ca(self) -> bool:
+        return self._smime_ca
+
+    @property
+    def object_signing_ca(self) -> bool:
+        return self._object_signing_ca
+
+    def __repr__(self) -> str:
+        return (
+            f"<NetscapeCertType(ssl_client={self.ssl_client}, "
+            f"ssl_server={self.ssl_server}, smime={self.smime}, "
+            f"object_signing={self.object_signing}, ssl_ca={self.ssl_ca}, "
+            f"smime_ca={self.smime_ca}, "
+            f"object_signing_ca={self.object_signing_ca})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, NetscapeCertType):
+            return NotImplemented
+
+        return (
+            self.ssl_client == other.ssl_client
+            and self.ssl_server == other.ssl_server
+            and self.smime == other.smime
+            and self.object_signing == other.object_signing
+            and self.ssl_ca == other.ssl_ca
+            and self.smime_ca == other.smime_ca
+            and self.object_signing_ca == other.object_signing_ca
+        )
+
+    def __hash__(self) -> int:
+        return hash(
+            (
+                self.ssl_client,
+                self.ssl_server,
+                self.smime,
+                self.object_signing,
+                self.ssl_ca,
+                self.smime_ca,
+                self.object_signing_ca,
+            )
+        )
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class NetscapeComment(ExtensionType):
+    oid = ExtensionOID.NETSCAPE_COMMENT
+
+    def __init__(self, comment: str) -> None:
+        if not isinstance(comment, str):
+            raise TypeError("comment must be a str")
+        self._comment = comment
+
+    @property
+    def comment(self) -> str:
+        return self._comment
+
+    def __repr__(self) -> str:
+        return f"<NetscapeComment(comment={self.comment!r})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, NetscapeComment):
+            return NotImplemented
+
+        return self.comment == other.comment
+
+    def __hash__(self) -> int:
+        return hash(self.comment)
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
+class EntrustVersionInfo(ExtensionType):
+    oid = ExtensionOID.ENTRUST_VERSION_INFO
+
+    def __init__(
+        self,
+        version: str,
+        key_update_allowed: bool,
+        new_extensions: bool,
+        pkix_certificate: bool,
+    ) -> None:
+        if not isinstance(version, str):
+            raise TypeError("version must be a str")
+
+        self._version = version
+        self._key_update_allowed = key_update_allowed
+        self._new_extensions = new_extensions
+        self._pkix_certificate = pkix_certificate
+
+    @property
+    def version(self) -> str:
+        return self._version
+
+    @property
+    def key_update_allowed(self) -> bool:
+        return self._key_update_allowed
+
+    @property
+    def new_extensions(self) -> bool:
+        return self._new_extensions
+
+    @property
+    def pkix_certificate(self) -> bool:
+        return self._pkix_certificate
+
+    def __repr__(self) -> str:
+        return (
+            f"<EntrustVersionInfo(version={self.version!r}, "
+            f"key_update_allowed={self.key_update_allowed}, "
+            f"new_extensions={self.new_extensions}, "
+            f"pkix_certificate={self.pkix_certificate})>"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, EntrustVersionInfo):
+            return NotImplemented
+
+        return (
+            self.version == other.version
+            and self.key_update_allowed == other.key_update_allowed
+            and self.new_extensions == other.new_extensions
+            and self.pkix_certificate == other.pkix_certificate
+        )
+
+    def __hash__(self) -> int:
+        return hash(
+            (
+                self.version,
+                self.key_update_allowed,
+                self.new_extensions,
+                self.pkix_certificate,
+            )
+        )
+
+    def public_bytes(self) -> bytes:
+        return rust_x509.encode_extension_value(self)
+
+
 class UnrecognizedExtension(ExtensionType):
     def __init__(self, oid: ObjectIdentifier, value: bytes) -> None:
         if not isinstance(oid, ObjectIdentifier):
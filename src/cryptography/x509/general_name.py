@@ -279,3 +279,27 @@ class OtherName(GeneralName):
 
     def __hash__(self) -> int:
         return hash((self.type_id, self.value))
+
+
+class X400Address(GeneralName):
+    def __init__(self, value: bytes) -> None:
+        if not isinstance(value, bytes):
+            raise TypeError("value must be a binary string")
+
+        self._value = value
+
+    @property
+    def value(self) -> bytes:
+        return self._value
+
+    def __repr__(self) -> str:
+        return f"<X400Address(value={self.value!r})>"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, X400Address):
+            return NotImplemented
+
+        return self.value == other.value
+
+    def __hash__(self) -> int:
+        return hash(self.value)
@@ -6,6 +6,7 @@ from __future__ import annotations
 
 import abc
 import datetime
+import os
 import typing
 
 from cryptography import utils, x509
@@ -170,6 +171,42 @@ class OCSPRequest(metaclass=abc.ABCMeta):
         """
 
 
+class OCSPCertID(metaclass=abc.ABCMeta):
+    @property
+    @abc.abstractmethod
+    def issuer_key_hash(self) -> bytes:
+        """
+        The hash of the issuer public key
+        """
+
+    @property
+    @abc.abstractmethod
+    def issuer_name_hash(self) -> bytes:
+        """
+        The hash of the issuer name
+        """
+
+    @property
+    @abc.abstractmethod
+    def hash_algorithm(self) -> hashes.HashAlgorithm:
+        """
+        The hash algorithm used in the issuer name and key hashes
+        """
+
+    @property
+    @abc.abstractmethod
+    def serial_number(self) -> int:
+        """
+        The serial number of the cert the CertID identifies
+        """
+
+    @abc.abstractmethod
+    def public_bytes(self, encoding: serialization.Encoding) -> bytes:
+        """
+        Serializes the CertID to DER
+        """
+
+
 class OCSPSingleResponse(metaclass=abc.ABCMeta):
     @property
     @abc.abstractmethod
@@ -461,10 +498,24 @@ class OCSPResponse(metaclass=abc.ABCMeta):
 
 
 OCSPRequest.register(ocsp.OCSPRequest)
+OCSPCertID.register(ocsp.OCSPCertID)
 OCSPResponse.register(ocsp.OCSPResponse)
 OCSPSingleResponse.register(ocsp.OCSPSingleResponse)
 
 
+_MAX_NONCE_LENGTH = 32
+
+
+def _check_nonce_length(extval: x509.ExtensionType) -> None:
+    # RFC 8954 section 2.1
+    if isinstance(extval, x509.OCSPNonce) and not (
+        1 <= len(extval.nonce) <= _MAX_NONCE_LENGTH
+    ):
+        raise ValueError(
+            f"OCSP nonce must be between 1 and {_MAX_NONCE_LENGTH} bytes long"
+        )
+
+
 class OCSPRequestBuilder:
     def __init__(
         self,
@@ -535,6 +586,8 @@ class OCSPRequestBuilder:
         if not isinstance(extval, x509.ExtensionType):
             raise TypeError("extension must be an ExtensionType")
 
+        _check_nonce_length(extval)
+
         extension = x509.Extension(extval.oid, critical, extval)
         _reject_duplicate_extension(extension, self._extensions)
 
@@ -542,6 +595,12 @@ class OCSPRequestBuilder:
             self._request, self._request_hash, [*self._extensions, extension]
         )
 
+    def add_nonce(self, nonce: bytes | None = None) -> OCSPRequestBuilder:
+        if nonce is None:
+            nonce = os.urandom(_MAX_NONCE_LENGTH)
+
+        return self.add_extension(x509.OCSPNonce(nonce), critical=False)
+
     def build(self) -> OCSPRequest:
         if self._request is None and self._request_hash is None:
             raise ValueError("You must add a certificate before building")
@@ -636,6 +695,8 @@ class OCSPResponseBuilder:
         if not isinstance(extval, x509.ExtensionType):
             raise TypeError("extension must be an ExtensionType")
 
+        _check_nonce_length(extval)
+
         extension = x509.Extension(extval.oid, critical, extval)
         _reject_duplicate_extension(extension, self._extensions)
 
@@ -674,5 +735,19 @@ class OCSPResponseBuilder:
         return ocsp.create_ocsp_response(response_status, None, None, None)
 
 
+def compute_cert_id(
+    cert: x509.Certificate,
+    issuer: x509.Certificate,
+    algorithm: hashes.HashAlgorithm,
+) -> OCSPCertID:
+    _verify_algorithm(algorithm)
+    if not isinstance(cert, x509.Certificate) or not isinstance(
+        issuer, x509.Certificate
+    ):
+        raise TypeError("cert and issuer must be a Certificate")
+
+    return ocsp.create_ocsp_cert_id(cert, issuer, algorithm)
+
+
 load_der_ocsp_request = ocsp.load_der_ocsp_request
 load_der_ocsp_response = ocsp.load_der_ocsp_response
@@ -11,12 +11,15 @@ from cryptography.hazmat._oid import (
     CRLEntryExtensionOID,
     ExtendedKeyUsageOID,
     ExtensionOID,
+    HoldInstructionOID,
     NameOID,
     ObjectIdentifier,
     OCSPExtensionOID,
     PublicKeyAlgorithmOID,
     SignatureAlgorithmOID,
     SubjectInformationAccessOID,
+    name_to_oid,
+    oid_to_name,
 )
 
 __all__ = [
@@ -26,10 +29,13 @@ __all__ = [
     "CertificatePoliciesOID",
     "ExtendedKeyUsageOID",
     "ExtensionOID",
+    "HoldInstructionOID",
     "NameOID",
     "OCSPExtensionOID",
     "ObjectIdentifier",
     "PublicKeyAlgorithmOID",
     "SignatureAlgorithmOID",
     "SubjectInformationAccessOID",
+    "name_to_oid",
+    "oid_to_name",
 ]
@@ -0,0 +1,47 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+from __future__ import annotations
+
+import base64
+import typing
+
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric.types import (
+    CertificatePublicKeyTypes,
+)
+from cryptography.x509.base import Certificate
+
+__all__ = [
+    "matches_pins",
+    "spki_pin",
+]
+
+
+def spki_pin(
+    key_or_certificate: Certificate | CertificatePublicKeyTypes,
+) -> str:
+    if isinstance(key_or_certificate, Certificate):
+        key_or_certificate = key_or_certificate.public_key()
+
+    try:
+        spki = key_or_certificate.public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+    except AttributeError:
+        raise TypeError(
+            "key_or_certificate must be a Certificate or a public key"
+        )
+
+    h = hashes.Hash(hashes.SHA256())
+    h.update(spki)
+    return base64.b64encode(h.finalize()).decode("ascii")
+
+
+def matches_pins(
+    chain: typing.Iterable[Certificate], pins: typing.Iterable[str]
+) -> bool:
+    pin_set = set(pins)
+    return any(spki_pin(cert) in pin_set for cert in chain)
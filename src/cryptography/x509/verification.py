@@ -15,14 +15,20 @@ __all__ = [
     "ServerVerifier",
     "Store",
     "Subject",
+    "TrustAnchor",
     "VerificationError",
     "VerifiedClient",
+    "authority_key_identifier_matches",
+    "chain_pem",
 ]
 
 Store = rust_x509.Store
 Subject = typing.Union[DNSName, IPAddress]
+TrustAnchor = rust_x509.TrustAnchor
 VerifiedClient = rust_x509.VerifiedClient
 ClientVerifier = rust_x509.ClientVerifier
 ServerVerifier = rust_x509.ServerVerifier
 PolicyBuilder = rust_x509.PolicyBuilder
 VerificationError = rust_x509.VerificationError
+authority_key_identifier_matches = rust_x509.authority_key_identifier_matches
+chain_pem = rust_x509.chain_pem
@@ -6,6 +6,34 @@ use cryptography_x509::common::{AlgorithmParameters, EcParameters, SubjectPublic
 
 use crate::{KeyParsingError, KeyParsingResult};
 
+/// Compares every parameter defining two EC groups: the field and curve
+/// coefficients, the generator, its order, and the cofactor.
+fn ec_groups_match(
+    a: &openssl::ec::EcGroupRef,
+    b: &openssl::ec::EcGroupRef,
+    bn_ctx: &mut openssl::bn::BigNumContextRef,
+) -> KeyParsingResult<bool> {
+    let mut a_values = vec![];
+    let mut b_values = vec![];
+    for (group, values) in [(a, &mut a_values), (b, &mut b_values)] {
+        let mut p = openssl::bn::BigNum::new()?;
+        let mut curve_a = openssl::bn::BigNum::new()?;
+        let mut curve_b = openssl::bn::BigNum::new()?;
+        group.components_gfp(&mut p, &mut curve_a, &mut curve_b, bn_ctx)?;
+        let mut x = openssl::bn::BigNum::new()?;
+        let mut y = openssl::bn::BigNum::new()?;
+        group
+            .generator()
+            .affine_coordinates(group, &mut x, &mut y, bn_ctx)?;
+        let mut order = openssl::bn::BigNum::new()?;
+        group.order(&mut order, bn_ctx)?;
+        let mut cofactor = openssl::bn::BigNum::new()?;
+        group.cofactor(&mut cofactor, bn_ctx)?;
+        values.extend([p, curve_a, curve_b, x, y, order, cofactor]);
+    }
+    Ok(a_values == b_values)
+}
+
 pub fn parse_public_key(
     data: &[u8],
 ) -> KeyParsingResult<openssl::pkey::PKey<openssl::pkey::Public>> {
@@ -41,6 +69,10 @@ pub fn parse_public_key(
                         openssl::nid::Nid::BRAINPOOL_P256R1
                     }
                     #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
+                    cryptography_x509::oid::EC_BRAINPOOLP320R1 => {
+                        openssl::nid::Nid::BRAINPOOL_P320R1
+                    }
+                    #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
                     cryptography_x509::oid::EC_BRAINPOOLP384R1 => {
                         openssl::nid::Nid::BRAINPOOL_P384R1
                     }
@@ -140,3 +172,42 @@ pub fn parse_public_key(
         )),
     }
 }
+
+/// Parses an EC public key, accepting explicit curve parameters as long as
+/// they describe one of the supported named curves, in which case the key is
+/// re-created on that curve. This keeps attacker-chosen curves out of any
+/// later operations on the key. Keys on a named curve are parsed as by
+/// `parse_public_key`, and other key types are rejected.
+pub fn parse_ec_public_key_with_explicit_parameters(
+    data: &[u8],
+) -> KeyParsingResult<openssl::pkey::PKey<openssl::pkey::Public>> {
+    let k = asn1::parse_single::<SubjectPublicKeyInfo<'_>>(data)?;
+
+    match k.algorithm.params {
+        AlgorithmParameters::Ec(EcParameters::SpecifiedCurve(_)) => {
+            let explicit_key = openssl::pkey::PKey::public_key_from_der(data)
+                .map_err(|_| KeyParsingError::InvalidKey)?
+                .ec_key()?;
+            let explicit_group = explicit_key.group();
+            let curve_nid = explicit_group
+                .curve_name()
+                .ok_or(KeyParsingError::ExplicitCurveUnsupported)?;
+            let group = openssl::ec::EcGroup::from_curve_name(curve_nid)?;
+
+            let mut bn_ctx = openssl::bn::BigNumContext::new()?;
+            if !ec_groups_match(explicit_group, &group, &mut bn_ctx)? {
+                return Err(KeyParsingError::ExplicitCurveUnsupported);
+            }
+            let ec_point = openssl::ec::EcPoint::from_bytes(
+                &group,
+                k.subject_public_key.as_bytes(),
+                &mut bn_ctx,
+            )
+            .map_err(|_| KeyParsingError::InvalidKey)?;
+            let ec_key = openssl::ec::EcKey::from_public_key(&group, &ec_point)?;
+            Ok(openssl::pkey::PKey::from_ec_key(ec_key)?)
+        }
+        AlgorithmParameters::Ec(_) => parse_public_key(data),
+        _ => Err(KeyParsingError::InvalidKey),
+    }
+}
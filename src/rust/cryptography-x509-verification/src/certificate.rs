@@ -5,17 +5,91 @@
 //! Validation-specific certificate functionality.
 
 use cryptography_x509::certificate::Certificate;
+use cryptography_x509::extensions::AuthorityKeyIdentifier;
+use cryptography_x509::name::GeneralName;
+use cryptography_x509::oid::{AUTHORITY_KEY_IDENTIFIER_OID, SUBJECT_KEY_IDENTIFIER_OID};
+
+use crate::name::names_match;
 
 pub(crate) fn cert_is_self_issued(cert: &Certificate<'_>) -> bool {
-    cert.issuer() == cert.subject()
+    names_match(cert.issuer(), cert.subject())
+}
+
+/// Returns true if `a` and `b` have the same subject and public key, i.e.
+/// they identify the same CA even if they were issued by different parties.
+pub(crate) fn same_subject_and_key(a: &Certificate<'_>, b: &Certificate<'_>) -> bool {
+    names_match(a.subject(), b.subject()) && a.tbs_cert.spki == b.tbs_cert.spki
+}
+
+/// Returns `cert`'s AuthorityKeyIdentifier, if present and well-formed.
+/// Malformed extensions are rejected by the policy, so they're treated as
+/// absent here.
+pub(crate) fn authority_key_identifier<'a>(
+    cert: &Certificate<'a>,
+) -> Option<AuthorityKeyIdentifier<'a>> {
+    cert.extensions()
+        .ok()?
+        .get_extension(&AUTHORITY_KEY_IDENTIFIER_OID)?
+        .value::<AuthorityKeyIdentifier<'a>>()
+        .ok()
+}
+
+/// Returns `cert`'s SubjectKeyIdentifier, if present and well-formed.
+pub(crate) fn subject_key_identifier<'a>(cert: &Certificate<'a>) -> Option<&'a [u8]> {
+    cert.extensions()
+        .ok()?
+        .get_extension(&SUBJECT_KEY_IDENTIFIER_OID)?
+        .value::<&[u8]>()
+        .ok()
+}
+
+/// Returns true if `issuer` is consistent with `aki`, the
+/// AuthorityKeyIdentifier of a certificate it may have issued.
+///
+/// Every field present in `aki` must match: the `keyIdentifier` against the
+/// issuer's SubjectKeyIdentifier, and the `authorityCertIssuer` and
+/// `authorityCertSerialNumber` against the issuer's own issuer name and
+/// serial number. An AuthorityKeyIdentifier with no fields matches any
+/// issuer. This doesn't check the issuer's signature.
+pub fn authority_key_identifier_matches(
+    aki: &AuthorityKeyIdentifier<'_>,
+    issuer: &Certificate<'_>,
+) -> bool {
+    if let Some(key_identifier) = aki.key_identifier {
+        if subject_key_identifier(issuer) != Some(key_identifier) {
+            return false;
+        }
+    }
+
+    if let Some(names) = &aki.authority_cert_issuer {
+        let mut names = names.unwrap_read().clone();
+        if !names.any(|name| match name {
+            GeneralName::DirectoryName(name) => names_match(name.unwrap_read(), issuer.issuer()),
+            _ => false,
+        }) {
+            return false;
+        }
+    }
+
+    if let Some(serial) = aki.authority_cert_serial_number {
+        if serial.as_bytes() != issuer.tbs_cert.serial.as_bytes() {
+            return false;
+        }
+    }
+
+    true
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use super::cert_is_self_issued;
+    use super::{
+        authority_key_identifier, authority_key_identifier_matches, cert_is_self_issued,
+        same_subject_and_key, subject_key_identifier,
+    };
     use crate::certificate::Certificate;
     use crate::ops::tests::{cert, v1_cert_pem};
     use crate::ops::CryptoOps;
+    use cryptography_x509::extensions::AuthorityKeyIdentifier;
 
     #[test]
     fn test_certificate_v1() {
@@ -23,6 +97,8 @@ pub(crate) mod tests {
         let cert = cert(&cert_pem);
 
         assert!(!cert_is_self_issued(&cert));
+        assert!(authority_key_identifier(&cert).is_none());
+        assert!(subject_key_identifier(&cert).is_none());
     }
 
     fn ca_pem() -> pem::Pem {
@@ -50,6 +126,43 @@ Xw4nMqk=
         assert!(cert_is_self_issued(&cert));
     }
 
+    #[test]
+    fn test_same_subject_and_key() {
+        let ca_pem = ca_pem();
+        let ca = cert(&ca_pem);
+        let v1_pem = v1_cert_pem();
+        let v1 = cert(&v1_pem);
+
+        assert!(same_subject_and_key(&ca, &ca));
+        assert!(!same_subject_and_key(&ca, &v1));
+    }
+
+    #[test]
+    fn test_authority_key_identifier_matches() {
+        // The CA has serial number 777 and no SubjectKeyIdentifier.
+        let ca_pem = ca_pem();
+        let ca = cert(&ca_pem);
+        let aki = |key_identifier, serial| AuthorityKeyIdentifier {
+            key_identifier,
+            authority_cert_issuer: None,
+            authority_cert_serial_number: serial,
+        };
+
+        assert!(authority_key_identifier_matches(&aki(None, None), &ca));
+        assert!(authority_key_identifier_matches(
+            &aki(None, Some(asn1::BigUint::new(&[0x03, 0x09]).unwrap())),
+            &ca
+        ));
+        assert!(!authority_key_identifier_matches(
+            &aki(None, Some(asn1::BigUint::new(&[0x03, 0x0a]).unwrap())),
+            &ca
+        ));
+        assert!(!authority_key_identifier_matches(
+            &aki(Some(b"\x01\x02"), None),
+            &ca
+        ));
+    }
+
     pub(crate) struct PublicKeyErrorOps {}
     impl CryptoOps for PublicKeyErrorOps {
         type Key = ();
@@ -0,0 +1,207 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+//! Certificate policy processing, per RFC 5280 6.1.
+//!
+//! This tracks the set of valid policies rather than a full policy tree,
+//! which is equivalent when the initial policy set is `anyPolicy` and no
+//! policy mappings are involved. Policy mappings aren't supported: a
+//! critical policyMappings extension is rejected by the extension policy,
+//! and a path with a non-critical one is rejected if it requires an explicit
+//! policy, since the set of valid policies can't be computed for it.
+
+use asn1::ObjectIdentifier;
+use cryptography_x509::extensions::{Extensions, PolicyConstraints, PolicyInformation};
+use cryptography_x509::oid::{
+    ANY_POLICY_OID, CERTIFICATE_POLICIES_OID, INHIBIT_ANY_POLICY_OID, POLICY_CONSTRAINTS_OID,
+    POLICY_MAPPINGS_OID,
+};
+
+use crate::certificate::cert_is_self_issued;
+use crate::ops::{CryptoOps, VerificationCertificate};
+use crate::ValidationError;
+
+/// Checks the certificate policies of `chain`, which runs from the leaf to
+/// the trust anchor (inclusive). The trust anchor's own extensions are not
+/// part of the path being validated, so they're ignored.
+///
+/// The check fails only if some certificate's policy constraints require an
+/// explicit policy and no policy remains valid for the whole path, or it
+/// can't be determined because the path has policy mappings. Skip
+/// counts from inhibitAnyPolicy and requireExplicitPolicy are decremented
+/// by each following certificate that isn't self-issued.
+pub(crate) fn check_certificate_policies<B: CryptoOps>(
+    chain: &[&VerificationCertificate<'_, B>],
+) -> Result<(), ValidationError> {
+    // Everything but the trust anchor, from the anchor's subject down to
+    // the leaf.
+    let path = match chain.split_last() {
+        Some((_, path)) => path,
+        None => return Ok(()),
+    };
+    let n = path.len() as u64;
+
+    // `None` corresponds to RFC 5280's NULL valid_policy_tree.
+    let mut valid_policies = Some(vec![ANY_POLICY_OID]);
+    let mut explicit_policy = n + 1;
+    let mut inhibit_any_policy = n + 1;
+    // Mappings only affect the certificates after the one that has them, so
+    // this is set once that certificate has been processed.
+    let mut has_mappings = false;
+
+    for (i, cert) in path.iter().rev().enumerate() {
+        let cert = cert.certificate();
+        let is_leaf = i as u64 == n - 1;
+        let extensions = cert.extensions()?;
+
+        // 6.1.3 (d) and (e): narrow the valid policies to the ones this
+        // certificate asserts.
+        valid_policies = match (
+            valid_policies,
+            extensions.get_extension(&CERTIFICATE_POLICIES_OID),
+        ) {
+            (Some(valid), Some(ext)) => {
+                let asserted = ext
+                    .value::<asn1::SequenceOf<'_, PolicyInformation<'_>>>()?
+                    .map(|p| p.policy_identifier)
+                    .collect::<Vec<_>>();
+                let any_policy_honored = asserted.contains(&ANY_POLICY_OID)
+                    && (inhibit_any_policy > 0 || (!is_leaf && cert_is_self_issued(cert)));
+                narrow(valid, &asserted, any_policy_honored)
+            }
+            _ => None,
+        };
+
+        // 6.1.3 (f)
+        if explicit_policy == 0 {
+            check_valid_policies(&valid_policies, has_mappings)?;
+        }
+
+        if is_leaf {
+            // 6.1.5 (a) and (b)
+            explicit_policy = explicit_policy.saturating_sub(1);
+            if require_explicit_policy(&extensions)? == Some(0) {
+                explicit_policy = 0;
+            }
+        } else {
+            // 6.1.4 (a) and (b) aren't implemented, see above.
+            if extensions.get_extension(&POLICY_MAPPINGS_OID).is_some() {
+                has_mappings = true;
+            }
+            // 6.1.4 (h)
+            if !cert_is_self_issued(cert) {
+                explicit_policy = explicit_policy.saturating_sub(1);
+                inhibit_any_policy = inhibit_any_policy.saturating_sub(1);
+            }
+            // 6.1.4 (i)
+            if let Some(r) = require_explicit_policy(&extensions)? {
+                explicit_policy = explicit_policy.min(r);
+            }
+            // 6.1.4 (j)
+            if let Some(ext) = extensions.get_extension(&INHIBIT_ANY_POLICY_OID) {
+                inhibit_any_policy = inhibit_any_policy.min(ext.value::<u64>()?);
+            }
+        }
+    }
+
+    // 6.1.5 (g): the initial policy set is `anyPolicy`, so the tree is left
+    // as is and only its emptiness matters.
+    if explicit_policy == 0 {
+        check_valid_policies(&valid_policies, has_mappings)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the policies valid after a certificate asserting `asserted`,
+/// given the ones valid before it. When `any_policy_honored`, the
+/// certificate's `anyPolicy` carries every previously valid policy through.
+fn narrow(
+    valid: Vec<ObjectIdentifier>,
+    asserted: &[ObjectIdentifier],
+    any_policy_honored: bool,
+) -> Option<Vec<ObjectIdentifier>> {
+    let valid_any = valid.contains(&ANY_POLICY_OID);
+    let mut narrowed = asserted
+        .iter()
+        .filter(|&p| *p != ANY_POLICY_OID && (valid_any || valid.contains(p)))
+        .cloned()
+        .collect::<Vec<_>>();
+    if any_policy_honored {
+        for p in valid {
+            if !narrowed.contains(&p) {
+                narrowed.push(p);
+            }
+        }
+    }
+
+    if narrowed.is_empty() {
+        None
+    } else {
+        Some(narrowed)
+    }
+}
+
+fn require_explicit_policy(extensions: &Extensions<'_>) -> Result<Option<u64>, ValidationError> {
+    match extensions.get_extension(&POLICY_CONSTRAINTS_OID) {
+        Some(ext) => Ok(ext.value::<PolicyConstraints>()?.require_explicit_policy),
+        None => Ok(None),
+    }
+}
+
+fn check_valid_policies(
+    valid_policies: &Option<Vec<ObjectIdentifier>>,
+    has_mappings: bool,
+) -> Result<(), ValidationError> {
+    if has_mappings {
+        Err(ValidationError::Other(
+            "policy mappings are not supported when an explicit policy is required".to_string(),
+        ))
+    } else if valid_policies.is_none() {
+        Err(ValidationError::Other(
+            "no certificate policy is valid for the chain".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cryptography_x509::oid::ANY_POLICY_OID;
+
+    use super::narrow;
+
+    #[test]
+    fn test_narrow() {
+        let a = asn1::oid!(1, 2, 3);
+        let b = asn1::oid!(1, 2, 4);
+
+        // anyPolicy as the only valid policy admits whatever is asserted.
+        assert_eq!(
+            narrow(vec![ANY_POLICY_OID], std::slice::from_ref(&a), false),
+            Some(vec![a.clone()])
+        );
+        // Otherwise only already-valid policies survive.
+        assert_eq!(
+            narrow(vec![a.clone()], std::slice::from_ref(&b), false),
+            None
+        );
+        assert_eq!(
+            narrow(vec![a.clone(), b.clone()], std::slice::from_ref(&b), false),
+            Some(vec![b.clone()])
+        );
+        // An inhibited anyPolicy doesn't match anything.
+        assert_eq!(narrow(vec![a.clone()], &[ANY_POLICY_OID], false), None);
+        // An honored one carries everything through.
+        assert_eq!(
+            narrow(vec![a.clone()], &[ANY_POLICY_OID], true),
+            Some(vec![a.clone()])
+        );
+        assert_eq!(
+            narrow(vec![ANY_POLICY_OID], &[ANY_POLICY_OID], true),
+            Some(vec![ANY_POLICY_OID])
+        );
+    }
+}
@@ -7,24 +7,35 @@
 #![allow(unknown_lints, clippy::result_large_err)]
 
 pub mod certificate;
+mod certificate_policies;
+pub mod name;
 pub mod ops;
 pub mod policy;
 pub mod trust_store;
 pub mod types;
 
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::vec;
 
 use asn1::ObjectIdentifier;
 use cryptography_x509::extensions::{DuplicateExtensionsError, Extensions};
 use cryptography_x509::{
-    extensions::{NameConstraints, SubjectAlternativeName},
+    extensions::{ExtendedKeyUsage, NameConstraints, SubjectAlternativeName},
     name::GeneralName,
-    oid::{NAME_CONSTRAINTS_OID, SUBJECT_ALTERNATIVE_NAME_OID},
+    oid::{
+        EKU_ANY_KEY_USAGE_OID, EKU_CERTIFICATE_TRANSPARENCY_OID, EXTENDED_KEY_USAGE_OID,
+        NAME_CONSTRAINTS_OID, PRECERT_POISON_OID, SUBJECT_ALTERNATIVE_NAME_OID,
+    },
 };
 use types::{RFC822Constraint, RFC822Name};
 
-use crate::certificate::cert_is_self_issued;
+use crate::certificate::{
+    authority_key_identifier, authority_key_identifier_matches, cert_is_self_issued,
+    same_subject_and_key,
+};
+use crate::certificate_policies::check_certificate_policies;
+use crate::name::CanonicalName;
 use crate::ops::{CryptoOps, VerificationCertificate};
 use crate::policy::Policy;
 use crate::trust_store::Store;
@@ -252,8 +263,65 @@ pub fn verify<'a, 'chain: 'a, B: CryptoOps>(
     builder.build_chain(leaf, &mut budget)
 }
 
+/// Returns the extended key usages that a verified `chain` is valid for:
+/// the intersection of the EKUs of each of its members, and of the purposes
+/// its trust anchor is trusted for in `store`. Members without an EKU
+/// extension, or with `anyExtendedKeyUsage`, don't narrow the set.
+///
+/// Returns `None` if nothing in the chain restricts its EKUs.
+pub fn chain_extended_key_usages<B: CryptoOps>(
+    chain: &[&VerificationCertificate<'_, B>],
+    store: &Store<'_, B>,
+) -> Result<Option<Vec<ObjectIdentifier>>, ValidationError> {
+    fn narrow(
+        satisfied: Option<Vec<ObjectIdentifier>>,
+        ekus: Vec<ObjectIdentifier>,
+    ) -> Vec<ObjectIdentifier> {
+        match satisfied {
+            Some(satisfied) => satisfied.into_iter().filter(|e| ekus.contains(e)).collect(),
+            None => ekus,
+        }
+    }
+
+    let leaf_is_precertificate = match chain.first() {
+        Some(leaf) => leaf
+            .certificate()
+            .extensions()?
+            .get_extension(&PRECERT_POISON_OID)
+            .is_some(),
+        None => false,
+    };
+
+    let mut satisfied = None;
+    for (depth, cert) in chain.iter().enumerate() {
+        let extensions = cert.certificate().extensions()?;
+        if let Some(ext) = extensions.get_extension(&EXTENDED_KEY_USAGE_OID) {
+            let ekus = ext.value::<ExtendedKeyUsage<'_>>()?.collect::<Vec<_>>();
+            // A Precertificate Signing Certificate issues precertificates on
+            // behalf of its own issuer, so its EKUs don't narrow the chain's.
+            if depth == 1
+                && leaf_is_precertificate
+                && ekus.contains(&EKU_CERTIFICATE_TRANSPARENCY_OID)
+            {
+                continue;
+            }
+            if !ekus.contains(&EKU_ANY_KEY_USAGE_OID) {
+                satisfied = Some(narrow(satisfied, ekus));
+            }
+        }
+    }
+
+    if let Some(purposes) = chain.last().and_then(|anchor| store.purposes_for(anchor)) {
+        satisfied = Some(narrow(satisfied, purposes));
+    }
+
+    Ok(satisfied)
+}
+
 struct ChainBuilder<'a, 'chain, B: CryptoOps> {
-    intermediates: &'a [&'a VerificationCertificate<'chain, B>],
+    /// The untrusted intermediates, indexed by subject so that each
+    /// path-building step is a lookup rather than a scan of the whole pool.
+    intermediates: HashMap<CanonicalName, Vec<&'a VerificationCertificate<'chain, B>>>,
     policy: &'a Policy<'a, B>,
     store: &'a Store<'chain, B>,
 }
@@ -283,8 +351,27 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
         policy: &'a Policy<'a, B>,
         store: &'a Store<'chain, B>,
     ) -> Self {
+        // NOTE: Like the `Store`, this is keyed on the subject's canonical
+        // form, so the index matches exactly the issuers whose subject
+        // matches under RFC 5280 7.1. Insertion order is preserved within
+        // each subject, so candidates are tried in the order given.
+        //
+        // Peers often send the same intermediate more than once, or include
+        // the trust anchor. Each copy would be explored as a separate
+        // candidate at every step, so only the first copy is kept, and
+        // anchors are left to the store.
+        let mut by_subject: HashMap<_, Vec<_>> = HashMap::new();
+        for &intermediate in intermediates {
+            let candidates = by_subject
+                .entry(CanonicalName::new(intermediate.certificate().subject()))
+                .or_default();
+            if !store.contains(intermediate) && !candidates.contains(&intermediate) {
+                candidates.push(intermediate);
+            }
+        }
+
         Self {
-            intermediates,
+            intermediates: by_subject,
             policy,
             store,
         }
@@ -294,22 +381,41 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
         &self,
         cert: &'a VerificationCertificate<'chain, B>,
     ) -> impl Iterator<Item = &'a VerificationCertificate<'chain, B>> + '_ {
-        // TODO: Optimizations:
-        // * Search by AKI and other identifiers?
-        self.store
-            .get_by_subject(&cert.certificate().tbs_cert.issuer)
-            .iter()
-            .chain(self.intermediates.iter().copied().filter(|&candidate| {
-                candidate.certificate().subject() == cert.certificate().issuer()
-            }))
+        let issuer = &cert.certificate().tbs_cert.issuer;
+        let mut candidates = self
+            .store
+            .get_by_subject(issuer)
+            .chain(
+                self.intermediates
+                    .get(&CanonicalName::new(issuer.unwrap_read()))
+                    .map(|v| v.as_slice())
+                    .unwrap_or_default()
+                    .iter()
+                    .copied(),
+            )
+            .collect::<Vec<_>>();
+
+        // Cross-signing and key rollover both produce multiple issuers with
+        // the same subject, most of which won't have signed `cert`. When
+        // `cert` has an AKI, try the candidates that match it first; the
+        // rest are still tried afterwards (in their original order), since
+        // the AKI is only a hint. Without an AKI, the order is unchanged.
+        if let Some(aki) = authority_key_identifier(cert.certificate()) {
+            candidates.sort_by_key(|c| !authority_key_identifier_matches(&aki, c.certificate()));
+        }
+
+        candidates.into_iter()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn build_chain_inner(
         &self,
         working_cert: &'a VerificationCertificate<'chain, B>,
         current_depth: u8,
+        path_len_depth: u8,
         working_cert_extensions: &Extensions<'chain>,
         name_chain: NameChain<'_, 'chain>,
+        path: &mut Vec<&'a VerificationCertificate<'chain, B>>,
         budget: &mut Budget,
     ) -> Result<Chain<'a, 'chain, B>, ValidationError> {
         if let Some(nc) = working_cert_extensions.get_extension(&NAME_CONSTRAINTS_OID) {
@@ -317,9 +423,27 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
         }
 
         // Look in the store's root set to see if the working cert is listed.
-        // If it is, we've reached the end.
+        // If it is, we've reached the end, provided the store trusts it for
+        // the policy's purpose. An anchor that isn't trusted for this purpose
+        // can't terminate the chain, nor can we build past it.
         if self.store.contains(working_cert) {
-            return Ok(vec![working_cert]);
+            if self
+                .store
+                .trusts_for(working_cert, &self.policy.extended_key_usage)
+            {
+                self.policy
+                    .permits_trust_anchor(working_cert.certificate())?;
+                // Unlike name constraints, policies are processed from the
+                // trust anchor down, so they can only be checked once a
+                // candidate path is complete. A path that fails the check is
+                // rejected like any other, so the caller tries the next
+                // candidate issuer.
+                check_certificate_policies(path)?;
+                return Ok(vec![working_cert]);
+            }
+            return Err(ValidationError::Other(
+                "trust anchor is not trusted for the policy's extended key usage".into(),
+            ));
         }
 
         // Check that our current depth does not exceed our policy-configured
@@ -335,6 +459,19 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
         // and continue with the first that verifies.
         let mut last_err: Option<ValidationError> = None;
         for issuing_cert_candidate in self.potential_issuers(working_cert) {
+            // Cross-signing can make the issuer graph cyclic. A candidate
+            // with the same subject and public key as a certificate already
+            // in the partial path can't lead anywhere that certificate
+            // couldn't, so skip it rather than building around the cycle.
+            if path.iter().any(|c| {
+                same_subject_and_key(c.certificate(), issuing_cert_candidate.certificate())
+            }) {
+                last_err = Some(ValidationError::Other(
+                    "candidate issuer creates a loop in the chain".to_string(),
+                ));
+                continue;
+            }
+
             // A candidate issuer is said to verify if it both
             // signs for the working certificate and conforms to the
             // policy.
@@ -342,32 +479,38 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
             match self.policy.valid_issuer(
                 issuing_cert_candidate,
                 working_cert.certificate(),
-                current_depth,
+                path_len_depth,
                 &issuer_extensions,
             ) {
                 Ok(_) => {
-                    match self.build_chain_inner(
+                    let issuer_is_self_issued =
+                        cert_is_self_issued(issuing_cert_candidate.certificate());
+                    path.push(issuing_cert_candidate);
+                    let result = self.build_chain_inner(
                         issuing_cert_candidate,
-                        // NOTE(ww): According to RFC 5280, we should only
-                        // increase the chain depth when the certificate is **not**
-                        // self-issued. In practice however, implementations widely
-                        // ignore this requirement, and unconditionally increment
-                        // the depth with every chain member. We choose to do the same;
-                        // see `pathlen::self-issued-certs-pathlen` from x509-limbo
-                        // for the testcase we intentionally fail.
-                        //
-                        // Implementation note for someone looking to change this in the future:
-                        // care should be taken to avoid infinite recursion with self-signed
-                        // certificates in the intermediate set; changing this behavior will
-                        // also require a "is not self-signed" check on intermediate candidates.
-                        //
-                        // See https://gist.github.com/woodruffw/776153088e0df3fc2f0675c5e835f7b8
-                        // for an example of this change.
+                        // NOTE(ww): The max depth counts every chain member,
+                        // self-issued or not, consistent with other widely used
+                        // implementations; see `pathlen::max-chain-depth-1-self-issued`
+                        // from x509-limbo for the testcase we intentionally fail.
+                        // This also bounds the recursion when the intermediate set
+                        // contains self-issued certificates.
                         current_depth.checked_add(1).ok_or_else(|| {
                             ValidationError::Other(
                                 "current depth calculation overflowed".to_string(),
                             )
                         })?,
+                        // Per RFC 5280 4.2.1.9, pathLenConstraint only limits the
+                        // number of non-self-issued intermediates that may follow a
+                        // CA, so self-issued certificates don't count towards it.
+                        if issuer_is_self_issued {
+                            path_len_depth
+                        } else {
+                            path_len_depth.checked_add(1).ok_or_else(|| {
+                                ValidationError::Other(
+                                    "path length calculation overflowed".to_string(),
+                                )
+                            })?
+                        },
                         &issuer_extensions,
                         NameChain::new(
                             Some(&name_chain),
@@ -377,10 +520,13 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
                             // certificate is the "final" (i.e., leaf) certificate in the path.
                             // We accomplish this by only collecting the SANs when the issuing
                             // candidate (which is a non-leaf by definition) isn't self-issued.
-                            cert_is_self_issued(issuing_cert_candidate.certificate()),
+                            issuer_is_self_issued,
                         )?,
+                        path,
                         budget,
-                    ) {
+                    );
+                    path.pop();
+                    match result {
                         Ok(mut chain) => {
                             chain.push(working_cert);
                             return Ok(chain);
@@ -428,8 +574,10 @@ impl<'a, 'chain: 'a, B: CryptoOps> ChainBuilder<'a, 'chain, B> {
         let mut chain = self.build_chain_inner(
             leaf,
             0,
+            0,
             &leaf_extensions,
             NameChain::new(None, &leaf_extensions, false)?,
+            &mut vec![leaf],
             budget,
         )?;
         // We build the chain in reverse order, fix it now.
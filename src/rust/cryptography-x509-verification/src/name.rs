@@ -0,0 +1,151 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+//! Distinguished name comparison, per RFC 5280 7.1.
+
+use asn1::SimpleAsn1Readable;
+use cryptography_x509::common::{AttributeTypeValue, RawTlv};
+use cryptography_x509::name::NameReadable;
+
+/// The canonical form of a distinguished name: two names match under
+/// RFC 5280 7.1 exactly when their canonical forms are equal, so this can be
+/// used to index certificates by name.
+///
+/// Each attribute is kept as its DER encoding, with PrintableString and
+/// UTF8String values prepared (see `prepare`) and re-encoded as UTF8String,
+/// and the attributes of each RDN are sorted, since an RDN is a set. Values
+/// of any other type are compared as is.
+#[derive(PartialEq, Eq, Hash)]
+pub(crate) struct CanonicalName(Vec<Vec<Vec<u8>>>);
+
+impl CanonicalName {
+    pub(crate) fn new(name: &NameReadable<'_>) -> CanonicalName {
+        CanonicalName(
+            name.clone()
+                .map(|rdn| {
+                    let mut attributes = rdn.map(canonical_attribute).collect::<Vec<_>>();
+                    attributes.sort();
+                    attributes
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Returns whether `a` and `b` match under RFC 5280 7.1.
+pub fn names_match(a: &NameReadable<'_>, b: &NameReadable<'_>) -> bool {
+    a == b || CanonicalName::new(a) == CanonicalName::new(b)
+}
+
+fn canonical_attribute(attribute: AttributeTypeValue<'_>) -> Vec<u8> {
+    let tag = attribute.value.tag();
+    let prepared = if tag == asn1::PrintableString::TAG || tag == asn1::Utf8String::TAG {
+        std::str::from_utf8(attribute.value.data())
+            .ok()
+            .map(prepare)
+    } else {
+        None
+    };
+
+    let result = match prepared {
+        Some(value) => asn1::write_single(&AttributeTypeValue {
+            type_id: attribute.type_id,
+            value: RawTlv::new(asn1::Utf8String::TAG, value.as_bytes()),
+        }),
+        None => asn1::write_single(&attribute),
+    };
+    // The attribute was parsed, so it can always be written back out.
+    result.unwrap()
+}
+
+/// Prepares a string value for comparison, approximating the LDAP StringPrep
+/// profile (RFC 4518) that RFC 5280 7.1 calls for: case is folded, and
+/// insignificant whitespace (leading, trailing, and all but one character of
+/// each internal run) is removed. Unicode normalization isn't performed, so
+/// values that only match after normalization don't match.
+fn prepare(value: &str) -> String {
+    value
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use asn1::SimpleAsn1Readable;
+    use cryptography_x509::common::{AttributeTypeValue, RawTlv};
+    use cryptography_x509::name::NameReadable;
+
+    use super::{canonical_attribute, names_match, prepare};
+
+    // A name with a single RDN of the given `(tag, value)` common names.
+    fn name(attributes: &[(u8, &str)]) -> Vec<u8> {
+        let mut rdn = vec![];
+        for (tag, value) in attributes {
+            // 2.5.4.3 is the common name.
+            rdn.extend_from_slice(&[0x30, value.len() as u8 + 7, 0x06, 0x03, 0x55, 0x04, 0x03]);
+            rdn.extend_from_slice(&[*tag, value.len() as u8]);
+            rdn.extend_from_slice(value.as_bytes());
+        }
+        let mut name = vec![0x30, rdn.len() as u8 + 2, 0x31, rdn.len() as u8];
+        name.extend_from_slice(&rdn);
+        name
+    }
+
+    fn matches(a: &[(u8, &str)], b: &[(u8, &str)]) -> bool {
+        let (a, b) = (name(a), name(b));
+        let a = asn1::parse_single::<NameReadable<'_>>(&a).unwrap();
+        let b = asn1::parse_single::<NameReadable<'_>>(&b).unwrap();
+        names_match(&a, &b)
+    }
+
+    #[test]
+    fn test_prepare() {
+        assert_eq!(prepare("Example"), "example");
+        assert_eq!(prepare("  an   Example\t CA "), "an example ca");
+        assert_eq!(prepare("ÉCOLE"), "école");
+        assert_eq!(prepare(""), "");
+    }
+
+    #[test]
+    fn test_names_match() {
+        const PRINTABLE: u8 = 0x13;
+        const UTF8: u8 = 0x0c;
+        const IA5: u8 = 0x16;
+
+        assert!(matches(&[(UTF8, "Example CA")], &[(UTF8, "Example CA")]));
+        assert!(matches(
+            &[(PRINTABLE, "Example CA")],
+            &[(UTF8, " example  ca")]
+        ));
+        assert!(!matches(&[(UTF8, "Example CA")], &[(UTF8, "ExampleCA")]));
+        // Other string types are compared exactly.
+        assert!(!matches(&[(IA5, "Example CA")], &[(IA5, "example ca")]));
+        assert!(!matches(&[(IA5, "Example CA")], &[(UTF8, "Example CA")]));
+        // An RDN's attributes are unordered.
+        assert!(matches(
+            &[(UTF8, "a"), (PRINTABLE, "B")],
+            &[(UTF8, "A"), (UTF8, "b")]
+        ));
+        assert!(!matches(&[(UTF8, "a"), (UTF8, "b")], &[(UTF8, "a")]));
+    }
+
+    #[test]
+    fn test_attribute_types_compared() {
+        let cn = AttributeTypeValue {
+            type_id: asn1::oid!(2, 5, 4, 3),
+            value: RawTlv::new(asn1::Utf8String::TAG, b"a"),
+        };
+        let o = AttributeTypeValue {
+            type_id: asn1::oid!(2, 5, 4, 10),
+            ..cn.clone()
+        };
+        assert_eq!(
+            canonical_attribute(cn.clone()),
+            canonical_attribute(cn.clone())
+        );
+        assert_ne!(canonical_attribute(cn), canonical_attribute(o));
+    }
+}
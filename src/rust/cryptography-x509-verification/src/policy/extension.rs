@@ -4,7 +4,8 @@
 
 use cryptography_x509::oid::{
     AUTHORITY_INFORMATION_ACCESS_OID, AUTHORITY_KEY_IDENTIFIER_OID, BASIC_CONSTRAINTS_OID,
-    EXTENDED_KEY_USAGE_OID, KEY_USAGE_OID, NAME_CONSTRAINTS_OID, SUBJECT_ALTERNATIVE_NAME_OID,
+    CERTIFICATE_POLICIES_OID, EXTENDED_KEY_USAGE_OID, INHIBIT_ANY_POLICY_OID, KEY_USAGE_OID,
+    NAME_CONSTRAINTS_OID, POLICY_CONSTRAINTS_OID, PRECERT_POISON_OID, SUBJECT_ALTERNATIVE_NAME_OID,
     SUBJECT_KEY_IDENTIFIER_OID,
 };
 use cryptography_x509::{
@@ -23,6 +24,7 @@ pub(crate) struct ExtensionPolicy<B: CryptoOps> {
     pub(crate) basic_constraints: ExtensionValidator<B>,
     pub(crate) name_constraints: ExtensionValidator<B>,
     pub(crate) extended_key_usage: ExtensionValidator<B>,
+    pub(crate) precert_poison: ExtensionValidator<B>,
 }
 
 impl<B: CryptoOps> ExtensionPolicy<B> {
@@ -40,6 +42,7 @@ impl<B: CryptoOps> ExtensionPolicy<B> {
         let mut basic_constraints_seen = false;
         let mut name_constraints_seen = false;
         let mut extended_key_usage_seen = false;
+        let mut precert_poison_seen = false;
 
         // Iterate over each extension and run its policy.
         for ext in extensions.iter() {
@@ -80,7 +83,14 @@ impl<B: CryptoOps> ExtensionPolicy<B> {
                     extended_key_usage_seen = true;
                     self.extended_key_usage.permits(policy, cert, Some(&ext))?;
                 }
-                _ if ext.critical => {
+                PRECERT_POISON_OID => {
+                    precert_poison_seen = true;
+                    self.precert_poison.permits(policy, cert, Some(&ext))?;
+                }
+                // These are processed across the whole chain once it's
+                // built, rather than per certificate.
+                CERTIFICATE_POLICIES_OID | POLICY_CONSTRAINTS_OID | INHIBIT_ANY_POLICY_OID => {}
+                _ if ext.critical && !policy.handled_critical_extensions.contains(&ext.extn_id) => {
                     return Err(ValidationError::ExtensionError {
                         oid: ext.extn_id,
                         reason: "certificate contains unaccounted-for critical extensions",
@@ -117,6 +127,9 @@ impl<B: CryptoOps> ExtensionPolicy<B> {
         if !extended_key_usage_seen {
             self.extended_key_usage.permits(policy, cert, None)?;
         }
+        if !precert_poison_seen {
+            self.precert_poison.permits(policy, cert, None)?;
+        }
 
         Ok(())
     }
@@ -258,6 +271,7 @@ impl<B: CryptoOps> ExtensionValidator<B> {
 pub(crate) mod ee {
     use cryptography_x509::{
         certificate::Certificate,
+        common::AlgorithmParameters,
         extensions::{
             BasicConstraints, ExtendedKeyUsage, Extension, KeyUsage, SubjectAlternativeName,
         },
@@ -347,10 +361,31 @@ pub(crate) mod ee {
         }
     }
 
-    pub(crate) fn key_usage<B: CryptoOps>(
-        _policy: &Policy<'_, B>,
+    pub(crate) fn precert_poison<B: CryptoOps>(
+        policy: &Policy<'_, B>,
         _cert: &Certificate<'_>,
         extn: Option<&Extension<'_>>,
+    ) -> Result<(), ValidationError> {
+        if let Some(extn) = extn {
+            // RFC 6962 3.1: a precertificate is not a usable certificate,
+            // and is only accepted when the policy explicitly permits it.
+            if !policy.permit_precertificates {
+                return Err(ValidationError::Other(
+                    "precertificates are not permitted by this policy".to_string(),
+                ));
+            }
+
+            // The poison extension's value must be an ASN.1 NULL.
+            extn.value::<()>()?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn key_usage<B: CryptoOps>(
+        policy: &Policy<'_, B>,
+        cert: &Certificate<'_>,
+        extn: Option<&Extension<'_>>,
     ) -> Result<(), ValidationError> {
         if let Some(extn) = extn {
             let key_usage: KeyUsage<'_> = extn.value()?;
@@ -360,6 +395,64 @@ pub(crate) mod ee {
                     "EE keyUsage must not assert keyCertSign".to_string(),
                 ));
             }
+
+            // 5280 4.2.1.3: encipherOnly and decipherOnly are undefined
+            // unless keyAgreement is also asserted.
+            if (key_usage.encipher_only() || key_usage.decipher_only())
+                && !key_usage.key_agreement()
+            {
+                return Err(ValidationError::Other(
+                    "EE keyUsage must assert keyAgreement with encipherOnly or decipherOnly"
+                        .to_string(),
+                ));
+            }
+
+            // Keys that can only be used for key agreement (X25519, X448
+            // and DH) are unusable if the keyUsage doesn't permit it.
+            if matches!(
+                cert.tbs_cert.spki.algorithm.params,
+                AlgorithmParameters::X25519
+                    | AlgorithmParameters::X448
+                    | AlgorithmParameters::Dh(_)
+                    | AlgorithmParameters::DhKeyAgreement(_)
+            ) && !key_usage.key_agreement()
+            {
+                return Err(ValidationError::Other(
+                    "EE keyUsage must assert keyAgreement for a key agreement public key"
+                        .to_string(),
+                ));
+            }
+        }
+
+        if policy.require_key_establishment {
+            // RSA keys establish keys by key transport (keyEncipherment),
+            // and the others by key agreement. dataEncipherment doesn't
+            // count: it covers encrypting user data directly.
+            let key_usage = extn.map(|extn| extn.value::<KeyUsage<'_>>()).transpose()?;
+            let (permitted, name) = match cert.tbs_cert.spki.algorithm.params {
+                AlgorithmParameters::Rsa(_) => (
+                    key_usage.as_ref().map_or(true, |ku| ku.key_encipherment()),
+                    "keyEncipherment",
+                ),
+                AlgorithmParameters::Ec(_)
+                | AlgorithmParameters::X25519
+                | AlgorithmParameters::X448
+                | AlgorithmParameters::Dh(_)
+                | AlgorithmParameters::DhKeyAgreement(_) => (
+                    key_usage.as_ref().map_or(true, |ku| ku.key_agreement()),
+                    "keyAgreement",
+                ),
+                _ => {
+                    return Err(ValidationError::Other(
+                        "EE public key can't be used for key establishment".to_string(),
+                    ))
+                }
+            };
+            if !permitted {
+                return Err(ValidationError::Other(format!(
+                    "EE keyUsage must assert {name} for key establishment"
+                )));
+            }
         }
 
         Ok(())
@@ -373,7 +466,7 @@ pub(crate) mod ca {
             AuthorityKeyIdentifier, BasicConstraints, ExtendedKeyUsage, Extension, KeyUsage,
             NameConstraints,
         },
-        oid::EKU_ANY_KEY_USAGE_OID,
+        oid::{EKU_ANY_KEY_USAGE_OID, EKU_CERTIFICATE_TRANSPARENCY_OID},
     };
 
     use crate::{
@@ -507,7 +600,16 @@ pub(crate) mod ca {
 
             // NOTE: CABF explicitly forbids anyEKU in and most CA certs,
             // but this is widely (universally?) ignored by other implementations.
-            if ekus.any(|eku| eku == policy.extended_key_usage || eku == EKU_ANY_KEY_USAGE_OID) {
+            //
+            // When precertificates are permitted, an RFC 6962 Precertificate
+            // Signing Certificate (identified by the Certificate Transparency
+            // EKU) is also accepted here; `Policy::valid_issuer` then limits
+            // it to issuing precertificates.
+            if ekus.any(|eku| {
+                eku == policy.extended_key_usage
+                    || eku == EKU_ANY_KEY_USAGE_OID
+                    || (policy.permit_precertificates && eku == EKU_CERTIFICATE_TRANSPARENCY_OID)
+            }) {
                 Ok(())
             } else {
                 Err(ValidationError::Other("required EKU not found".to_string()))
@@ -16,11 +16,14 @@ use cryptography_x509::common::{
     PSS_SHA256_HASH_ALG, PSS_SHA256_MASK_GEN_ALG, PSS_SHA384_HASH_ALG, PSS_SHA384_MASK_GEN_ALG,
     PSS_SHA512_HASH_ALG, PSS_SHA512_MASK_GEN_ALG,
 };
-use cryptography_x509::extensions::{BasicConstraints, Extensions, SubjectAlternativeName};
+use cryptography_x509::extensions::{
+    BasicConstraints, ExtendedKeyUsage, Extensions, SubjectAlternativeName,
+};
 use cryptography_x509::name::GeneralName;
 use cryptography_x509::oid::{
-    BASIC_CONSTRAINTS_OID, EC_SECP256R1, EC_SECP384R1, EC_SECP521R1, EKU_CLIENT_AUTH_OID,
-    EKU_SERVER_AUTH_OID,
+    BASIC_CONSTRAINTS_OID, EC_SECP256R1, EC_SECP384R1, EC_SECP521R1, EKU_ANY_KEY_USAGE_OID,
+    EKU_CERTIFICATE_TRANSPARENCY_OID, EKU_CLIENT_AUTH_OID, EKU_SERVER_AUTH_OID,
+    EXTENDED_KEY_USAGE_OID, PRECERT_POISON_OID, SHA256_OID, SHA384_OID, SHA512_OID,
 };
 use once_cell::sync::Lazy;
 
@@ -158,6 +161,26 @@ pub static WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS: Lazy<Arc<HashSet<AlgorithmIden
         ]))
     });
 
+/// Returns the OID of the digest used by the given signature algorithm, for
+/// the algorithms in [`WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS`].
+fn signature_hash_oid<'b>(
+    signature_alg: &'b AlgorithmIdentifier<'_>,
+) -> Option<&'b ObjectIdentifier> {
+    match &signature_alg.params {
+        AlgorithmParameters::RsaWithSha256(_) | AlgorithmParameters::EcDsaWithSha256(_) => {
+            Some(&SHA256_OID)
+        }
+        AlgorithmParameters::RsaWithSha384(_) | AlgorithmParameters::EcDsaWithSha384(_) => {
+            Some(&SHA384_OID)
+        }
+        AlgorithmParameters::RsaWithSha512(_) | AlgorithmParameters::EcDsaWithSha512(_) => {
+            Some(&SHA512_OID)
+        }
+        AlgorithmParameters::RsaPss(Some(pss)) => Some(pss.hash_algorithm.oid()),
+        _ => None,
+    }
+}
+
 /// A default reasonable maximum chain depth.
 ///
 /// This depth was chosen to balance between common validation lengths
@@ -230,6 +253,34 @@ pub struct Policy<'a, B: CryptoOps> {
     /// algorithm identifiers.
     pub permitted_signature_algorithms: Arc<HashSet<AlgorithmIdentifier<'a>>>,
 
+    /// Whether the trust anchor's own signature algorithm must also be in
+    /// `permitted_signature_algorithms`. A trust anchor's self-signature
+    /// isn't relied upon, so this is `false` by default.
+    pub check_trust_anchor_signature: bool,
+
+    /// Whether EE certificates may be RFC 6962 precertificates, i.e. carry
+    /// the critical precertificate poison extension. When set, a
+    /// precertificate may also be issued by a Precertificate Signing
+    /// Certificate, which is in turn only permitted to issue precertificates.
+    /// This is `false` by default.
+    pub permit_precertificates: bool,
+
+    /// Whether the EE certificate's key must be usable for key establishment:
+    /// if the EE has a keyUsage extension, it must assert keyEncipherment for
+    /// an RSA key, or keyAgreement for an EC, X25519, X448 or DH key. Other
+    /// keys can't establish keys at all. This is `false` by default.
+    pub require_key_establishment: bool,
+
+    /// Whether each certificate's validity period must lie within that of its
+    /// issuer. RFC 5280 doesn't require this, but some policies do. This is
+    /// `false` by default.
+    pub require_nested_validity: bool,
+
+    /// Critical extensions that this policy doesn't recognize, but which the
+    /// caller handles itself. Certificates carrying any other unrecognized
+    /// critical extension are rejected. This is empty by default.
+    pub handled_critical_extensions: HashSet<ObjectIdentifier>,
+
     ca_extension_policy: ExtensionPolicy<B>,
     ee_extension_policy: ExtensionPolicy<B>,
 }
@@ -251,6 +302,11 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
             minimum_rsa_modulus: WEBPKI_MINIMUM_RSA_MODULUS,
             permitted_public_key_algorithms: Arc::clone(&*WEBPKI_PERMITTED_SPKI_ALGORITHMS),
             permitted_signature_algorithms: Arc::clone(&*WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS),
+            check_trust_anchor_signature: false,
+            permit_precertificates: false,
+            require_key_establishment: false,
+            require_nested_validity: false,
+            handled_critical_extensions: HashSet::new(),
             ca_extension_policy: ExtensionPolicy {
                 // 5280 4.2.2.1: Authority Information Access
                 authority_information_access: ExtensionValidator::maybe_present(
@@ -294,6 +350,8 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
                     Criticality::NonCritical,
                     Some(ca::extended_key_usage),
                 ),
+                // RFC 6962 3.1: Only precertificates carry the poison extension.
+                precert_poison: ExtensionValidator::not_present(),
             },
             ee_extension_policy: ExtensionPolicy {
                 // 5280 4.2.2.1: Authority Information Access
@@ -336,6 +394,11 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
                     Criticality::NonCritical,
                     Some(ee::extended_key_usage),
                 ),
+                // RFC 6962 3.1: Precertificate poison
+                precert_poison: ExtensionValidator::maybe_present(
+                    Criticality::Critical,
+                    Some(ee::precert_poison),
+                ),
             },
         }
     }
@@ -373,6 +436,41 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         )
     }
 
+    /// Restricts `permitted_signature_algorithms` to the algorithms whose
+    /// digest is one of `hash_oids`.
+    ///
+    /// This only narrows the permitted set: algorithms that aren't already
+    /// permitted (such as those using SHA-1) are not added.
+    pub fn restrict_signature_hash_algorithms(&mut self, hash_oids: &[ObjectIdentifier]) {
+        let permitted = self
+            .permitted_signature_algorithms
+            .iter()
+            .filter(|alg| signature_hash_oid(alg).map_or(false, |oid| hash_oids.contains(oid)))
+            .cloned()
+            .collect();
+        self.permitted_signature_algorithms = Arc::new(permitted);
+    }
+
+    /// Checks whether the given trust anchor, which terminates a chain, is
+    /// compatible with this policy.
+    pub(crate) fn permits_trust_anchor(
+        &self,
+        cert: &Certificate<'_>,
+    ) -> Result<(), ValidationError> {
+        if self.check_trust_anchor_signature
+            && !self
+                .permitted_signature_algorithms
+                .contains(&cert.signature_alg)
+        {
+            return Err(ValidationError::Other(format!(
+                "Forbidden trust anchor signature algorithm: {:?}",
+                &cert.signature_alg
+            )));
+        }
+
+        Ok(())
+    }
+
     fn permits_basic(&self, cert: &Certificate<'_>) -> Result<(), ValidationError> {
         // CA/B 7.1.1:
         // Certificates MUST be of type X.509 v3.
@@ -435,11 +533,12 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         Ok(())
     }
 
-    /// Checks whether the given CA certificate is compatible with this policy.
+    /// Checks whether the given CA certificate is compatible with this policy,
+    /// when followed by `path_len_depth` non-self-issued intermediates.
     pub(crate) fn permits_ca(
         &self,
         cert: &Certificate<'_>,
-        current_depth: u8,
+        path_len_depth: u8,
         extensions: &Extensions<'_>,
     ) -> Result<(), ValidationError> {
         self.permits_basic(cert)?;
@@ -452,7 +551,7 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
 
         // NOTE: This conceptually belongs in `valid_issuer`, but is easier
         // to test here. It's also conceptually an extension policy, but
-        // requires a bit of extra external state (`current_depth`) that isn't
+        // requires a bit of extra external state (`path_len_depth`) that isn't
         // presently convenient to push into that layer.
         //
         // NOTE: BasicConstraints is required via `ca_extension_policies`,
@@ -462,7 +561,7 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
 
             if bc
                 .path_length
-                .map_or(false, |len| u64::from(current_depth) > len)
+                .map_or(false, |len| u64::from(path_len_depth) > len)
             {
                 return Err(ValidationError::Other(
                     "path length constraint violated".to_string(),
@@ -488,28 +587,73 @@ impl<'a, B: CryptoOps> Policy<'a, B> {
         Ok(())
     }
 
-    /// Checks whether `issuer` is a valid issuing CA for `child` at a
-    /// path-building depth of `current_depth`.
+    /// Returns whether a CA with the given extensions is only acceptable
+    /// under this policy as an RFC 6962 Precertificate Signing Certificate,
+    /// i.e. its EKUs include the Certificate Transparency EKU but neither
+    /// the policy's EKU nor `anyExtendedKeyUsage`.
+    fn is_precertificate_signer(
+        &self,
+        extensions: &Extensions<'_>,
+    ) -> Result<bool, ValidationError> {
+        if !self.permit_precertificates {
+            return Ok(false);
+        }
+
+        match extensions.get_extension(&EXTENDED_KEY_USAGE_OID) {
+            Some(ext) => {
+                let ekus = ext.value::<ExtendedKeyUsage<'_>>()?.collect::<Vec<_>>();
+                Ok(ekus.contains(&EKU_CERTIFICATE_TRANSPARENCY_OID)
+                    && !ekus.contains(&self.extended_key_usage)
+                    && !ekus.contains(&EKU_ANY_KEY_USAGE_OID))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Checks whether `issuer` is a valid issuing CA for `child`, with
+    /// `path_len_depth` non-self-issued intermediates between `issuer` and
+    /// the leaf.
     ///
     /// This checks that `issuer` is permitted under this policy and that
     /// it was used to sign for `child`.
     ///
     /// As a precondition, the caller must have already checked that
     /// `issuer.subject() == child.issuer()`.
-    ///
-    /// On success, this function returns the new path-building depth. This
-    /// may or may not be a higher number than the original depth, depending
-    /// on the kind of validation performed (e.g., whether the issuer was
-    /// self-issued).
     pub(crate) fn valid_issuer(
         &self,
         issuer: &VerificationCertificate<'_, B>,
         child: &Certificate<'_>,
-        current_depth: u8,
+        path_len_depth: u8,
         issuer_extensions: &Extensions<'_>,
     ) -> Result<(), ValidationError> {
         // The issuer needs to be a valid CA at the current depth.
-        self.permits_ca(issuer.certificate(), current_depth, issuer_extensions)?;
+        self.permits_ca(issuer.certificate(), path_len_depth, issuer_extensions)?;
+
+        // RFC 6962 3.1: A Precertificate Signing Certificate issues
+        // precertificates on behalf of its own issuer, and may not issue
+        // anything else. This keeps the policy's EKU enforced down-chain.
+        if self.is_precertificate_signer(issuer_extensions)?
+            && child
+                .extensions()?
+                .get_extension(&PRECERT_POISON_OID)
+                .is_none()
+        {
+            return Err(ValidationError::Other(
+                "precertificate signing certificate may only issue precertificates".to_string(),
+            ));
+        }
+
+        if self.require_nested_validity {
+            let issuer_validity = &issuer.certificate().tbs_cert.validity;
+            let validity = &child.tbs_cert.validity;
+            if validity.not_before.as_datetime() < issuer_validity.not_before.as_datetime()
+                || validity.not_after.as_datetime() > issuer_validity.not_after.as_datetime()
+            {
+                return Err(ValidationError::Other(
+                    "certificate's validity period is not within its issuer's".to_string(),
+                ));
+            }
+        }
 
         // CA/B 7.1.3.1 SubjectPublicKeyInfo
         // NOTE: We check the issuer's SPKI here, since the issuer is
@@ -591,15 +735,16 @@ mod tests {
 
     use asn1::{DateTime, SequenceOfWriter};
     use cryptography_x509::common::Time;
+    use cryptography_x509::oid::{SHA256_OID, SHA384_OID, SHA512_OID};
     use cryptography_x509::{
         extensions::SubjectAlternativeName,
         name::{GeneralName, UnvalidatedIA5String},
     };
 
     use super::{
-        permits_validity_date, ECDSA_SHA256, ECDSA_SHA384, ECDSA_SHA512, RSASSA_PKCS1V15_SHA256,
-        RSASSA_PKCS1V15_SHA384, RSASSA_PKCS1V15_SHA512, RSASSA_PSS_SHA256, RSASSA_PSS_SHA384,
-        RSASSA_PSS_SHA512, WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS,
+        permits_validity_date, signature_hash_oid, ECDSA_SHA256, ECDSA_SHA384, ECDSA_SHA512,
+        RSASSA_PKCS1V15_SHA256, RSASSA_PKCS1V15_SHA384, RSASSA_PKCS1V15_SHA512, RSASSA_PSS_SHA256,
+        RSASSA_PSS_SHA384, RSASSA_PSS_SHA512, WEBPKI_PERMITTED_SIGNATURE_ALGORITHMS,
     };
     use crate::{
         policy::{
@@ -813,4 +958,23 @@ mod tests {
             assert!(permits_validity_date(&generalized_validity).is_ok());
         }
     }
+
+    #[test]
+    fn test_signature_hash_oid() {
+        for (alg, hash_oid) in [
+            (&RSASSA_PKCS1V15_SHA256, &SHA256_OID),
+            (&RSASSA_PKCS1V15_SHA384, &SHA384_OID),
+            (&RSASSA_PKCS1V15_SHA512, &SHA512_OID),
+            (RSASSA_PSS_SHA256.deref(), &SHA256_OID),
+            (RSASSA_PSS_SHA384.deref(), &SHA384_OID),
+            (RSASSA_PSS_SHA512.deref(), &SHA512_OID),
+            (&ECDSA_SHA256, &SHA256_OID),
+            (&ECDSA_SHA384, &SHA384_OID),
+            (&ECDSA_SHA512, &SHA512_OID),
+        ] {
+            assert_eq!(signature_hash_oid(alg), Some(hash_oid));
+        }
+
+        assert_eq!(signature_hash_oid(&SPKI_RSA), None);
+    }
 }
@@ -4,25 +4,53 @@
 
 use std::collections::HashMap;
 
+use asn1::ObjectIdentifier;
 use cryptography_x509::name::Name;
 
+use crate::name::CanonicalName;
 use crate::CryptoOps;
 use crate::VerificationCertificate;
 
+/// A trusted certificate, along with the purposes it's trusted for.
+struct TrustAnchor<'a, B: CryptoOps> {
+    cert: VerificationCertificate<'a, B>,
+    /// The extended key usages this anchor may terminate a chain for,
+    /// or `None` if it's trusted for every purpose.
+    purposes: Option<Vec<ObjectIdentifier>>,
+}
+
 /// A `Store` represents the core state needed for X.509 path validation.
 pub struct Store<'a, B: CryptoOps> {
-    by_subject: HashMap<Name<'a>, Vec<VerificationCertificate<'a, B>>>,
+    /// Anchors are indexed by the canonical form of their subject, so that
+    /// lookups match names under RFC 5280 7.1.
+    by_subject: HashMap<CanonicalName, Vec<TrustAnchor<'a, B>>>,
 }
 
 impl<'a, B: CryptoOps> Store<'a, B> {
     /// Create a new `Store` from the given iterable certificate source.
+    /// Every certificate is trusted for every purpose.
     pub fn new(trusted: impl IntoIterator<Item = VerificationCertificate<'a, B>>) -> Self {
-        let mut by_subject: HashMap<Name<'a>, Vec<VerificationCertificate<'a, B>>> = HashMap::new();
-        for cert in trusted {
+        Self::new_with_purposes(trusted.into_iter().map(|cert| (cert, None)))
+    }
+
+    /// Create a new `Store` from the given iterable certificate source,
+    /// where each certificate is paired with the extended key usages it's
+    /// trusted for (or `None` for every purpose), as with the per-purpose
+    /// trust bits carried by real-world trust stores.
+    pub fn new_with_purposes(
+        trusted: impl IntoIterator<
+            Item = (
+                VerificationCertificate<'a, B>,
+                Option<Vec<ObjectIdentifier>>,
+            ),
+        >,
+    ) -> Self {
+        let mut by_subject: HashMap<CanonicalName, Vec<TrustAnchor<'a, B>>> = HashMap::new();
+        for (cert, purposes) in trusted {
             by_subject
-                .entry(cert.certificate().tbs_cert.subject.clone())
+                .entry(CanonicalName::new(cert.certificate().subject()))
                 .or_default()
-                .push(cert);
+                .push(TrustAnchor { cert, purposes });
         }
         Store { by_subject }
     }
@@ -30,12 +58,61 @@ impl<'a, B: CryptoOps> Store<'a, B> {
     /// Returns whether this store contains the given certificate.
     pub fn contains(&self, cert: &VerificationCertificate<'a, B>) -> bool {
         self.get_by_subject(&cert.certificate().tbs_cert.subject)
-            .contains(cert)
+            .any(|c| c == cert)
+    }
+
+    /// Returns whether this store contains the given certificate, and
+    /// trusts it for `purpose`.
+    pub fn trusts_for(
+        &self,
+        cert: &VerificationCertificate<'a, B>,
+        purpose: &ObjectIdentifier,
+    ) -> bool {
+        self.anchors(&cert.certificate().tbs_cert.subject)
+            .iter()
+            .filter(|a| &a.cert == cert)
+            .any(|a| match &a.purposes {
+                Some(purposes) => purposes.contains(purpose),
+                None => true,
+            })
+    }
+
+    /// Returns the purposes this store trusts `cert` for, or `None` if it's
+    /// trusted for every purpose (or isn't in the store at all).
+    pub fn purposes_for(
+        &self,
+        cert: &VerificationCertificate<'a, B>,
+    ) -> Option<Vec<ObjectIdentifier>> {
+        let mut purposes = vec![];
+        for anchor in self
+            .anchors(&cert.certificate().tbs_cert.subject)
+            .iter()
+            .filter(|a| &a.cert == cert)
+        {
+            for purpose in anchor.purposes.as_ref()? {
+                if !purposes.contains(purpose) {
+                    purposes.push(purpose.clone());
+                }
+            }
+        }
+
+        if purposes.is_empty() {
+            None
+        } else {
+            Some(purposes)
+        }
     }
 
-    pub fn get_by_subject(&self, subject: &Name<'a>) -> &[VerificationCertificate<'a, B>] {
+    pub fn get_by_subject<'s>(
+        &'s self,
+        subject: &Name<'a>,
+    ) -> impl Iterator<Item = &'s VerificationCertificate<'a, B>> {
+        self.anchors(subject).iter().map(|a| &a.cert)
+    }
+
+    fn anchors(&self, subject: &Name<'a>) -> &[TrustAnchor<'a, B>] {
         self.by_subject
-            .get(subject)
+            .get(&CanonicalName::new(subject.unwrap_read()))
             .map(|v| v.as_slice())
             .unwrap_or_default()
     }
@@ -47,6 +124,7 @@ mod tests {
     use crate::certificate::tests::PublicKeyErrorOps;
     use crate::ops::tests::{cert, v1_cert_pem};
     use crate::VerificationCertificate;
+    use cryptography_x509::oid::{EKU_CLIENT_AUTH_OID, EKU_SERVER_AUTH_OID};
 
     #[test]
     fn test_store() {
@@ -56,5 +134,23 @@ mod tests {
         let store = Store::<'_, PublicKeyErrorOps>::new([cert1]);
 
         assert!(store.contains(&cert2));
+        assert!(store.trusts_for(&cert2, &EKU_SERVER_AUTH_OID));
+        assert!(store.purposes_for(&cert2).is_none());
+    }
+
+    #[test]
+    fn test_store_with_purposes() {
+        let cert_pem = v1_cert_pem();
+        let cert1 = VerificationCertificate::new(cert(&cert_pem), ());
+        let cert2 = VerificationCertificate::new(cert(&cert_pem), ());
+        let store = Store::<'_, PublicKeyErrorOps>::new_with_purposes([(
+            cert1,
+            Some(vec![EKU_CLIENT_AUTH_OID]),
+        )]);
+
+        assert!(store.contains(&cert2));
+        assert!(store.trusts_for(&cert2, &EKU_CLIENT_AUTH_OID));
+        assert!(!store.trusts_for(&cert2, &EKU_SERVER_AUTH_OID));
+        assert_eq!(store.purposes_for(&cert2), Some(vec![EKU_CLIENT_AUTH_OID]));
     }
 }
@@ -66,3 +66,47 @@ pub struct Validity {
     pub not_before: common::Time,
     pub not_after: common::Time,
 }
+
+/// A certificate decoded only as far as is needed to identify it. Every field
+/// borrows from the input and decoding never allocates, which makes this
+/// suitable for scanning large numbers of certificates. Fields that aren't
+/// needed for that are left as unparsed TLVs.
+#[derive(asn1::Asn1Read)]
+pub struct CertificateView<'a> {
+    pub tbs_cert: TbsCertificateView<'a>,
+    pub signature_alg: asn1::Tlv<'a>,
+    pub signature: asn1::BitString<'a>,
+}
+
+impl CertificateView<'_> {
+    /// Returns the certificate's issuer.
+    pub fn issuer(&self) -> &NameReadable<'_> {
+        &self.tbs_cert.issuer
+    }
+
+    /// Returns the certificate's subject.
+    pub fn subject(&self) -> &NameReadable<'_> {
+        &self.tbs_cert.subject
+    }
+}
+
+#[derive(asn1::Asn1Read)]
+pub struct TbsCertificateView<'a> {
+    #[explicit(0)]
+    #[default(0)]
+    pub version: u8,
+    pub serial: asn1::BigInt<'a>,
+    pub signature_alg: asn1::Tlv<'a>,
+
+    pub issuer: common::WithTlv<'a, NameReadable<'a>>,
+    pub validity: Validity,
+    pub subject: common::WithTlv<'a, NameReadable<'a>>,
+
+    pub spki: asn1::Tlv<'a>,
+    #[implicit(1)]
+    pub issuer_unique_id: Option<asn1::BitString<'a>>,
+    #[implicit(2)]
+    pub subject_unique_id: Option<asn1::BitString<'a>>,
+    #[explicit(3)]
+    pub raw_extensions: Option<asn1::Tlv<'a>>,
+}
@@ -108,6 +108,15 @@ pub enum AlgorithmParameters<'a> {
     #[defined_by(oid::RSASSA_PSS_OID)]
     RsaPss(Option<Box<RsaPssParameters<'a>>>),
 
+    // Like RSASSA-PSS, the parameters are required in an RSAES-OAEP
+    // AlgorithmIdentifier, except in an SPKI, where their absence means the
+    // key isn't restricted to any particular OAEP parameters (RFC 4055 section
+    // 4.1).
+    #[defined_by(oid::RSAES_OAEP_OID)]
+    RsaesOaep(Option<Box<RsaesOaepParameters<'a>>>),
+    #[defined_by(oid::PSPECIFIED_OID)]
+    PSpecified(&'a [u8]),
+
     #[defined_by(oid::DSA_OID)]
     Dsa(DssParams<'a>),
 
@@ -133,6 +142,13 @@ pub enum AlgorithmParameters<'a> {
     #[defined_by(oid::AES_256_CBC_OID)]
     Aes256Cbc([u8; 16]),
 
+    #[defined_by(oid::AES_128_GCM_OID)]
+    Aes128Gcm(GcmParameters<'a>),
+    #[defined_by(oid::AES_192_GCM_OID)]
+    Aes192Gcm(GcmParameters<'a>),
+    #[defined_by(oid::AES_256_GCM_OID)]
+    Aes256Gcm(GcmParameters<'a>),
+
     #[default]
     Other(asn1::ObjectIdentifier, Option<asn1::Tlv<'a>>),
 }
@@ -200,6 +216,96 @@ impl Time {
     }
 }
 
+/// A `GeneralizedTime` that may have fractional seconds. RFC 5280 forbids
+/// these in certificates and CRLs, which is all `asn1::GeneralizedTime`
+/// accepts, but RFC 6960 places no such restriction on OCSP responses.
+///
+/// As required by DER (X.690 section 11.7), the fraction must not have
+/// trailing zeros, so that the encoding round-trips exactly.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct FractionalGeneralizedTime {
+    datetime: asn1::DateTime,
+    nanoseconds: Option<u32>,
+}
+
+impl FractionalGeneralizedTime {
+    pub fn new(
+        datetime: asn1::DateTime,
+        nanoseconds: Option<u32>,
+    ) -> asn1::ParseResult<FractionalGeneralizedTime> {
+        if let Some(ns) = nanoseconds {
+            if ns == 0 || ns >= 1_000_000_000 {
+                return Err(asn1::ParseError::new(asn1::ParseErrorKind::InvalidValue));
+            }
+        }
+        Ok(FractionalGeneralizedTime {
+            datetime,
+            nanoseconds,
+        })
+    }
+
+    pub fn as_datetime(&self) -> &asn1::DateTime {
+        &self.datetime
+    }
+
+    pub fn nanoseconds(&self) -> Option<u32> {
+        self.nanoseconds
+    }
+}
+
+impl asn1::SimpleAsn1Readable<'_> for FractionalGeneralizedTime {
+    const TAG: asn1::Tag = asn1::GeneralizedTime::TAG;
+    fn parse_data(data: &[u8]) -> asn1::ParseResult<FractionalGeneralizedTime> {
+        let invalid = || asn1::ParseError::new(asn1::ParseErrorKind::InvalidValue);
+
+        // Everything but the fraction is validated by `asn1::GeneralizedTime`.
+        let (Some(b'.'), Some(b'Z')) = (data.get(14), data.last()) else {
+            let time = asn1::GeneralizedTime::parse_data(data)?;
+            return FractionalGeneralizedTime::new(time.as_datetime().clone(), None);
+        };
+        let whole = [&data[..14], b"Z"].concat();
+        let time = asn1::GeneralizedTime::parse_data(&whole)?;
+
+        let fraction = &data[15..data.len() - 1];
+        if fraction.is_empty()
+            || fraction.len() > 9
+            || fraction.last() == Some(&b'0')
+            || !fraction.iter().all(u8::is_ascii_digit)
+        {
+            return Err(invalid());
+        }
+        let nanoseconds = fraction
+            .iter()
+            .chain(std::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |acc, d| acc * 10 + u32::from(d - b'0'));
+        FractionalGeneralizedTime::new(time.as_datetime().clone(), Some(nanoseconds))
+    }
+}
+
+impl asn1::SimpleAsn1Writable for FractionalGeneralizedTime {
+    const TAG: asn1::Tag = asn1::GeneralizedTime::TAG;
+    fn write_data(&self, dest: &mut asn1::WriteBuf) -> asn1::WriteResult {
+        let dt = &self.datetime;
+        let time = format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            dt.year(),
+            dt.month(),
+            dt.day(),
+            dt.hour(),
+            dt.minute(),
+            dt.second()
+        );
+        dest.push_slice(time.as_bytes())?;
+        if let Some(ns) = self.nanoseconds {
+            let fraction = format!("{ns:09}");
+            dest.push_slice(b".")?;
+            dest.push_slice(fraction.trim_end_matches('0').as_bytes())?;
+        }
+        dest.push_slice(b"Z")
+    }
+}
+
 #[derive(Hash, PartialEq, Eq, Clone)]
 pub enum Asn1ReadableOrWritable<T, U> {
     Read(T),
@@ -250,13 +356,6 @@ pub struct DssSignature<'a> {
     pub s: asn1::BigUint<'a>,
 }
 
-#[derive(asn1::Asn1Read, asn1::Asn1Write)]
-pub struct DHParams<'a> {
-    pub p: asn1::BigUint<'a>,
-    pub g: asn1::BigUint<'a>,
-    pub q: Option<asn1::BigUint<'a>>,
-}
-
 // From PKCS#3 Section 9
 // DHParameter ::= SEQUENCE {
 //     prime INTEGER, -- p
@@ -341,6 +440,12 @@ pub const PSS_SHA512_MASK_GEN_ALG: MaskGenAlgorithm<'_> = MaskGenAlgorithm {
     params: PSS_SHA512_HASH_ALG,
 };
 
+// RSAES-OAEP ASN.1 default pSourceFunc: an empty label.
+pub const OAEP_PSPECIFIED_EMPTY_ALG: AlgorithmIdentifier<'_> = AlgorithmIdentifier {
+    oid: asn1::DefinedByMarker::marker(),
+    params: AlgorithmParameters::PSpecified(b""),
+};
+
 // From RFC 5480 section 2.1.1:
 // ECParameters ::= CHOICE {
 //     namedCurve         OBJECT IDENTIFIER
@@ -389,6 +494,27 @@ pub struct RsaPssParameters<'a> {
     pub _trailer_field: Option<u8>,
 }
 
+// From RFC 4055 section 4.1:
+// RSAES-OAEP-params  ::=  SEQUENCE  {
+//     hashFunc          [0] AlgorithmIdentifier DEFAULT
+//                              sha1Identifier,
+//     maskGenFunc       [1] AlgorithmIdentifier DEFAULT
+//                              mgf1SHA1Identifier,
+//     pSourceFunc       [2] AlgorithmIdentifier DEFAULT
+//                              pSpecifiedEmptyIdentifier  }
+#[derive(asn1::Asn1Read, asn1::Asn1Write, Hash, Clone, PartialEq, Eq, Debug)]
+pub struct RsaesOaepParameters<'a> {
+    #[explicit(0)]
+    #[default(PSS_SHA1_HASH_ALG)]
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    #[explicit(1)]
+    #[default(PSS_SHA1_MASK_GEN_ALG)]
+    pub mask_gen_algorithm: MaskGenAlgorithm<'a>,
+    #[explicit(2)]
+    #[default(OAEP_PSPECIFIED_EMPTY_ALG)]
+    pub p_source_algorithm: AlgorithmIdentifier<'a>,
+}
+
 // https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.2
 //
 // Dss-Parms ::= SEQUENCE  {
@@ -403,6 +529,17 @@ pub struct DssParams<'a> {
     pub g: asn1::BigUint<'a>,
 }
 
+// From RFC 5084 section 3.2:
+// GCMParameters ::= SEQUENCE {
+//     aes-nonce        OCTET STRING, -- recommended size is 12 octets
+//     aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+#[derive(asn1::Asn1Read, asn1::Asn1Write, Hash, Clone, PartialEq, Eq, Debug)]
+pub struct GcmParameters<'a> {
+    pub nonce: &'a [u8],
+    #[default(12u8)]
+    pub icv_len: u8,
+}
+
 /// A VisibleString ASN.1 element whose contents is not validated as meeting the
 /// requirements (visible characters of IA5), and instead is only known to be
 /// valid UTF-8.
@@ -431,6 +568,58 @@ impl<'a> asn1::SimpleAsn1Writable for UnvalidatedVisibleString<'a> {
     }
 }
 
+/// A TeletexString (T61String) ASN.1 element. There is no well-defined mapping
+/// from the T.61 character set to Unicode, so its contents is kept as the raw
+/// bytes.
+pub struct RawTeletexString<'a>(pub &'a [u8]);
+
+impl<'a> RawTeletexString<'a> {
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a> asn1::SimpleAsn1Readable<'a> for RawTeletexString<'a> {
+    const TAG: asn1::Tag = asn1::Tag::primitive(0x14);
+    fn parse_data(data: &'a [u8]) -> asn1::ParseResult<Self> {
+        Ok(RawTeletexString(data))
+    }
+}
+
+impl<'a> asn1::SimpleAsn1Writable for RawTeletexString<'a> {
+    const TAG: asn1::Tag = asn1::Tag::primitive(0x14);
+    fn write_data(&self, dest: &mut asn1::WriteBuf) -> asn1::WriteResult {
+        dest.push_slice(self.0)
+    }
+}
+
+/// A GeneralString ASN.1 element whose contents is not validated against any
+/// particular character set, and instead is only known to be valid UTF-8.
+pub struct UnvalidatedGeneralString<'a>(pub &'a str);
+
+impl<'a> UnvalidatedGeneralString<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> asn1::SimpleAsn1Readable<'a> for UnvalidatedGeneralString<'a> {
+    const TAG: asn1::Tag = asn1::Tag::primitive(0x1b);
+    fn parse_data(data: &'a [u8]) -> asn1::ParseResult<Self> {
+        Ok(UnvalidatedGeneralString(
+            std::str::from_utf8(data)
+                .map_err(|_| asn1::ParseError::new(asn1::ParseErrorKind::InvalidValue))?,
+        ))
+    }
+}
+
+impl<'a> asn1::SimpleAsn1Writable for UnvalidatedGeneralString<'a> {
+    const TAG: asn1::Tag = asn1::Tag::primitive(0x1b);
+    fn write_data(&self, dest: &mut asn1::WriteBuf) -> asn1::WriteResult {
+        dest.push_slice(self.0.as_bytes())
+    }
+}
+
 /// A BMPString ASN.1 element, where it is stored as a UTF-8 string in memory.
 pub struct Utf8StoredBMPString<'a>(pub &'a str);
 
@@ -506,7 +695,10 @@ impl<T: std::hash::Hash> std::hash::Hash for WithTlv<'_, T> {
 mod tests {
     use asn1::Asn1Readable;
 
-    use super::{Asn1ReadableOrWritable, RawTlv, UnvalidatedVisibleString, WithTlv};
+    use super::{
+        Asn1ReadableOrWritable, FractionalGeneralizedTime, RawTlv, UnvalidatedVisibleString,
+        WithTlv,
+    };
 
     #[test]
     #[should_panic]
@@ -540,4 +732,37 @@ mod tests {
         assert!(WithTlv::<asn1::Sequence<'_>>::can_parse(t));
         assert!(!WithTlv::<bool>::can_parse(t));
     }
+
+    #[test]
+    fn test_fractional_generalized_time() {
+        let dt = asn1::DateTime::new(2024, 1, 2, 3, 4, 5).unwrap();
+        for (der, nanoseconds) in [
+            (&b"\x18\x0f20240102030405Z"[..], None),
+            (b"\x18\x1120240102030405.5Z", Some(500_000_000)),
+            (b"\x18\x1320240102030405.025Z", Some(25_000_000)),
+            (b"\x18\x1920240102030405.123456789Z", Some(123_456_789)),
+        ] {
+            let t = asn1::parse_single::<FractionalGeneralizedTime>(der).unwrap();
+            assert_eq!(t.as_datetime(), &dt);
+            assert_eq!(t.nanoseconds(), nanoseconds);
+            assert_eq!(asn1::write_single(&t).unwrap(), der);
+        }
+
+        for der in [
+            // Not DER: trailing zeros, or no digits after the dot.
+            &b"\x18\x1220240102030405.50Z"[..],
+            b"\x18\x1020240102030405.Z",
+            // More precision than nanoseconds.
+            b"\x18\x1a20240102030405.1234567891Z",
+            b"\x18\x1120240102030405.aZ",
+            b"\x18\x1120240102030405,5Z",
+            b"\x18\x1120240102030405.5+",
+            b"\x18\x1120240132030405.5Z",
+        ] {
+            assert!(asn1::parse_single::<FractionalGeneralizedTime>(der).is_err());
+        }
+
+        assert!(FractionalGeneralizedTime::new(dt.clone(), Some(0)).is_err());
+        assert!(FractionalGeneralizedTime::new(dt, Some(1_000_000_000)).is_err());
+    }
 }
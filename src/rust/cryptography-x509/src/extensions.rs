@@ -88,6 +88,12 @@ pub struct PolicyConstraints {
     pub inhibit_policy_mapping: Option<u64>,
 }
 
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct PolicyMapping {
+    pub issuer_domain_policy: asn1::ObjectIdentifier,
+    pub subject_domain_policy: asn1::ObjectIdentifier,
+}
+
 #[derive(asn1::Asn1Read, asn1::Asn1Write)]
 pub struct AccessDescription<'a> {
     pub access_method: asn1::ObjectIdentifier,
@@ -183,6 +189,90 @@ pub struct MSCertificateTemplate {
     pub minor_version: Option<u32>,
 }
 
+// DirectoryString ::= CHOICE {
+//     teletexString   TeletexString (SIZE (1..MAX)),
+//     printableString PrintableString (SIZE (1..MAX)),
+//     universalString UniversalString (SIZE (1..MAX)),
+//     utf8String      UTF8String (SIZE (1..MAX)),
+//     bmpString       BMPString (SIZE (1..MAX)) }
+#[allow(clippy::enum_variant_names)]
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub enum DirectoryString<'a> {
+    TeletexString(common::RawTeletexString<'a>),
+    PrintableString(asn1::PrintableString<'a>),
+    UniversalString(asn1::UniversalString<'a>),
+    Utf8String(asn1::Utf8String<'a>),
+    BmpString(asn1::BMPString<'a>),
+}
+
+// From the Common PKI (formerly ISIS-MTT) specification, part 4, section
+// 3.5.2:
+//
+// NamingAuthority ::= SEQUENCE {
+//     namingAuthorityId   OBJECT IDENTIFIER OPTIONAL,
+//     namingAuthorityUrl  IA5String OPTIONAL,
+//     namingAuthorityText DirectoryString(SIZE(1..128)) OPTIONAL }
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct NamingAuthority<'a> {
+    pub id: Option<asn1::ObjectIdentifier>,
+    pub url: Option<asn1::IA5String<'a>>,
+    pub text: Option<DirectoryString<'a>>,
+}
+
+pub type SequenceOfDirectoryStrings<'a> = common::Asn1ReadableOrWritable<
+    asn1::SequenceOf<'a, DirectoryString<'a>>,
+    asn1::SequenceOfWriter<'a, DirectoryString<'a>, Vec<DirectoryString<'a>>>,
+>;
+
+pub type SequenceOfObjectIdentifiers<'a> = common::Asn1ReadableOrWritable<
+    asn1::SequenceOf<'a, asn1::ObjectIdentifier>,
+    asn1::SequenceOfWriter<'a, asn1::ObjectIdentifier, Vec<asn1::ObjectIdentifier>>,
+>;
+
+// ProfessionInfo ::= SEQUENCE {
+//     namingAuthority    [0] EXPLICIT NamingAuthority OPTIONAL,
+//     professionItems    SEQUENCE OF DirectoryString (SIZE(1..128)),
+//     professionOIDs     SEQUENCE OF OBJECT IDENTIFIER OPTIONAL,
+//     registrationNumber PrintableString(SIZE(1..128)) OPTIONAL,
+//     addProfessionInfo  OCTET STRING OPTIONAL }
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct ProfessionInfo<'a> {
+    #[explicit(0)]
+    pub naming_authority: Option<NamingAuthority<'a>>,
+    pub profession_items: SequenceOfDirectoryStrings<'a>,
+    pub profession_oids: Option<SequenceOfObjectIdentifiers<'a>>,
+    pub registration_number: Option<asn1::PrintableString<'a>>,
+    pub add_profession_info: Option<&'a [u8]>,
+}
+
+// Admissions ::= SEQUENCE {
+//     admissionAuthority [0] EXPLICIT GeneralName OPTIONAL,
+//     namingAuthority    [1] EXPLICIT NamingAuthority OPTIONAL,
+//     professionInfos    SEQUENCE OF ProfessionInfo }
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct Admission<'a> {
+    #[explicit(0)]
+    pub admission_authority: Option<name::GeneralName<'a>>,
+    #[explicit(1)]
+    pub naming_authority: Option<NamingAuthority<'a>>,
+    pub profession_infos: common::Asn1ReadableOrWritable<
+        asn1::SequenceOf<'a, ProfessionInfo<'a>>,
+        asn1::SequenceOfWriter<'a, ProfessionInfo<'a>, Vec<ProfessionInfo<'a>>>,
+    >,
+}
+
+// AdmissionSyntax ::= SEQUENCE {
+//     admissionAuthority   GeneralName OPTIONAL,
+//     contentsOfAdmissions SEQUENCE OF Admissions }
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct Admissions<'a> {
+    pub admission_authority: Option<name::GeneralName<'a>>,
+    pub contents_of_admissions: common::Asn1ReadableOrWritable<
+        asn1::SequenceOf<'a, Admission<'a>>,
+        asn1::SequenceOfWriter<'a, Admission<'a>, Vec<Admission<'a>>>,
+    >,
+}
+
 #[derive(asn1::Asn1Read, asn1::Asn1Write)]
 pub struct DistributionPoint<'a> {
     #[explicit(0)]
@@ -233,9 +323,16 @@ pub struct BasicConstraints {
 pub type SubjectAlternativeName<'a> = asn1::SequenceOf<'a, name::GeneralName<'a>>;
 pub type IssuerAlternativeName<'a> = asn1::SequenceOf<'a, name::GeneralName<'a>>;
 pub type ExtendedKeyUsage<'a> = asn1::SequenceOf<'a, asn1::ObjectIdentifier>;
+pub type TlsFeature<'a> = asn1::SequenceOf<'a, u64>;
 
 pub struct KeyUsage<'a>(asn1::BitString<'a>);
 
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct EntrustVersionInfo<'a> {
+    pub entrust_vers: common::UnvalidatedGeneralString<'a>,
+    pub entrust_info_flags: Option<asn1::BitString<'a>>,
+}
+
 impl<'a> asn1::SimpleAsn1Readable<'a> for KeyUsage<'a> {
     const TAG: asn1::Tag = asn1::BitString::TAG;
 
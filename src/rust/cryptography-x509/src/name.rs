@@ -61,7 +61,6 @@ pub enum GeneralName<'a> {
     DNSName(UnvalidatedIA5String<'a>),
 
     #[implicit(3)]
-    // unsupported
     X400Address(asn1::Sequence<'a>),
 
     // Name is explicit per RFC 5280 Appendix A.1.
@@ -6,7 +6,10 @@ use crate::{certificate, common, csr, name};
 
 pub const PKCS7_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 1);
 pub const PKCS7_SIGNED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 2);
+pub const PKCS7_ENVELOPED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 3);
 pub const PKCS7_ENCRYPTED_DATA_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 7, 6);
+pub const PKCS7_AUTH_ENVELOPED_DATA_OID: asn1::ObjectIdentifier =
+    asn1::oid!(1, 2, 840, 113549, 1, 9, 16, 1, 23);
 
 #[derive(asn1::Asn1Write)]
 pub struct ContentInfo<'a> {
@@ -39,7 +42,21 @@ pub struct SignedData<'a> {
     pub signer_infos: asn1::SetOfWriter<'a, SignerInfo<'a>>,
 }
 
-#[derive(asn1::Asn1Write)]
+// The parts of a SignedData needed to verify its signatures. Certificates
+// and CRLs are left unparsed.
+#[derive(asn1::Asn1Read)]
+pub struct RawSignedData<'a> {
+    pub version: u8,
+    pub digest_algorithms: asn1::SetOf<'a, common::AlgorithmIdentifier<'a>>,
+    pub content_info: RawContentInfo<'a>,
+    #[implicit(0)]
+    pub certificates: Option<asn1::Sequence<'a>>,
+    #[implicit(1)]
+    pub crls: Option<asn1::Sequence<'a>>,
+    pub signer_infos: asn1::SetOf<'a, SignerInfo<'a>>,
+}
+
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
 pub struct SignerInfo<'a> {
     pub version: u8,
     pub issuer_and_serial_number: IssuerAndSerialNumber<'a>,
@@ -54,12 +71,74 @@ pub struct SignerInfo<'a> {
     pub unauthenticated_attributes: Option<csr::Attributes<'a>>,
 }
 
-#[derive(asn1::Asn1Write)]
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
 pub struct IssuerAndSerialNumber<'a> {
     pub issuer: name::Name<'a>,
     pub serial_number: asn1::BigInt<'a>,
 }
 
+// A ContentInfo whose content is left unparsed, for use when the content
+// type isn't known ahead of time.
+#[derive(asn1::Asn1Read)]
+pub struct RawContentInfo<'a> {
+    pub content_type: asn1::ObjectIdentifier,
+    #[explicit(0)]
+    pub content: Option<asn1::Tlv<'a>>,
+}
+
+// From RFC 5083 section 2.1
+#[derive(asn1::Asn1Read)]
+pub struct AuthEnvelopedData<'a> {
+    pub version: u8,
+    #[implicit(0)]
+    pub originator_info: Option<asn1::Sequence<'a>>,
+    pub recipient_infos: asn1::SetOf<'a, RecipientInfo<'a>>,
+    pub auth_encrypted_content_info: EncryptedContentInfo<'a>,
+    #[implicit(1)]
+    pub auth_attrs: Option<csr::Attributes<'a>>,
+    pub mac: &'a [u8],
+    #[implicit(2)]
+    pub unauth_attrs: Option<csr::Attributes<'a>>,
+}
+
+// From RFC 5652 section 6.2. Only key transport recipients are parsed; the
+// other kinds are kept opaque.
+#[derive(asn1::Asn1Read)]
+pub enum RecipientInfo<'a> {
+    KeyTrans(KeyTransRecipientInfo<'a>),
+    #[implicit(1)]
+    KeyAgree(asn1::Sequence<'a>),
+    #[implicit(2)]
+    Kek(asn1::Sequence<'a>),
+    #[implicit(3)]
+    Password(asn1::Sequence<'a>),
+    #[implicit(4)]
+    Other(asn1::Sequence<'a>),
+}
+
+#[derive(asn1::Asn1Read)]
+pub struct KeyTransRecipientInfo<'a> {
+    pub version: u8,
+    pub rid: RecipientIdentifier<'a>,
+    pub key_encryption_algorithm: common::AlgorithmIdentifier<'a>,
+    pub encrypted_key: &'a [u8],
+}
+
+#[derive(asn1::Asn1Read)]
+pub enum RecipientIdentifier<'a> {
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+    #[implicit(0)]
+    SubjectKeyIdentifier(&'a [u8]),
+}
+
+#[derive(asn1::Asn1Read)]
+pub struct EncryptedContentInfo<'a> {
+    pub content_type: asn1::ObjectIdentifier,
+    pub content_encryption_algorithm: common::AlgorithmIdentifier<'a>,
+    #[implicit(0)]
+    pub encrypted_content: Option<&'a [u8]>,
+}
+
 #[derive(asn1::Asn1Write)]
 pub struct DigestInfo<'a> {
     pub algorithm: common::AlgorithmIdentifier<'a>,
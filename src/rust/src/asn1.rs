@@ -104,11 +104,7 @@ pub(crate) fn encode_der_data<'p>(
     } else if encoding.is(&types::ENCODING_PEM.get(py)?) {
         Ok(pyo3::types::PyBytes::new_bound(
             py,
-            &pem::encode_config(
-                &pem::Pem::new(pem_tag, data),
-                pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
-            )
-            .into_bytes(),
+            pem_encode(pem_tag, data).as_bytes(),
         ))
     } else {
         Err(
@@ -118,6 +114,59 @@ pub(crate) fn encode_der_data<'p>(
     }
 }
 
+pub(crate) fn pem_encode(pem_tag: String, data: Vec<u8>) -> String {
+    pem::encode_config(
+        &pem::Pem::new(pem_tag, data),
+        pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+    )
+}
+
+// Labels are defined in RFC 7468 (section 3): printable ASCII, with single
+// hyphens or spaces allowed between other characters.
+fn is_valid_pem_label(label: &str) -> bool {
+    let is_label_char = |c: u8| (0x21..=0x7e).contains(&c) && c != b'-';
+    let bytes = label.as_bytes();
+    match (bytes.first(), bytes.last()) {
+        (Some(&first), Some(&last)) if is_label_char(first) && is_label_char(last) => {}
+        _ => return false,
+    }
+    bytes
+        .iter()
+        .all(|&c| is_label_char(c) || c == b'-' || c == b' ')
+        && !bytes
+            .windows(2)
+            .any(|w| !is_label_char(w[0]) && !is_label_char(w[1]))
+}
+
+#[pyo3::pyfunction]
+fn der_to_pem<'p>(
+    py: pyo3::Python<'p>,
+    label: &str,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    if !is_valid_pem_label(label) {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!("Invalid PEM label: {label:?}")),
+        ));
+    }
+    Ok(pyo3::types::PyBytes::new_bound(
+        py,
+        pem_encode(label.to_string(), data.to_vec()).as_bytes(),
+    ))
+}
+
+#[pyo3::pyfunction]
+fn pem_to_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<(String, pyo3::Bound<'p, pyo3::types::PyBytes>)> {
+    let parsed = pem::parse(data)?;
+    Ok((
+        parsed.tag().to_string(),
+        pyo3::types::PyBytes::new_bound(py, parsed.contents()),
+    ))
+}
+
 #[pyo3::pyfunction]
 fn encode_dss_signature<'p>(
     py: pyo3::Python<'p>,
@@ -149,10 +198,9 @@ struct TestCertificate {
 fn parse_name_value_tags(rdns: &Name<'_>) -> Vec<u8> {
     let mut tags = vec![];
     for rdn in rdns.unwrap_read().clone() {
-        let mut attributes = rdn.collect::<Vec<_>>();
-        assert_eq!(attributes.len(), 1);
-
-        tags.push(attributes.pop().unwrap().value.tag().as_u8().unwrap());
+        for attribute in rdn {
+            tags.push(attribute.value.tag().as_u8().unwrap());
+        }
     }
     tags
 }
@@ -176,6 +224,49 @@ fn test_parse_certificate(data: &[u8]) -> Result<TestCertificate, CryptographyEr
     })
 }
 
+const TEST_COMMON_NAME_OID: asn1::ObjectIdentifier = asn1::oid!(2, 5, 4, 3);
+
+// Builds a self-signed Ed25519 certificate whose key is derived from `seed`
+// and whose serial number, subject and validity period are fixed. Ed25519
+// signatures are deterministic, so the same seed always produces the same
+// DER.
+#[pyo3::pyfunction]
+fn test_generate_certificate(
+    py: pyo3::Python<'_>,
+    seed: &[u8],
+) -> CryptographyResult<crate::x509::certificate::Certificate> {
+    let pkey = openssl::pkey::PKey::private_key_from_raw_bytes(
+        &openssl::sha::sha256(seed),
+        openssl::pkey::Id::ED25519,
+    )?;
+    let private_key = pyo3::Bound::new(py, crate::backend::ed25519::private_key_from_pkey(&pkey))?;
+
+    let common_name = types::NAME_ATTRIBUTE.get(py)?.call1((
+        crate::oid::ObjectIdentifier {
+            oid: TEST_COMMON_NAME_OID,
+        },
+        "cryptography.io",
+    ))?;
+    let subject_name = types::NAME.get(py)?.call1(([common_name],))?;
+    let serial_number = 1u8.to_object(py).into_bound(py);
+    let not_valid_before =
+        crate::x509::common::datetime_to_py_utc(py, &asn1::DateTime::new(2020, 1, 1, 0, 0, 0)?)?;
+    let not_valid_after =
+        crate::x509::common::datetime_to_py_utc(py, &asn1::DateTime::new(2070, 1, 1, 0, 0, 0)?)?;
+
+    crate::x509::certificate::create_self_signed_x509_certificate(
+        py,
+        &subject_name,
+        private_key.as_any(),
+        serial_number.downcast::<pyo3::types::PyLong>()?.clone(),
+        &not_valid_before,
+        &not_valid_after,
+        pyo3::types::PyList::empty_bound(py).as_any(),
+        &py.None().into_bound(py),
+        &py.None().into_bound(py),
+    )
+}
+
 pub(crate) fn create_submodule(
     py: pyo3::Python<'_>,
 ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyModule>> {
@@ -185,10 +276,17 @@ pub(crate) fn create_submodule(
     submod.add_function(pyo3::wrap_pyfunction_bound!(decode_dss_signature, &submod)?)?;
     submod.add_function(pyo3::wrap_pyfunction_bound!(encode_dss_signature, &submod)?)?;
 
+    submod.add_function(pyo3::wrap_pyfunction_bound!(der_to_pem, &submod)?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(pem_to_der, &submod)?)?;
+
     submod.add_function(pyo3::wrap_pyfunction_bound!(
         test_parse_certificate,
         &submod
     )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        test_generate_certificate,
+        &submod
+    )?)?;
 
     Ok(submod)
 }
@@ -137,7 +137,7 @@ impl EvpCipherAead {
         ctx.copy(&self.base_encryption_ctx)?;
         Self::encrypt_with_context(
             py,
-            ctx,
+            &mut ctx,
             plaintext,
             aad,
             nonce,
@@ -150,7 +150,7 @@ impl EvpCipherAead {
     #[allow(clippy::too_many_arguments)]
     fn encrypt_with_context<'p>(
         py: pyo3::Python<'p>,
-        mut ctx: openssl::cipher_ctx::CipherCtx,
+        ctx: &mut openssl::cipher_ctx::CipherCtx,
         plaintext: &[u8],
         aad: Option<Aad<'_>>,
         nonce: Option<&[u8]>,
@@ -170,7 +170,7 @@ impl EvpCipherAead {
             ctx.set_data_len(plaintext.len())?;
         }
 
-        Self::process_aad(&mut ctx, aad)?;
+        Self::process_aad(ctx, aad)?;
 
         Ok(pyo3::types::PyBytes::new_bound_with(
             py,
@@ -184,7 +184,7 @@ impl EvpCipherAead {
                     (ciphertext, tag) = b.split_at_mut(plaintext.len());
                 }
 
-                Self::process_data(&mut ctx, plaintext, ciphertext, is_ccm)?;
+                Self::process_data(ctx, plaintext, ciphertext, is_ccm)?;
 
                 ctx.tag(tag).map_err(CryptographyError::from)?;
 
@@ -204,7 +204,7 @@ impl EvpCipherAead {
         ctx.copy(&self.base_decryption_ctx)?;
         Self::decrypt_with_context(
             py,
-            ctx,
+            &mut ctx,
             ciphertext,
             aad,
             nonce,
@@ -217,7 +217,7 @@ impl EvpCipherAead {
     #[allow(clippy::too_many_arguments)]
     fn decrypt_with_context<'p>(
         py: pyo3::Python<'p>,
-        mut ctx: openssl::cipher_ctx::CipherCtx,
+        ctx: &mut openssl::cipher_ctx::CipherCtx,
         ciphertext: &[u8],
         aad: Option<Aad<'_>>,
         nonce: Option<&[u8]>,
@@ -252,13 +252,13 @@ impl EvpCipherAead {
             ctx.set_data_len(ciphertext_data.len())?;
         }
 
-        Self::process_aad(&mut ctx, aad)?;
+        Self::process_aad(ctx, aad)?;
 
         Ok(pyo3::types::PyBytes::new_bound_with(
             py,
             ciphertext_data.len(),
             |b| {
-                Self::process_data(&mut ctx, ciphertext_data, b, is_ccm)
+                Self::process_data(ctx, ciphertext_data, b, is_ccm)
                     .map_err(|_| exceptions::InvalidTag::new_err(()))?;
 
                 Ok(())
@@ -315,7 +315,7 @@ impl LazyEvpCipherAead {
 
         EvpCipherAead::encrypt_with_context(
             py,
-            encryption_ctx,
+            &mut encryption_ctx,
             plaintext,
             aad,
             nonce,
@@ -353,7 +353,7 @@ impl LazyEvpCipherAead {
 
         EvpCipherAead::decrypt_with_context(
             py,
-            decryption_ctx,
+            &mut decryption_ctx,
             ciphertext,
             aad,
             nonce,
@@ -600,25 +600,23 @@ struct AesGcm {
         not(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER),
     )))]
     ctx: LazyEvpCipherAead,
+
+    min_nonce_length: usize,
 }
 
 #[pyo3::pymethods]
 impl AesGcm {
     #[new]
-    fn new(py: pyo3::Python<'_>, key: pyo3::Py<pyo3::PyAny>) -> CryptographyResult<AesGcm> {
+    #[pyo3(signature = (key, *, min_nonce_length=8))]
+    fn new(
+        py: pyo3::Python<'_>,
+        key: pyo3::Py<pyo3::PyAny>,
+        min_nonce_length: usize,
+    ) -> CryptographyResult<AesGcm> {
+        check_min_nonce_length(min_nonce_length)?;
+
         let key_buf = key.extract::<CffiBuf<'_>>(py)?;
-        let cipher = match key_buf.as_bytes().len() {
-            16 => openssl::cipher::Cipher::aes_128_gcm(),
-            24 => openssl::cipher::Cipher::aes_192_gcm(),
-            32 => openssl::cipher::Cipher::aes_256_gcm(),
-            _ => {
-                return Err(CryptographyError::from(
-                    pyo3::exceptions::PyValueError::new_err(
-                        "AESGCM key must be 128, 192, or 256 bits.",
-                    ),
-                ))
-            }
-        };
+        let cipher = aes_gcm_cipher(key_buf.as_bytes())?;
 
         cfg_if::cfg_if! {
             if #[cfg(any(
@@ -629,10 +627,12 @@ impl AesGcm {
             )))] {
                 Ok(AesGcm {
                     ctx: EvpCipherAead::new(cipher, key_buf.as_bytes(), 16, false)?,
+                    min_nonce_length,
                 })
             } else {
                 Ok(AesGcm {
                     ctx: LazyEvpCipherAead::new(cipher, key, 16, false, false),
+                    min_nonce_length,
                 })
 
             }
@@ -664,11 +664,7 @@ impl AesGcm {
         let nonce_bytes = nonce.as_bytes();
         let aad = associated_data.map(Aad::Single);
 
-        if nonce_bytes.len() < 8 || nonce_bytes.len() > 128 {
-            return Err(CryptographyError::from(
-                pyo3::exceptions::PyValueError::new_err("Nonce must be between 8 and 128 bytes"),
-            ));
-        }
+        check_gcm_nonce_length(nonce_bytes, self.min_nonce_length)?;
 
         self.ctx
             .encrypt(py, data.as_bytes(), aad, Some(nonce_bytes))
@@ -685,17 +681,131 @@ impl AesGcm {
         let nonce_bytes = nonce.as_bytes();
         let aad = associated_data.map(Aad::Single);
 
-        if nonce_bytes.len() < 8 || nonce_bytes.len() > 128 {
-            return Err(CryptographyError::from(
-                pyo3::exceptions::PyValueError::new_err("Nonce must be between 8 and 128 bytes"),
-            ));
-        }
+        check_gcm_nonce_length(nonce_bytes, self.min_nonce_length)?;
 
         self.ctx
             .decrypt(py, data.as_bytes(), aad, Some(nonce_bytes))
     }
 }
 
+fn aes_gcm_cipher(key: &[u8]) -> CryptographyResult<&'static openssl::cipher::CipherRef> {
+    match key.len() {
+        16 => Ok(openssl::cipher::Cipher::aes_128_gcm()),
+        24 => Ok(openssl::cipher::Cipher::aes_192_gcm()),
+        32 => Ok(openssl::cipher::Cipher::aes_256_gcm()),
+        _ => Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("AESGCM key must be 128, 192, or 256 bits."),
+        )),
+    }
+}
+
+fn check_min_nonce_length(min_nonce_length: usize) -> CryptographyResult<()> {
+    if !(8..=128).contains(&min_nonce_length) {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("min_nonce_length must be between 8 and 128"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_gcm_nonce_length(nonce: &[u8], min_nonce_length: usize) -> CryptographyResult<()> {
+    if nonce.len() < min_nonce_length || nonce.len() > 128 {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Nonce must be between {min_nonce_length} and 128 bytes"
+            )),
+        ));
+    }
+    Ok(())
+}
+
+// Unlike `AesGcm`, which starts every operation from a fresh (or copied)
+// context, this keeps a single encryption and decryption context whose key
+// schedule is computed once. Each call only re-initializes the nonce, which
+// avoids the per-call key setup that `AesGcm` pays on OpenSSL 3.0 and 3.1.
+// Because the contexts are mutated, a single instance can't be used by
+// several threads at once.
+#[pyo3::pyclass(
+    module = "cryptography.hazmat.bindings._rust.openssl.aead",
+    name = "AESGCMContext"
+)]
+struct AesGcmContext {
+    encryption_ctx: openssl::cipher_ctx::CipherCtx,
+    decryption_ctx: openssl::cipher_ctx::CipherCtx,
+    min_nonce_length: usize,
+}
+
+#[pyo3::pymethods]
+impl AesGcmContext {
+    #[new]
+    #[pyo3(signature = (key, *, min_nonce_length=8))]
+    fn new(key: CffiBuf<'_>, min_nonce_length: usize) -> CryptographyResult<AesGcmContext> {
+        check_min_nonce_length(min_nonce_length)?;
+        let cipher = aes_gcm_cipher(key.as_bytes())?;
+
+        let mut encryption_ctx = openssl::cipher_ctx::CipherCtx::new()?;
+        encryption_ctx.encrypt_init(Some(cipher), Some(key.as_bytes()), None)?;
+        let mut decryption_ctx = openssl::cipher_ctx::CipherCtx::new()?;
+        decryption_ctx.decrypt_init(Some(cipher), Some(key.as_bytes()), None)?;
+
+        Ok(AesGcmContext {
+            encryption_ctx,
+            decryption_ctx,
+            min_nonce_length,
+        })
+    }
+
+    #[pyo3(signature = (nonce, data, associated_data))]
+    fn encrypt<'p>(
+        &mut self,
+        py: pyo3::Python<'p>,
+        nonce: CffiBuf<'_>,
+        data: CffiBuf<'_>,
+        associated_data: Option<CffiBuf<'_>>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let nonce_bytes = nonce.as_bytes();
+        let aad = associated_data.map(Aad::Single);
+
+        check_gcm_nonce_length(nonce_bytes, self.min_nonce_length)?;
+
+        EvpCipherAead::encrypt_with_context(
+            py,
+            &mut self.encryption_ctx,
+            data.as_bytes(),
+            aad,
+            Some(nonce_bytes),
+            16,
+            false,
+            false,
+        )
+    }
+
+    #[pyo3(signature = (nonce, data, associated_data))]
+    fn decrypt<'p>(
+        &mut self,
+        py: pyo3::Python<'p>,
+        nonce: CffiBuf<'_>,
+        data: CffiBuf<'_>,
+        associated_data: Option<CffiBuf<'_>>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let nonce_bytes = nonce.as_bytes();
+        let aad = associated_data.map(Aad::Single);
+
+        check_gcm_nonce_length(nonce_bytes, self.min_nonce_length)?;
+
+        EvpCipherAead::decrypt_with_context(
+            py,
+            &mut self.decryption_ctx,
+            data.as_bytes(),
+            aad,
+            Some(nonce_bytes),
+            16,
+            false,
+            false,
+        )
+    }
+}
+
 #[pyo3::pyclass(
     frozen,
     module = "cryptography.hazmat.bindings._rust.openssl.aead",
@@ -1159,6 +1269,7 @@ pub(crate) fn create_module(
     let m = pyo3::types::PyModule::new_bound(py, "aead")?;
 
     m.add_class::<AesGcm>()?;
+    m.add_class::<AesGcmContext>()?;
     m.add_class::<ChaCha20Poly1305>()?;
     m.add_class::<AesCcm>()?;
     m.add_class::<AesSiv>()?;
@@ -7,7 +7,8 @@ use crate::buf::{CffiBuf, CffiMutBuf};
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::exceptions;
 use crate::types;
-use pyo3::types::{PyAnyMethods, PyModuleMethods};
+use crate::zeroize::Zeroizing;
+use pyo3::types::{PyAnyMethods, PyBytesMethods, PyModuleMethods};
 use pyo3::IntoPy;
 
 struct CipherContext {
@@ -220,6 +221,83 @@ struct PyAEADDecryptionContext {
     updated: bool,
     bytes_remaining: u64,
     aad_bytes_remaining: u64,
+    // When present, decrypted data is accumulated here rather than returned
+    // from `update()`, and only released once the tag has been verified.
+    buffered: Option<PlaintextBuffer>,
+}
+
+// Plaintext that hasn't been authenticated yet. The buffer is grown by hand,
+// rather than with `Vec::resize`, so that every allocation that held
+// plaintext is zeroed when it's released, including the ones left behind by
+// growing it.
+struct PlaintextBuffer {
+    buf: Zeroizing<Vec<u8>>,
+    len: usize,
+}
+
+impl PlaintextBuffer {
+    fn new() -> PlaintextBuffer {
+        PlaintextBuffer {
+            buf: Zeroizing::from(Vec::new()),
+            len: 0,
+        }
+    }
+
+    // Returns space for at least `n` more bytes after the buffered data.
+    fn reserve(&mut self, n: usize) -> &mut [u8] {
+        let needed = self.len + n;
+        if needed > self.buf.len() {
+            let mut grown = Zeroizing::from(vec![0; needed.max(2 * self.buf.len())]);
+            grown[..self.len].copy_from_slice(&self.buf[..self.len]);
+            self.buf = grown;
+        }
+        &mut self.buf[self.len..]
+    }
+}
+
+impl PyAEADDecryptionContext {
+    // Returns whether `data` was decrypted into the buffer, in which case
+    // there's nothing to return to the caller yet.
+    fn buffer_update(&mut self, py: pyo3::Python<'_>, data: &[u8]) -> CryptographyResult<bool> {
+        let ctx = get_mut_ctx(self.ctx.as_mut())?;
+        let buffered = match self.buffered.as_mut() {
+            Some(b) => b,
+            None => return Ok(false),
+        };
+        let out = buffered.reserve(data.len() + ctx.ctx.block_size());
+        buffered.len += ctx.update_into(py, data, out)?;
+        Ok(true)
+    }
+
+    fn finalize_buffered<'p>(
+        &mut self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let ctx = get_mut_ctx(self.ctx.as_mut())?;
+        // Take the buffer before finalizing, so that if the tag doesn't
+        // verify the unauthenticated plaintext is zeroed and discarded along
+        // with the context rather than being retained.
+        let buffered = self.buffered.take();
+        let result = ctx.finalize(py);
+        self.ctx = None;
+        let result = result?;
+        match buffered {
+            Some(buffered) => {
+                let head = &buffered.buf[..buffered.len];
+                let tail = result.as_bytes();
+                Ok(pyo3::types::PyBytes::new_bound_with(
+                    py,
+                    head.len() + tail.len(),
+                    |b| {
+                        b[..head.len()].copy_from_slice(head);
+                        b[head.len()..].copy_from_slice(tail);
+                        Ok(())
+                    },
+                )?)
+            }
+            None => Ok(result),
+        }
+    }
 }
 
 fn get_mut_ctx(ctx: Option<&mut CipherContext>) -> pyo3::PyResult<&mut CipherContext> {
@@ -358,6 +436,9 @@ impl PyAEADDecryptionContext {
             .ok_or_else(|| {
                 pyo3::exceptions::PyValueError::new_err("Exceeded maximum encrypted byte limit")
             })?;
+        if self.buffer_update(py, data)? {
+            return Ok(pyo3::types::PyBytes::new_bound(py, b""));
+        }
         get_mut_ctx(self.ctx.as_mut())?.update(py, data)
     }
 
@@ -376,6 +457,9 @@ impl PyAEADDecryptionContext {
             .ok_or_else(|| {
                 pyo3::exceptions::PyValueError::new_err("Exceeded maximum encrypted byte limit")
             })?;
+        if self.buffer_update(py, data)? {
+            return Ok(0);
+        }
         get_mut_ctx(self.ctx.as_mut())?.update_into(py, data, out_buf.as_mut_bytes())
     }
 
@@ -416,9 +500,7 @@ impl PyAEADDecryptionContext {
             ));
         }
 
-        let result = ctx.finalize(py)?;
-        self.ctx = None;
-        Ok(result)
+        self.finalize_buffered(py)
     }
 
     fn finalize_with_tag<'p>(
@@ -464,9 +546,7 @@ impl PyAEADDecryptionContext {
         }
 
         ctx.ctx.set_tag(tag)?;
-        let result = ctx.finalize(py)?;
-        self.ctx = None;
-        Ok(result)
+        self.finalize_buffered(py)
     }
 }
 
@@ -497,10 +577,12 @@ fn create_encryption_ctx(
 }
 
 #[pyo3::pyfunction]
+#[pyo3(signature = (algorithm, mode, buffered=false))]
 fn create_decryption_ctx(
     py: pyo3::Python<'_>,
     algorithm: pyo3::Bound<'_, pyo3::PyAny>,
     mode: pyo3::Bound<'_, pyo3::PyAny>,
+    buffered: bool,
 ) -> CryptographyResult<pyo3::PyObject> {
     let mut ctx = CipherContext::new(py, algorithm, mode.clone(), openssl::symm::Mode::Decrypt)?;
 
@@ -521,8 +603,19 @@ fn create_decryption_ctx(
             aad_bytes_remaining: mode
                 .getattr(pyo3::intern!(py, "_MAX_AAD_BYTES"))?
                 .extract()?,
+            buffered: if buffered {
+                Some(PlaintextBuffer::new())
+            } else {
+                None
+            },
         }
         .into_py(py))
+    } else if buffered {
+        Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err(
+                "Buffered decryption is only supported for modes with an authentication tag.",
+            ),
+        ))
     } else {
         Ok(PyCipherContext { ctx: Some(ctx) }.into_py(py))
     }
@@ -81,9 +81,10 @@ impl Cmac {
         Ok(pyo3::types::PyBytes::new_bound(py, &data))
     }
 
-    fn verify(&mut self, py: pyo3::Python<'_>, signature: &[u8]) -> CryptographyResult<()> {
+    fn verify(&mut self, py: pyo3::Python<'_>, signature: CffiBuf<'_>) -> CryptographyResult<()> {
         let actual = self.finalize(py)?;
         let actual = actual.as_bytes();
+        let signature = signature.as_bytes();
         if actual.len() != signature.len() || !openssl::memcmp::eq(actual, signature) {
             return Err(CryptographyError::from(
                 exceptions::InvalidSignature::new_err("Signature did not match digest."),
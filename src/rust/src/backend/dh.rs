@@ -94,14 +94,23 @@ fn from_der_parameters(
     backend: Option<pyo3::Bound<'_, pyo3::PyAny>>,
 ) -> CryptographyResult<DHParameters> {
     let _ = backend;
-    let asn1_params = asn1::parse_single::<common::DHParams<'_>>(data)?;
+    // PKCS#3 DHParameter and X9.42 DomainParameters are both SEQUENCEs of
+    // INTEGERs, and a PKCS#3 privateValueLength can't be told apart from an
+    // X9.42 q purely by structure. privateValueLength is a bit count, so
+    // anything that parses with it fitting in a u32 is treated as PKCS#3.
+    let (p, q, g) = match asn1::parse_single::<common::BasicDHParams<'_>>(data) {
+        Ok(asn1_params) => (asn1_params.p, None, asn1_params.g),
+        Err(_) => {
+            let asn1_params = asn1::parse_single::<common::DHXParams<'_>>(data)?;
+            (asn1_params.p, Some(asn1_params.q), asn1_params.g)
+        }
+    };
 
-    let p = openssl::bn::BigNum::from_slice(asn1_params.p.as_bytes())?;
-    let q = asn1_params
-        .q
+    let p = openssl::bn::BigNum::from_slice(p.as_bytes())?;
+    let q = q
         .map(|q| openssl::bn::BigNum::from_slice(q.as_bytes()))
         .transpose()?;
-    let g = openssl::bn::BigNum::from_slice(asn1_params.g.as_bytes())?;
+    let g = openssl::bn::BigNum::from_slice(g.as_bytes())?;
 
     Ok(DHParameters {
         dh: openssl::dh::Dh::from_pqg(p, q, g)?,
@@ -361,16 +370,27 @@ impl DHParameters {
             .map(utils::bn_to_big_endian_bytes)
             .transpose()?;
         let g_bytes = utils::bn_to_big_endian_bytes(self.dh.generator())?;
-        let asn1dh_params = common::DHParams {
-            p: asn1::BigUint::new(&p_bytes).unwrap(),
-            q: q_bytes.as_ref().map(|q| asn1::BigUint::new(q).unwrap()),
-            g: asn1::BigUint::new(&g_bytes).unwrap(),
-        };
-        let data = asn1::write_single(&asn1dh_params)?;
-        let tag = if q_bytes.is_none() {
-            "DH PARAMETERS"
-        } else {
-            "X9.42 DH PARAMETERS"
+        let p = asn1::BigUint::new(&p_bytes).unwrap();
+        let g = asn1::BigUint::new(&g_bytes).unwrap();
+        let (tag, data) = match q_bytes {
+            Some(ref q_bytes) => (
+                "X9.42 DH PARAMETERS",
+                asn1::write_single(&common::DHXParams {
+                    p,
+                    g,
+                    q: asn1::BigUint::new(q_bytes).unwrap(),
+                    j: None,
+                    validation_params: None,
+                })?,
+            ),
+            None => (
+                "DH PARAMETERS",
+                asn1::write_single(&common::BasicDHParams {
+                    p,
+                    g,
+                    private_value_length: None,
+                })?,
+            ),
         };
         encode_der_data(py, tag.to_string(), data, &encoding)
     }
@@ -69,6 +69,8 @@ fn curve_from_py_curve(
         #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
         "brainpoolP256r1" => openssl::nid::Nid::BRAINPOOL_P256R1,
         #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
+        "brainpoolP320r1" => openssl::nid::Nid::BRAINPOOL_P320R1,
+        #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
         "brainpoolP384r1" => openssl::nid::Nid::BRAINPOOL_P384R1,
         #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
         "brainpoolP512r1" => openssl::nid::Nid::BRAINPOOL_P512R1,
@@ -112,16 +114,67 @@ fn py_curve_from_curve<'p>(
         })
 }
 
-fn check_key_infinity(
+// Replaces `s` with `n - s` when `s` is in the upper half of the group order,
+// so that there is only one valid encoding of each signature (as required by,
+// e.g., Bitcoin's BIP 62).
+fn normalize_low_s(group: &openssl::ec::EcGroupRef, sig: &[u8]) -> CryptographyResult<Vec<u8>> {
+    let ecdsa_sig = openssl::ecdsa::EcdsaSig::from_der(sig)?;
+    let mut bn_ctx = openssl::bn::BigNumContext::new()?;
+    let mut order = openssl::bn::BigNum::new()?;
+    group.order(&mut order, &mut bn_ctx)?;
+    let mut half_order = openssl::bn::BigNum::new()?;
+    half_order.rshift1(&order)?;
+    if ecdsa_sig.s() <= &*half_order {
+        return Ok(sig.to_vec());
+    }
+
+    let mut s = openssl::bn::BigNum::new()?;
+    s.checked_sub(&order, ecdsa_sig.s())?;
+    let r = ecdsa_sig.r().to_owned()?;
+    Ok(openssl::ecdsa::EcdsaSig::from_private_components(r, s)?.to_der()?)
+}
+
+// Accepting an arbitrary point allows invalid-curve and small-subgroup
+// attacks, which can recover an ECDH private key. So the point must be on
+// the curve, not the point at infinity and, on curves with a cofactor
+// (where there are points of small order), in the prime-order subgroup.
+fn check_public_point(
     ec: &openssl::ec::EcKeyRef<impl openssl::pkey::HasPublic>,
 ) -> CryptographyResult<()> {
-    if ec.public_key().is_infinity(ec.group()) {
+    let group = ec.group();
+    let point = ec.public_key();
+    if point.is_infinity(group) {
         return Err(CryptographyError::from(
             pyo3::exceptions::PyValueError::new_err(
                 "Cannot load an EC public key where the point is at infinity",
             ),
         ));
     }
+
+    let mut bn_ctx = openssl::bn::BigNumContext::new()?;
+    if !point.is_on_curve(group, &mut bn_ctx)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "Invalid EC key. Point is not on the curve specified.",
+            ),
+        ));
+    }
+
+    let mut cofactor = openssl::bn::BigNum::new()?;
+    group.cofactor(&mut cofactor, &mut bn_ctx)?;
+    if cofactor != openssl::bn::BigNum::from_u32(1)? {
+        let mut order = openssl::bn::BigNum::new()?;
+        group.order(&mut order, &mut bn_ctx)?;
+        let mut product = openssl::ec::EcPoint::new(group)?;
+        product.mul(group, point, &order, &bn_ctx)?;
+        if !product.is_infinity(group) {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Invalid EC key. Point is not in the curve's prime order subgroup.",
+                ),
+            ));
+        }
+    }
     Ok(())
 }
 
@@ -135,7 +188,7 @@ pub(crate) fn private_key_from_pkey(
     pkey: &openssl::pkey::PKeyRef<openssl::pkey::Private>,
 ) -> CryptographyResult<ECPrivateKey> {
     let curve = py_curve_from_curve(py, pkey.ec_key().unwrap().group())?;
-    check_key_infinity(&pkey.ec_key().unwrap())?;
+    check_public_point(&pkey.ec_key().unwrap())?;
     Ok(ECPrivateKey {
         pkey: pkey.to_owned(),
         curve: curve.into(),
@@ -148,7 +201,7 @@ pub(crate) fn public_key_from_pkey(
 ) -> CryptographyResult<ECPublicKey> {
     let ec = pkey.ec_key()?;
     let curve = py_curve_from_curve(py, ec.group())?;
-    check_key_infinity(&ec)?;
+    check_public_point(&ec)?;
     Ok(ECPublicKey {
         pkey: pkey.to_owned(),
         curve: curve.into(),
@@ -186,7 +239,7 @@ fn derive_private_key(
     point.mul_generator(&curve, &private_value, &bn_ctx)?;
     let ec = openssl::ec::EcKey::from_private_components(&curve, &private_value, &point)
         .map_err(|_| pyo3::exceptions::PyValueError::new_err("Invalid EC key"))?;
-    check_key_infinity(&ec)?;
+    check_public_point(&ec)?;
     let pkey = openssl::pkey::PKey::from_ec_key(ec)?;
 
     Ok(ECPrivateKey {
@@ -207,6 +260,7 @@ fn from_public_bytes(
     let point = openssl::ec::EcPoint::from_bytes(&curve, data, &mut bn_ctx)
         .map_err(|_| pyo3::exceptions::PyValueError::new_err("Invalid EC key."))?;
     let ec = openssl::ec::EcKey::from_public_key(&curve, &point)?;
+    check_public_point(&ec)?;
     let pkey = openssl::pkey::PKey::from_ec_key(ec)?;
 
     Ok(ECPublicKey {
@@ -215,6 +269,36 @@ fn from_public_bytes(
     })
 }
 
+#[pyo3::pyfunction]
+fn load_public_key_with_explicit_parameters(
+    py: pyo3::Python<'_>,
+    data: CffiBuf<'_>,
+    encoding: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<ECPublicKey> {
+    let pem;
+    let der = if encoding.is(&types::ENCODING_PEM.get(py)?) {
+        pem = pem::parse(data.as_bytes())?;
+        if pem.tag() != "PUBLIC KEY" {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Valid PEM but no BEGIN PUBLIC KEY/END PUBLIC KEY delimiters. Are you sure this is a public key?",
+                ),
+            ));
+        }
+        pem.contents()
+    } else if encoding.is(&types::ENCODING_DER.get(py)?) {
+        data.as_bytes()
+    } else {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "encoding must be Encoding.PEM or Encoding.DER",
+            ),
+        ));
+    };
+    let pkey = cryptography_key_parsing::spki::parse_ec_public_key_with_explicit_parameters(der)?;
+    public_key_from_pkey(py, &pkey)
+}
+
 #[pyo3::pymethods]
 impl ECPrivateKey {
     #[getter]
@@ -312,6 +396,13 @@ impl ECPrivateKey {
         // will be a byte or two shorter than the maximum possible length).
         let mut sig = vec![];
         signer.sign_to_vec(data.as_bytes(), &mut sig)?;
+
+        let low_s: bool = signature_algorithm
+            .getattr(pyo3::intern!(py, "low_s"))?
+            .extract()?;
+        if low_s {
+            sig = normalize_low_s(self.pkey.ec_key().unwrap().group(), &sig)?;
+        }
         Ok(pyo3::types::PyBytes::new_bound(py, &sig))
     }
 
@@ -441,13 +532,46 @@ impl ECPublicKey {
         })
     }
 
+    #[pyo3(signature = (encoding, format, *, explicit_parameters=false))]
     fn public_bytes<'p>(
         slf: &pyo3::Bound<'p, Self>,
         py: pyo3::Python<'p>,
         encoding: &pyo3::Bound<'p, pyo3::PyAny>,
         format: &pyo3::Bound<'p, pyo3::PyAny>,
+        explicit_parameters: bool,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
-        utils::pkey_public_bytes(py, slf, &slf.borrow().pkey, encoding, format, true, false)
+        if !explicit_parameters {
+            return utils::pkey_public_bytes(
+                py,
+                slf,
+                &slf.borrow().pkey,
+                encoding,
+                format,
+                true,
+                false,
+            );
+        }
+
+        if !format.is(&types::PUBLIC_FORMAT_SUBJECT_PUBLIC_KEY_INFO.get(py)?) {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "explicit_parameters is only supported with the SubjectPublicKeyInfo format",
+                ),
+            ));
+        }
+        let ec = slf.borrow().pkey.ec_key()?;
+        let curve_nid = ec.group().curve_name().ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "explicit_parameters is only supported for keys on a named curve",
+            ))
+        })?;
+        let mut group = openssl::ec::EcGroup::from_curve_name(curve_nid)?;
+        group.set_asn1_flag(openssl::ec::Asn1Flag::EXPLICIT_CURVE);
+        let pkey = openssl::pkey::PKey::from_ec_key(openssl::ec::EcKey::from_public_key(
+            &group,
+            ec.public_key(),
+        )?)?;
+        utils::pkey_public_bytes(py, slf, &pkey, encoding, format, false, false)
     }
 
     fn __eq__(&self, other: pyo3::PyRef<'_, Self>) -> bool {
@@ -503,7 +627,9 @@ fn public_key_from_numbers(
             )
         })?;
 
-    Ok(openssl::ec::EcKey::from_public_key(curve, &point)?)
+    let ec = openssl::ec::EcKey::from_public_key(curve, &point)?;
+    check_public_point(&ec)?;
+    Ok(ec)
 }
 
 #[pyo3::pymethods]
@@ -678,6 +804,10 @@ pub(crate) fn create_module(
     m.add_function(pyo3::wrap_pyfunction_bound!(generate_private_key, &m)?)?;
     m.add_function(pyo3::wrap_pyfunction_bound!(derive_private_key, &m)?)?;
     m.add_function(pyo3::wrap_pyfunction_bound!(from_public_bytes, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(
+        load_public_key_with_explicit_parameters,
+        &m
+    )?)?;
 
     m.add_class::<ECPrivateKey>()?;
     m.add_class::<ECPublicKey>()?;
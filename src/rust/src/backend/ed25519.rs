@@ -6,6 +6,7 @@ use crate::backend::utils;
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::exceptions;
+use crate::zeroize::Zeroizing;
 use pyo3::types::PyModuleMethods;
 
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.openssl.ed25519")]
@@ -94,7 +95,7 @@ impl Ed25519PrivateKey {
         &self,
         py: pyo3::Python<'p>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
-        let raw_bytes = self.pkey.raw_private_key()?;
+        let raw_bytes = Zeroizing::from(self.pkey.raw_private_key()?);
         Ok(pyo3::types::PyBytes::new_bound(py, &raw_bytes))
     }
 
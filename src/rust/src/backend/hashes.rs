@@ -5,6 +5,7 @@
 use pyo3::types::{PyAnyMethods, PyModuleMethods};
 use pyo3::IntoPy;
 use std::borrow::Cow;
+use std::io::Read;
 
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
@@ -138,11 +139,37 @@ impl Hash {
     }
 }
 
+// Large enough to amortize the per-call overhead of `read` and `update`,
+// while keeping memory use small for arbitrarily large files.
+const HASH_FILE_BUFFER_SIZE: usize = 64 * 1024;
+
+#[pyo3::pyfunction]
+fn hash_file<'p>(
+    py: pyo3::Python<'p>,
+    algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    path: std::path::PathBuf,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let mut hash = Hash::new(py, algorithm, None)?;
+    let mut file = std::fs::File::open(path)?;
+    let mut buf = vec![0; HASH_FILE_BUFFER_SIZE];
+    loop {
+        let n = match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        hash.update_bytes(&buf[..n])?;
+    }
+    hash.finalize(py)
+}
+
 pub(crate) fn create_module(
     py: pyo3::Python<'_>,
 ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyModule>> {
     let m = pyo3::types::PyModule::new_bound(py, "hashes")?;
     m.add_class::<Hash>()?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(hash_file, &m)?)?;
 
     Ok(m)
 }
@@ -6,7 +6,7 @@ use crate::backend::hashes::{already_finalized_error, message_digest_from_algori
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::exceptions;
-use pyo3::types::{PyBytesMethods, PyModuleMethods};
+use pyo3::types::{PyAnyMethods, PyBytesMethods, PyModuleMethods};
 
 #[pyo3::pyclass(
     module = "cryptography.hazmat.bindings._rust.openssl.hmac",
@@ -25,12 +25,21 @@ impl Hmac {
         algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
     ) -> CryptographyResult<Hmac> {
         let md = message_digest_from_algorithm(py, algorithm)?;
-        let ctx = cryptography_openssl::hmac::Hmac::new(key, md).map_err(|_| {
+        // HMAC is only defined for hashes with a fixed block size, which
+        // excludes extendable output functions such as SHAKE.
+        let unsupported = || {
             exceptions::UnsupportedAlgorithm::new_err((
                 "Digest is not supported for HMAC",
                 exceptions::Reasons::UNSUPPORTED_HASH,
             ))
-        })?;
+        };
+        if algorithm
+            .getattr(pyo3::intern!(py, "block_size"))?
+            .is_none()
+        {
+            return Err(CryptographyError::from(unsupported()));
+        }
+        let ctx = cryptography_openssl::hmac::Hmac::new(key, md).map_err(|_| unsupported())?;
 
         Ok(Hmac {
             ctx: Some(ctx),
@@ -86,9 +95,10 @@ impl Hmac {
         Ok(pyo3::types::PyBytes::new_bound(py, &data))
     }
 
-    fn verify(&mut self, py: pyo3::Python<'_>, signature: &[u8]) -> CryptographyResult<()> {
+    fn verify(&mut self, py: pyo3::Python<'_>, signature: CffiBuf<'_>) -> CryptographyResult<()> {
         let actual_bound = self.finalize(py)?;
         let actual = actual_bound.as_bytes();
+        let signature = signature.as_bytes();
         if actual.len() != signature.len() || !openssl::memcmp::eq(actual, signature) {
             return Err(CryptographyError::from(
                 exceptions::InvalidSignature::new_err("Signature did not match digest."),
@@ -4,9 +4,67 @@
 
 use crate::backend::hashes;
 use crate::buf::CffiBuf;
-use crate::error::CryptographyResult;
+use crate::error::{CryptographyError, CryptographyResult};
 use pyo3::types::PyModuleMethods;
 
+// KDF2 as described in ISO 18033-2 and used by RSA-KEM (RFC 5990). This is
+// the same construction as the ANSI X9.63 KDF with no shared info.
+pub(crate) fn kdf2(
+    md: openssl::hash::MessageDigest,
+    secret: &[u8],
+    out: &mut [u8],
+) -> CryptographyResult<()> {
+    for (i, chunk) in out.chunks_mut(md.size()).enumerate() {
+        let mut h = openssl::hash::Hasher::new(md)?;
+        h.update(secret)?;
+        // The caller has checked that there are fewer than 2^32 blocks.
+        h.update(&(i as u32 + 1).to_be_bytes())?;
+        let block = h.finish()?;
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    Ok(())
+}
+
+// Derives `length` bytes from a key exchange's shared secret with HKDF, so
+// that the shared secret itself is never handed to Python.
+pub(crate) fn derive_hkdf_from_shared_secret<'p>(
+    py: pyo3::Python<'p>,
+    shared_secret: &[u8],
+    algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    length: usize,
+    salt: Option<&[u8]>,
+    info: Option<&[u8]>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let md = hashes::message_digest_from_algorithm(py, algorithm)?;
+    let max_length = 255 * md.size();
+    if length > max_length {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Cannot derive keys larger than {max_length} octets."
+            )),
+        ));
+    }
+
+    // OpenSSL's HKDF cleanses its intermediate PRK and output blocks.
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new_id(openssl::pkey::Id::HKDF)?;
+    ctx.derive_init()?;
+    ctx.set_hkdf_md(openssl::md::Md::from_nid(md.type_()).unwrap())?;
+    ctx.set_hkdf_key(shared_secret)?;
+    if let Some(salt) = salt {
+        ctx.set_hkdf_salt(salt)?;
+    }
+    if let Some(info) = info {
+        ctx.add_hkdf_info(info)?;
+    }
+    Ok(pyo3::types::PyBytes::new_bound_with(py, length, |b| {
+        // OpenSSL rejects deriving zero bytes.
+        if !b.is_empty() {
+            ctx.derive(Some(b)).map_err(CryptographyError::from)?;
+        }
+        Ok(())
+    })?)
+}
+
 #[pyo3::pyfunction]
 fn derive_pbkdf2_hmac<'p>(
     py: pyo3::Python<'p>,
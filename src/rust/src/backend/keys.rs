@@ -2,25 +2,129 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
-use pyo3::types::PyModuleMethods;
+use pyo3::types::{PyAnyMethods, PyModuleMethods};
 use pyo3::IntoPy;
 
 use crate::backend::utils;
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
-use crate::exceptions;
+use crate::zeroize::Zeroizing;
+use crate::{exceptions, types};
+
+fn check_password_callback<'p>(
+    password: &Option<CffiBuf<'_>>,
+    password_callback: Option<pyo3::Bound<'p, pyo3::PyAny>>,
+) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::PyAny>>> {
+    match password_callback {
+        Some(_) if password.is_some() => Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err(
+                "password and password_callback cannot both be provided.",
+            ),
+        )),
+        Some(cb) if !cb.is_callable() => Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err("password_callback must be callable."),
+        )),
+        cb => Ok(cb),
+    }
+}
+
+// The number of times `password_callback` is invoked before giving up on an
+// incorrect password.
+const MAX_PASSWORD_ATTEMPTS: usize = 3;
+
+fn is_bad_decrypt(e: &openssl::error::ErrorStack) -> bool {
+    e.errors().iter().any(|e| e.reason() == Some("bad decrypt"))
+}
+
+// Loads a private key, only invoking `password_callback` once OpenSSL has
+// determined that the key is encrypted. If decryption fails because the
+// password is incorrect the callback is invoked again, up to
+// `MAX_PASSWORD_ATTEMPTS` times, until it returns `None` or raises an
+// exception.
+fn private_pkey_with_password_callback(
+    py: pyo3::Python<'_>,
+    password_callback: &pyo3::Bound<'_, pyo3::PyAny>,
+    load: impl Fn(
+        &mut dyn FnMut(&mut [u8]) -> Result<usize, openssl::error::ErrorStack>,
+    )
+        -> Result<openssl::pkey::PKey<openssl::pkey::Private>, openssl::error::ErrorStack>,
+) -> CryptographyResult<openssl::pkey::PKey<openssl::pkey::Private>> {
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let mut status = utils::PasswordCallbackStatus::Unused;
+        let mut password = None;
+        let mut callback_error = None;
+        let pkey = load(&mut |buf| {
+            status = utils::PasswordCallbackStatus::Used;
+            let result = password_callback
+                .call0()
+                .and_then(|p| p.extract::<Option<pyo3::pybacked::PyBackedBytes>>());
+            match result {
+                Ok(Some(p)) if p.len() <= buf.len() => {
+                    buf[..p.len()].copy_from_slice(&p);
+                    let len = p.len();
+                    password = Some(p);
+                    Ok(len)
+                }
+                Ok(Some(_)) => {
+                    status = utils::PasswordCallbackStatus::BufferTooSmall(buf.len());
+                    Ok(0)
+                }
+                Ok(None) => Ok(0),
+                Err(e) => {
+                    callback_error = Some(e);
+                    Err(openssl::error::ErrorStack::get())
+                }
+            }
+        });
+        if let Some(e) = callback_error {
+            return Err(CryptographyError::from(e));
+        }
+        if let (Err(e), utils::PasswordCallbackStatus::Used, Some(p)) = (&pkey, &status, &password)
+        {
+            if attempts < MAX_PASSWORD_ATTEMPTS && (p.is_empty() || is_bad_decrypt(e)) {
+                continue;
+            }
+        }
+        return utils::handle_key_load_result(py, pkey, status, password.as_deref());
+    }
+}
 
 #[pyo3::pyfunction]
-#[pyo3(signature = (data, password, backend=None, *, unsafe_skip_rsa_key_validation=false))]
+#[pyo3(signature = (data, password, backend=None, *, unsafe_skip_rsa_key_validation=false, password_callback=None))]
 fn load_der_private_key(
     py: pyo3::Python<'_>,
     data: CffiBuf<'_>,
     password: Option<CffiBuf<'_>>,
     backend: Option<pyo3::Bound<'_, pyo3::PyAny>>,
     unsafe_skip_rsa_key_validation: bool,
+    password_callback: Option<pyo3::Bound<'_, pyo3::PyAny>>,
 ) -> CryptographyResult<pyo3::PyObject> {
     let _ = backend;
-    if let Ok(pkey) = openssl::pkey::PKey::private_key_from_der(data.as_bytes()) {
+    let pkey = match check_password_callback(&password, password_callback)? {
+        Some(password_callback) => {
+            match openssl::pkey::PKey::private_key_from_der(data.as_bytes()) {
+                Ok(pkey) => pkey,
+                Err(_) => private_pkey_with_password_callback(py, &password_callback, |cb| {
+                    openssl::pkey::PKey::private_key_from_pkcs8_callback(data.as_bytes(), cb)
+                })?,
+            }
+        }
+        None => {
+            let password = password.as_ref().map(CffiBuf::as_bytes);
+            private_pkey_from_der(py, data.as_bytes(), password)?
+        }
+    };
+    private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation)
+}
+
+fn private_pkey_from_der(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    password: Option<&[u8]>,
+) -> CryptographyResult<openssl::pkey::PKey<openssl::pkey::Private>> {
+    if let Ok(pkey) = openssl::pkey::PKey::private_key_from_der(data) {
         if password.is_some() {
             return Err(CryptographyError::from(
                 pyo3::exceptions::PyTypeError::new_err(
@@ -28,37 +132,123 @@ fn load_der_private_key(
                 ),
             ));
         }
-        return private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation);
+        return Ok(pkey);
     }
 
-    let password = password.as_ref().map(CffiBuf::as_bytes);
     let mut status = utils::PasswordCallbackStatus::Unused;
     let pkey = openssl::pkey::PKey::private_key_from_pkcs8_callback(
-        data.as_bytes(),
+        data,
         utils::password_callback(&mut status, password),
     );
-    let pkey = utils::handle_key_load_result(py, pkey, status, password)?;
-    private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation)
+    utils::handle_key_load_result(py, pkey, status, password)
 }
 
 #[pyo3::pyfunction]
-#[pyo3(signature = (data, password, backend=None, *, unsafe_skip_rsa_key_validation=false))]
+#[pyo3(signature = (data, password, backend=None, *, unsafe_skip_rsa_key_validation=false, password_callback=None))]
 fn load_pem_private_key(
     py: pyo3::Python<'_>,
     data: CffiBuf<'_>,
     password: Option<CffiBuf<'_>>,
     backend: Option<pyo3::Bound<'_, pyo3::PyAny>>,
     unsafe_skip_rsa_key_validation: bool,
+    password_callback: Option<pyo3::Bound<'_, pyo3::PyAny>>,
 ) -> CryptographyResult<pyo3::PyObject> {
     let _ = backend;
-    let password = password.as_ref().map(CffiBuf::as_bytes);
+    let pkey = match check_password_callback(&password, password_callback)? {
+        Some(password_callback) => {
+            private_pkey_with_password_callback(py, &password_callback, |cb| {
+                openssl::pkey::PKey::private_key_from_pem_callback(data.as_bytes(), cb)
+            })?
+        }
+        None => {
+            let password = password.as_ref().map(CffiBuf::as_bytes);
+            private_pkey_from_pem(py, data.as_bytes(), password)?
+        }
+    };
+    private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation)
+}
+
+fn private_pkey_from_pem(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    password: Option<&[u8]>,
+) -> CryptographyResult<openssl::pkey::PKey<openssl::pkey::Private>> {
     let mut status = utils::PasswordCallbackStatus::Unused;
     let pkey = openssl::pkey::PKey::private_key_from_pem_callback(
-        data.as_bytes(),
+        data,
         utils::password_callback(&mut status, password),
     );
-    let pkey = utils::handle_key_load_result(py, pkey, status, password)?;
-    private_key_from_pkey(py, &pkey, unsafe_skip_rsa_key_validation)
+    utils::handle_key_load_result(py, pkey, status, password)
+}
+
+// Extracts the password to re-encrypt a key with. `NoEncryption` is rejected
+// since the point of re-encrypting is that the plaintext key is never
+// serialized.
+fn reencryption_password(
+    py: pyo3::Python<'_>,
+    encryption_algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::pybacked::PyBackedBytes> {
+    if !encryption_algorithm.is_instance(&types::KEY_SERIALIZATION_ENCRYPTION.get(py)?)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err(
+                "Encryption algorithm must be a KeySerializationEncryption instance",
+            ),
+        ));
+    }
+    if !encryption_algorithm.is_instance(&types::BEST_AVAILABLE_ENCRYPTION.get(py)?)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "Unsupported encryption type, re-encrypting requires BestAvailableEncryption",
+            ),
+        ));
+    }
+
+    let password = encryption_algorithm
+        .getattr(pyo3::intern!(py, "password"))?
+        .extract::<pyo3::pybacked::PyBackedBytes>()?;
+    if password.len() > 1023 {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "Passwords longer than 1023 bytes are not supported by this backend",
+            ),
+        ));
+    }
+    Ok(password)
+}
+
+// The decrypted key only ever lives inside OpenSSL's key structure, which is
+// cleansed when it is freed, and is serialized directly to its encrypted
+// form, so no plaintext buffer is created.
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, password, encryption_algorithm))]
+fn reencrypt_pem_private_key<'p>(
+    py: pyo3::Python<'p>,
+    data: CffiBuf<'_>,
+    password: Option<CffiBuf<'_>>,
+    encryption_algorithm: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let new_password = reencryption_password(py, &encryption_algorithm)?;
+    let password = password.as_ref().map(CffiBuf::as_bytes);
+    let pkey = private_pkey_from_pem(py, data.as_bytes(), password)?;
+    let pem_bytes = pkey
+        .private_key_to_pem_pkcs8_passphrase(openssl::symm::Cipher::aes_256_cbc(), &new_password)?;
+    Ok(pyo3::types::PyBytes::new_bound(py, &pem_bytes))
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, password, encryption_algorithm))]
+fn reencrypt_der_private_key<'p>(
+    py: pyo3::Python<'p>,
+    data: CffiBuf<'_>,
+    password: Option<CffiBuf<'_>>,
+    encryption_algorithm: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let new_password = reencryption_password(py, &encryption_algorithm)?;
+    let password = password.as_ref().map(CffiBuf::as_bytes);
+    let pkey = private_pkey_from_der(py, data.as_bytes(), password)?;
+    let der_bytes =
+        pkey.private_key_to_pkcs8_passphrase(openssl::symm::Cipher::aes_256_cbc(), &new_password)?;
+    Ok(pyo3::types::PyBytes::new_bound(py, &der_bytes))
 }
 
 pub(crate) fn private_key_from_pkey(
@@ -77,7 +267,7 @@ pub(crate) fn private_key_from_pkey(
             // PSS constraints from them and treat them as normal RSA keys
             // Unfortunately the RSA * itself tracks this data so we need to
             // extract, serialize, and reload it without the constraints.
-            let der_bytes = pkey.rsa()?.private_key_to_der()?;
+            let der_bytes = Zeroizing::from(pkey.rsa()?.private_key_to_der()?);
             let rsa = openssl::rsa::Rsa::private_key_from_der(&der_bytes)?;
             let pkey = openssl::pkey::PKey::from_rsa(rsa)?;
             Ok(
@@ -228,6 +418,8 @@ pub(crate) fn create_module(
 
     m.add_function(pyo3::wrap_pyfunction_bound!(load_pem_private_key, &m)?)?;
     m.add_function(pyo3::wrap_pyfunction_bound!(load_der_private_key, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(reencrypt_pem_private_key, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(reencrypt_der_private_key, &m)?)?;
     m.add_function(pyo3::wrap_pyfunction_bound!(load_der_public_key, &m)?)?;
     m.add_function(pyo3::wrap_pyfunction_bound!(load_pem_public_key, &m)?)?;
 
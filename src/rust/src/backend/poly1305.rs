@@ -126,7 +126,7 @@ impl Poly1305 {
         py: pyo3::Python<'_>,
         key: CffiBuf<'_>,
         data: CffiBuf<'_>,
-        tag: &[u8],
+        tag: CffiBuf<'_>,
     ) -> CryptographyResult<()> {
         let mut p = Poly1305::new(key)?;
         p.update(data)?;
@@ -152,9 +152,10 @@ impl Poly1305 {
         res
     }
 
-    fn verify(&mut self, py: pyo3::Python<'_>, signature: &[u8]) -> CryptographyResult<()> {
+    fn verify(&mut self, py: pyo3::Python<'_>, signature: CffiBuf<'_>) -> CryptographyResult<()> {
         let actual_bound = self.finalize(py)?;
         let actual = actual_bound.as_bytes();
+        let signature = signature.as_bytes();
         if actual.len() != signature.len() || !openssl::memcmp::eq(actual, signature) {
             return Err(CryptographyError::from(
                 exceptions::InvalidSignature::new_err("Value did not match computed tag."),
@@ -5,9 +5,10 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-use crate::backend::{hashes, utils};
+use crate::backend::{hashes, kdf, utils};
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
+use crate::zeroize::Zeroizing;
 use crate::{exceptions, types};
 use pyo3::types::{PyAnyMethods, PyModuleMethods};
 
@@ -69,6 +70,508 @@ fn generate_private_key(public_exponent: u32, key_size: u32) -> CryptographyResu
     Ok(RsaPrivateKey { pkey })
 }
 
+fn check_raw_input_length(modulus_size: usize, data: &[u8]) -> CryptographyResult<()> {
+    if data.len() != modulus_size {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("Data length must be equal to key size."),
+        ));
+    }
+    Ok(())
+}
+
+fn raw_input_not_less_than_modulus() -> pyo3::PyErr {
+    pyo3::exceptions::PyValueError::new_err("Data must be less than the modulus.")
+}
+
+#[pyo3::pyfunction]
+#[pyo3(name = "unsafe_raw_public_operation")]
+fn raw_public_operation<'p>(
+    py: pyo3::Python<'p>,
+    key: &RsaPublicKey,
+    data: CffiBuf<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let data = data.as_bytes();
+    check_raw_input_length(key.pkey.size(), data)?;
+
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new(&key.pkey)?;
+    ctx.encrypt_init()?;
+    ctx.set_rsa_padding(openssl::rsa::Padding::NONE)?;
+
+    Ok(pyo3::types::PyBytes::new_bound_with(py, data.len(), |b| {
+        let length = ctx
+            .encrypt(data, Some(b))
+            .map_err(|_| raw_input_not_less_than_modulus())?;
+        assert_eq!(length, b.len());
+        Ok(())
+    })?)
+}
+
+#[pyo3::pyfunction]
+#[pyo3(name = "unsafe_raw_private_operation")]
+fn raw_private_operation<'p>(
+    py: pyo3::Python<'p>,
+    key: &RsaPrivateKey,
+    data: CffiBuf<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let data = data.as_bytes();
+    check_raw_input_length(key.pkey.size(), data)?;
+
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new(&key.pkey)?;
+    ctx.decrypt_init()?;
+    ctx.set_rsa_padding(openssl::rsa::Padding::NONE)?;
+
+    Ok(pyo3::types::PyBytes::new_bound_with(py, data.len(), |b| {
+        let length = ctx
+            .decrypt(data, Some(b))
+            .map_err(|_| raw_input_not_less_than_modulus())?;
+        assert_eq!(length, b.len());
+        Ok(())
+    })?)
+}
+
+/// Returns 0xFF if a == 0 else 0x00, but does so in a constant time fashion.
+fn constant_time_is_zero(a: u8) -> u8 {
+    0u8.wrapping_sub((!a & a.wrapping_sub(1)) >> 7)
+}
+
+#[pyo3::pyfunction]
+fn decrypt_pkcs1v15_implicit_rejection<'p>(
+    py: pyo3::Python<'p>,
+    key: &RsaPrivateKey,
+    ciphertext: CffiBuf<'_>,
+    synthetic_plaintext: CffiBuf<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let ciphertext = ciphertext.as_bytes();
+    let synthetic = synthetic_plaintext.as_bytes();
+    let k = key.pkey.size();
+    if ciphertext.len() != k {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("Ciphertext length must be equal to key size."),
+        ));
+    }
+    // EM = 0x00 || 0x02 || PS || 0x00 || M, where PS is at least 8 bytes.
+    let max_length = match k.checked_sub(11) {
+        Some(max_length) if max_length > 0 => max_length,
+        _ => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The key is too small for PKCS1v15 encryption.",
+                ),
+            ))
+        }
+    };
+    if synthetic.is_empty() || synthetic.len() > max_length {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "synthetic_plaintext must be between 1 and {max_length} bytes for this key.",
+            )),
+        ));
+    }
+
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new(&key.pkey)?;
+    ctx.decrypt_init()?;
+    ctx.set_rsa_padding(openssl::rsa::Padding::NONE)?;
+
+    // Everything from this line onwards is written with the goal of being
+    // as constant-time as is practical. The padding is checked with a
+    // branch-free accumulation of mismatches and the output is selected by
+    // masking, so that a padding failure (including a plaintext of the wrong
+    // length) is only observable as the synthetic plaintext being returned.
+    // A ciphertext that is not less than the modulus is rejected by OpenSSL
+    // before any private key operation, which reveals nothing that isn't
+    // already public, and is treated as a padding failure.
+    let mut em = Zeroizing::from(vec![0; k]);
+    let mut mismatch = match ctx.decrypt(ciphertext, Some(&mut em[..])) {
+        Ok(length) => {
+            assert_eq!(length, k);
+            0
+        }
+        Err(_) => 0xff,
+    };
+    let separator = k - synthetic.len() - 1;
+    mismatch |= em[0];
+    mismatch |= em[1] ^ 0x02;
+    for b in &em[2..separator] {
+        mismatch |= constant_time_is_zero(*b);
+    }
+    mismatch |= em[separator];
+    let good = constant_time_is_zero(mismatch);
+
+    let result = pyo3::types::PyBytes::new_bound_with(py, synthetic.len(), |b| {
+        for ((out, m), s) in b.iter_mut().zip(&em[separator + 1..]).zip(synthetic) {
+            *out = (m & good) | (s & !good);
+        }
+        Ok(())
+    })?;
+    em.fill(0);
+    Ok(result)
+}
+
+fn mgf1(
+    md: openssl::hash::MessageDigest,
+    seed: &[u8],
+    length: usize,
+) -> CryptographyResult<Vec<u8>> {
+    let mut mask = Vec::with_capacity(length + md.size());
+    let mut counter = 0u32;
+    while mask.len() < length {
+        let mut h = openssl::hash::Hasher::new(md)?;
+        h.update(seed)?;
+        h.update(&counter.to_be_bytes())?;
+        mask.extend_from_slice(&h.finish()?);
+        counter += 1;
+    }
+    mask.truncate(length);
+    Ok(mask)
+}
+
+// EMSA-PSS-ENCODE from RFC 8017 section 9.1.1, operating on an already
+// computed message digest.
+fn emsa_pss_encode(
+    digest: &[u8],
+    md: openssl::hash::MessageDigest,
+    mgf1_md: openssl::hash::MessageDigest,
+    salt: &[u8],
+    em_bits: usize,
+) -> CryptographyResult<Vec<u8>> {
+    let em_len = (em_bits + 7) / 8;
+    let h_len = md.size();
+    let salt_length = salt.len();
+    if em_len < h_len + salt_length + 2 {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "Digest and salt too large for key size. Use a larger key or a shorter salt.",
+            ),
+        ));
+    }
+
+    let mut h = openssl::hash::Hasher::new(md)?;
+    h.update(&[0; 8])?;
+    h.update(digest)?;
+    h.update(salt)?;
+    let h = h.finish()?;
+
+    let db_len = em_len - h_len - 1;
+    let mut db = mgf1(mgf1_md, &h, db_len)?;
+    db[db_len - salt_length - 1] ^= 0x01;
+    for (b, s) in db[db_len - salt_length..].iter_mut().zip(salt.iter()) {
+        *b ^= s;
+    }
+    db[0] &= 0xff >> (8 * em_len - em_bits);
+
+    let mut em = db;
+    em.extend_from_slice(&h);
+    em.push(0xbc);
+    Ok(em)
+}
+
+fn pss_salt_length(
+    py: pyo3::Python<'_>,
+    padding: &pyo3::Bound<'_, pyo3::PyAny>,
+    md: openssl::hash::MessageDigest,
+    em_bits: usize,
+) -> CryptographyResult<usize> {
+    let salt = padding.getattr(pyo3::intern!(py, "_salt_length"))?;
+    if salt.is_instance(&types::PADDING_MAX_LENGTH.get(py)?)? {
+        Ok(((em_bits + 7) / 8).saturating_sub(md.size() + 2))
+    } else if salt.is_instance(&types::PADDING_DIGEST_LENGTH.get(py)?)? {
+        Ok(md.size())
+    } else if salt.is_instance(&types::PADDING_AUTO.get(py)?)? {
+        Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "PSS salt length can only be set to Auto when verifying",
+            ),
+        ))
+    } else {
+        Ok(salt.extract::<usize>()?)
+    }
+}
+
+#[pyo3::pyclass(
+    frozen,
+    module = "cryptography.hazmat.bindings._rust.openssl.rsa",
+    name = "RSABlindingState"
+)]
+struct RsaBlindingState {
+    public_key: pyo3::Py<RsaPublicKey>,
+    digest: Vec<u8>,
+    padding: pyo3::PyObject,
+    algorithm: pyo3::PyObject,
+    inv: openssl::bn::BigNum,
+}
+
+#[pyo3::pyfunction]
+fn blind<'p>(
+    py: pyo3::Python<'p>,
+    message: CffiBuf<'_>,
+    public_key: pyo3::Bound<'p, RsaPublicKey>,
+    padding: &pyo3::Bound<'p, pyo3::PyAny>,
+    algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<(pyo3::Bound<'p, pyo3::types::PyBytes>, RsaBlindingState)> {
+    blind_message(py, message, public_key, padding, algorithm, None, None)
+}
+
+// Only used to check the implementation against the RFC 9474 test vectors,
+// which fix the PSS salt and the inverse of the blinding factor. It isn't
+// exposed outside of the bindings.
+#[pyo3::pyfunction]
+fn _blind_with_fixed_randomness<'p>(
+    py: pyo3::Python<'p>,
+    message: CffiBuf<'_>,
+    public_key: pyo3::Bound<'p, RsaPublicKey>,
+    padding: &pyo3::Bound<'p, pyo3::PyAny>,
+    algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+    salt: CffiBuf<'_>,
+    inv: CffiBuf<'_>,
+) -> CryptographyResult<(pyo3::Bound<'p, pyo3::types::PyBytes>, RsaBlindingState)> {
+    blind_message(
+        py,
+        message,
+        public_key,
+        padding,
+        algorithm,
+        Some(salt.as_bytes()),
+        Some(inv.as_bytes()),
+    )
+}
+
+// Blinds `message` as in RFC 9474 section 4.3. The salt and the inverse of
+// the blinding factor are generated randomly unless they're given.
+fn blind_message<'p>(
+    py: pyo3::Python<'p>,
+    message: CffiBuf<'_>,
+    public_key: pyo3::Bound<'p, RsaPublicKey>,
+    padding: &pyo3::Bound<'p, pyo3::PyAny>,
+    algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+    fixed_salt: Option<&[u8]>,
+    fixed_inv: Option<&[u8]>,
+) -> CryptographyResult<(pyo3::Bound<'p, pyo3::types::PyBytes>, RsaBlindingState)> {
+    if !padding.is_instance(&types::PSS.get(py)?)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err("Padding must be an instance of PSS."),
+        ));
+    }
+    if !padding
+        .getattr(pyo3::intern!(py, "_mgf"))?
+        .is_instance(&types::MGF1.get(py)?)?
+    {
+        return Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err((
+                "Only MGF1 is supported.",
+                exceptions::Reasons::UNSUPPORTED_MGF,
+            )),
+        ));
+    }
+
+    let (digest, algorithm) =
+        utils::calculate_digest_and_algorithm(py, message.as_bytes(), algorithm)?;
+    let md = hashes::message_digest_from_algorithm(py, &algorithm)?;
+    let mgf1_md = hashes::message_digest_from_algorithm(
+        py,
+        &padding
+            .getattr(pyo3::intern!(py, "_mgf"))?
+            .getattr(pyo3::intern!(py, "_algorithm"))?,
+    )?;
+
+    let rsa = public_key.get().pkey.rsa()?;
+    let n = rsa.n();
+    let mod_len = rsa.size() as usize;
+    let em_bits = n.num_bits() as usize - 1;
+    let salt_length = pss_salt_length(py, padding, md, em_bits)?;
+    let salt = match fixed_salt {
+        Some(salt) if salt.len() != salt_length => {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The salt's length doesn't match the padding's salt length.",
+                ),
+            ))
+        }
+        Some(salt) => salt.to_vec(),
+        None => {
+            let mut salt = vec![0; salt_length];
+            openssl::rand::rand_bytes(&mut salt)?;
+            salt
+        }
+    };
+    let encoded = emsa_pss_encode(digest.as_bytes(), md, mgf1_md, &salt, em_bits)?;
+
+    let mut bn_ctx = openssl::bn::BigNumContext::new_secure()?;
+    let m = openssl::bn::BigNum::from_slice(&encoded)?;
+    let mut g = openssl::bn::BigNum::new()?;
+    g.gcd(&m, n, &mut bn_ctx)?;
+    if g != openssl::bn::BigNum::from_u32(1)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("Encoded message is not invertible."),
+        ));
+    }
+
+    // The blinding factor is secret, so all arithmetic involving it is
+    // performed in constant time.
+    let mut r = openssl::bn::BigNum::new_secure()?;
+    let mut inv = openssl::bn::BigNum::new_secure()?;
+    match fixed_inv {
+        Some(fixed_inv) => {
+            inv.copy_from_slice(fixed_inv)?;
+            inv.set_const_time();
+            r.mod_inverse(&inv, n, &mut bn_ctx)?;
+            r.set_const_time();
+        }
+        None => loop {
+            // r must be invertible modulo n, so a draw of zero or of a
+            // multiple of one of n's factors is discarded, as in RFC 9474.
+            n.rand_range(&mut r)?;
+            r.set_const_time();
+            if r.num_bits() != 0 && inv.mod_inverse(&r, n, &mut bn_ctx).is_ok() {
+                inv.set_const_time();
+                break;
+            }
+        },
+    }
+
+    let mut x = openssl::bn::BigNum::new_secure()?;
+    x.mod_exp(&r, rsa.e(), n, &mut bn_ctx)?;
+    x.set_const_time();
+    let mut z = openssl::bn::BigNum::new()?;
+    z.mod_mul(&m, &x, n, &mut bn_ctx)?;
+
+    let blinded = pyo3::types::PyBytes::new_bound(py, &z.to_vec_padded(mod_len as i32)?);
+    Ok((
+        blinded,
+        RsaBlindingState {
+            public_key: public_key.unbind(),
+            digest: digest.as_bytes().to_vec(),
+            padding: padding.clone().unbind(),
+            algorithm: algorithm.unbind(),
+            inv,
+        },
+    ))
+}
+
+#[pyo3::pyfunction]
+fn unblind<'p>(
+    py: pyo3::Python<'p>,
+    blinded_signature: CffiBuf<'_>,
+    state: &RsaBlindingState,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let public_key = state.public_key.get();
+    let rsa = public_key.pkey.rsa()?;
+    let n = rsa.n();
+    let mod_len = rsa.size() as usize;
+    check_raw_input_length(mod_len, blinded_signature.as_bytes())?;
+
+    let z = openssl::bn::BigNum::from_slice(blinded_signature.as_bytes())?;
+    if &z >= n {
+        return Err(CryptographyError::from(raw_input_not_less_than_modulus()));
+    }
+    let mut bn_ctx = openssl::bn::BigNumContext::new_secure()?;
+    let mut s = openssl::bn::BigNum::new()?;
+    s.mod_mul(&z, &state.inv, n, &mut bn_ctx)?;
+    let signature = s.to_vec_padded(mod_len as i32)?;
+
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new(&public_key.pkey)?;
+    ctx.verify_init()?;
+    setup_signature_ctx(
+        py,
+        &mut ctx,
+        state.padding.bind(py),
+        state.algorithm.bind(py),
+        mod_len,
+        false,
+    )?;
+    if !ctx.verify(&state.digest, &signature).unwrap_or(false) {
+        return Err(CryptographyError::from(
+            exceptions::InvalidSignature::new_err(()),
+        ));
+    }
+
+    Ok(pyo3::types::PyBytes::new_bound(py, &signature))
+}
+
+// Checks the KDF parameters for RSA-KEM and derives the shared secret from
+// the encoded random integer `z`.
+fn rsa_kem_derive<'p>(
+    py: pyo3::Python<'p>,
+    z: &[u8],
+    algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    length: usize,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let md = hashes::message_digest_from_algorithm(py, algorithm)?;
+    let max_length = md.size().saturating_mul(u32::MAX as usize);
+    if length == 0 || length > max_length {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "length must be between 1 and {max_length} bytes."
+            )),
+        ));
+    }
+
+    Ok(pyo3::types::PyBytes::new_bound_with(py, length, |b| {
+        kdf::kdf2(md, z, b)?;
+        Ok(())
+    })?)
+}
+
+// RSA-KEM from RFC 5990 section 2.3: a random integer `z` less than the
+// modulus is encrypted with raw RSA and the shared secret is derived from it
+// with KDF2.
+#[pyo3::pyfunction]
+fn rsa_kem_encapsulate<'p>(
+    py: pyo3::Python<'p>,
+    key: &RsaPublicKey,
+    algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+    length: usize,
+) -> CryptographyResult<(
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+    pyo3::Bound<'p, pyo3::types::PyBytes>,
+)> {
+    let rsa = key.pkey.rsa()?;
+    let n = rsa.n();
+    let mod_len = rsa.size() as i32;
+
+    let mut z = openssl::bn::BigNum::new_secure()?;
+    n.rand_range(&mut z)?;
+    z.set_const_time();
+    let z_bytes = Zeroizing::from(z.to_vec_padded(mod_len)?);
+
+    let mut bn_ctx = openssl::bn::BigNumContext::new_secure()?;
+    let mut c = openssl::bn::BigNum::new()?;
+    c.mod_exp(&z, rsa.e(), n, &mut bn_ctx)?;
+    z.clear();
+
+    let shared_secret = rsa_kem_derive(py, &z_bytes, algorithm, length)?;
+    let ciphertext = pyo3::types::PyBytes::new_bound(py, &c.to_vec_padded(mod_len)?);
+    Ok((ciphertext, shared_secret))
+}
+
+#[pyo3::pyfunction]
+fn rsa_kem_decapsulate<'p>(
+    py: pyo3::Python<'p>,
+    key: &RsaPrivateKey,
+    ciphertext: CffiBuf<'_>,
+    algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
+    length: usize,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let ciphertext = ciphertext.as_bytes();
+    let k = key.pkey.size();
+    if ciphertext.len() != k {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("Ciphertext length must be equal to key size."),
+        ));
+    }
+
+    let mut ctx = openssl::pkey_ctx::PkeyCtx::new(&key.pkey)?;
+    ctx.decrypt_init()?;
+    ctx.set_rsa_padding(openssl::rsa::Padding::NONE)?;
+
+    let mut z = Zeroizing::from(vec![0; k]);
+    let length_z = ctx.decrypt(ciphertext, Some(&mut z[..])).map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err("Ciphertext must be less than the modulus.")
+    })?;
+    assert_eq!(length_z, k);
+
+    rsa_kem_derive(py, &z, algorithm, length)
+}
+
 fn oaep_hash_supported(md: &openssl::hash::MessageDigest) -> bool {
     (!cryptography_openssl::fips::is_enabled() && md == &openssl::hash::MessageDigest::sha1())
         || md == &openssl::hash::MessageDigest::sha224()
@@ -341,8 +844,8 @@ impl RsaPrivateKey {
         // Once OpenSSL 3.2.0 is out, this can be simplified, as OpenSSL will
         // have its own mitigations for Bleichenbacher's attack.
         let length = ctx.decrypt(ciphertext, None).unwrap();
-        let mut plaintext = vec![0; length];
-        let result = ctx.decrypt(ciphertext, Some(&mut plaintext));
+        let mut plaintext = Zeroizing::from(vec![0; length]);
+        let result = ctx.decrypt(ciphertext, Some(&mut plaintext[..]));
 
         let py_result =
             pyo3::types::PyBytes::new_bound(py, &plaintext[..*result.as_ref().unwrap_or(&length)]);
@@ -470,7 +973,7 @@ impl RsaPublicKey {
     fn recover_data_from_signature<'p>(
         &self,
         py: pyo3::Python<'p>,
-        signature: &[u8],
+        signature: CffiBuf<'_>,
         padding: &pyo3::Bound<'_, pyo3::PyAny>,
         algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
@@ -486,10 +989,10 @@ impl RsaPublicKey {
         ctx.verify_recover_init()?;
         setup_signature_ctx(py, &mut ctx, padding, algorithm, self.pkey.size(), false)?;
 
-        let length = ctx.verify_recover(signature, None)?;
+        let length = ctx.verify_recover(signature.as_bytes(), None)?;
         let mut buf = vec![0u8; length];
         let length = ctx
-            .verify_recover(signature, Some(&mut buf))
+            .verify_recover(signature.as_bytes(), Some(&mut buf))
             .map_err(|_| exceptions::InvalidSignature::new_err(()))?;
 
         Ok(pyo3::types::PyBytes::new_bound(py, &buf[..length]))
@@ -819,11 +1322,26 @@ pub(crate) fn create_module(
 ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyModule>> {
     let m = pyo3::types::PyModule::new_bound(py, "rsa")?;
     m.add_function(pyo3::wrap_pyfunction_bound!(generate_private_key, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(raw_public_operation, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(raw_private_operation, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(blind, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(
+        _blind_with_fixed_randomness,
+        &m
+    )?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(unblind, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(
+        decrypt_pkcs1v15_implicit_rejection,
+        &m
+    )?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(rsa_kem_encapsulate, &m)?)?;
+    m.add_function(pyo3::wrap_pyfunction_bound!(rsa_kem_decapsulate, &m)?)?;
 
     m.add_class::<RsaPrivateKey>()?;
     m.add_class::<RsaPublicKey>()?;
     m.add_class::<RsaPrivateNumbers>()?;
     m.add_class::<RsaPublicNumbers>()?;
+    m.add_class::<RsaBlindingState>()?;
 
     Ok(m)
 }
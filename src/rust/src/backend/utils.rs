@@ -2,8 +2,9 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
-use crate::backend::hashes::Hash;
+use crate::backend::hashes::{self, Hash};
 use crate::error::{CryptographyError, CryptographyResult};
+use crate::zeroize::Zeroizing;
 use crate::{error, types};
 use pyo3::types::{PyAnyMethods, PyBytesMethods};
 use pyo3::ToPyObject;
@@ -86,7 +87,7 @@ pub(crate) fn pkey_private_bytes<'p>(
                     "When using Raw both encoding and format must be Raw and encryption_algorithm must be NoEncryption()"
                 )));
         }
-        let raw_bytes = pkey.raw_private_key()?;
+        let raw_bytes = Zeroizing::from(pkey.raw_private_key()?);
         return Ok(pyo3::types::PyBytes::new_bound(py, &raw_bytes));
     }
 
@@ -119,24 +120,24 @@ pub(crate) fn pkey_private_bytes<'p>(
 
     if format.is(&types::PRIVATE_FORMAT_PKCS8.get(py)?) {
         if encoding.is(&types::ENCODING_PEM.get(py)?) {
-            let pem_bytes = if password.is_empty() {
+            let pem_bytes = Zeroizing::from(if password.is_empty() {
                 pkey.private_key_to_pem_pkcs8()?
             } else {
                 pkey.private_key_to_pem_pkcs8_passphrase(
                     openssl::symm::Cipher::aes_256_cbc(),
                     password,
                 )?
-            };
+            });
             return Ok(pyo3::types::PyBytes::new_bound(py, &pem_bytes));
         } else if encoding.is(&types::ENCODING_DER.get(py)?) {
-            let der_bytes = if password.is_empty() {
+            let der_bytes = Zeroizing::from(if password.is_empty() {
                 pkey.private_key_to_pkcs8()?
             } else {
                 pkey.private_key_to_pkcs8_passphrase(
                     openssl::symm::Cipher::aes_256_cbc(),
                     password,
                 )?
-            };
+            });
             return Ok(pyo3::types::PyBytes::new_bound(py, &der_bytes));
         }
         return Err(CryptographyError::from(
@@ -154,14 +155,14 @@ pub(crate) fn pkey_private_bytes<'p>(
         }
         if let Ok(rsa) = pkey.rsa() {
             if encoding.is(&types::ENCODING_PEM.get(py)?) {
-                let pem_bytes = if password.is_empty() {
+                let pem_bytes = Zeroizing::from(if password.is_empty() {
                     rsa.private_key_to_pem()?
                 } else {
                     rsa.private_key_to_pem_passphrase(
                         openssl::symm::Cipher::aes_256_cbc(),
                         password,
                     )?
-                };
+                });
                 return Ok(pyo3::types::PyBytes::new_bound(py, &pem_bytes));
             } else if encoding.is(&types::ENCODING_DER.get(py)?) {
                 if !password.is_empty() {
@@ -172,19 +173,19 @@ pub(crate) fn pkey_private_bytes<'p>(
                     ));
                 }
 
-                let der_bytes = rsa.private_key_to_der()?;
+                let der_bytes = Zeroizing::from(rsa.private_key_to_der()?);
                 return Ok(pyo3::types::PyBytes::new_bound(py, &der_bytes));
             }
         } else if let Ok(dsa) = pkey.dsa() {
             if encoding.is(&types::ENCODING_PEM.get(py)?) {
-                let pem_bytes = if password.is_empty() {
+                let pem_bytes = Zeroizing::from(if password.is_empty() {
                     dsa.private_key_to_pem()?
                 } else {
                     dsa.private_key_to_pem_passphrase(
                         openssl::symm::Cipher::aes_256_cbc(),
                         password,
                     )?
-                };
+                });
                 return Ok(pyo3::types::PyBytes::new_bound(py, &pem_bytes));
             } else if encoding.is(&types::ENCODING_DER.get(py)?) {
                 if !password.is_empty() {
@@ -195,19 +196,19 @@ pub(crate) fn pkey_private_bytes<'p>(
                     ));
                 }
 
-                let der_bytes = dsa.private_key_to_der()?;
+                let der_bytes = Zeroizing::from(dsa.private_key_to_der()?);
                 return Ok(pyo3::types::PyBytes::new_bound(py, &der_bytes));
             }
         } else if let Ok(ec) = pkey.ec_key() {
             if encoding.is(&types::ENCODING_PEM.get(py)?) {
-                let pem_bytes = if password.is_empty() {
+                let pem_bytes = Zeroizing::from(if password.is_empty() {
                     ec.private_key_to_pem()?
                 } else {
                     ec.private_key_to_pem_passphrase(
                         openssl::symm::Cipher::aes_256_cbc(),
                         password,
                     )?
-                };
+                });
                 return Ok(pyo3::types::PyBytes::new_bound(py, &pem_bytes));
             } else if encoding.is(&types::ENCODING_DER.get(py)?) {
                 if !password.is_empty() {
@@ -218,7 +219,7 @@ pub(crate) fn pkey_private_bytes<'p>(
                     ));
                 }
 
-                let der_bytes = ec.private_key_to_der()?;
+                let der_bytes = Zeroizing::from(ec.private_key_to_der()?);
                 return Ok(pyo3::types::PyBytes::new_bound(py, &der_bytes));
             }
         }
@@ -364,6 +365,7 @@ pub(crate) fn pkey_public_bytes<'p>(
 pub(crate) enum BytesOrPyBytes<'a> {
     Bytes(&'a [u8]),
     PyBytes(pyo3::Bound<'a, pyo3::types::PyBytes>),
+    DigestBytes(openssl::hash::DigestBytes),
 }
 
 impl BytesOrPyBytes<'_> {
@@ -371,6 +373,7 @@ impl BytesOrPyBytes<'_> {
         match self {
             BytesOrPyBytes::Bytes(v) => v,
             BytesOrPyBytes::PyBytes(v) => v.as_bytes(),
+            BytesOrPyBytes::DigestBytes(v) => v,
         }
     }
 }
@@ -385,12 +388,18 @@ pub(crate) fn calculate_digest_and_algorithm<'p>(
             algorithm.getattr("_algorithm")?,
             BytesOrPyBytes::Bytes(data),
         )
-    } else {
-        // Potential optimization: rather than allocate a PyBytes in
-        // `h.finalize()`, have a way to get the `DigestBytes` directly.
+    } else if algorithm.is_instance(&types::EXTENDABLE_OUTPUT_FUNCTION.get(py)?)? {
         let mut h = Hash::new(py, algorithm, None)?;
         h.update_bytes(data)?;
         (algorithm.clone(), BytesOrPyBytes::PyBytes(h.finalize(py)?))
+    } else {
+        // Hash directly with OpenSSL so that signing doesn't need to
+        // allocate a `Hash` object and a `PyBytes` for the digest.
+        let md = hashes::message_digest_from_algorithm(py, algorithm)?;
+        (
+            algorithm.clone(),
+            BytesOrPyBytes::DigestBytes(openssl::hash::hash(md, data)?),
+        )
     };
 
     if data.as_bytes().len() != algorithm.getattr("digest_size")?.extract()? {
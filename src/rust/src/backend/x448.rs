@@ -2,9 +2,10 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
-use crate::backend::utils;
+use crate::backend::{kdf, utils};
 use crate::buf::CffiBuf;
 use crate::error::CryptographyResult;
+use crate::zeroize::Zeroizing;
 use pyo3::types::PyModuleMethods;
 
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.openssl.x448")]
@@ -83,6 +84,27 @@ impl X448PrivateKey {
         )?)
     }
 
+    #[pyo3(signature = (peer_public_key, algorithm, length, salt, info))]
+    fn exchange_hkdf<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        peer_public_key: &X448PublicKey,
+        algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+        length: usize,
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let mut deriver = openssl::derive::Deriver::new(&self.pkey)?;
+        deriver.set_peer(&peer_public_key.pkey)?;
+        let mut shared_secret = Zeroizing::from(vec![0; deriver.len()?]);
+        let n = deriver
+            .derive(&mut shared_secret)
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err("Error computing shared key."))?;
+        assert_eq!(n, shared_secret.len());
+
+        kdf::derive_hkdf_from_shared_secret(py, &shared_secret, algorithm, length, salt, info)
+    }
+
     fn public_key(&self) -> CryptographyResult<X448PublicKey> {
         let raw_bytes = self.pkey.raw_public_key()?;
         Ok(X448PublicKey {
@@ -97,7 +119,7 @@ impl X448PrivateKey {
         &self,
         py: pyo3::Python<'p>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
-        let raw_bytes = self.pkey.raw_private_key()?;
+        let raw_bytes = Zeroizing::from(self.pkey.raw_private_key()?);
         Ok(pyo3::types::PyBytes::new_bound(py, &raw_bytes))
     }
 
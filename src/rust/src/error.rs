@@ -5,7 +5,7 @@
 use pyo3::types::PyListMethods;
 use pyo3::ToPyObject;
 
-use crate::exceptions;
+use crate::{exceptions, x509};
 
 pub enum CryptographyError {
     Asn1Parse(asn1::ParseError),
@@ -45,6 +45,12 @@ impl From<openssl::error::ErrorStack> for CryptographyError {
     }
 }
 
+impl From<std::io::Error> for CryptographyError {
+    fn from(e: std::io::Error) -> CryptographyError {
+        CryptographyError::Py(e.into())
+    }
+}
+
 impl From<pem::PemError> for CryptographyError {
     fn from(e: pem::PemError) -> CryptographyError {
         CryptographyError::Py(pyo3::exceptions::PyValueError::new_err(format!(
@@ -67,9 +73,17 @@ impl From<cryptography_key_parsing::KeyParsingError> for CryptographyError {
                 ))
             }
             cryptography_key_parsing::KeyParsingError::UnsupportedKeyType(oid) => {
-                CryptographyError::Py(pyo3::exceptions::PyValueError::new_err(format!(
-                    "Unknown key type: {oid}"
-                )))
+                match x509::sign::UNSUPPORTED_NATIONAL_ALGORITHMS.get(&oid) {
+                    Some(name) => {
+                        CryptographyError::Py(exceptions::UnsupportedAlgorithm::new_err((
+                            format!("{name} ({oid}) public keys are not supported"),
+                            exceptions::Reasons::UNSUPPORTED_PUBLIC_KEY_ALGORITHM,
+                        )))
+                    }
+                    None => CryptographyError::Py(pyo3::exceptions::PyValueError::new_err(
+                        format!("Unknown key type: {oid}"),
+                    )),
+                }
             }
             cryptography_key_parsing::KeyParsingError::UnsupportedEllipticCurve(oid) => {
                 CryptographyError::Py(exceptions::UnsupportedAlgorithm::new_err((
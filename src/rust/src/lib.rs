@@ -24,6 +24,7 @@ mod pkcs12;
 mod pkcs7;
 pub(crate) mod types;
 mod x509;
+mod zeroize;
 
 #[cfg(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER)]
 #[pyo3::pyclass(module = "cryptography.hazmat.bindings._rust")]
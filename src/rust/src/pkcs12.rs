@@ -4,7 +4,8 @@
 
 use crate::backend::{hashes, hmac, keys};
 use crate::buf::CffiBuf;
-use crate::error::CryptographyResult;
+use crate::error::{CryptographyError, CryptographyResult};
+use crate::exceptions;
 use crate::x509::certificate::Certificate;
 use crate::{types, x509};
 use cryptography_x509::common::Utf8StoredBMPString;
@@ -399,13 +400,68 @@ fn decode_p12(
         // same.
         ""
     };
-    let parsed = p12
-        .parse2(password)
-        .map_err(|_| pyo3::exceptions::PyValueError::new_err("Invalid password or PKCS12 data"))?;
+    let parsed = p12.parse2(password).map_err(|e| {
+        if is_unsupported_algorithm(&e) {
+            return CryptographyError::from(exceptions::UnsupportedAlgorithm::new_err((
+                "PKCS12 data is encrypted with an algorithm that is not available. Legacy PBES1 algorithms such as pbeWithSHA1And40BitRC2-CBC require OpenSSL's legacy provider.",
+                exceptions::Reasons::UNSUPPORTED_CIPHER,
+            )));
+        }
+        let message = if has_pkcs12_error(&e, PKCS12_R_MAC_ABSENT) {
+            // OpenSSL only skips the MAC check when no password is given.
+            "PKCS12 data has no MAC, which is only supported when loading it without a password"
+        } else if has_pkcs12_error(&e, PKCS12_R_MAC_VERIFY_FAILURE) {
+            if password.is_empty() {
+                "PKCS12 data is protected by a password, but no password was provided"
+            } else {
+                "Invalid password: PKCS12 MAC verification failed"
+            }
+        } else if has_pkcs12_error(&e, PKCS12_R_PKCS12_CIPHERFINAL_ERROR) {
+            "Invalid password: PKCS12 data could not be decrypted"
+        } else {
+            "Invalid password or PKCS12 data"
+        };
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(message))
+    })?;
 
     Ok(parsed)
 }
 
+// Library and reason codes from OpenSSL's err.h and pkcs12err.h. They're
+// matched instead of the reason strings, which are only meant for display.
+const ERR_LIB_PKCS12: std::os::raw::c_int = 35;
+const PKCS12_R_MAC_ABSENT: std::os::raw::c_int = 108;
+const PKCS12_R_MAC_VERIFY_FAILURE: std::os::raw::c_int = 113;
+const PKCS12_R_PKCS12_CIPHERFINAL_ERROR: std::os::raw::c_int = 116;
+
+// OpenSSL 3 reports a cipher that no loaded provider implements (e.g. RC2
+// without the legacy provider) as an ERR_R_UNSUPPORTED fetch failure, which
+// we want to distinguish from a bad password. ERR_R_UNSUPPORTED is one of
+// the reasons common to all libraries, so it carries ERR_RFLAG_COMMON.
+#[cfg(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER)]
+fn is_unsupported_algorithm(errors: &openssl::error::ErrorStack) -> bool {
+    const ERR_LIB_EVP: std::os::raw::c_int = 6;
+    const ERR_R_UNSUPPORTED: std::os::raw::c_int = 268 | (0x2 << 18);
+    errors
+        .errors()
+        .iter()
+        .any(|e| e.library_code() == ERR_LIB_EVP && e.reason_code() == ERR_R_UNSUPPORTED)
+}
+
+// Before OpenSSL 3 there are no providers, so a known cipher is always
+// available.
+#[cfg(not(CRYPTOGRAPHY_OPENSSL_300_OR_GREATER))]
+fn is_unsupported_algorithm(_errors: &openssl::error::ErrorStack) -> bool {
+    false
+}
+
+fn has_pkcs12_error(errors: &openssl::error::ErrorStack, reason: std::os::raw::c_int) -> bool {
+    errors
+        .errors()
+        .iter()
+        .any(|e| e.library_code() == ERR_LIB_PKCS12 && e.reason_code() == reason)
+}
+
 #[pyo3::pyfunction]
 #[pyo3(signature = (data, password, backend=None))]
 fn load_key_and_certificates<'p>(
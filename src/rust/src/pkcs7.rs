@@ -7,6 +7,8 @@ use std::collections::HashMap;
 use std::ops::Deref;
 
 use cryptography_x509::csr::Attribute;
+use cryptography_x509::extensions::SubjectAlternativeName;
+use cryptography_x509::name::GeneralName;
 use cryptography_x509::{common, oid, pkcs7};
 use once_cell::sync::Lazy;
 #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
@@ -16,16 +18,20 @@ use pyo3::types::{PyAnyMethods, PyBytesMethods, PyListMethods, PyModuleMethods};
 use pyo3::IntoPy;
 
 use crate::asn1::encode_der_data;
+use crate::backend::keys;
 use crate::buf::CffiBuf;
 use crate::error::{CryptographyError, CryptographyResult};
 #[cfg(not(CRYPTOGRAPHY_IS_BORINGSSL))]
 use crate::x509::certificate::load_der_x509_certificate;
+use crate::zeroize::Zeroizing;
 use crate::{exceptions, types, x509};
 
 const PKCS7_CONTENT_TYPE_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 3);
 const PKCS7_MESSAGE_DIGEST_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 4);
 const PKCS7_SIGNING_TIME_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 5);
+const PKCS7_COUNTERSIGNATURE_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 6);
 const PKCS7_SMIME_CAP_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 15);
+const EMAIL_ADDRESS_OID: asn1::ObjectIdentifier = asn1::oid!(1, 2, 840, 113549, 1, 9, 1);
 
 static OIDS_TO_MIC_NAME: Lazy<HashMap<&asn1::ObjectIdentifier, &str>> = Lazy::new(|| {
     let mut h = HashMap::new();
@@ -407,6 +413,747 @@ fn load_der_pkcs7_certificates<'p>(
     }
 }
 
+fn pkcs7_der_from_pem(data: &[u8]) -> CryptographyResult<Vec<u8>> {
+    let parsed = x509::find_in_pem(
+        data,
+        |p| p.tag() == "PKCS7" || p.tag() == "CMS",
+        "Valid PEM but no BEGIN PKCS7/END PKCS7 delimiters. Are you sure this is a PKCS7?",
+    )?;
+    Ok(parsed.into_contents())
+}
+
+fn parse_content_info(data: &[u8]) -> CryptographyResult<pkcs7::RawContentInfo<'_>> {
+    asn1::parse_single(data).map_err(|_| {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "Unable to parse PKCS7 data",
+        ))
+    })
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_content_type_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let content_info = parse_content_info(data)?;
+    Ok(crate::asn1::oid_to_py_oid(py, &content_info.content_type)?)
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_content_type_pem<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_content_type_der(py, &der)
+}
+
+fn recipient_matches(
+    rid: &pkcs7::RecipientIdentifier<'_>,
+    cert: &cryptography_x509::certificate::Certificate<'_>,
+) -> CryptographyResult<bool> {
+    match rid {
+        pkcs7::RecipientIdentifier::IssuerAndSerialNumber(ias) => Ok(ias.serial_number
+            == cert.tbs_cert.serial
+            && ias.issuer.unwrap_read() == cert.issuer()),
+        pkcs7::RecipientIdentifier::SubjectKeyIdentifier(ski) => {
+            let extensions = cert.extensions().map_err(|_| {
+                CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                    "The recipient certificate has duplicate extensions",
+                ))
+            })?;
+            match extensions.get_extension(&oid::SUBJECT_KEY_IDENTIFIER_OID) {
+                Some(ext) => Ok(ext.value::<&[u8]>()? == *ski),
+                None => Ok(false),
+            }
+        }
+    }
+}
+
+fn decrypt_auth_enveloped_data<'p>(
+    py: pyo3::Python<'p>,
+    content: asn1::Tlv<'_>,
+    certificate: &x509::certificate::Certificate,
+    private_key: &pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let invalid = || {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "Unable to decrypt the PKCS7 data. It may be corrupted, have been tampered with, or not be addressed to this recipient.",
+        ))
+    };
+
+    let enveloped = content
+        .parse::<pkcs7::AuthEnvelopedData<'_>>()
+        .map_err(|_| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "Unable to parse PKCS7 data",
+            ))
+        })?;
+
+    let eci = &enveloped.auth_encrypted_content_info;
+    let (cipher, params) = match &eci.content_encryption_algorithm.params {
+        common::AlgorithmParameters::Aes128Gcm(params) => {
+            (openssl::symm::Cipher::aes_128_gcm(), params)
+        }
+        common::AlgorithmParameters::Aes192Gcm(params) => {
+            (openssl::symm::Cipher::aes_192_gcm(), params)
+        }
+        common::AlgorithmParameters::Aes256Gcm(params) => {
+            (openssl::symm::Cipher::aes_256_gcm(), params)
+        }
+        _ => {
+            return Err(CryptographyError::from(
+                exceptions::UnsupportedAlgorithm::new_err((
+                    format!(
+                    "Only AES-GCM content encryption is supported for AuthEnvelopedData, not {}",
+                    eci.content_encryption_algorithm.oid()
+                ),
+                    exceptions::Reasons::UNSUPPORTED_CIPHER,
+                )),
+            ))
+        }
+    };
+    if !(12..=16).contains(&params.icv_len) || enveloped.mac.len() != usize::from(params.icv_len) {
+        return Err(invalid());
+    }
+
+    let raw_cert = certificate.raw.borrow_dependent();
+    let mut recipient = None;
+    for ri in enveloped.recipient_infos.clone() {
+        if let pkcs7::RecipientInfo::KeyTrans(ktri) = ri {
+            if recipient_matches(&ktri.rid, raw_cert)? {
+                recipient = Some(ktri);
+                break;
+            }
+        }
+    }
+    let recipient = recipient.ok_or_else(|| {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "The PKCS7 data has no key transport recipient matching the provided certificate",
+        ))
+    })?;
+    if !matches!(
+        recipient.key_encryption_algorithm.params,
+        common::AlgorithmParameters::Rsa(_)
+    ) {
+        return Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err((
+                format!(
+                    "Only RSA PKCS1v15 key transport is supported, not {}",
+                    recipient.key_encryption_algorithm.oid()
+                ),
+                exceptions::Reasons::UNSUPPORTED_PADDING,
+            )),
+        ));
+    }
+
+    // Per RFC 3218 section 2.3.2, a failure to unwrap the content-encryption
+    // key is replaced by a random key, so that it is indistinguishable from a
+    // failure to authenticate the content.
+    let unwrapped = private_key
+        .call_method1(
+            pyo3::intern!(py, "decrypt"),
+            (
+                pyo3::types::PyBytes::new_bound(py, recipient.encrypted_key),
+                types::PKCS1V15.get(py)?.call0()?,
+            ),
+        )
+        .and_then(|k| k.extract::<Vec<u8>>());
+    let key = Zeroizing::from(match unwrapped {
+        Ok(k) if k.len() == cipher.key_len() => k,
+        _ => {
+            let mut k = vec![0; cipher.key_len()];
+            openssl::rand::rand_bytes(&mut k)?;
+            k
+        }
+    });
+
+    let aad = match &enveloped.auth_attrs {
+        Some(attrs) => asn1::write_single(attrs)?,
+        None => vec![],
+    };
+    let plaintext = Zeroizing::from(
+        openssl::symm::decrypt_aead(
+            cipher,
+            &key,
+            Some(params.nonce),
+            &aad,
+            eci.encrypted_content.unwrap_or_default(),
+            enveloped.mac,
+        )
+        .map_err(|_| invalid())?,
+    );
+
+    Ok(pyo3::types::PyBytes::new_bound(py, &plaintext))
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_decrypt_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'p, x509::certificate::Certificate>,
+    private_key: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    if !private_key.is_instance(&types::RSA_PRIVATE_KEY.get(py)?)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err("private_key must be an RSA private key"),
+        ));
+    }
+
+    let content_info = parse_content_info(data)?;
+    if content_info.content_type != pkcs7::PKCS7_AUTH_ENVELOPED_DATA_OID {
+        return Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err((
+                format!(
+                    "Only AuthEnvelopedData structures are currently supported for decryption. Content type for this data was {}",
+                    content_info.content_type
+                ),
+                exceptions::Reasons::UNSUPPORTED_SERIALIZATION,
+            )),
+        ));
+    }
+    let content = content_info.content.ok_or_else(|| {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "The provided PKCS7 has no content",
+        ))
+    })?;
+
+    decrypt_auth_enveloped_data(py, content, &certificate, &private_key)
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_decrypt_pem<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'p, x509::certificate::Certificate>,
+    private_key: pyo3::Bound<'p, pyo3::PyAny>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_decrypt_der(py, &der, certificate, private_key)
+}
+
+fn verify_signer_info(
+    py: pyo3::Python<'_>,
+    signer_info: &pkcs7::SignerInfo<'_>,
+    certificate: &cryptography_x509::certificate::Certificate<'_>,
+    content: Option<&[u8]>,
+    content_digest: Option<&[u8]>,
+    content_type: Option<&asn1::ObjectIdentifier>,
+) -> CryptographyResult<()> {
+    let invalid = || CryptographyError::from(exceptions::InvalidSignature::new_err(()));
+
+    let hash_name = x509::ocsp::ALGORITHM_PARAMETERS_TO_HASH
+        .get(&signer_info.digest_algorithm.params)
+        .ok_or_else(|| {
+            CryptographyError::from(exceptions::UnsupportedAlgorithm::new_err((
+                format!(
+                    "Digest algorithm {} is not supported for PKCS7 verification",
+                    signer_info.digest_algorithm.oid()
+                ),
+                exceptions::Reasons::UNSUPPORTED_HASH,
+            )))
+        })?;
+    let py_hash_alg = types::HASHES_MODULE.get(py)?.getattr(*hash_name)?.call0()?;
+
+    let signed_attrs_bytes;
+    let signed_data = match &signer_info.authenticated_attributes {
+        Some(attrs) => {
+            let computed_digest;
+            let digest = match (content, content_digest) {
+                (_, Some(d)) => d,
+                (Some(c), None) => {
+                    computed_digest = x509::ocsp::hash_data(py, &py_hash_alg, c)?;
+                    computed_digest.as_bytes()
+                }
+                (None, None) => {
+                    return Err(CryptographyError::from(
+                        pyo3::exceptions::PyValueError::new_err(
+                            "The PKCS7 data has no content, either content or content_digest must be provided",
+                        ),
+                    ))
+                }
+            };
+
+            let mut message_digests = attrs
+                .unwrap_read()
+                .clone()
+                .filter(|attr| attr.type_id == PKCS7_MESSAGE_DIGEST_OID)
+                .flat_map(|attr| attr.values.unwrap_read().clone());
+            let message_digest = match (message_digests.next(), message_digests.next()) {
+                (Some(v), None) => v.parse::<&[u8]>()?,
+                _ => {
+                    return Err(CryptographyError::from(
+                        pyo3::exceptions::PyValueError::new_err(
+                            "The signer's authenticated attributes must contain exactly one message digest",
+                        ),
+                    ))
+                }
+            };
+            // `memcmp::eq` panics on inputs of different lengths.
+            if message_digest.len() != digest.len()
+                || !openssl::memcmp::eq(message_digest, digest)
+            {
+                return Err(invalid());
+            }
+
+            // The content type attribute must match the type of the content
+            // that was signed (RFC 5652, section 5.6). Countersignatures
+            // don't have one, so `content_type` is `None` for them.
+            if let Some(content_type) = content_type {
+                let mut content_types = attrs
+                    .unwrap_read()
+                    .clone()
+                    .filter(|attr| attr.type_id == PKCS7_CONTENT_TYPE_OID)
+                    .flat_map(|attr| attr.values.unwrap_read().clone());
+                let signed_content_type = match (content_types.next(), content_types.next()) {
+                    (Some(v), None) => v.parse::<asn1::ObjectIdentifier>()?,
+                    _ => {
+                        return Err(CryptographyError::from(
+                            pyo3::exceptions::PyValueError::new_err(
+                                "The signer's authenticated attributes must contain exactly one content type",
+                            ),
+                        ))
+                    }
+                };
+                if &signed_content_type != content_type {
+                    return Err(invalid());
+                }
+            }
+
+            // The signature covers the DER encoding of the attributes, with
+            // a SET tag rather than the IMPLICIT [0] they're stored under.
+            signed_attrs_bytes = asn1::write_single(attrs)?;
+            &signed_attrs_bytes[..]
+        }
+        None => content.ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "The signer has no authenticated attributes, so the content itself is required to verify it",
+            ))
+        })?,
+    };
+
+    let public_key =
+        keys::load_der_public_key_bytes(py, certificate.tbs_cert.spki.tlv().full_data())?
+            .into_bound(py);
+    match signer_info.digest_encryption_algorithm.params {
+        // For RSA PKCS#1 v1.5, the hash comes from the digest algorithm; see
+        // RFC 3370 (section 3.2).
+        common::AlgorithmParameters::Rsa(_) => {
+            public_key
+                .call_method1(
+                    pyo3::intern!(py, "verify"),
+                    (
+                        pyo3::types::PyBytes::new_bound(py, signer_info.encrypted_digest),
+                        pyo3::types::PyBytes::new_bound(py, signed_data),
+                        types::PKCS1V15.get(py)?.call0()?,
+                        py_hash_alg,
+                    ),
+                )
+                .map_err(|_| invalid())?;
+        }
+        _ => x509::sign::verify_signature_with_signature_algorithm(
+            py,
+            public_key,
+            &signer_info.digest_encryption_algorithm,
+            signer_info.encrypted_digest,
+            signed_data,
+        )?,
+    };
+    Ok(())
+}
+
+fn parse_signed_data_for_verification(
+    data: &[u8],
+    content_provided: bool,
+    content_digest_provided: bool,
+) -> CryptographyResult<pkcs7::RawSignedData<'_>> {
+    if content_provided && content_digest_provided {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(
+                "Only one of content and content_digest may be provided",
+            ),
+        ));
+    }
+
+    parse_signed_data(data, "verified")
+}
+
+fn parse_signed_data<'a>(
+    data: &'a [u8],
+    operation: &str,
+) -> CryptographyResult<pkcs7::RawSignedData<'a>> {
+    let content_info = parse_content_info(data)?;
+    if content_info.content_type != pkcs7::PKCS7_SIGNED_DATA_OID {
+        return Err(CryptographyError::from(
+            exceptions::UnsupportedAlgorithm::new_err((
+                format!(
+                    "Only SignedData structures can be {operation}. Content type for this data was {}",
+                    content_info.content_type
+                ),
+                exceptions::Reasons::UNSUPPORTED_SERIALIZATION,
+            )),
+        ));
+    }
+    let signed_data = content_info
+        .content
+        .ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "The provided PKCS7 has no content",
+            ))
+        })?
+        .parse::<pkcs7::RawSignedData<'_>>()
+        .map_err(|_| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "Unable to parse PKCS7 SignedData",
+            ))
+        })?;
+    check_digest_algorithms(&signed_data)?;
+    Ok(signed_data)
+}
+
+// The digestAlgorithms set lists the digest algorithms used by all of the
+// signers, so that the content can be digested in a single pass (RFC 5652,
+// section 5.1). A signer using an undeclared algorithm indicates a
+// malformed or tampered message, so it's rejected rather than ignored.
+fn check_digest_algorithms(signed_data: &pkcs7::RawSignedData<'_>) -> CryptographyResult<()> {
+    for signer_info in signed_data.signer_infos.clone() {
+        let oid = signer_info.digest_algorithm.oid();
+        if !signed_data
+            .digest_algorithms
+            .clone()
+            .any(|alg| alg.oid() == oid)
+        {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "The digest algorithm {oid} of one of the PKCS7 signers is not listed in the SignedData digestAlgorithms"
+                )),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_digest_algorithms_der<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let signed_data = parse_signed_data(data, "inspected")?;
+    let algorithms = pyo3::types::PyList::empty_bound(py);
+    for alg in signed_data.digest_algorithms.clone() {
+        algorithms.append(crate::asn1::oid_to_py_oid(py, alg.oid())?)?;
+    }
+    Ok(algorithms)
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_digest_algorithms_pem<'p>(
+    py: pyo3::Python<'p>,
+    data: &[u8],
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_digest_algorithms_der(py, &der)
+}
+
+// Without detached content or a digest, the encapsulated content is verified.
+fn content_for_verification<'a>(
+    signed_data: &pkcs7::RawSignedData<'a>,
+    content: Option<&'a [u8]>,
+    content_digest_provided: bool,
+) -> CryptographyResult<Option<&'a [u8]>> {
+    if content.is_some() || content_digest_provided {
+        return Ok(content);
+    }
+    Ok(signed_data
+        .content_info
+        .content
+        .map(|c| c.parse::<&[u8]>())
+        .transpose()?)
+}
+
+fn signer_matches(
+    signer_info: &pkcs7::SignerInfo<'_>,
+    cert: &cryptography_x509::certificate::Certificate<'_>,
+) -> bool {
+    signer_info.issuer_and_serial_number.serial_number == cert.tbs_cert.serial
+        && signer_info.issuer_and_serial_number.issuer.unwrap_read() == cert.issuer()
+}
+
+// Local parts are compared exactly, while domains are compared
+// case-insensitively (RFC 5321, section 2.4).
+fn email_address_matches(address: &str, expected: &str) -> bool {
+    match (address.rsplit_once('@'), expected.rsplit_once('@')) {
+        (Some((local, domain)), Some((expected_local, expected_domain))) => {
+            local == expected_local && domain.eq_ignore_ascii_case(expected_domain)
+        }
+        _ => false,
+    }
+}
+
+// The signer's address is taken from the rfc822Name entries of its
+// subjectAltName, or from emailAddress attributes in its subject, which
+// older certificates use instead (RFC 8550, section 3).
+fn check_signer_email(
+    cert: &cryptography_x509::certificate::Certificate<'_>,
+    expected_email: &str,
+) -> CryptographyResult<()> {
+    let extensions = cert.extensions().map_err(|_| {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "The signer's certificate has duplicate extensions",
+        ))
+    })?;
+    let mut addresses = vec![];
+    if let Some(ext) = extensions.get_extension(&oid::SUBJECT_ALTERNATIVE_NAME_OID) {
+        for name in ext.value::<SubjectAlternativeName<'_>>()? {
+            if let GeneralName::RFC822Name(address) = name {
+                addresses.push(address.0);
+            }
+        }
+    }
+    for rdn in cert.subject().clone() {
+        for attribute in rdn {
+            if attribute.type_id == EMAIL_ADDRESS_OID {
+                if let Ok(address) = std::str::from_utf8(attribute.value.data()) {
+                    addresses.push(address);
+                }
+            }
+        }
+    }
+
+    if !addresses
+        .iter()
+        .any(|address| email_address_matches(address, expected_email))
+    {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "The signer's certificate is not for the expected email address {expected_email}"
+            )),
+        ));
+    }
+    Ok(())
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, certificate, *, content = None, content_digest = None, expected_email = None))]
+fn pkcs7_verify_der(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    content: Option<CffiBuf<'_>>,
+    content_digest: Option<&[u8]>,
+    expected_email: Option<&str>,
+) -> CryptographyResult<()> {
+    let signed_data =
+        parse_signed_data_for_verification(data, content.is_some(), content_digest.is_some())?;
+    let content = content_for_verification(
+        &signed_data,
+        content.as_ref().map(|c| c.as_bytes()),
+        content_digest.is_some(),
+    )?;
+
+    let cert = certificate.raw.borrow_dependent();
+    let signer_info = signed_data
+        .signer_infos
+        .clone()
+        .find(|si| signer_matches(si, cert))
+        .ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "No signer in the PKCS7 data matches the provided certificate",
+            ))
+        })?;
+
+    verify_signer_info(
+        py,
+        &signer_info,
+        cert,
+        content,
+        content_digest,
+        Some(&signed_data.content_info.content_type),
+    )?;
+    if let Some(expected_email) = expected_email {
+        check_signer_email(cert, expected_email)?;
+    }
+    Ok(())
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, certificates, *, content = None, content_digest = None, require_all = true))]
+fn pkcs7_verify_signers_der(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificates: Vec<pyo3::Py<x509::certificate::Certificate>>,
+    content: Option<CffiBuf<'_>>,
+    content_digest: Option<&[u8]>,
+    require_all: bool,
+) -> CryptographyResult<Vec<pyo3::Py<x509::certificate::Certificate>>> {
+    let signed_data =
+        parse_signed_data_for_verification(data, content.is_some(), content_digest.is_some())?;
+    let content = content_for_verification(
+        &signed_data,
+        content.as_ref().map(|c| c.as_bytes()),
+        content_digest.is_some(),
+    )?;
+
+    let mut verified = vec![];
+    // Unless every signer is required to verify, a signer that fails for
+    // any reason is skipped, and the first failure is only reported if no
+    // signer verified.
+    let mut first_failure = None;
+    for signer_info in signed_data.signer_infos.clone() {
+        let certificate = certificates
+            .iter()
+            .find(|c| signer_matches(&signer_info, c.get().raw.borrow_dependent()));
+        let certificate = match certificate {
+            Some(c) => c,
+            None if require_all => {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "No certificate was provided for one of the PKCS7 signers",
+                    ),
+                ))
+            }
+            None => continue,
+        };
+
+        match verify_signer_info(
+            py,
+            &signer_info,
+            certificate.get().raw.borrow_dependent(),
+            content,
+            content_digest,
+            Some(&signed_data.content_info.content_type),
+        ) {
+            Ok(()) => verified.push(certificate.clone_ref(py)),
+            Err(e) if require_all => return Err(e),
+            Err(e) => {
+                first_failure.get_or_insert(e);
+            }
+        }
+    }
+
+    if verified.is_empty() {
+        return Err(first_failure.unwrap_or_else(|| {
+            CryptographyError::from(exceptions::InvalidSignature::new_err(()))
+        }));
+    }
+    Ok(verified)
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, certificate, *, content = None, content_digest = None, expected_email = None))]
+fn pkcs7_verify_pem(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    content: Option<CffiBuf<'_>>,
+    content_digest: Option<&[u8]>,
+    expected_email: Option<&str>,
+) -> CryptographyResult<()> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_verify_der(
+        py,
+        &der,
+        certificate,
+        content,
+        content_digest,
+        expected_email,
+    )
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (data, certificates, *, content = None, content_digest = None, require_all = true))]
+fn pkcs7_verify_signers_pem(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificates: Vec<pyo3::Py<x509::certificate::Certificate>>,
+    content: Option<CffiBuf<'_>>,
+    content_digest: Option<&[u8]>,
+    require_all: bool,
+) -> CryptographyResult<Vec<pyo3::Py<x509::certificate::Certificate>>> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_verify_signers_der(py, &der, certificates, content, content_digest, require_all)
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_verify_countersignature_der(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    countersigner_certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+) -> CryptographyResult<()> {
+    let signed_data = parse_signed_data_for_verification(data, false, false)?;
+
+    let cert = certificate.raw.borrow_dependent();
+    let signer_info = signed_data
+        .signer_infos
+        .clone()
+        .find(|si| signer_matches(si, cert))
+        .ok_or_else(|| {
+            CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+                "No signer in the PKCS7 data matches the provided certificate",
+            ))
+        })?;
+
+    // A countersignature is a SignerInfo carried in an unauthenticated
+    // attribute of the signer it countersigns; see RFC 5652 (section 11.4).
+    let countersigner_cert = countersigner_certificate.raw.borrow_dependent();
+    let mut countersignature = None;
+    if let Some(attrs) = &signer_info.unauthenticated_attributes {
+        for attr in attrs.unwrap_read().clone() {
+            if attr.type_id != PKCS7_COUNTERSIGNATURE_OID {
+                continue;
+            }
+            for value in attr.values.unwrap_read().clone() {
+                let si = value.parse::<pkcs7::SignerInfo<'_>>()?;
+                if signer_matches(&si, countersigner_cert) {
+                    countersignature = Some(si);
+                }
+            }
+        }
+    }
+    let countersignature = countersignature.ok_or_else(|| {
+        CryptographyError::from(pyo3::exceptions::PyValueError::new_err(
+            "The signer has no countersignature matching the provided countersigner certificate",
+        ))
+    })?;
+
+    if let Some(attrs) = &countersignature.authenticated_attributes {
+        if attrs
+            .unwrap_read()
+            .clone()
+            .any(|attr| attr.type_id == PKCS7_CONTENT_TYPE_OID)
+        {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Countersignatures must not have a content type attribute",
+                ),
+            ));
+        }
+    }
+
+    // The countersignature covers the contents of the signature it
+    // countersigns.
+    verify_signer_info(
+        py,
+        &countersignature,
+        countersigner_cert,
+        Some(signer_info.encrypted_digest),
+        None,
+        None,
+    )
+}
+
+#[pyo3::pyfunction]
+fn pkcs7_verify_countersignature_pem(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+    certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    countersigner_certificate: pyo3::PyRef<'_, x509::certificate::Certificate>,
+) -> CryptographyResult<()> {
+    let der = pkcs7_der_from_pem(data)?;
+    pkcs7_verify_countersignature_der(py, &der, certificate, countersigner_certificate)
+}
+
 pub(crate) fn create_submodule(
     py: pyo3::Python<'_>,
 ) -> pyo3::PyResult<pyo3::Bound<'_, pyo3::types::PyModule>> {
@@ -425,6 +1172,42 @@ pub(crate) fn create_submodule(
         load_der_pkcs7_certificates,
         &submod
     )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_content_type_der,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_content_type_pem,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(pkcs7_decrypt_der, &submod)?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(pkcs7_decrypt_pem, &submod)?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(pkcs7_verify_der, &submod)?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(pkcs7_verify_pem, &submod)?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_verify_signers_der,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_verify_signers_pem,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_verify_countersignature_der,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_verify_countersignature_pem,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_digest_algorithms_der,
+        &submod
+    )?)?;
+    submod.add_function(pyo3::wrap_pyfunction_bound!(
+        pkcs7_digest_algorithms_pem,
+        &submod
+    )?)?;
 
     Ok(submod)
 }
@@ -434,7 +1217,24 @@ mod tests {
     use std::borrow::Cow;
     use std::ops::Deref;
 
-    use super::smime_canonicalize;
+    use super::{email_address_matches, smime_canonicalize};
+
+    #[test]
+    fn test_email_address_matches() {
+        for (address, expected, matches) in [
+            ("alice@example.com", "alice@example.com", true),
+            ("alice@EXAMPLE.com", "alice@example.COM", true),
+            ("Alice@example.com", "alice@example.com", false),
+            ("alice@example.com", "bob@example.com", false),
+            ("alice@example.com", "alice@example.org", false),
+            ("alice@example.com", "alice@mail.example.com", false),
+            ("\"a@b\"@example.com", "\"a@b\"@example.com", true),
+            ("alice", "alice", false),
+            ("alice@example.com", "alice", false),
+        ] {
+            assert_eq!(email_address_matches(address, expected), matches);
+        }
+    }
 
     #[test]
     fn test_smime_canonicalize() {
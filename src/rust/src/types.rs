@@ -181,9 +181,13 @@ pub static CERTIFICATE_ISSUER: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["CertificateIssuer"]);
 pub static INVALIDITY_DATE: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["InvalidityDate"]);
+pub static HOLD_INSTRUCTION_CODE: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["HoldInstructionCode"]);
 pub static OCSP_NONCE: LazyPyImport = LazyPyImport::new("cryptography.x509", &["OCSPNonce"]);
 pub static OCSP_ACCEPTABLE_RESPONSES: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["OCSPAcceptableResponses"]);
+pub static OCSP_ARCHIVE_CUTOFF: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["OCSPArchiveCutoff"]);
 pub static SIGNED_CERTIFICATE_TIMESTAMPS: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["SignedCertificateTimestamps"]);
 pub static PRECERT_POISON: LazyPyImport =
@@ -209,8 +213,24 @@ pub static NAME_ATTRIBUTE: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["NameAttribute"]);
 pub static NAME_CONSTRAINTS: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["NameConstraints"]);
+pub static MS_CERTIFICATE_TEMPLATE_NAME: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["MSCertificateTemplateName"]);
 pub static MS_CERTIFICATE_TEMPLATE: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["MSCertificateTemplate"]);
+pub static MS_APPLICATION_POLICIES: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["MSApplicationPolicies"]);
+pub static ADMISSIONS: LazyPyImport = LazyPyImport::new("cryptography.x509", &["Admissions"]);
+pub static NETSCAPE_CERT_TYPE: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["NetscapeCertType"]);
+pub static NETSCAPE_COMMENT: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["NetscapeComment"]);
+pub static ENTRUST_VERSION_INFO: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["EntrustVersionInfo"]);
+pub static ADMISSION: LazyPyImport = LazyPyImport::new("cryptography.x509", &["Admission"]);
+pub static PROFESSION_INFO: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["ProfessionInfo"]);
+pub static NAMING_AUTHORITY: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["NamingAuthority"]);
 pub static CRL_DISTRIBUTION_POINTS: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["CRLDistributionPoints"]);
 pub static BASIC_CONSTRAINTS: LazyPyImport =
@@ -220,6 +240,10 @@ pub static INHIBIT_ANY_POLICY: LazyPyImport =
 pub static OCSP_NO_CHECK: LazyPyImport = LazyPyImport::new("cryptography.x509", &["OCSPNoCheck"]);
 pub static POLICY_CONSTRAINTS: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["PolicyConstraints"]);
+pub static POLICY_MAPPINGS: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["PolicyMappings"]);
+pub static POLICY_MAPPING: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["PolicyMapping"]);
 pub static CERTIFICATE_POLICIES: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["CertificatePolicies"]);
 pub static SUBJECT_INFORMATION_ACCESS: LazyPyImport =
@@ -238,6 +262,7 @@ pub static USER_NOTICE: LazyPyImport = LazyPyImport::new("cryptography.x509", &[
 pub static NOTICE_REFERENCE: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["NoticeReference"]);
 pub static REGISTERED_ID: LazyPyImport = LazyPyImport::new("cryptography.x509", &["RegisteredID"]);
+pub static X400_ADDRESS: LazyPyImport = LazyPyImport::new("cryptography.x509", &["X400Address"]);
 pub static DIRECTORY_NAME: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["DirectoryName"]);
 pub static UNIFORM_RESOURCE_IDENTIFIER: LazyPyImport =
@@ -250,6 +275,16 @@ pub static CERTIFICATE_VERSION_V1: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["Version", "v1"]);
 pub static CERTIFICATE_VERSION_V3: LazyPyImport =
     LazyPyImport::new("cryptography.x509", &["Version", "v3"]);
+pub static CERTIFICATE_VALIDITY_STATUS_VALID: LazyPyImport =
+    LazyPyImport::new("cryptography.x509", &["CertificateValidityStatus", "VALID"]);
+pub static CERTIFICATE_VALIDITY_STATUS_EXPIRED: LazyPyImport = LazyPyImport::new(
+    "cryptography.x509",
+    &["CertificateValidityStatus", "EXPIRED"],
+);
+pub static CERTIFICATE_VALIDITY_STATUS_NOT_YET_VALID: LazyPyImport = LazyPyImport::new(
+    "cryptography.x509",
+    &["CertificateValidityStatus", "NOT_YET_VALID"],
+);
 
 pub static CRL_REASON_FLAGS: LazyPyImport =
     LazyPyImport::new("cryptography.x509.extensions", &["_CRLREASONFLAGS"]);
@@ -3,25 +3,31 @@
 // for complete details.
 
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-use cryptography_x509::certificate::Certificate as RawCertificate;
+use cryptography_x509::certificate::{
+    Certificate as RawCertificate, CertificateView as RawCertificateView,
+};
 use cryptography_x509::common::{AlgorithmParameters, Asn1ReadableOrWritable};
 use cryptography_x509::extensions::{
-    AuthorityKeyIdentifier, BasicConstraints, DisplayText, DistributionPoint,
-    DistributionPointName, DuplicateExtensionsError, IssuerAlternativeName, KeyUsage,
-    MSCertificateTemplate, NameConstraints, PolicyConstraints, PolicyInformation,
-    PolicyQualifierInfo, Qualifier, RawExtensions, SequenceOfAccessDescriptions,
-    SequenceOfSubtrees, UserNotice,
+    Admissions, AuthorityKeyIdentifier, BasicConstraints, DirectoryString, DisplayText,
+    DistributionPoint, DistributionPointName, DuplicateExtensionsError, EntrustVersionInfo,
+    IssuerAlternativeName, KeyUsage, MSCertificateTemplate, NameConstraints, NamingAuthority,
+    PolicyConstraints, PolicyInformation, PolicyMapping, PolicyQualifierInfo, ProfessionInfo,
+    Qualifier, RawExtensions, SequenceOfAccessDescriptions, SequenceOfSubtrees, TlsFeature,
+    UserNotice,
 };
 use cryptography_x509::extensions::{Extension, SubjectAlternativeName};
+use cryptography_x509::name::UnvalidatedIA5String;
 use cryptography_x509::{common, oid};
 use cryptography_x509_verification::ops::CryptoOps;
 use pyo3::types::{PyAnyMethods, PyListMethods, PyModuleMethods};
 use pyo3::{IntoPy, ToPyObject};
 
 use crate::asn1::{
-    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_uint_to_big_endian_bytes,
+    big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_oid_to_oid,
+    py_uint_to_big_endian_bytes,
 };
 use crate::backend::{hashes, keys};
 use crate::error::{CryptographyError, CryptographyResult};
@@ -84,6 +90,28 @@ impl Certificate {
         )
     }
 
+    #[getter]
+    fn public_key_algorithm_parameters<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        sign::identify_public_key_algorithm_parameters(
+            py,
+            &self.raw.borrow_dependent().tbs_cert.spki.algorithm,
+        )
+    }
+
+    #[getter]
+    fn public_key_hash_algorithm<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        sign::identify_public_key_hash_algorithm(
+            py,
+            &self.raw.borrow_dependent().tbs_cert.spki.algorithm,
+        )
+    }
+
     fn fingerprint<'p>(
         &self,
         py: pyo3::Python<'p>,
@@ -116,6 +144,27 @@ impl Certificate {
         Ok(big_byte_slice_to_py_int(py, bytes)?)
     }
 
+    #[getter]
+    fn serial_number_raw<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<(
+        pyo3::Bound<'p, pyo3::types::PyBytes>,
+        pyo3::Bound<'p, pyo3::PyAny>,
+    )> {
+        let bytes = self.raw.borrow_dependent().tbs_cert.serial.as_bytes();
+        warn_if_negative_serial(py, bytes)?;
+        Ok((
+            pyo3::types::PyBytes::new_bound(py, bytes),
+            big_byte_slice_to_py_int(py, bytes)?,
+        ))
+    }
+
+    #[getter]
+    fn serial_number_is_negative(&self) -> bool {
+        serial_is_negative(self.raw.borrow_dependent().tbs_cert.serial.as_bytes())
+    }
+
     #[getter]
     fn version<'p>(
         &self,
@@ -147,7 +196,7 @@ impl Certificate {
     }
 
     #[getter]
-    fn tbs_precertificate_bytes<'p>(
+    pub(crate) fn tbs_precertificate_bytes<'p>(
         &self,
         py: pyo3::Python<'p>,
     ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
@@ -269,6 +318,31 @@ impl Certificate {
         x509::datetime_to_py_utc(py, dt)
     }
 
+    #[pyo3(signature = (time=None))]
+    fn validity_status<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        time: Option<pyo3::Bound<'p, pyo3::PyAny>>,
+    ) -> CryptographyResult<(pyo3::Bound<'p, pyo3::PyAny>, i64)> {
+        let time = match time {
+            Some(t) => x509::py_to_datetime(py, t)?,
+            None => x509::common::datetime_now(py)?,
+        };
+        let validity = &self.raw.borrow_dependent().tbs_cert.validity;
+        let now = x509::common::datetime_to_unix_seconds(&time);
+        let not_before = x509::common::datetime_to_unix_seconds(validity.not_before.as_datetime());
+        let not_after = x509::common::datetime_to_unix_seconds(validity.not_after.as_datetime());
+
+        let status = if now < not_before {
+            types::CERTIFICATE_VALIDITY_STATUS_NOT_YET_VALID.get(py)?
+        } else if now > not_after {
+            types::CERTIFICATE_VALIDITY_STATUS_EXPIRED.get(py)?
+        } else {
+            types::CERTIFICATE_VALIDITY_STATUS_VALID.get(py)?
+        };
+        Ok((status, not_after - now))
+    }
+
     #[getter]
     fn signature_hash_algorithm<'p>(
         &self,
@@ -321,6 +395,36 @@ impl Certificate {
         )
     }
 
+    #[getter]
+    fn raw_extensions<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let raw_exts = match &self.raw.borrow_dependent().tbs_cert.raw_extensions {
+            Some(raw_exts) => raw_exts.unwrap_read().clone().collect::<Vec<_>>(),
+            None => vec![],
+        };
+
+        // Duplicate extensions are forbidden by RFC 5280, but since this
+        // accessor doesn't reject them, every occurrence of a repeated OID is
+        // flagged instead.
+        let mut counts = HashMap::new();
+        for ext in &raw_exts {
+            *counts.entry(&ext.extn_id).or_insert(0) += 1;
+        }
+
+        let result = pyo3::types::PyList::empty_bound(py);
+        for ext in &raw_exts {
+            result.append((
+                oid_to_py_oid(py, &ext.extn_id)?,
+                ext.critical,
+                pyo3::types::PyBytes::new_bound(py, ext.extn_value),
+                counts[&ext.extn_id] > 1,
+            ))?;
+        }
+        Ok(result)
+    }
+
     fn verify_directly_issued_by(
         &self,
         issuer: pyo3::PyRef<'_, Certificate>,
@@ -411,6 +515,36 @@ fn load_pem_x509_certificates(
     Ok(certs)
 }
 
+#[pyo3::pyfunction]
+fn load_der_x509_certificates(
+    py: pyo3::Python<'_>,
+    data: &[u8],
+) -> CryptographyResult<Vec<Certificate>> {
+    // The certificates are back-to-back with no framing, so split them on
+    // their outer TLVs. `asn1::parse` fails on anything that isn't a whole
+    // TLV, including trailing data.
+    let certs = asn1::parse(data, |p| {
+        let mut certs = vec![];
+        while !p.is_empty() {
+            certs.push(p.read_element::<asn1::Tlv<'_>>()?.full_data());
+        }
+        Ok::<_, asn1::ParseError>(certs)
+    })?
+    .into_iter()
+    .map(|der| {
+        load_der_x509_certificate(py, pyo3::types::PyBytes::new_bound(py, der).unbind(), None)
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    if certs.is_empty() {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err("No certificates found in data"),
+        ));
+    }
+
+    Ok(certs)
+}
+
 #[pyo3::pyfunction]
 #[pyo3(signature = (data, backend=None))]
 pub(crate) fn load_der_x509_certificate(
@@ -441,8 +575,103 @@ pub(crate) fn load_der_x509_certificate(
     })
 }
 
+self_cell::self_cell!(
+    struct OwnedCertificateView {
+        owner: pyo3::Py<pyo3::types::PyBytes>,
+
+        #[covariant]
+        dependent: RawCertificateView,
+    }
+);
+
+#[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.x509")]
+pub(crate) struct CertificateView {
+    raw: OwnedCertificateView,
+}
+
+#[pyo3::pymethods]
+impl CertificateView {
+    #[getter]
+    fn serial_number<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        let bytes = self.raw.borrow_dependent().tbs_cert.serial.as_bytes();
+        Ok(big_byte_slice_to_py_int(py, bytes)?)
+    }
+
+    #[getter]
+    fn issuer<'p>(&self, py: pyo3::Python<'p>) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        Ok(x509::parse_name(py, self.raw.borrow_dependent().issuer())
+            .map_err(|e| e.add_location(asn1::ParseLocation::Field("issuer")))?)
+    }
+
+    #[getter]
+    fn subject<'p>(&self, py: pyo3::Python<'p>) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        Ok(x509::parse_name(py, self.raw.borrow_dependent().subject())
+            .map_err(|e| e.add_location(asn1::ParseLocation::Field("subject")))?)
+    }
+
+    #[getter]
+    fn issuer_bytes<'p>(&self, py: pyo3::Python<'p>) -> pyo3::Bound<'p, pyo3::types::PyBytes> {
+        let tlv = self.raw.borrow_dependent().tbs_cert.issuer.tlv();
+        pyo3::types::PyBytes::new_bound(py, tlv.full_data())
+    }
+
+    #[getter]
+    fn subject_bytes<'p>(&self, py: pyo3::Python<'p>) -> pyo3::Bound<'p, pyo3::types::PyBytes> {
+        let tlv = self.raw.borrow_dependent().tbs_cert.subject.tlv();
+        pyo3::types::PyBytes::new_bound(py, tlv.full_data())
+    }
+
+    #[getter]
+    fn not_valid_before_utc<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        let dt = &self
+            .raw
+            .borrow_dependent()
+            .tbs_cert
+            .validity
+            .not_before
+            .as_datetime();
+        x509::datetime_to_py_utc(py, dt)
+    }
+
+    #[getter]
+    fn not_valid_after_utc<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        let dt = &self
+            .raw
+            .borrow_dependent()
+            .tbs_cert
+            .validity
+            .not_after
+            .as_datetime();
+        x509::datetime_to_py_utc(py, dt)
+    }
+}
+
+#[pyo3::pyfunction]
+fn load_der_x509_certificate_view(
+    py: pyo3::Python<'_>,
+    data: pyo3::Py<pyo3::types::PyBytes>,
+) -> CryptographyResult<CertificateView> {
+    let raw = OwnedCertificateView::try_new(data, |data| asn1::parse_single(data.as_bytes(py)))?;
+    cert_version(py, raw.borrow_dependent().tbs_cert.version)?;
+
+    Ok(CertificateView { raw })
+}
+
+fn serial_is_negative(bytes: &[u8]) -> bool {
+    bytes[0] & 0x80 != 0
+}
+
 fn warn_if_negative_serial(py: pyo3::Python<'_>, bytes: &'_ [u8]) -> pyo3::PyResult<()> {
-    if bytes[0] & 0x80 != 0 {
+    if serial_is_negative(bytes) {
         let warning_cls = types::DEPRECATED_IN_36.get(py)?;
         pyo3::PyErr::warn_bound(
             py,
@@ -597,6 +826,125 @@ fn parse_cp(
     Ok(certificate_policies.to_object(py))
 }
 
+fn parse_directory_string(
+    py: pyo3::Python<'_>,
+    text: DirectoryString<'_>,
+) -> CryptographyResult<pyo3::PyObject> {
+    let (data, encoding) = match text {
+        DirectoryString::PrintableString(o) => {
+            return Ok(pyo3::types::PyString::new_bound(py, o.as_str()).to_object(py))
+        }
+        DirectoryString::Utf8String(o) => {
+            return Ok(pyo3::types::PyString::new_bound(py, o.as_str()).to_object(py))
+        }
+        // Like in names, TeletexStrings are decoded as UTF-8.
+        DirectoryString::TeletexString(o) => (o.as_bytes(), "utf_8"),
+        DirectoryString::UniversalString(o) => (o.as_utf32_be_bytes(), "utf_32_be"),
+        DirectoryString::BmpString(o) => (o.as_utf16_be_bytes(), "utf_16_be"),
+    };
+    Ok(pyo3::types::PyBytes::new_bound(py, data)
+        .call_method1(pyo3::intern!(py, "decode"), (encoding,))?
+        .to_object(py))
+}
+
+fn parse_naming_authority<'p>(
+    py: pyo3::Python<'p>,
+    authority: NamingAuthority<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let py_id = match &authority.id {
+        Some(data) => oid_to_py_oid(py, data)?.to_object(py),
+        None => py.None(),
+    };
+    let py_url = match authority.url {
+        Some(data) => pyo3::types::PyString::new_bound(py, data.as_str()).to_object(py),
+        None => py.None(),
+    };
+    let py_text = match authority.text {
+        Some(data) => parse_directory_string(py, data)?,
+        None => py.None(),
+    };
+
+    Ok(types::NAMING_AUTHORITY
+        .get(py)?
+        .call1((py_id, py_url, py_text))?)
+}
+
+fn parse_profession_info<'p>(
+    py: pyo3::Python<'p>,
+    profession_info: ProfessionInfo<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let py_naming_authority = match profession_info.naming_authority {
+        Some(data) => parse_naming_authority(py, data)?.to_object(py),
+        None => py.None(),
+    };
+    let py_profession_items = pyo3::types::PyList::empty_bound(py);
+    for item in profession_info.profession_items.unwrap_read().clone() {
+        py_profession_items.append(parse_directory_string(py, item)?)?;
+    }
+    let py_profession_oids = match profession_info.profession_oids {
+        Some(oids) => {
+            let py_oids = pyo3::types::PyList::empty_bound(py);
+            for oid in oids.unwrap_read().clone() {
+                py_oids.append(oid_to_py_oid(py, &oid)?)?;
+            }
+            py_oids.to_object(py)
+        }
+        None => py.None(),
+    };
+    let py_registration_number = match profession_info.registration_number {
+        Some(data) => pyo3::types::PyString::new_bound(py, data.as_str()).to_object(py),
+        None => py.None(),
+    };
+    let py_add_profession_info = match profession_info.add_profession_info {
+        Some(data) => pyo3::types::PyBytes::new_bound(py, data).to_object(py),
+        None => py.None(),
+    };
+
+    Ok(types::PROFESSION_INFO.get(py)?.call1((
+        py_naming_authority,
+        py_profession_items,
+        py_profession_oids,
+        py_registration_number,
+        py_add_profession_info,
+    ))?)
+}
+
+fn parse_admissions<'p>(
+    py: pyo3::Python<'p>,
+    ext: &Extension<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let admissions = ext.value::<Admissions<'_>>()?;
+    let py_authority = match admissions.admission_authority {
+        Some(data) => x509::parse_general_name(py, data)?,
+        None => py.None(),
+    };
+
+    let py_admissions = pyo3::types::PyList::empty_bound(py);
+    for admission in admissions.contents_of_admissions.unwrap_read().clone() {
+        let py_admission_authority = match admission.admission_authority {
+            Some(data) => x509::parse_general_name(py, data)?,
+            None => py.None(),
+        };
+        let py_naming_authority = match admission.naming_authority {
+            Some(data) => parse_naming_authority(py, data)?.to_object(py),
+            None => py.None(),
+        };
+        let py_profession_infos = pyo3::types::PyList::empty_bound(py);
+        for profession_info in admission.profession_infos.unwrap_read().clone() {
+            py_profession_infos.append(parse_profession_info(py, profession_info)?)?;
+        }
+        py_admissions.append(types::ADMISSION.get(py)?.call1((
+            py_admission_authority,
+            py_naming_authority,
+            py_profession_infos,
+        ))?)?;
+    }
+
+    Ok(types::ADMISSIONS
+        .get(py)?
+        .call1((py_authority, py_admissions))?)
+}
+
 fn parse_general_subtrees(
     py: pyo3::Python<'_>,
     subtrees: SequenceOfSubtrees<'_>,
@@ -752,7 +1100,13 @@ pub fn parse_cert_ext<'p>(
             let tls_feature_type_to_enum = types::TLS_FEATURE_TYPE_TO_ENUM.get(py)?;
 
             let features = pyo3::types::PyList::empty_bound(py);
-            for feature in ext.value::<asn1::SequenceOf<'_, u64>>()? {
+            for feature in ext.value::<TlsFeature<'_>>()? {
+                // Feature types we don't know about can't be represented by
+                // `TLSFeatureType`, so surface the whole extension as an
+                // `UnrecognizedExtension` rather than failing to parse it.
+                if !tls_feature_type_to_enum.contains(feature)? {
+                    return Ok(None);
+                }
                 let py_feature = tls_feature_type_to_enum.get_item(feature)?;
                 features.append(py_feature)?;
             }
@@ -789,6 +1143,33 @@ pub fn parse_cert_ext<'p>(
                 kus.decipher_only(),
             ))?))
         }
+        oid::NETSCAPE_CERT_TYPE_OID => {
+            let bits = ext.value::<asn1::BitString<'_>>()?;
+            Ok(Some(types::NETSCAPE_CERT_TYPE.get(py)?.call1((
+                bits.has_bit_set(0),
+                bits.has_bit_set(1),
+                bits.has_bit_set(2),
+                bits.has_bit_set(3),
+                bits.has_bit_set(5),
+                bits.has_bit_set(6),
+                bits.has_bit_set(7),
+            ))?))
+        }
+        oid::NETSCAPE_COMMENT_OID => {
+            let comment = ext.value::<UnvalidatedIA5String<'_>>()?;
+            Ok(Some(types::NETSCAPE_COMMENT.get(py)?.call1((comment.0,))?))
+        }
+        oid::ENTRUST_VERSION_INFO_OID => {
+            let info = ext.value::<EntrustVersionInfo<'_>>()?;
+            let flags = info.entrust_info_flags;
+            let has_flag = |n| flags.as_ref().map_or(false, |f| f.has_bit_set(n));
+            Ok(Some(types::ENTRUST_VERSION_INFO.get(py)?.call1((
+                info.entrust_vers.as_str(),
+                has_flag(0),
+                has_flag(1),
+                has_flag(2),
+            ))?))
+        }
         oid::AUTHORITY_INFORMATION_ACCESS_OID => {
             let ads = parse_access_descriptions(py, ext)?;
             Ok(Some(
@@ -812,6 +1193,16 @@ pub fn parse_cert_ext<'p>(
                 pc.inhibit_policy_mapping,
             ))?))
         }
+        oid::POLICY_MAPPINGS_OID => {
+            let mappings = pyo3::types::PyList::empty_bound(py);
+            for mapping in ext.value::<asn1::SequenceOf<'_, PolicyMapping>>()? {
+                mappings.append(types::POLICY_MAPPING.get(py)?.call1((
+                    oid_to_py_oid(py, &mapping.issuer_domain_policy)?,
+                    oid_to_py_oid(py, &mapping.subject_domain_policy)?,
+                ))?)?;
+            }
+            Ok(Some(types::POLICY_MAPPINGS.get(py)?.call1((mappings,))?))
+        }
         oid::OCSP_NO_CHECK_OID => {
             ext.value::<()>()?;
             Ok(Some(types::OCSP_NO_CHECK.get(py)?.call0()?))
@@ -840,6 +1231,22 @@ pub fn parse_cert_ext<'p>(
         }
         oid::NAME_CONSTRAINTS_OID => {
             let nc = ext.value::<NameConstraints<'_>>()?;
+            // RFC 5280 requires each subtree's `minimum` to be 0 and its
+            // `maximum` to be absent, and NameConstraints can't represent
+            // anything else. Rather than silently dropping them, such
+            // extensions are parsed as UnrecognizedExtension.
+            let has_nonstandard_bounds = [&nc.permitted_subtrees, &nc.excluded_subtrees]
+                .into_iter()
+                .flatten()
+                .any(|subtrees| {
+                    subtrees
+                        .unwrap_read()
+                        .clone()
+                        .any(|gs| gs.minimum != 0 || gs.maximum.is_some())
+                });
+            if has_nonstandard_bounds {
+                return Ok(None);
+            }
             let permitted_subtrees = match nc.permitted_subtrees {
                 Some(data) => parse_general_subtrees(py, data)?,
                 None => py.None(),
@@ -854,6 +1261,16 @@ pub fn parse_cert_ext<'p>(
                     .call1((permitted_subtrees, excluded_subtrees))?,
             ))
         }
+        oid::MS_CERTIFICATE_TEMPLATE_NAME => {
+            let name = ext.value::<asn1::BMPString<'_>>()?;
+            let py_name = pyo3::types::PyBytes::new_bound(py, name.as_utf16_be_bytes())
+                .call_method1(pyo3::intern!(py, "decode"), ("utf_16_be",))?;
+            Ok(Some(
+                types::MS_CERTIFICATE_TEMPLATE_NAME
+                    .get(py)?
+                    .call1((py_name,))?,
+            ))
+        }
         oid::MS_CERTIFICATE_TEMPLATE => {
             let ms_cert_tpl = ext.value::<MSCertificateTemplate>()?;
             let py_oid = oid_to_py_oid(py, &ms_cert_tpl.template_id)?;
@@ -863,6 +1280,11 @@ pub fn parse_cert_ext<'p>(
                 ms_cert_tpl.minor_version,
             ))?))
         }
+        oid::MS_APPLICATION_POLICIES => {
+            let cp = parse_cp(py, ext)?;
+            Ok(Some(types::MS_APPLICATION_POLICIES.get(py)?.call1((cp,))?))
+        }
+        oid::ADMISSIONS_OID => Ok(Some(parse_admissions(py, ext)?)),
         _ => Ok(None),
     }
 }
@@ -929,7 +1351,7 @@ fn create_x509_certificate(
             .getattr(pyo3::intern!(py, "value"))?
             .extract()?,
         serial: asn1::BigInt::new(&serial_bytes).unwrap(),
-        signature_alg: sigalg.clone(),
+        signature_alg: sigalg,
         issuer: x509::common::encode_name(py, &ka, &py_issuer_name)?,
         validity: cryptography_x509::certificate::Validity {
             not_before: time_from_py(py, &py_not_before)?,
@@ -948,6 +1370,111 @@ fn create_x509_certificate(
         )?,
     };
 
+    sign_tbs_certificate(py, tbs_cert, private_key, hash_algorithm, rsa_padding)
+}
+
+#[pyo3::pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_self_signed_x509_certificate(
+    py: pyo3::Python<'_>,
+    subject_name: &pyo3::Bound<'_, pyo3::PyAny>,
+    private_key: &pyo3::Bound<'_, pyo3::PyAny>,
+    serial_number: pyo3::Bound<'_, pyo3::types::PyLong>,
+    not_valid_before: &pyo3::Bound<'_, pyo3::PyAny>,
+    not_valid_after: &pyo3::Bound<'_, pyo3::PyAny>,
+    py_extensions: &pyo3::Bound<'_, pyo3::PyAny>,
+    hash_algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    rsa_padding: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<Certificate> {
+    let der = types::ENCODING_DER.get(py)?;
+    let spki = types::PUBLIC_FORMAT_SUBJECT_PUBLIC_KEY_INFO.get(py)?;
+    let spki_bytes = private_key
+        .call_method0(pyo3::intern!(py, "public_key"))?
+        .call_method1(pyo3::intern!(py, "public_bytes"), (der, spki))?
+        .extract::<pyo3::pybacked::PyBackedBytes>()?;
+    let spki =
+        asn1::parse_single::<common::WithTlv<'_, common::SubjectPublicKeyInfo<'_>>>(&spki_bytes)?;
+
+    // The subject key identifier is the SHA-1 hash of the subjectPublicKey
+    // (RFC 5280 section 4.2.1.2, method 1), and as the certificate is its own
+    // issuer, the authority key identifier is the same value.
+    let key_identifier = pyo3::types::PyBytes::new_bound(
+        py,
+        &openssl::sha::sha1(spki.subject_public_key.as_bytes()),
+    );
+    let ski = types::SUBJECT_KEY_IDENTIFIER
+        .get(py)?
+        .call1((&key_identifier,))?;
+    let aki =
+        types::AUTHORITY_KEY_IDENTIFIER
+            .get(py)?
+            .call1((&key_identifier, py.None(), py.None()))?;
+    let extensions = pyo3::types::PyList::empty_bound(py);
+    for (oid, value) in [
+        (&oid::SUBJECT_KEY_IDENTIFIER_OID, ski),
+        (&oid::AUTHORITY_KEY_IDENTIFIER_OID, aki),
+    ] {
+        let py_oid = oid_to_py_oid(py, oid)?;
+        extensions.append(types::EXTENSION.get(py)?.call1((py_oid, false, value))?)?;
+    }
+    for py_ext in py_extensions.iter()? {
+        let py_ext = py_ext?;
+        let oid = py_oid_to_oid(py_ext.getattr(pyo3::intern!(py, "oid"))?)?;
+        if oid == oid::SUBJECT_KEY_IDENTIFIER_OID || oid == oid::AUTHORITY_KEY_IDENTIFIER_OID {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The subject and authority key identifiers of a self-signed certificate are derived from its key and may not be provided.",
+                ),
+            ));
+        }
+        extensions.append(py_ext)?;
+    }
+
+    let ka_vec = cryptography_keepalive::KeepAlive::new();
+    let ka_bytes = cryptography_keepalive::KeepAlive::new();
+    let ka = cryptography_keepalive::KeepAlive::new();
+
+    let serial_bytes = py_uint_to_big_endian_bytes(py, serial_number)?;
+    // A self-signed certificate's issuer is, by definition, its subject.
+    let name = x509::common::encode_name(py, &ka, subject_name)?;
+
+    let tbs_cert = cryptography_x509::certificate::TbsCertificate {
+        version: 2,
+        serial: asn1::BigInt::new(&serial_bytes).unwrap(),
+        signature_alg: x509::sign::compute_signature_algorithm(
+            py,
+            private_key.clone(),
+            hash_algorithm.clone(),
+            rsa_padding.clone(),
+        )?,
+        issuer: name.clone(),
+        validity: cryptography_x509::certificate::Validity {
+            not_before: time_from_py(py, not_valid_before)?,
+            not_after: time_from_py(py, not_valid_after)?,
+        },
+        subject: name,
+        spki,
+        issuer_unique_id: None,
+        subject_unique_id: None,
+        raw_extensions: x509::common::encode_extensions(
+            py,
+            &ka_vec,
+            &ka_bytes,
+            &extensions,
+            extensions::encode_extension,
+        )?,
+    };
+
+    sign_tbs_certificate(py, tbs_cert, private_key, hash_algorithm, rsa_padding)
+}
+
+fn sign_tbs_certificate(
+    py: pyo3::Python<'_>,
+    tbs_cert: cryptography_x509::certificate::TbsCertificate<'_>,
+    private_key: &pyo3::Bound<'_, pyo3::PyAny>,
+    hash_algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+    rsa_padding: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<Certificate> {
     let tbs_bytes = asn1::write_single(&tbs_cert)?;
     let signature = x509::sign::sign_data(
         py,
@@ -956,9 +1483,10 @@ fn create_x509_certificate(
         rsa_padding.clone(),
         &tbs_bytes,
     )?;
+    let signature_alg = tbs_cert.signature_alg.clone();
     let data = asn1::write_single(&cryptography_x509::certificate::Certificate {
         tbs_cert,
-        signature_alg: sigalg,
+        signature_alg,
         signature: asn1::BitString::new(&signature, 0).unwrap(),
     })?;
     load_der_x509_certificate(
@@ -989,12 +1517,26 @@ pub(crate) fn add_to_module(module: &pyo3::Bound<'_, pyo3::types::PyModule>) ->
         load_pem_x509_certificates,
         module
     )?)?;
+    module.add_function(pyo3::wrap_pyfunction_bound!(
+        load_der_x509_certificates,
+        module
+    )?)?;
     module.add_function(pyo3::wrap_pyfunction_bound!(
         create_x509_certificate,
         module
     )?)?;
+    module.add_function(pyo3::wrap_pyfunction_bound!(
+        create_self_signed_x509_certificate,
+        module
+    )?)?;
+
+    module.add_function(pyo3::wrap_pyfunction_bound!(
+        load_der_x509_certificate_view,
+        module
+    )?)?;
 
     module.add_class::<Certificate>()?;
+    module.add_class::<CertificateView>()?;
 
     Ok(())
 }
@@ -154,6 +154,14 @@ pub(crate) fn encode_general_name<'a>(
     } else if gn_type.is(&types::REGISTERED_ID.get(py)?) {
         let oid = py_oid_to_oid(gn_value)?;
         Ok(GeneralName::RegisteredID(oid))
+    } else if gn_type.is(&types::X400_ADDRESS.get(py)?) {
+        Ok(GeneralName::X400Address(
+            asn1::parse_single(ka_bytes.add(gn_value.extract()?)).map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "X400Address value must be a DER encoded SEQUENCE: {e:?}"
+                ))
+            })?,
+        ))
     } else {
         Err(CryptographyError::from(
             pyo3::exceptions::PyValueError::new_err("Unsupported GeneralName type"),
@@ -291,10 +299,19 @@ pub(crate) fn parse_general_name(
             let oid = oid_to_py_oid(py, &data)?;
             types::REGISTERED_ID.get(py)?.call1((oid,))?.to_object(py)
         }
-        _ => {
+        GeneralName::X400Address(data) => {
+            // The ORAddress is implicitly tagged; re-encode it as a plain
+            // SEQUENCE so that it can be parsed on its own.
+            let der = asn1::write_single(&data)?;
+            types::X400_ADDRESS
+                .get(py)?
+                .call1((pyo3::types::PyBytes::new_bound(py, &der),))?
+                .to_object(py)
+        }
+        GeneralName::EDIPartyName(_) => {
             return Err(CryptographyError::from(
                 exceptions::UnsupportedGeneralNameType::new_err(
-                    "x400Address/EDIPartyName are not supported types",
+                    "EDIPartyName is not a supported type",
                 ),
             ))
         }
@@ -530,6 +547,23 @@ pub(crate) fn py_to_datetime(
     .unwrap())
 }
 
+// Returns the number of seconds since the Unix epoch, using the
+// days-from-civil algorithm for the proleptic Gregorian calendar.
+pub(crate) fn datetime_to_unix_seconds(dt: &asn1::DateTime) -> i64 {
+    let month = i64::from(dt.month());
+    let year = i64::from(dt.year()) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(dt.day()) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    days * 86400
+        + i64::from(dt.hour()) * 3600
+        + i64::from(dt.minute()) * 60
+        + i64::from(dt.second())
+}
+
 pub(crate) fn datetime_now(py: pyo3::Python<'_>) -> pyo3::PyResult<asn1::DateTime> {
     let utc = types::DATETIME_TIMEZONE_UTC.get(py)?;
 
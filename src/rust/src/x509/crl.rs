@@ -4,7 +4,9 @@
 
 use std::sync::Arc;
 
-use cryptography_x509::extensions::{Extension, IssuerAlternativeName};
+use cryptography_x509::extensions::{
+    DuplicateExtensionsError, Extension, Extensions, IssuerAlternativeName, KeyUsage,
+};
 use cryptography_x509::{
     common,
     crl::{
@@ -20,6 +22,7 @@ use crate::asn1::{
     big_byte_slice_to_py_int, encode_der_data, oid_to_py_oid, py_uint_to_big_endian_bytes,
 };
 use crate::backend::hashes::Hash;
+use crate::backend::keys;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::x509::{certificate, extensions, sign};
 use crate::{exceptions, types, x509};
@@ -87,7 +90,8 @@ self_cell::self_cell!(
 struct CertificateRevocationList {
     owned: Arc<OwnedCertificateRevocationList>,
 
-    revoked_certs: pyo3::sync::GILOnceCell<Vec<OwnedRevokedCertificate>>,
+    revoked_certs:
+        pyo3::sync::GILOnceCell<Vec<(OwnedRevokedCertificate, Option<OwnedRevokedCertificate>)>>,
     cached_extensions: pyo3::sync::GILOnceCell<pyo3::PyObject>,
 }
 
@@ -97,8 +101,10 @@ impl CertificateRevocationList {
     }
 
     fn revoked_cert(&self, py: pyo3::Python<'_>, idx: usize) -> RevokedCertificate {
+        let (owned, issuer_entry) = &self.revoked_certs.get(py).unwrap()[idx];
         RevokedCertificate {
-            owned: self.revoked_certs.get(py).unwrap()[idx].clone(),
+            owned: owned.clone(),
+            issuer_entry: issuer_entry.clone(),
             cached_extensions: pyo3::sync::GILOnceCell::new(),
         }
     }
@@ -113,6 +119,33 @@ impl CertificateRevocationList {
     }
 }
 
+impl CertificateRevocationList {
+    // Looks up a single CRL extension without building the Python
+    // `Extensions` object.
+    fn get_extension(
+        &self,
+        py: pyo3::Python<'_>,
+        extn_id: &asn1::ObjectIdentifier,
+    ) -> CryptographyResult<Option<Extension<'_>>> {
+        let raw_extensions = &self
+            .owned
+            .borrow_dependent()
+            .tbs_cert_list
+            .raw_crl_extensions;
+        match Extensions::from_raw_extensions(raw_extensions.as_ref()) {
+            Ok(extensions) => Ok(extensions.get_extension(extn_id)),
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into())
+            }
+        }
+    }
+}
+
 #[pyo3::pymethods]
 impl CertificateRevocationList {
     fn __eq__(&self, other: pyo3::PyRef<'_, CertificateRevocationList>) -> bool {
@@ -135,6 +168,7 @@ impl CertificateRevocationList {
                 )
             })
             .unwrap(),
+            issuer_entry: None,
         }
     }
 
@@ -147,7 +181,7 @@ impl CertificateRevocationList {
             let mut revoked_certs = vec![];
             let mut it = self.__iter__();
             while let Some(c) = it.__next__() {
-                revoked_certs.push(c.owned);
+                revoked_certs.push((c.owned, c.issuer_entry));
             }
             revoked_certs
         });
@@ -198,15 +232,11 @@ impl CertificateRevocationList {
     fn signature_hash_algorithm<'p>(
         &self,
         py: pyo3::Python<'p>,
-    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
-        let oid = self.signature_algorithm_oid(py)?;
-        match types::SIG_OIDS_TO_HASH.get(py)?.get_item(oid) {
-            Ok(v) => Ok(v),
-            Err(_) => Err(exceptions::UnsupportedAlgorithm::new_err(format!(
-                "Signature algorithm OID: {} not recognized",
-                self.owned.borrow_dependent().signature_algorithm.oid()
-            ))),
-        }
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        sign::identify_signature_hash_algorithm(
+            py,
+            &self.owned.borrow_dependent().signature_algorithm,
+        )
     }
 
     #[getter]
@@ -322,6 +352,31 @@ impl CertificateRevocationList {
         )
     }
 
+    #[getter]
+    fn crl_number<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::PyAny>>> {
+        match self.get_extension(py, &oid::CRL_NUMBER_OID)? {
+            Some(ext) => {
+                let bignum = ext.value::<asn1::BigUint<'_>>()?;
+                Ok(Some(big_byte_slice_to_py_int(py, bignum.as_bytes())?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[getter]
+    fn authority_key_identifier<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<Option<pyo3::Bound<'p, pyo3::PyAny>>> {
+        match self.get_extension(py, &oid::AUTHORITY_KEY_IDENTIFIER_OID)? {
+            Some(ext) => Ok(Some(certificate::parse_authority_key_identifier(py, &ext)?)),
+            None => Ok(None),
+        }
+    }
+
     #[getter]
     fn extensions(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
         let tbs_cert_list = &self.owned.borrow_dependent().tbs_cert_list;
@@ -396,27 +451,14 @@ impl CertificateRevocationList {
         serial: pyo3::Bound<'_, pyo3::types::PyLong>,
     ) -> pyo3::PyResult<Option<RevokedCertificate>> {
         let serial_bytes = py_uint_to_big_endian_bytes(py, serial)?;
-        let owned = OwnedRevokedCertificate::try_new(Arc::clone(&self.owned), |v| {
-            let certs = match &v.borrow_dependent().tbs_cert_list.revoked_certificates {
-                Some(certs) => certs.unwrap_read().clone(),
-                None => return Err(()),
-            };
-
-            // TODO: linear scan. Make a hash or bisect!
-            for cert in certs {
-                if serial_bytes == cert.user_certificate.as_bytes() {
-                    return Ok(cert);
-                }
+        // TODO: linear scan. Make a hash or bisect!
+        let mut it = self.__iter__();
+        while let Some(revoked) = it.__next__() {
+            if serial_bytes == revoked.owned.borrow_dependent().user_certificate.as_bytes() {
+                return Ok(Some(revoked));
             }
-            Err(())
-        });
-        match owned {
-            Ok(o) => Ok(Some(RevokedCertificate {
-                owned: o,
-                cached_extensions: pyo3::sync::GILOnceCell::new(),
-            })),
-            Err(()) => Ok(None),
         }
+        Ok(None)
     }
 
     fn is_signature_valid<'p>(
@@ -443,6 +485,63 @@ impl CertificateRevocationList {
         )
         .is_ok())
     }
+
+    fn verify_directly_issued_by(
+        &self,
+        py: pyo3::Python<'_>,
+        issuer: pyo3::PyRef<'_, certificate::Certificate>,
+    ) -> CryptographyResult<()> {
+        let crl = self.owned.borrow_dependent();
+        if crl.tbs_cert_list.signature != crl.signature_algorithm {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Inner and outer signature algorithms do not match. This is an invalid CRL.",
+                ),
+            ));
+        };
+        let issuer_cert = issuer.raw.borrow_dependent();
+        if crl.tbs_cert_list.issuer != issuer_cert.tbs_cert.subject {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "Issuer certificate subject does not match CRL issuer.",
+                ),
+            ));
+        };
+
+        // RFC 5280 4.2.1.3: if the issuer has a key usage extension, the
+        // cRLSign bit must be asserted for it to sign CRLs.
+        let issuer_extensions = match issuer_cert.extensions() {
+            Ok(extensions) => extensions,
+            Err(DuplicateExtensionsError(oid)) => {
+                let oid_obj = oid_to_py_oid(py, &oid)?;
+                return Err(exceptions::DuplicateExtension::new_err((
+                    format!("Duplicate {} extension found", &oid),
+                    oid_obj.unbind(),
+                ))
+                .into());
+            }
+        };
+        if let Some(ext) = issuer_extensions.get_extension(&oid::KEY_USAGE_OID) {
+            let key_usage: KeyUsage<'_> = ext.value()?;
+            if !key_usage.crl_sign() {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "Issuer certificate's key usage does not permit CRL signing.",
+                    ),
+                ));
+            }
+        }
+
+        let issuer_key =
+            keys::load_der_public_key_bytes(py, issuer_cert.tbs_cert.spki.tlv().full_data())?;
+        sign::verify_signature_with_signature_algorithm(
+            py,
+            issuer_key.bind(py).clone(),
+            &crl.signature_algorithm,
+            crl.signature_value.as_bytes(),
+            &asn1::write_single(&crl.tbs_cert_list)?,
+        )
+    }
 }
 
 type RawCRLIterator<'a> = Option<asn1::SequenceOf<'a, crl::RevokedCertificate<'a>>>;
@@ -458,6 +557,10 @@ self_cell::self_cell!(
 #[pyo3::pyclass(module = "cryptography.hazmat.bindings._rust.x509")]
 struct CRLIterator {
     contents: OwnedCRLIteratorData,
+    // The most recently seen entry with a certificateIssuer extension. Per
+    // RFC 5280 5.3.3 its issuer applies to all following entries until
+    // another certificateIssuer extension is encountered.
+    issuer_entry: Option<OwnedRevokedCertificate>,
 }
 
 // Open-coded implementation of the API discussed in
@@ -507,8 +610,12 @@ impl CRLIterator {
             None => Err(()),
         })
         .ok()?;
+        if has_certificate_issuer(revoked.borrow_dependent()) {
+            self.issuer_entry = Some(revoked.clone());
+        }
         Some(RevokedCertificate {
             owned: revoked,
+            issuer_entry: self.issuer_entry.clone(),
             cached_extensions: pyo3::sync::GILOnceCell::new(),
         })
     }
@@ -537,9 +644,39 @@ impl Clone for OwnedRevokedCertificate {
 #[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.x509")]
 struct RevokedCertificate {
     owned: OwnedRevokedCertificate,
+    // The entry whose certificateIssuer extension applies to this one (which
+    // may be this entry itself), or `None` if it was issued by the CRL issuer.
+    issuer_entry: Option<OwnedRevokedCertificate>,
     cached_extensions: pyo3::sync::GILOnceCell<pyo3::PyObject>,
 }
 
+// Whether the CRL's issuing distribution point extension asserts that it is
+// an indirect CRL. A CRL whose extensions can't be parsed is treated as not
+// being indirect.
+fn is_indirect_crl(crl: &RawCertificateRevocationList<'_>) -> bool {
+    let extensions =
+        match Extensions::from_raw_extensions(crl.tbs_cert_list.raw_crl_extensions.as_ref()) {
+            Ok(extensions) => extensions,
+            Err(_) => return false,
+        };
+    match extensions.get_extension(&oid::ISSUING_DISTRIBUTION_POINT_OID) {
+        Some(ext) => matches!(
+            ext.value::<crl::IssuingDistributionPoint<'_>>(),
+            Ok(idp) if idp.indirect_crl
+        ),
+        None => false,
+    }
+}
+
+fn has_certificate_issuer(revoked: &RawRevokedCertificate<'_>) -> bool {
+    match Extensions::from_raw_extensions(revoked.raw_crl_entry_extensions.as_ref()) {
+        Ok(extensions) => extensions
+            .get_extension(&oid::CERTIFICATE_ISSUER_OID)
+            .is_some(),
+        Err(_) => false,
+    }
+}
+
 #[pyo3::pymethods]
 impl RevokedCertificate {
     #[getter]
@@ -591,6 +728,46 @@ impl RevokedCertificate {
             |ext| parse_crl_entry_ext(py, ext),
         )
     }
+
+    #[getter]
+    fn certificate_issuer(&self, py: pyo3::Python<'_>) -> CryptographyResult<pyo3::PyObject> {
+        match &self.issuer_entry {
+            // RFC 5280 5.3.3: certificateIssuer entries are only meaningful
+            // in a CRL whose issuing distribution point has indirectCRL set.
+            Some(entry) if !is_indirect_crl(entry.borrow_owner().borrow_dependent()) => {
+                Err(CryptographyError::from(
+                    pyo3::exceptions::PyValueError::new_err(
+                        "The CRL has certificate issuer entry extensions, but it is not an indirect CRL.",
+                    ),
+                ))
+            }
+            Some(entry) => {
+                // `issuer_entry` is only set for entries whose extensions
+                // parsed and contain a certificateIssuer extension.
+                let ext = Extensions::from_raw_extensions(
+                    entry.borrow_dependent().raw_crl_entry_extensions.as_ref(),
+                )
+                .unwrap()
+                .get_extension(&oid::CERTIFICATE_ISSUER_OID)
+                .unwrap();
+                let gn_seq = ext.value::<asn1::SequenceOf<'_, name::GeneralName<'_>>>()?;
+                x509::parse_general_names(py, &gn_seq)
+            }
+            None => {
+                let issuer = x509::parse_name(
+                    py,
+                    self.owned
+                        .borrow_owner()
+                        .borrow_dependent()
+                        .tbs_cert_list
+                        .issuer
+                        .unwrap_read(),
+                )?;
+                let gn = types::DIRECTORY_NAME.get(py)?.call1((issuer,))?;
+                Ok(pyo3::types::PyList::new_bound(py, [gn]).to_object(py))
+            }
+        }
+    }
 }
 
 pub(crate) fn parse_crl_reason_flags<'p>(
@@ -638,6 +815,14 @@ pub fn parse_crl_entry_ext<'p>(
             let py_dt = x509::datetime_to_py(py, time.as_datetime())?;
             Ok(Some(types::INVALIDITY_DATE.get(py)?.call1((py_dt,))?))
         }
+        oid::HOLD_INSTRUCTION_CODE_OID => {
+            let instruction = ext.value::<asn1::ObjectIdentifier>()?;
+            Ok(Some(
+                types::HOLD_INSTRUCTION_CODE
+                    .get(py)?
+                    .call1((oid_to_py_oid(py, &instruction)?,))?,
+            ))
+        }
         _ => Ok(None),
     }
 }
@@ -66,6 +66,28 @@ impl CertificateSigningRequest {
         )
     }
 
+    #[getter]
+    fn public_key_algorithm_parameters<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        sign::identify_public_key_algorithm_parameters(
+            py,
+            &self.raw.borrow_dependent().csr_info.spki.algorithm,
+        )
+    }
+
+    #[getter]
+    fn public_key_hash_algorithm<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+        sign::identify_public_key_hash_algorithm(
+            py,
+            &self.raw.borrow_dependent().csr_info.spki.algorithm,
+        )
+    }
+
     #[getter]
     fn subject<'p>(&self, py: pyo3::Python<'p>) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
         Ok(x509::parse_name(
@@ -223,15 +245,29 @@ impl CertificateSigningRequest {
         slf: pyo3::PyRef<'_, Self>,
         py: pyo3::Python<'_>,
     ) -> CryptographyResult<bool> {
+        match Self::verify_signature(slf, py) {
+            Ok(()) => Ok(true),
+            Err(CryptographyError::Py(e))
+                if e.is_instance_of::<exceptions::InvalidSignature>(py) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn verify_signature(
+        slf: pyo3::PyRef<'_, Self>,
+        py: pyo3::Python<'_>,
+    ) -> CryptographyResult<()> {
         let public_key = slf.public_key(py)?;
-        Ok(sign::verify_signature_with_signature_algorithm(
+        sign::verify_signature_with_signature_algorithm(
             py,
             public_key.bind(py).clone(),
             &slf.raw.borrow_dependent().signature_alg,
             slf.raw.borrow_dependent().signature.as_bytes(),
             &asn1::write_single(&slf.raw.borrow_dependent().csr_info)?,
         )
-        .is_ok())
     }
 }
 
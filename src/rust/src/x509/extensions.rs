@@ -221,6 +221,16 @@ fn encode_key_usage(
     Ok(asn1::write_single(&v)?)
 }
 
+// Builds the DER form of a single byte `NamedBitList` BIT STRING, where
+// trailing zero bits are removed.
+fn named_bit_string(bs: &[u8; 1]) -> asn1::BitString<'_> {
+    if bs[0] == 0 {
+        asn1::BitString::new(&[], 0).unwrap()
+    } else {
+        asn1::BitString::new(bs, bs[0].trailing_zeros() as u8).unwrap()
+    }
+}
+
 fn encode_certificate_policies(
     py: pyo3::Python<'_>,
     ext: &pyo3::Bound<'_, pyo3::PyAny>,
@@ -416,6 +426,190 @@ fn encode_scts(ext: &pyo3::Bound<'_, pyo3::PyAny>) -> CryptographyResult<Vec<u8>
     Ok(asn1::write_single(&result.as_slice())?)
 }
 
+fn encode_naming_authority<'a>(
+    py: pyo3::Python<'_>,
+    ka_str: &'a cryptography_keepalive::KeepAlive<PyBackedStr>,
+    py_naming_authority: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<extensions::NamingAuthority<'a>> {
+    let py_id = py_naming_authority.getattr(pyo3::intern!(py, "id"))?;
+    let id = if py_id.is_none() {
+        None
+    } else {
+        Some(py_oid_to_oid(py_id)?)
+    };
+    let py_url = py_naming_authority.getattr(pyo3::intern!(py, "url"))?;
+    let url = if py_url.is_none() {
+        None
+    } else {
+        let py_url_str = ka_str.add(py_url.extract::<PyBackedStr>()?);
+        match asn1::IA5String::new(py_url_str) {
+            Some(s) => Some(s),
+            None => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Naming authority url must be an ASCII-string.",
+                )
+                .into())
+            }
+        }
+    };
+    let py_text = py_naming_authority.getattr(pyo3::intern!(py, "text"))?;
+    let text = if py_text.is_none() {
+        None
+    } else {
+        let py_text_str = ka_str.add(py_text.extract::<PyBackedStr>()?);
+        Some(extensions::DirectoryString::Utf8String(
+            asn1::Utf8String::new(py_text_str),
+        ))
+    };
+    Ok(extensions::NamingAuthority { id, url, text })
+}
+
+fn encode_profession_info<'a>(
+    py: pyo3::Python<'_>,
+    ka_bytes: &'a cryptography_keepalive::KeepAlive<pyo3::pybacked::PyBackedBytes>,
+    ka_str: &'a cryptography_keepalive::KeepAlive<PyBackedStr>,
+    py_profession_info: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<extensions::ProfessionInfo<'a>> {
+    let py_naming_authority = py_profession_info.getattr(pyo3::intern!(py, "naming_authority"))?;
+    let naming_authority = if py_naming_authority.is_none() {
+        None
+    } else {
+        Some(encode_naming_authority(py, ka_str, &py_naming_authority)?)
+    };
+
+    let mut profession_items = vec![];
+    for py_item in py_profession_info
+        .getattr(pyo3::intern!(py, "profession_items"))?
+        .iter()?
+    {
+        let py_item_str = ka_str.add(py_item?.extract::<PyBackedStr>()?);
+        profession_items.push(extensions::DirectoryString::Utf8String(
+            asn1::Utf8String::new(py_item_str),
+        ));
+    }
+
+    let py_profession_oids = py_profession_info.getattr(pyo3::intern!(py, "profession_oids"))?;
+    let profession_oids = if py_profession_oids.is_none() {
+        None
+    } else {
+        let mut oids = vec![];
+        for py_oid in py_profession_oids.iter()? {
+            oids.push(py_oid_to_oid(py_oid?)?);
+        }
+        Some(common::Asn1ReadableOrWritable::new_write(
+            asn1::SequenceOfWriter::new(oids),
+        ))
+    };
+
+    let py_registration_number =
+        py_profession_info.getattr(pyo3::intern!(py, "registration_number"))?;
+    let registration_number = if py_registration_number.is_none() {
+        None
+    } else {
+        let py_number_str = ka_str.add(py_registration_number.extract::<PyBackedStr>()?);
+        match asn1::PrintableString::new(py_number_str) {
+            Some(s) => Some(s),
+            None => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Registration number must be a valid PrintableString.",
+                )
+                .into())
+            }
+        }
+    };
+
+    let py_add_profession_info =
+        py_profession_info.getattr(pyo3::intern!(py, "add_profession_info"))?;
+    let add_profession_info = if py_add_profession_info.is_none() {
+        None
+    } else {
+        Some(ka_bytes.add(py_add_profession_info.extract::<pyo3::pybacked::PyBackedBytes>()?))
+    };
+
+    Ok(extensions::ProfessionInfo {
+        naming_authority,
+        profession_items: common::Asn1ReadableOrWritable::new_write(asn1::SequenceOfWriter::new(
+            profession_items,
+        )),
+        profession_oids,
+        registration_number,
+        add_profession_info,
+    })
+}
+
+fn encode_admissions(
+    py: pyo3::Python<'_>,
+    ext: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<Vec<u8>> {
+    let ka_bytes = cryptography_keepalive::KeepAlive::new();
+    let ka_str = cryptography_keepalive::KeepAlive::new();
+
+    let py_authority = ext.getattr(pyo3::intern!(py, "authority"))?;
+    let admission_authority = if py_authority.is_none() {
+        None
+    } else {
+        Some(x509::common::encode_general_name(
+            py,
+            &ka_bytes,
+            &ka_str,
+            &py_authority,
+        )?)
+    };
+
+    let mut admissions = vec![];
+    for py_admission in ext.iter()? {
+        let py_admission = py_admission?;
+
+        let py_admission_authority =
+            py_admission.getattr(pyo3::intern!(py, "admission_authority"))?;
+        let admission_authority = if py_admission_authority.is_none() {
+            None
+        } else {
+            Some(x509::common::encode_general_name(
+                py,
+                &ka_bytes,
+                &ka_str,
+                &py_admission_authority,
+            )?)
+        };
+
+        let py_naming_authority = py_admission.getattr(pyo3::intern!(py, "naming_authority"))?;
+        let naming_authority = if py_naming_authority.is_none() {
+            None
+        } else {
+            Some(encode_naming_authority(py, &ka_str, &py_naming_authority)?)
+        };
+
+        let mut profession_infos = vec![];
+        for py_profession_info in py_admission
+            .getattr(pyo3::intern!(py, "profession_infos"))?
+            .iter()?
+        {
+            profession_infos.push(encode_profession_info(
+                py,
+                &ka_bytes,
+                &ka_str,
+                &py_profession_info?,
+            )?);
+        }
+
+        admissions.push(extensions::Admission {
+            admission_authority,
+            naming_authority,
+            profession_infos: common::Asn1ReadableOrWritable::new_write(
+                asn1::SequenceOfWriter::new(profession_infos),
+            ),
+        });
+    }
+
+    Ok(asn1::write_single(&extensions::Admissions {
+        admission_authority,
+        contents_of_admissions: common::Asn1ReadableOrWritable::new_write(
+            asn1::SequenceOfWriter::new(admissions),
+        ),
+    })?)
+}
+
 pub(crate) fn encode_extension(
     py: pyo3::Python<'_>,
     oid: &asn1::ObjectIdentifier,
@@ -436,6 +630,54 @@ pub(crate) fn encode_extension(
             let der = encode_key_usage(py, ext)?;
             Ok(Some(der))
         }
+        &oid::NETSCAPE_CERT_TYPE_OID => {
+            let mut bs = [0];
+            for (n, attr) in [
+                (0, pyo3::intern!(py, "ssl_client")),
+                (1, pyo3::intern!(py, "ssl_server")),
+                (2, pyo3::intern!(py, "smime")),
+                (3, pyo3::intern!(py, "object_signing")),
+                (5, pyo3::intern!(py, "ssl_ca")),
+                (6, pyo3::intern!(py, "smime_ca")),
+                (7, pyo3::intern!(py, "object_signing_ca")),
+            ] {
+                certificate::set_bit(&mut bs, n, ext.getattr(attr)?.is_truthy()?);
+            }
+            Ok(Some(asn1::write_single(&named_bit_string(&bs))?))
+        }
+        &oid::NETSCAPE_COMMENT_OID => {
+            let comment = ext
+                .getattr(pyo3::intern!(py, "comment"))?
+                .extract::<PyBackedStr>()?;
+            let comment = asn1::IA5String::new(&comment).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(
+                    "Netscape comments must contain only ASCII characters.",
+                )
+            })?;
+            Ok(Some(asn1::write_single(&comment)?))
+        }
+        &oid::ENTRUST_VERSION_INFO_OID => {
+            let version = ext
+                .getattr(pyo3::intern!(py, "version"))?
+                .extract::<PyBackedStr>()?;
+            let mut bs = [0];
+            for (n, attr) in [
+                (0, pyo3::intern!(py, "key_update_allowed")),
+                (1, pyo3::intern!(py, "new_extensions")),
+                (2, pyo3::intern!(py, "pkix_certificate")),
+            ] {
+                certificate::set_bit(&mut bs, n, ext.getattr(attr)?.is_truthy()?);
+            }
+            let info = extensions::EntrustVersionInfo {
+                entrust_vers: common::UnvalidatedGeneralString(&version),
+                entrust_info_flags: if bs[0] == 0 {
+                    None
+                } else {
+                    Some(named_bit_string(&bs))
+                },
+            };
+            Ok(Some(asn1::write_single(&info)?))
+        }
         &oid::AUTHORITY_INFORMATION_ACCESS_OID | &oid::SUBJECT_INFORMATION_ACCESS_OID => {
             let der = x509::common::encode_access_descriptions(ext.py(), ext)?;
             Ok(Some(der))
@@ -444,7 +686,7 @@ pub(crate) fn encode_extension(
             let der = encode_oid_sequence(ext)?;
             Ok(Some(der))
         }
-        &oid::CERTIFICATE_POLICIES_OID => {
+        &oid::CERTIFICATE_POLICIES_OID | &oid::MS_APPLICATION_POLICIES => {
             let der = encode_certificate_policies(py, ext)?;
             Ok(Some(der))
         }
@@ -459,6 +701,23 @@ pub(crate) fn encode_extension(
             };
             Ok(Some(asn1::write_single(&pc)?))
         }
+        &oid::POLICY_MAPPINGS_OID => {
+            let mut mappings = vec![];
+            for mapping in ext.iter()? {
+                let mapping = mapping?;
+                mappings.push(extensions::PolicyMapping {
+                    issuer_domain_policy: py_oid_to_oid(
+                        mapping.getattr(pyo3::intern!(py, "issuer_domain_policy"))?,
+                    )?,
+                    subject_domain_policy: py_oid_to_oid(
+                        mapping.getattr(pyo3::intern!(py, "subject_domain_policy"))?,
+                    )?,
+                });
+            }
+            Ok(Some(asn1::write_single(&asn1::SequenceOfWriter::new(
+                mappings,
+            ))?))
+        }
         &oid::NAME_CONSTRAINTS_OID => {
             let ka_bytes = cryptography_keepalive::KeepAlive::new();
             let ka_str = cryptography_keepalive::KeepAlive::new();
@@ -534,6 +793,10 @@ pub(crate) fn encode_extension(
             let dt = x509::py_to_datetime(py, py_dt)?;
             Ok(Some(asn1::write_single(&asn1::GeneralizedTime::new(dt)?)?))
         }
+        &oid::HOLD_INSTRUCTION_CODE_OID => {
+            let instruction = py_oid_to_oid(ext.getattr(pyo3::intern!(py, "hold_instruction"))?)?;
+            Ok(Some(asn1::write_single(&instruction)?))
+        }
         &oid::CRL_NUMBER_OID | &oid::DELTA_CRL_INDICATOR_OID => {
             let intval = ext
                 .getattr(pyo3::intern!(py, "crl_number"))?
@@ -548,12 +811,25 @@ pub(crate) fn encode_extension(
             let der = encode_issuing_distribution_point(py, ext)?;
             Ok(Some(der))
         }
+        &oid::ARCHIVE_CUTOFF_OID => {
+            let py_dt = ext.getattr(pyo3::intern!(py, "archive_cutoff_utc"))?;
+            let dt = x509::py_to_datetime(py, py_dt)?;
+            Ok(Some(asn1::write_single(&asn1::GeneralizedTime::new(dt)?)?))
+        }
         &oid::NONCE_OID => {
             let nonce = ext
                 .getattr(pyo3::intern!(py, "nonce"))?
                 .extract::<pyo3::pybacked::PyBackedBytes>()?;
             Ok(Some(asn1::write_single(&nonce.as_ref())?))
         }
+        &oid::MS_CERTIFICATE_TEMPLATE_NAME => {
+            let name = ext
+                .getattr(pyo3::intern!(py, "name"))?
+                .extract::<PyBackedStr>()?;
+            Ok(Some(asn1::write_single(
+                &common::Utf8StoredBMPString::new(&name),
+            )?))
+        }
         &oid::MS_CERTIFICATE_TEMPLATE => {
             let py_template_id = ext.getattr(pyo3::intern!(py, "template_id"))?;
             let mstpl = extensions::MSCertificateTemplate {
@@ -563,6 +839,10 @@ pub(crate) fn encode_extension(
             };
             Ok(Some(asn1::write_single(&mstpl)?))
         }
+        &oid::ADMISSIONS_OID => {
+            let der = encode_admissions(py, ext)?;
+            Ok(Some(der))
+        }
         _ => Ok(None),
     }
 }
@@ -5,12 +5,15 @@
 use std::collections::HashMap;
 
 use cryptography_x509::common;
+use cryptography_x509::extensions::Extension;
 use cryptography_x509::ocsp_req::CertID;
 use once_cell::sync::Lazy;
-use pyo3::types::PyAnyMethods;
+use pyo3::types::{PyAnyMethods, PyListMethods};
 
+use crate::asn1::oid_to_py_oid;
 use crate::backend::hashes::Hash;
 use crate::error::CryptographyResult;
+use crate::types;
 use crate::x509::certificate::Certificate;
 
 pub(crate) static ALGORITHM_PARAMETERS_TO_HASH: Lazy<
@@ -79,7 +82,9 @@ pub(crate) fn certid_new<'p>(
     issuer: &'p Certificate,
     hash_algorithm: &pyo3::Bound<'p, pyo3::PyAny>,
 ) -> CryptographyResult<CertID<'p>> {
-    let issuer_der = asn1::write_single(&cert.raw.borrow_dependent().tbs_cert.issuer)?;
+    // RFC 6960 (section 4.1.1) defines issuerNameHash as the hash of the
+    // issuer's DN, so use the encoding from the issuer's subject field.
+    let issuer_der = asn1::write_single(&issuer.raw.borrow_dependent().tbs_cert.subject)?;
     let issuer_name_hash =
         pyo3::pybacked::PyBackedBytes::from(hash_data(py, hash_algorithm, &issuer_der)?);
     let issuer_key_hash = pyo3::pybacked::PyBackedBytes::from(hash_data(
@@ -132,3 +137,18 @@ pub(crate) fn hash_data<'p>(
     h.update_bytes(data)?;
     Ok(h.finalize(py)?)
 }
+
+pub(crate) fn parse_acceptable_responses<'p>(
+    py: pyo3::Python<'p>,
+    ext: &Extension<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let oids = ext.value::<asn1::SequenceOf<'_, asn1::ObjectIdentifier>>()?;
+    let py_oids = pyo3::types::PyList::empty_bound(py);
+    for oid in oids {
+        py_oids.append(oid_to_py_oid(py, &oid)?)?;
+    }
+
+    Ok(types::OCSP_ACCEPTABLE_RESPONSES
+        .get(py)?
+        .call1((py_oids,))?)
+}
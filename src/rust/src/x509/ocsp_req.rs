@@ -2,18 +2,29 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use cryptography_x509::{
     common,
-    ocsp_req::{self, OCSPRequest as RawOCSPRequest},
+    ocsp_req::{self, CertID as RawCertID, OCSPRequest as RawOCSPRequest},
     oid,
 };
-use pyo3::types::{PyAnyMethods, PyListMethods, PyModuleMethods};
+use pyo3::types::{PyAnyMethods, PyModuleMethods};
 
-use crate::asn1::{big_byte_slice_to_py_int, oid_to_py_oid, py_uint_to_big_endian_bytes};
+use crate::asn1::{big_byte_slice_to_py_int, py_uint_to_big_endian_bytes};
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::x509::{extensions, ocsp};
 use crate::{exceptions, types, x509};
 
+self_cell::self_cell!(
+    struct OwnedCertID {
+        owner: pyo3::Py<pyo3::types::PyBytes>,
+        #[covariant]
+        dependent: RawCertID,
+    }
+);
+
 self_cell::self_cell!(
     struct OwnedOCSPRequest {
         owner: pyo3::Py<pyo3::types::PyBytes>,
@@ -131,17 +142,7 @@ impl OCSPRequest {
                         Ok(Some(types::OCSP_NONCE.get(py)?.call1((nonce,))?))
                     }
                     oid::ACCEPTABLE_RESPONSES_OID => {
-                        let oids = ext.value::<asn1::SequenceOf<'_, asn1::ObjectIdentifier>>()?;
-                        let py_oids = pyo3::types::PyList::empty_bound(py);
-                        for oid in oids {
-                            py_oids.append(oid_to_py_oid(py, &oid)?)?;
-                        }
-
-                        Ok(Some(
-                            types::OCSP_ACCEPTABLE_RESPONSES
-                                .get(py)?
-                                .call1((py_oids,))?,
-                        ))
+                        Ok(Some(ocsp::parse_acceptable_responses(py, ext)?))
                     }
                     _ => Ok(None),
                 }
@@ -229,11 +230,96 @@ fn create_ocsp_request(
     load_der_ocsp_request(py, pyo3::types::PyBytes::new_bound(py, &data).unbind())
 }
 
+#[pyo3::pyclass(frozen, module = "cryptography.hazmat.bindings._rust.ocsp")]
+struct OCSPCertID {
+    raw: OwnedCertID,
+}
+
+#[pyo3::pymethods]
+impl OCSPCertID {
+    fn __hash__(&self, py: pyo3::Python<'_>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.raw.borrow_owner().as_bytes(py).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __eq__(&self, py: pyo3::Python<'_>, other: pyo3::PyRef<'_, OCSPCertID>) -> bool {
+        self.raw.borrow_owner().as_bytes(py) == other.raw.borrow_owner().as_bytes(py)
+    }
+
+    #[getter]
+    fn issuer_name_hash(&self) -> &[u8] {
+        self.raw.borrow_dependent().issuer_name_hash
+    }
+
+    #[getter]
+    fn issuer_key_hash(&self) -> &[u8] {
+        self.raw.borrow_dependent().issuer_key_hash
+    }
+
+    #[getter]
+    fn hash_algorithm<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> Result<pyo3::Bound<'p, pyo3::PyAny>, CryptographyError> {
+        let cert_id = self.raw.borrow_dependent();
+
+        match ocsp::ALGORITHM_PARAMETERS_TO_HASH.get(&cert_id.hash_algorithm.params) {
+            Some(alg_name) => Ok(types::HASHES_MODULE.get(py)?.getattr(*alg_name)?.call0()?),
+            None => Err(CryptographyError::from(
+                exceptions::UnsupportedAlgorithm::new_err(format!(
+                    "Signature algorithm OID: {} not recognized",
+                    cert_id.hash_algorithm.oid()
+                )),
+            )),
+        }
+    }
+
+    #[getter]
+    fn serial_number<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> Result<pyo3::Bound<'p, pyo3::PyAny>, CryptographyError> {
+        let bytes = self.raw.borrow_dependent().serial_number.as_bytes();
+        Ok(big_byte_slice_to_py_int(py, bytes)?)
+    }
+
+    fn public_bytes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        encoding: &pyo3::Bound<'p, pyo3::PyAny>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        if !encoding.is(&types::ENCODING_DER.get(py)?) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "The only allowed encoding value is Encoding.DER",
+            )
+            .into());
+        }
+        Ok(self.raw.borrow_owner().clone_ref(py).into_bound(py))
+    }
+}
+
+#[pyo3::pyfunction]
+fn create_ocsp_cert_id(
+    py: pyo3::Python<'_>,
+    cert: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    issuer: pyo3::PyRef<'_, x509::certificate::Certificate>,
+    hash_algorithm: &pyo3::Bound<'_, pyo3::PyAny>,
+) -> CryptographyResult<OCSPCertID> {
+    let ka = cryptography_keepalive::KeepAlive::new();
+    let cert_id = ocsp::certid_new(py, &ka, &cert, &issuer, hash_algorithm)?;
+    let data = pyo3::types::PyBytes::new_bound(py, &asn1::write_single(&cert_id)?).unbind();
+    let raw = OwnedCertID::try_new(data, |data| asn1::parse_single(data.as_bytes(py)))?;
+    Ok(OCSPCertID { raw })
+}
+
 pub(crate) fn add_to_module(module: &pyo3::Bound<'_, pyo3::types::PyModule>) -> pyo3::PyResult<()> {
     module.add_function(pyo3::wrap_pyfunction_bound!(load_der_ocsp_request, module)?)?;
     module.add_function(pyo3::wrap_pyfunction_bound!(create_ocsp_request, module)?)?;
+    module.add_function(pyo3::wrap_pyfunction_bound!(create_ocsp_cert_id, module)?)?;
 
     module.add_class::<OCSPRequest>()?;
+    module.add_class::<OCSPCertID>()?;
 
     Ok(())
 }
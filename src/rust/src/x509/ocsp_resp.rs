@@ -10,7 +10,7 @@ use cryptography_x509::{
     ocsp_resp::{self, OCSPResponse as RawOCSPResponse, SingleResponse as RawSingleResponse},
     oid,
 };
-use pyo3::types::{PyAnyMethods, PyBytesMethods, PyListMethods, PyModuleMethods};
+use pyo3::types::{IntoPyDict, PyAnyMethods, PyBytesMethods, PyListMethods, PyModuleMethods};
 
 use crate::asn1::{big_byte_slice_to_py_int, oid_to_py_oid};
 use crate::error::{CryptographyError, CryptographyResult};
@@ -187,7 +187,7 @@ impl OCSPResponse {
                 1,
             )?;
         let resp = self.requires_successful_response()?;
-        x509::datetime_to_py(py, resp.tbs_response_data.produced_at.as_datetime())
+        produced_at_to_py(py, &resp.tbs_response_data.produced_at, false)
     }
 
     #[getter]
@@ -196,7 +196,7 @@ impl OCSPResponse {
         py: pyo3::Python<'p>,
     ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
         let resp = self.requires_successful_response()?;
-        x509::datetime_to_py_utc(py, resp.tbs_response_data.produced_at.as_datetime())
+        produced_at_to_py(py, &resp.tbs_response_data.produced_at, true)
     }
 
     #[getter]
@@ -455,6 +455,13 @@ impl OCSPResponse {
                         // the nonce. So we try parsing as a TLV and fall back to just using
                         // the raw value.
                         let nonce = ext.value::<&[u8]>().unwrap_or(ext.extn_value);
+                        // RFC 8954 section 2.1 limits nonces to 32 bytes.
+                        if nonce.is_empty() || nonce.len() > 32 {
+                            return Err(pyo3::exceptions::PyValueError::new_err(
+                                "OCSP nonce must be between 1 and 32 bytes long",
+                            )
+                            .into());
+                        }
                         Ok(Some(types::OCSP_NONCE.get(py)?.call1((nonce,))?))
                     }
                     _ => Ok(None),
@@ -480,8 +487,8 @@ impl OCSPResponse {
             py,
             &self.cached_single_extensions,
             &single_resp.raw_single_extensions,
-            |ext| match &ext.extn_id {
-                &oid::SIGNED_CERTIFICATE_TIMESTAMPS_OID => {
+            |ext| match ext.extn_id {
+                oid::SIGNED_CERTIFICATE_TIMESTAMPS_OID => {
                     let contents = ext.value::<&[u8]>()?;
                     let scts = sct::parse_scts(py, contents, sct::LogEntryType::Certificate)?;
                     Ok(Some(
@@ -490,6 +497,14 @@ impl OCSPResponse {
                             .call1((scts,))?,
                     ))
                 }
+                oid::ARCHIVE_CUTOFF_OID => {
+                    let time = ext.value::<asn1::GeneralizedTime>()?;
+                    let py_dt = x509::datetime_to_py(py, time.as_datetime())?;
+                    Ok(Some(types::OCSP_ARCHIVE_CUTOFF.get(py)?.call1((py_dt,))?))
+                }
+                oid::ACCEPTABLE_RESPONSES_OID => {
+                    Ok(Some(ocsp::parse_acceptable_responses(py, ext)?))
+                }
                 _ => crl::parse_crl_entry_ext(py, ext),
             },
         )
@@ -558,6 +573,27 @@ fn try_map_arc_data_mut_ocsp_response_iterator<E>(
     })
 }
 
+fn produced_at_to_py<'p>(
+    py: pyo3::Python<'p>,
+    produced_at: &common::FractionalGeneralizedTime,
+    utc: bool,
+) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    let dt = if utc {
+        x509::datetime_to_py_utc(py, produced_at.as_datetime())?
+    } else {
+        x509::datetime_to_py(py, produced_at.as_datetime())?
+    };
+    match produced_at.nanoseconds() {
+        // datetime only has microsecond precision, so anything finer is
+        // truncated.
+        Some(ns) => {
+            let kwargs = [(pyo3::intern!(py, "microsecond"), ns / 1000)].into_py_dict_bound(py);
+            dt.call_method(pyo3::intern!(py, "replace"), (), Some(&kwargs))
+        }
+        None => Ok(dt),
+    }
+}
+
 fn single_response<'a>(
     resp: &ocsp_resp::BasicOCSPResponse<'a>,
 ) -> Result<ocsp_resp::SingleResponse<'a>, CryptographyError> {
@@ -807,7 +843,7 @@ fn create_ocsp_response(
 
     let tbs_response_data = ocsp_resp::ResponseData {
         version: 0,
-        produced_at: asn1::GeneralizedTime::new(x509::common::datetime_now(py)?)?,
+        produced_at: common::FractionalGeneralizedTime::new(x509::common::datetime_now(py)?, None)?,
         responder_id,
         responses: common::Asn1ReadableOrWritable::new_write(asn1::SequenceOfWriter::new(
             responses,
@@ -5,11 +5,13 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-use pyo3::types::{PyAnyMethods, PyDictMethods, PyListMethods, PyModuleMethods};
+use pyo3::types::{PyAnyMethods, PyBytesMethods, PyDictMethods, PyListMethods, PyModuleMethods};
 use pyo3::ToPyObject;
 
-use crate::error::CryptographyError;
+use crate::error::{CryptographyError, CryptographyResult};
 use crate::types;
+use crate::x509::certificate::Certificate;
+use crate::x509::sign;
 
 struct TLSReader<'a> {
     data: &'a [u8],
@@ -217,6 +219,106 @@ impl Sct {
     fn extension_bytes(&self) -> &[u8] {
         &self.extension_bytes
     }
+
+    fn verify(
+        &self,
+        py: pyo3::Python<'_>,
+        log_public_key: pyo3::Bound<'_, pyo3::PyAny>,
+        certificate: pyo3::PyRef<'_, Certificate>,
+        issuer: pyo3::PyRef<'_, Certificate>,
+    ) -> CryptographyResult<()> {
+        let key_type = sign::identify_public_key_type(py, log_public_key.clone())?;
+        let expected_key_type = match self.signature_algorithm {
+            SignatureAlgorithm::Rsa => sign::KeyType::Rsa,
+            SignatureAlgorithm::Dsa => sign::KeyType::Dsa,
+            SignatureAlgorithm::Ecdsa => sign::KeyType::Ec,
+        };
+        if key_type != expected_key_type {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "SCT signature algorithm does not match log key type",
+                ),
+            ));
+        }
+
+        let log_spki = log_public_key.call_method1(
+            pyo3::intern!(py, "public_bytes"),
+            (
+                types::ENCODING_DER.get(py)?,
+                types::PUBLIC_FORMAT_SUBJECT_PUBLIC_KEY_INFO.get(py)?,
+            ),
+        )?;
+        if openssl::sha::sha256(log_spki.extract()?) != self.log_id {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "SCT log ID does not match the log public key",
+                ),
+            ));
+        }
+
+        // The digitally-signed struct from RFC 6962 section 3.2.
+        let mut signed_data = vec![
+            0, // sct_version: v1
+            0, // signature_type: certificate_timestamp
+        ];
+        signed_data.extend_from_slice(&self.timestamp.to_be_bytes());
+        let entry = match self.entry_type {
+            LogEntryType::Certificate => {
+                signed_data.extend_from_slice(&0_u16.to_be_bytes());
+                // The log signed the certificate's original encoding, which
+                // re-encoding the parsed certificate might not reproduce.
+                certificate.raw.borrow_owner().as_bytes(py).to_vec()
+            }
+            LogEntryType::PreCertificate => {
+                signed_data.extend_from_slice(&1_u16.to_be_bytes());
+                let issuer_spki = issuer
+                    .raw
+                    .borrow_dependent()
+                    .tbs_cert
+                    .spki
+                    .tlv()
+                    .full_data();
+                signed_data.extend_from_slice(&openssl::sha::sha256(issuer_spki));
+                certificate
+                    .tbs_precertificate_bytes(py)?
+                    .as_bytes()
+                    .to_vec()
+            }
+        };
+        signed_data.extend_from_slice(&(entry.len() as u32).to_be_bytes()[1..]);
+        signed_data.extend_from_slice(&entry);
+        signed_data.extend_from_slice(&(self.extension_bytes.len() as u16).to_be_bytes());
+        signed_data.extend_from_slice(&self.extension_bytes);
+
+        let hash_algorithm = self.signature_hash_algorithm(py)?;
+        match key_type {
+            sign::KeyType::Ec => {
+                let ecdsa = types::ECDSA.get(py)?.call1((hash_algorithm,))?;
+                log_public_key.call_method1(
+                    pyo3::intern!(py, "verify"),
+                    (self.signature.as_slice(), signed_data.as_slice(), ecdsa),
+                )?
+            }
+            sign::KeyType::Rsa => log_public_key.call_method1(
+                pyo3::intern!(py, "verify"),
+                (
+                    self.signature.as_slice(),
+                    signed_data.as_slice(),
+                    types::PKCS1V15.get(py)?.call0()?,
+                    hash_algorithm,
+                ),
+            )?,
+            _ => log_public_key.call_method1(
+                pyo3::intern!(py, "verify"),
+                (
+                    self.signature.as_slice(),
+                    signed_data.as_slice(),
+                    hash_algorithm,
+                ),
+            )?,
+        };
+        Ok(())
+    }
 }
 
 pub(crate) fn parse_scts(
@@ -29,6 +29,30 @@ static HASH_OIDS_TO_HASH: Lazy<HashMap<&asn1::ObjectIdentifier, &str>> = Lazy::n
     h
 });
 
+// Signature and public key algorithms that are recognized, so that they can
+// be reported by name, but that can't be used.
+pub(crate) static UNSUPPORTED_NATIONAL_ALGORITHMS: Lazy<HashMap<&asn1::ObjectIdentifier, &str>> =
+    Lazy::new(|| {
+        let mut h = HashMap::new();
+        h.insert(&oid::GOSTR3410_2001_OID, "GOST R 34.10-2001");
+        h.insert(&oid::GOSTR3410_2012_256_OID, "GOST R 34.10-2012 (256 bit)");
+        h.insert(&oid::GOSTR3410_2012_512_OID, "GOST R 34.10-2012 (512 bit)");
+        h.insert(
+            &oid::GOSTR3411_94_WITH_3410_2001_OID,
+            "GOST R 34.11-94 with GOST R 34.10-2001",
+        );
+        h.insert(
+            &oid::GOSTR3410_2012_WITH_3411_2012_256_OID,
+            "GOST R 34.10-2012 with GOST R 34.11-2012 (256 bit)",
+        );
+        h.insert(
+            &oid::GOSTR3410_2012_WITH_3411_2012_512_OID,
+            "GOST R 34.10-2012 with GOST R 34.11-2012 (512 bit)",
+        );
+        h.insert(&oid::SM2_WITH_SM3_OID, "SM2 with SM3");
+        h
+    });
+
 #[derive(Debug, PartialEq)]
 pub(crate) enum KeyType {
     Rsa,
@@ -103,6 +127,18 @@ fn identify_hash_type(
     }
 }
 
+// SHA-1 is not accepted as a signature digest, but it remains the RFC 4055
+// default for MGF1 and some signers pair it with a stronger message digest.
+fn identify_mgf1_hash_params(
+    py: pyo3::Python<'_>,
+    hash_algorithm: pyo3::Bound<'_, pyo3::PyAny>,
+) -> pyo3::PyResult<common::AlgorithmParameters<'static>> {
+    if hash_algorithm.is_instance(&types::SHA1.get(py)?)? {
+        return Ok(common::AlgorithmParameters::Sha1(Some(())));
+    }
+    identify_alg_params_for_hash_type(identify_hash_type(py, hash_algorithm)?)
+}
+
 fn compute_pss_salt_length<'p>(
     py: pyo3::Python<'p>,
     private_key: pyo3::Bound<'p, pyo3::PyAny>,
@@ -150,10 +186,9 @@ pub(crate) fn compute_signature_algorithm<'p>(
         let py_mgf_alg = rsa_padding
             .getattr(pyo3::intern!(py, "_mgf"))?
             .getattr(pyo3::intern!(py, "_algorithm"))?;
-        let mgf_hash_type = identify_hash_type(py, py_mgf_alg)?;
         let mgf_alg = common::AlgorithmIdentifier {
             oid: asn1::DefinedByMarker::marker(),
-            params: identify_alg_params_for_hash_type(mgf_hash_type)?,
+            params: identify_mgf1_hash_params(py, py_mgf_alg)?,
         };
         let params =
             common::AlgorithmParameters::RsaPss(Some(Box::new(common::RsaPssParameters {
@@ -403,6 +438,17 @@ fn identify_key_type_for_algorithm_params(
         | common::AlgorithmParameters::DsaWithSha256(..)
         | common::AlgorithmParameters::DsaWithSha384(..)
         | common::AlgorithmParameters::DsaWithSha512(..) => Ok(KeyType::Dsa),
+        common::AlgorithmParameters::Other(oid, _) => {
+            match UNSUPPORTED_NATIONAL_ALGORITHMS.get(oid) {
+                Some(name) => Err(exceptions::UnsupportedAlgorithm::new_err((
+                    format!("Signature algorithm {name} ({oid}) is not supported"),
+                    exceptions::Reasons::UNSUPPORTED_PUBLIC_KEY_ALGORITHM,
+                ))),
+                None => Err(pyo3::exceptions::PyValueError::new_err(
+                    "Unsupported signature algorithm",
+                )),
+            }
+        }
         _ => Err(pyo3::exceptions::PyValueError::new_err(
             "Unsupported signature algorithm",
         )),
@@ -479,17 +525,7 @@ pub(crate) fn identify_signature_algorithm_parameters<'p>(
             let pss = opt_pss.as_ref().ok_or_else(|| {
                 pyo3::exceptions::PyValueError::new_err("Invalid RSA PSS parameters")
             })?;
-            if pss.mask_gen_algorithm.oid != oid::MGF1_OID {
-                return Err(CryptographyError::from(
-                    pyo3::exceptions::PyValueError::new_err(format!(
-                        "Unsupported mask generation OID: {}",
-                        pss.mask_gen_algorithm.oid
-                    )),
-                ));
-            }
-            let py_mask_gen_hash_alg =
-                hash_oid_py_hash(py, pss.mask_gen_algorithm.params.oid().clone())?;
-            let py_mgf = types::MGF1.get(py)?.call1((py_mask_gen_hash_alg,))?;
+            let py_mgf = mgf1_py_mgf(py, &pss.mask_gen_algorithm)?;
             Ok(types::PSS.get(py)?.call1((py_mgf, pss.salt_length))?)
         }
         common::AlgorithmParameters::RsaWithSha1(_)
@@ -521,6 +557,76 @@ pub(crate) fn identify_signature_algorithm_parameters<'p>(
     }
 }
 
+fn mgf1_py_mgf<'p>(
+    py: pyo3::Python<'p>,
+    mask_gen_algorithm: &common::MaskGenAlgorithm<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    if mask_gen_algorithm.oid != oid::MGF1_OID {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Unsupported mask generation OID: {}",
+                mask_gen_algorithm.oid
+            )),
+        ));
+    }
+    let py_mask_gen_hash_alg = hash_oid_py_hash(py, mask_gen_algorithm.params.oid().clone())?;
+    Ok(types::MGF1.get(py)?.call1((py_mask_gen_hash_alg,))?)
+}
+
+// An RSASSA-PSS or RSAES-OAEP SPKI may carry parameters that restrict the
+// key to a single set of padding parameters (RFC 4055 sections 1.2 and 4.1).
+// Keys without such a restriction return `None`.
+pub(crate) fn identify_public_key_algorithm_parameters<'p>(
+    py: pyo3::Python<'p>,
+    spki_algorithm: &common::AlgorithmIdentifier<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    match &spki_algorithm.params {
+        common::AlgorithmParameters::RsaPss(Some(pss)) => {
+            let py_mgf = mgf1_py_mgf(py, &pss.mask_gen_algorithm)?;
+            Ok(types::PSS.get(py)?.call1((py_mgf, pss.salt_length))?)
+        }
+        common::AlgorithmParameters::RsaesOaep(Some(oaep)) => {
+            let label = match &oaep.p_source_algorithm.params {
+                common::AlgorithmParameters::PSpecified(label) => label,
+                _ => {
+                    return Err(CryptographyError::from(
+                        pyo3::exceptions::PyValueError::new_err(format!(
+                            "Unsupported OAEP label source OID: {}",
+                            oaep.p_source_algorithm.oid()
+                        )),
+                    ))
+                }
+            };
+            let py_mgf = mgf1_py_mgf(py, &oaep.mask_gen_algorithm)?;
+            let py_hash_alg = hash_oid_py_hash(py, oaep.hash_algorithm.oid().clone())?;
+            let py_label = if label.is_empty() {
+                py.None().into_bound(py)
+            } else {
+                pyo3::types::PyBytes::new_bound(py, label).into_any()
+            };
+            Ok(types::OAEP
+                .get(py)?
+                .call1((py_mgf, py_hash_alg, py_label))?)
+        }
+        _ => Ok(py.None().into_bound(py)),
+    }
+}
+
+pub(crate) fn identify_public_key_hash_algorithm<'p>(
+    py: pyo3::Python<'p>,
+    spki_algorithm: &common::AlgorithmIdentifier<'_>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::PyAny>> {
+    match &spki_algorithm.params {
+        common::AlgorithmParameters::RsaPss(Some(pss)) => {
+            hash_oid_py_hash(py, pss.hash_algorithm.oid().clone())
+        }
+        common::AlgorithmParameters::RsaesOaep(Some(oaep)) => {
+            hash_oid_py_hash(py, oaep.hash_algorithm.oid().clone())
+        }
+        _ => Ok(py.None().into_bound(py)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cryptography_x509::{common, oid};
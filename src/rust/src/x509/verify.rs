@@ -2,10 +2,20 @@
 // 2.0, and the BSD License. See the LICENSE file in the root of this repository
 // for complete details.
 
+use std::collections::HashSet;
+
 use cryptography_x509::{
-    certificate::Certificate, extensions::SubjectAlternativeName, oid::SUBJECT_ALTERNATIVE_NAME_OID,
+    certificate::Certificate,
+    extensions::{AuthorityKeyIdentifier, SequenceOfAccessDescriptions, SubjectAlternativeName},
+    name::GeneralName,
+    oid::{
+        self, AUTHORITY_INFORMATION_ACCESS_OID, CA_ISSUERS_OID, EKU_ANY_KEY_USAGE_OID,
+        SUBJECT_ALTERNATIVE_NAME_OID,
+    },
 };
 use cryptography_x509_verification::{
+    certificate,
+    name::names_match,
     ops::{CryptoOps, VerificationCertificate},
     policy::{Policy, Subject},
     trust_store::Store,
@@ -13,15 +23,21 @@ use cryptography_x509_verification::{
 };
 use pyo3::types::{PyAnyMethods, PyListMethods, PyModuleMethods};
 
+use crate::asn1::{oid_to_py_oid, pem_encode, py_oid_to_oid};
 use crate::backend::keys;
 use crate::error::{CryptographyError, CryptographyResult};
 use crate::types;
-use crate::x509::certificate::Certificate as PyCertificate;
+use crate::x509::certificate::{load_der_x509_certificate, Certificate as PyCertificate};
 use crate::x509::common::{datetime_now, datetime_to_py, py_to_datetime};
+use crate::x509::extensions::encode_authority_key_identifier;
 use crate::x509::sign;
 
 use super::parse_general_names;
 
+/// The number of caIssuers URLs fetched per verification, unless set with
+/// `PolicyBuilder.max_aia_fetches`.
+const DEFAULT_MAX_AIA_FETCHES: u8 = 8;
+
 pub(crate) struct PyCryptoOps {}
 
 impl CryptoOps for PyCryptoOps {
@@ -59,6 +75,51 @@ struct PolicyBuilder {
     time: Option<asn1::DateTime>,
     store: Option<pyo3::Py<PyStore>>,
     max_chain_depth: Option<u8>,
+    signature_hash_algorithms: Option<Vec<asn1::ObjectIdentifier>>,
+    check_trust_anchor_signature: bool,
+    permit_precertificates: bool,
+    require_key_establishment: bool,
+    require_nested_validity: bool,
+    handled_critical_extensions: Option<Vec<asn1::ObjectIdentifier>>,
+    ca_issuers_fetcher: Option<pyo3::Py<pyo3::PyAny>>,
+    max_aia_fetches: Option<u8>,
+}
+
+impl PolicyBuilder {
+    // `PolicyBuilder` can't derive `Clone` because of its `Py` fields, so
+    // the builder methods copy it with this and replace the field they set.
+    fn py_clone(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            time: self.time.clone(),
+            store: self.store.as_ref().map(|s| s.clone_ref(py)),
+            max_chain_depth: self.max_chain_depth,
+            signature_hash_algorithms: self.signature_hash_algorithms.clone(),
+            check_trust_anchor_signature: self.check_trust_anchor_signature,
+            permit_precertificates: self.permit_precertificates,
+            require_key_establishment: self.require_key_establishment,
+            require_nested_validity: self.require_nested_validity,
+            handled_critical_extensions: self.handled_critical_extensions.clone(),
+            ca_issuers_fetcher: self.ca_issuers_fetcher.as_ref().map(|f| f.clone_ref(py)),
+            max_aia_fetches: self.max_aia_fetches,
+        }
+    }
+
+    fn apply_signature_hash_algorithms(&self, policy: &mut Policy<'_, PyCryptoOps>) {
+        if let Some(hash_oids) = &self.signature_hash_algorithms {
+            policy.restrict_signature_hash_algorithms(hash_oids);
+            policy.check_trust_anchor_signature = self.check_trust_anchor_signature;
+        }
+    }
+
+    fn apply_options(&self, policy: &mut Policy<'_, PyCryptoOps>) {
+        self.apply_signature_hash_algorithms(policy);
+        policy.permit_precertificates = self.permit_precertificates;
+        policy.require_key_establishment = self.require_key_establishment;
+        policy.require_nested_validity = self.require_nested_validity;
+        if let Some(oids) = &self.handled_critical_extensions {
+            policy.handled_critical_extensions = oids.iter().cloned().collect();
+        }
+    }
 }
 
 #[pyo3::pymethods]
@@ -69,6 +130,14 @@ impl PolicyBuilder {
             time: None,
             store: None,
             max_chain_depth: None,
+            signature_hash_algorithms: None,
+            check_trust_anchor_signature: false,
+            permit_precertificates: false,
+            require_key_establishment: false,
+            require_nested_validity: false,
+            handled_critical_extensions: None,
+            ca_issuers_fetcher: None,
+            max_aia_fetches: None,
         }
     }
 
@@ -86,21 +155,23 @@ impl PolicyBuilder {
         }
         Ok(PolicyBuilder {
             time: Some(py_to_datetime(py, new_time)?),
-            store: self.store.as_ref().map(|s| s.clone_ref(py)),
-            max_chain_depth: self.max_chain_depth,
+            ..self.py_clone(py)
         })
     }
 
-    fn store(&self, new_store: pyo3::Py<PyStore>) -> CryptographyResult<PolicyBuilder> {
+    fn store(
+        &self,
+        py: pyo3::Python<'_>,
+        new_store: pyo3::Py<PyStore>,
+    ) -> CryptographyResult<PolicyBuilder> {
         if self.store.is_some() {
             return Err(CryptographyError::from(
                 pyo3::exceptions::PyValueError::new_err("The trust store may only be set once."),
             ));
         }
         Ok(PolicyBuilder {
-            time: self.time.clone(),
             store: Some(new_store),
-            max_chain_depth: self.max_chain_depth,
+            ..self.py_clone(py)
         })
     }
 
@@ -117,9 +188,145 @@ impl PolicyBuilder {
             ));
         }
         Ok(PolicyBuilder {
-            time: self.time.clone(),
-            store: self.store.as_ref().map(|s| s.clone_ref(py)),
             max_chain_depth: Some(new_max_chain_depth),
+            ..self.py_clone(py)
+        })
+    }
+
+    #[pyo3(signature = (new_signature_hash_algorithms, *, check_trust_anchor = false))]
+    fn signature_hash_algorithms(
+        &self,
+        py: pyo3::Python<'_>,
+        new_signature_hash_algorithms: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+        check_trust_anchor: bool,
+    ) -> CryptographyResult<PolicyBuilder> {
+        if self.signature_hash_algorithms.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The signature hash algorithms may only be set once.",
+                ),
+            ));
+        }
+
+        let mut hash_oids = vec![];
+        for py_hash_alg in new_signature_hash_algorithms {
+            if !py_hash_alg.is_instance(&types::HASH_ALGORITHM.get(py)?)? {
+                return Err(CryptographyError::from(
+                    pyo3::exceptions::PyTypeError::new_err(
+                        "Algorithm must be a registered hash algorithm.",
+                    ),
+                ));
+            }
+            let hash_oid = match &*py_hash_alg
+                .getattr(pyo3::intern!(py, "name"))?
+                .extract::<pyo3::pybacked::PyBackedStr>()?
+            {
+                "sha256" => oid::SHA256_OID,
+                "sha384" => oid::SHA384_OID,
+                "sha512" => oid::SHA512_OID,
+                _ => {
+                    return Err(CryptographyError::from(
+                        pyo3::exceptions::PyValueError::new_err(
+                            "Signature hash algorithms must be SHA256, SHA384, or SHA512.",
+                        ),
+                    ))
+                }
+            };
+            hash_oids.push(hash_oid);
+        }
+
+        Ok(PolicyBuilder {
+            signature_hash_algorithms: Some(hash_oids),
+            check_trust_anchor_signature: check_trust_anchor,
+            ..self.py_clone(py)
+        })
+    }
+
+    fn permit_precertificates(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            permit_precertificates: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn require_key_establishment(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            require_key_establishment: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn require_nested_validity(&self, py: pyo3::Python<'_>) -> PolicyBuilder {
+        PolicyBuilder {
+            require_nested_validity: true,
+            ..self.py_clone(py)
+        }
+    }
+
+    fn handled_critical_extensions(
+        &self,
+        py: pyo3::Python<'_>,
+        new_handled_critical_extensions: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        if self.handled_critical_extensions.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The handled critical extensions may only be set once.",
+                ),
+            ));
+        }
+
+        let oids = new_handled_critical_extensions
+            .into_iter()
+            .map(py_oid_to_oid)
+            .collect::<pyo3::PyResult<Vec<_>>>()?;
+
+        Ok(PolicyBuilder {
+            handled_critical_extensions: Some(oids),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn ca_issuers_fetcher(
+        &self,
+        py: pyo3::Python<'_>,
+        new_ca_issuers_fetcher: pyo3::Py<pyo3::PyAny>,
+    ) -> CryptographyResult<PolicyBuilder> {
+        if self.ca_issuers_fetcher.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The caIssuers fetcher may only be set once.",
+                ),
+            ));
+        }
+        if !new_ca_issuers_fetcher.bind(py).is_callable() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyTypeError::new_err("The caIssuers fetcher must be callable."),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            ca_issuers_fetcher: Some(new_ca_issuers_fetcher),
+            ..self.py_clone(py)
+        })
+    }
+
+    fn max_aia_fetches(
+        &self,
+        py: pyo3::Python<'_>,
+        new_max_aia_fetches: u8,
+    ) -> CryptographyResult<PolicyBuilder> {
+        if self.max_aia_fetches.is_some() {
+            return Err(CryptographyError::from(
+                pyo3::exceptions::PyValueError::new_err(
+                    "The maximum number of AIA fetches may only be set once.",
+                ),
+            ));
+        }
+
+        Ok(PolicyBuilder {
+            max_aia_fetches: Some(new_max_aia_fetches),
+            ..self.py_clone(py)
         })
     }
 
@@ -140,9 +347,16 @@ impl PolicyBuilder {
             None => datetime_now(py)?,
         };
 
-        let policy = PyCryptoPolicy(Policy::client(PyCryptoOps {}, time, self.max_chain_depth));
+        let mut policy = Policy::client(PyCryptoOps {}, time, self.max_chain_depth);
+        self.apply_options(&mut policy);
+        let policy = PyCryptoPolicy(policy);
 
-        Ok(PyClientVerifier { policy, store })
+        Ok(PyClientVerifier {
+            policy,
+            store,
+            ca_issuers_fetcher: self.ca_issuers_fetcher.as_ref().map(|f| f.clone_ref(py)),
+            max_aia_fetches: self.max_aia_fetches.unwrap_or(DEFAULT_MAX_AIA_FETCHES),
+        })
     }
 
     fn build_server_verifier(
@@ -169,18 +383,17 @@ impl PolicyBuilder {
 
         let policy = OwnedPolicy::try_new(subject_owner, |subject_owner| {
             let subject = build_subject(py, subject_owner)?;
-            Ok::<PyCryptoPolicy<'_>, pyo3::PyErr>(PyCryptoPolicy(Policy::server(
-                PyCryptoOps {},
-                subject,
-                time,
-                self.max_chain_depth,
-            )))
+            let mut policy = Policy::server(PyCryptoOps {}, subject, time, self.max_chain_depth);
+            self.apply_options(&mut policy);
+            Ok::<PyCryptoPolicy<'_>, pyo3::PyErr>(PyCryptoPolicy(policy))
         })?;
 
         Ok(PyServerVerifier {
             py_subject: subject,
             policy,
             store,
+            ca_issuers_fetcher: self.ca_issuers_fetcher.as_ref().map(|f| f.clone_ref(py)),
+            max_aia_fetches: self.max_aia_fetches.unwrap_or(DEFAULT_MAX_AIA_FETCHES),
         })
     }
 }
@@ -219,6 +432,52 @@ struct PyVerifiedClient {
     chain: pyo3::Py<pyo3::types::PyList>,
 }
 
+#[pyo3::pymethods]
+impl PyVerifiedClient {
+    #[pyo3(signature = (*, include_root = true))]
+    fn chain_pem<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        include_root: bool,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+        let chain = self
+            .chain
+            .bind(py)
+            .extract::<Vec<pyo3::Py<PyCertificate>>>()?;
+        encode_chain_pem(py, &chain, include_root)
+    }
+}
+
+/// Encodes a chain, ordered from the leaf to the trust anchor, as
+/// concatenated PEM certificates. The trust anchor is left out unless
+/// `include_root` is set, or it is the leaf itself.
+fn encode_chain_pem<'p>(
+    py: pyo3::Python<'p>,
+    chain: &[pyo3::Py<PyCertificate>],
+    include_root: bool,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    let chain = match chain.split_last() {
+        Some((_, rest)) if !include_root && !rest.is_empty() => rest,
+        _ => chain,
+    };
+    let mut pem = String::new();
+    for cert in chain {
+        let der = asn1::write_single(cert.get().raw.borrow_dependent())?;
+        pem.push_str(&pem_encode("CERTIFICATE".to_string(), der));
+    }
+    Ok(pyo3::types::PyBytes::new_bound(py, pem.as_bytes()))
+}
+
+#[pyo3::pyfunction]
+#[pyo3(signature = (chain, *, include_root = true))]
+fn chain_pem<'p>(
+    py: pyo3::Python<'p>,
+    chain: Vec<pyo3::Py<PyCertificate>>,
+    include_root: bool,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyBytes>> {
+    encode_chain_pem(py, &chain, include_root)
+}
+
 #[pyo3::pyclass(
     frozen,
     name = "ClientVerifier",
@@ -228,6 +487,8 @@ struct PyClientVerifier {
     policy: PyCryptoPolicy<'static>,
     #[pyo3(get)]
     store: pyo3::Py<PyStore>,
+    ca_issuers_fetcher: Option<pyo3::Py<pyo3::PyAny>>,
+    max_aia_fetches: u8,
 }
 
 impl PyClientVerifier {
@@ -260,6 +521,14 @@ impl PyClientVerifier {
         let policy = self.as_policy();
         let store = self.store.get();
 
+        let intermediates = chase_ca_issuers(
+            py,
+            self.ca_issuers_fetcher.as_ref(),
+            store,
+            &leaf,
+            intermediates,
+            self.max_aia_fetches,
+        )?;
         let intermediates = intermediates
             .iter()
             .map(|i| {
@@ -307,6 +576,23 @@ impl PyClientVerifier {
             chain: py_chain.unbind(),
         })
     }
+
+    fn satisfied_extended_key_usages<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: Vec<pyo3::Py<PyCertificate>>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        satisfied_extended_key_usages(
+            py,
+            self.as_policy(),
+            self.store.get(),
+            self.ca_issuers_fetcher.as_ref(),
+            self.max_aia_fetches,
+            leaf,
+            intermediates,
+        )
+    }
 }
 
 #[pyo3::pyclass(
@@ -320,6 +606,8 @@ struct PyServerVerifier {
     policy: OwnedPolicy,
     #[pyo3(get)]
     store: pyo3::Py<PyStore>,
+    ca_issuers_fetcher: Option<pyo3::Py<pyo3::PyAny>>,
+    max_aia_fetches: u8,
 }
 
 impl PyServerVerifier {
@@ -352,6 +640,14 @@ impl PyServerVerifier {
         let policy = self.as_policy();
         let store = self.store.get();
 
+        let intermediates = chase_ca_issuers(
+            py,
+            self.ca_issuers_fetcher.as_ref(),
+            store,
+            &leaf,
+            intermediates,
+            self.max_aia_fetches,
+        )?;
         let intermediates = intermediates
             .iter()
             .map(|i| {
@@ -382,6 +678,155 @@ impl PyServerVerifier {
         }
         Ok(result)
     }
+
+    fn satisfied_extended_key_usages<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+        leaf: pyo3::Py<PyCertificate>,
+        intermediates: Vec<pyo3::Py<PyCertificate>>,
+    ) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        satisfied_extended_key_usages(
+            py,
+            self.as_policy(),
+            self.store.get(),
+            self.ca_issuers_fetcher.as_ref(),
+            self.max_aia_fetches,
+            leaf,
+            intermediates,
+        )
+    }
+}
+
+/// Verifies `leaf` under `policy`, and returns the extended key usages
+/// that the resulting chain is valid for. An unrestricted chain is reported
+/// as `anyExtendedKeyUsage`.
+fn satisfied_extended_key_usages<'p>(
+    py: pyo3::Python<'p>,
+    policy: &Policy<'_, PyCryptoOps>,
+    store: &PyStore,
+    ca_issuers_fetcher: Option<&pyo3::Py<pyo3::PyAny>>,
+    max_aia_fetches: u8,
+    leaf: pyo3::Py<PyCertificate>,
+    intermediates: Vec<pyo3::Py<PyCertificate>>,
+) -> CryptographyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+    let intermediates = chase_ca_issuers(
+        py,
+        ca_issuers_fetcher,
+        store,
+        &leaf,
+        intermediates,
+        max_aia_fetches,
+    )?;
+    let intermediates = intermediates
+        .iter()
+        .map(|i| {
+            VerificationCertificate::new(i.get().raw.borrow_dependent().clone(), i.clone_ref(py))
+        })
+        .collect::<Vec<_>>();
+    let intermediate_refs = intermediates.iter().collect::<Vec<_>>();
+
+    let v = VerificationCertificate::new(
+        leaf.get().raw.borrow_dependent().clone(),
+        leaf.clone_ref(py),
+    );
+
+    let store = store.raw.borrow_dependent();
+    let chain = cryptography_x509_verification::verify(&v, &intermediate_refs, policy, store)
+        .map_err(|e| VerificationError::new_err(format!("validation failed: {e}")))?;
+    let ekus = cryptography_x509_verification::chain_extended_key_usages(&chain, store)
+        .map_err(|e| VerificationError::new_err(format!("validation failed: {e}")))?
+        .unwrap_or_else(|| vec![EKU_ANY_KEY_USAGE_OID]);
+
+    let result = pyo3::types::PyList::empty_bound(py);
+    for eku in &ekus {
+        result.append(oid_to_py_oid(py, eku)?)?;
+    }
+    Ok(result)
+}
+
+/// Returns the caIssuers URLs from `cert`'s Authority Information Access
+/// extension. A missing or malformed extension yields no URLs; the policy
+/// reports malformed extensions itself.
+fn ca_issuers_urls<'a>(cert: &Certificate<'a>) -> Vec<&'a str> {
+    let aia = match cert.extensions() {
+        Ok(extensions) => extensions.get_extension(&AUTHORITY_INFORMATION_ACCESS_OID),
+        Err(_) => None,
+    };
+    let descriptions = match aia.map(|aia| aia.value::<SequenceOfAccessDescriptions<'a>>()) {
+        Some(Ok(descriptions)) => descriptions,
+        _ => return vec![],
+    };
+    descriptions
+        .unwrap_read()
+        .clone()
+        .filter(|d| d.access_method == CA_ISSUERS_OID)
+        .filter_map(|d| match d.access_location {
+            GeneralName::UniformResourceIdentifier(uri) => Some(uri.0),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Follows the caIssuers URLs of certificates whose issuer is in neither
+/// `store` nor `intermediates`, and returns `intermediates` extended with
+/// the fetched certificates that name the missing issuer as their subject.
+/// The fetched certificates are only candidates: chain building still
+/// checks their signatures and the policy like any other intermediate.
+/// At most `max_fetches` URLs are fetched.
+fn chase_ca_issuers(
+    py: pyo3::Python<'_>,
+    fetcher: Option<&pyo3::Py<pyo3::PyAny>>,
+    store: &PyStore,
+    leaf: &pyo3::Py<PyCertificate>,
+    mut intermediates: Vec<pyo3::Py<PyCertificate>>,
+    max_fetches: u8,
+) -> CryptographyResult<Vec<pyo3::Py<PyCertificate>>> {
+    let fetcher = match fetcher {
+        Some(fetcher) => fetcher,
+        None => return Ok(intermediates),
+    };
+    let store = store.raw.borrow_dependent();
+
+    let mut pending = vec![leaf.clone_ref(py)];
+    pending.extend(intermediates.iter().map(|i| i.clone_ref(py)));
+    let mut fetched_urls = HashSet::new();
+    while let Some(cert) = pending.pop() {
+        let cert = cert.get().raw.borrow_dependent();
+        if store.get_by_subject(&cert.tbs_cert.issuer).next().is_some()
+            || intermediates
+                .iter()
+                .any(|i| names_match(i.get().raw.borrow_dependent().subject(), cert.issuer()))
+        {
+            continue;
+        }
+
+        for url in ca_issuers_urls(cert) {
+            if fetched_urls.len() >= usize::from(max_fetches)
+                || !fetched_urls.insert(url.to_owned())
+            {
+                continue;
+            }
+            let fetched = fetcher
+                .bind(py)
+                .call1((url,))?
+                .extract::<Vec<pyo3::Py<pyo3::types::PyBytes>>>()?;
+            for der in fetched {
+                // Anything that doesn't parse, or isn't for the missing
+                // issuer, can't help build the chain.
+                let candidate = match load_der_x509_certificate(py, der, None) {
+                    Ok(candidate) => candidate,
+                    Err(_) => continue,
+                };
+                if !names_match(candidate.raw.borrow_dependent().subject(), cert.issuer()) {
+                    continue;
+                }
+                let candidate = pyo3::Py::new(py, candidate)?;
+                intermediates.push(candidate.clone_ref(py));
+                pending.push(candidate);
+            }
+        }
+    }
+    Ok(intermediates)
 }
 
 fn build_subject_owner(
@@ -433,15 +878,62 @@ fn build_subject<'a>(
 
 type PyCryptoOpsStore<'a> = Store<'a, PyCryptoOps>;
 
+type StoreEntry = (pyo3::Py<PyCertificate>, Option<Vec<asn1::ObjectIdentifier>>);
+
 self_cell::self_cell!(
     struct RawPyStore {
-        owner: Vec<pyo3::Py<PyCertificate>>,
+        owner: Vec<StoreEntry>,
 
         #[covariant]
         dependent: PyCryptoOpsStore,
     }
 );
 
+#[pyo3::pyclass(
+    frozen,
+    name = "TrustAnchor",
+    module = "cryptography.hazmat.bindings._rust.x509"
+)]
+struct PyTrustAnchor {
+    #[pyo3(get)]
+    certificate: pyo3::Py<PyCertificate>,
+    purposes: Vec<asn1::ObjectIdentifier>,
+}
+
+#[pyo3::pymethods]
+impl PyTrustAnchor {
+    #[new]
+    fn new(
+        certificate: pyo3::Py<PyCertificate>,
+        purposes: Vec<pyo3::Bound<'_, pyo3::PyAny>>,
+    ) -> pyo3::PyResult<Self> {
+        if purposes.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "A trust anchor must be trusted for at least one purpose.",
+            ));
+        }
+        Ok(PyTrustAnchor {
+            certificate,
+            purposes: purposes
+                .into_iter()
+                .map(py_oid_to_oid)
+                .collect::<pyo3::PyResult<_>>()?,
+        })
+    }
+
+    #[getter]
+    fn purposes<'p>(
+        &self,
+        py: pyo3::Python<'p>,
+    ) -> pyo3::PyResult<pyo3::Bound<'p, pyo3::types::PyList>> {
+        let purposes = pyo3::types::PyList::empty_bound(py);
+        for purpose in &self.purposes {
+            purposes.append(oid_to_py_oid(py, purpose)?)?;
+        }
+        Ok(purposes)
+    }
+}
+
 #[pyo3::pyclass(
     frozen,
     name = "Store",
@@ -454,18 +946,39 @@ struct PyStore {
 #[pyo3::pymethods]
 impl PyStore {
     #[new]
-    fn new(py: pyo3::Python<'_>, certs: Vec<pyo3::Py<PyCertificate>>) -> pyo3::PyResult<Self> {
+    fn new(py: pyo3::Python<'_>, certs: Vec<pyo3::Bound<'_, pyo3::PyAny>>) -> pyo3::PyResult<Self> {
         if certs.is_empty() {
             return Err(pyo3::exceptions::PyValueError::new_err(
                 "can't create an empty store",
             ));
         }
+        let entries = certs
+            .into_iter()
+            .map(|c| -> pyo3::PyResult<StoreEntry> {
+                if let Ok(anchor) = c.downcast::<PyTrustAnchor>() {
+                    let anchor = anchor.get();
+                    Ok((
+                        anchor.certificate.clone_ref(py),
+                        Some(anchor.purposes.clone()),
+                    ))
+                } else if let Ok(cert) = c.downcast::<PyCertificate>() {
+                    Ok((cert.clone().unbind(), None))
+                } else {
+                    Err(pyo3::exceptions::PyTypeError::new_err(
+                        "Store entries must be Certificate or TrustAnchor instances.",
+                    ))
+                }
+            })
+            .collect::<pyo3::PyResult<Vec<_>>>()?;
         Ok(Self {
-            raw: RawPyStore::new(certs, |v| {
-                Store::new(v.iter().map(|t| {
-                    VerificationCertificate::new(
-                        t.get().raw.borrow_dependent().clone(),
-                        t.clone_ref(py),
+            raw: RawPyStore::new(entries, |v| {
+                Store::new_with_purposes(v.iter().map(|(t, purposes)| {
+                    (
+                        VerificationCertificate::new(
+                            t.get().raw.borrow_dependent().clone(),
+                            t.clone_ref(py),
+                        ),
+                        purposes.clone(),
                     )
                 }))
             }),
@@ -473,12 +986,39 @@ impl PyStore {
     }
 }
 
+#[pyo3::pyfunction]
+fn authority_key_identifier_matches(
+    py: pyo3::Python<'_>,
+    aki: &pyo3::Bound<'_, pyo3::PyAny>,
+    issuer: &PyCertificate,
+) -> CryptographyResult<bool> {
+    if !aki.is_instance(&types::AUTHORITY_KEY_IDENTIFIER.get(py)?)? {
+        return Err(CryptographyError::from(
+            pyo3::exceptions::PyTypeError::new_err(
+                "aki must be an AuthorityKeyIdentifier instance.",
+            ),
+        ));
+    }
+    let der = encode_authority_key_identifier(py, aki)?;
+    let aki = asn1::parse_single::<AuthorityKeyIdentifier<'_>>(&der)?;
+    Ok(certificate::authority_key_identifier_matches(
+        &aki,
+        issuer.raw.borrow_dependent(),
+    ))
+}
+
 pub(crate) fn add_to_module(module: &pyo3::Bound<'_, pyo3::types::PyModule>) -> pyo3::PyResult<()> {
     module.add_class::<PyVerifiedClient>()?;
     module.add_class::<PyClientVerifier>()?;
     module.add_class::<PyServerVerifier>()?;
     module.add_class::<PyStore>()?;
+    module.add_class::<PyTrustAnchor>()?;
     module.add_class::<PolicyBuilder>()?;
+    module.add_function(pyo3::wrap_pyfunction_bound!(
+        authority_key_identifier_matches,
+        module
+    )?)?;
+    module.add_function(pyo3::wrap_pyfunction_bound!(chain_pem, module)?)?;
     module.add(
         "VerificationError",
         module.py().get_type_bound::<VerificationError>(),
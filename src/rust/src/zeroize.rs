@@ -0,0 +1,82 @@
+// This file is dual licensed under the terms of the Apache License, Version
+// 2.0, and the BSD License. See the LICENSE file in the root of this repository
+// for complete details.
+
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// Overwrites `buf` with zeros. Unlike `buf.fill(0)`, the writes are
+/// volatile, so the compiler can't elide them when `buf` is about to be
+/// freed.
+pub(crate) fn zeroize(buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        // SAFETY: `b` is a valid, aligned and exclusive reference.
+        unsafe { std::ptr::write_volatile(b, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A buffer of secret data (private key bytes, shared secrets, decrypted
+/// plaintexts) that is zeroed when it is dropped, so that the data doesn't
+/// linger in freed memory.
+///
+/// This only covers the buffer itself. Secrets that are copied into a Python
+/// `bytes` object live as long as that object does.
+pub(crate) struct Zeroizing<T: AsMut<[u8]>>(T);
+
+impl From<Vec<u8>> for Zeroizing<Vec<u8>> {
+    fn from(buf: Vec<u8>) -> Zeroizing<Vec<u8>> {
+        Zeroizing(buf)
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> Deref for Zeroizing<T> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl<T: AsMut<[u8]>> Drop for Zeroizing<T> {
+    fn drop(&mut self) {
+        zeroize(self.0.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{zeroize, Zeroizing};
+
+    #[test]
+    fn test_zeroize() {
+        let mut buf = vec![0xa5; 37];
+        zeroize(&mut buf);
+        assert_eq!(buf, vec![0; 37]);
+
+        zeroize(&mut []);
+    }
+
+    #[test]
+    fn test_zeroizing_deref() {
+        let mut buf = Zeroizing::from(vec![1, 2, 3]);
+        assert_eq!(&*buf, &[1, 2, 3]);
+        buf[0] = 4;
+        assert_eq!(&*buf, &[4, 2, 3]);
+    }
+
+    #[test]
+    fn test_zeroizing_drop() {
+        // Borrow the buffer, so that it can be inspected after the
+        // `Zeroizing` is dropped.
+        let mut buf = [0xa5; 16];
+        drop(Zeroizing(&mut buf[..]));
+        assert_eq!(buf, [0; 16]);
+    }
+}
@@ -8,6 +8,7 @@ from cryptography.exceptions import UnsupportedAlgorithm
 from cryptography.hazmat.primitives.ciphers.aead import (
     AESCCM,
     AESGCM,
+    AESGCMContext,
     AESOCB3,
     AESSIV,
     ChaCha20Poly1305,
@@ -52,6 +53,17 @@ def test_aesgcm_decrypt(benchmark):
     benchmark(aes.decrypt, b"\x00" * 12, ct, None)
 
 
+
+def test_aesgcm_context_encrypt(benchmark):
+    ctx = AESGCMContext(b"\x00" * 32)
+    benchmark(ctx.encrypt, b"\x00" * 12, b"hello world plaintext", None)
+
+
+def test_aesgcm_context_decrypt(benchmark):
+    ctx = AESGCMContext(b"\x00" * 32)
+    ct = ctx.encrypt(b"\x00" * 12, b"hello world plaintext", None)
+    benchmark(ctx.decrypt, b"\x00" * 12, ct, None)
+
 @pytest.mark.skipif(
     not _aead_supported(AESSIV),
     reason="Requires OpenSSL with AES-SIV support",
@@ -0,0 +1,32 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec, padding
+
+from ..hazmat.primitives.fixtures_ec import EC_KEY_SECP256R1
+from ..hazmat.primitives.fixtures_rsa import RSA_KEY_2048
+
+MESSAGES = [i.to_bytes(4, "big") for i in range(10000)]
+
+
+def test_rsa_sign_small_messages(benchmark):
+    key = RSA_KEY_2048.private_key(unsafe_skip_rsa_key_validation=True)
+
+    def bench():
+        for message in MESSAGES:
+            key.sign(message, padding.PKCS1v15(), hashes.SHA256())
+
+    benchmark(bench)
+
+
+def test_ecdsa_sign_small_messages(benchmark):
+    key = EC_KEY_SECP256R1.private_key()
+    algorithm = ec.ECDSA(hashes.SHA256())
+
+    def bench():
+        for message in MESSAGES:
+            key.sign(message, algorithm)
+
+    benchmark(bench)
@@ -9,8 +9,15 @@ import os
 import certifi
 
 from cryptography import x509
+from cryptography.hazmat.primitives.asymmetric import ec
 
 from ..utils import load_vectors_from_file
+from ..x509.verification.utils import (
+    VALIDATION_TIME,
+    make_ca,
+    make_leaf,
+    make_name,
+)
 
 
 def test_object_identifier_constructor(benchmark):
@@ -36,6 +43,16 @@ def test_load_der_certificate(benchmark):
     benchmark(x509.load_der_x509_certificate, cert_bytes)
 
 
+def test_load_der_certificate_view(benchmark):
+    cert_bytes = load_vectors_from_file(
+        os.path.join("x509", "PKITS_data", "certs", "GoodCACert.crt"),
+        loader=lambda pemfile: pemfile.read(),
+        mode="rb",
+    )
+
+    benchmark(x509.load_der_x509_certificate_view, cert_bytes)
+
+
 def test_load_pem_certificate(benchmark):
     cert_bytes = load_vectors_from_file(
         os.path.join("x509", "cryptography.io.pem"),
@@ -79,3 +96,32 @@ def test_verify_docs_python_org(benchmark, pytestconfig):
         verifier.verify(leaf, intermediates)
 
     benchmark(bench)
+
+
+def test_verify_large_intermediate_pool(benchmark):
+    root_key = ec.generate_private_key(ec.SECP256R1())
+    root = make_ca(make_name("root"), root_key)
+    store = x509.verification.Store([root])
+
+    decoy_key = ec.generate_private_key(ec.SECP256R1())
+    intermediates = [
+        make_ca(make_name(f"decoy {i}"), decoy_key, root.subject, root_key)
+        for i in range(1000)
+    ]
+    int_key = ec.generate_private_key(ec.SECP256R1())
+    intermediate = make_ca(
+        make_name("intermediate"), int_key, root.subject, root_key
+    )
+    intermediates.append(intermediate)
+    leaf = make_leaf("example.com", intermediate.subject, int_key)
+
+    def bench():
+        verifier = (
+            x509.verification.PolicyBuilder()
+            .store(store)
+            .time(VALIDATION_TIME)
+            .build_server_verifier(x509.DNSName("example.com"))
+        )
+        verifier.verify(leaf, intermediates)
+
+    benchmark(bench)
@@ -15,6 +15,7 @@ from cryptography.hazmat.primitives.ciphers.aead import (
     AESCCM,
     AESGCM,
     AESGCMSIV,
+    AESGCMContext,
     AESOCB3,
     AESSIV,
     ChaCha20Poly1305,
@@ -460,6 +461,72 @@ class TestAESGCM:
         with pytest.raises(ValueError):
             aesgcm.decrypt(b"\x00" * length, b"hi", None)
 
+    @pytest.mark.parametrize(
+        ("nonce", "ct", "tag"),
+        [
+            # Test Case 5 from the GCM specification, a 64-bit nonce.
+            (
+                b"cafebabefacedbad",
+                b"61353b4c2806934a777ff51fa22a4755699b2a714fcdc6f83766e5f9"
+                b"7b6c742373806900e49f24b22b097544d4896b424989b5e1ebac0f07"
+                b"c23f4598",
+                b"3612d2e79e3b0785561be14aaca2fccb",
+            ),
+            # A 128-bit nonce with the same key, plaintext and associated
+            # data, computed with an independent GHASH implementation.
+            (
+                b"cafebabefacedbaddecaf88800000001",
+                b"22f8a8257acd3bca86b959b432fb1b8a086a9dafd1ccebef870dc6bf"
+                b"6d4b5be81800f59c45417e68dede8bafaf8cf13c952918cec1f59fd6"
+                b"1816a5d1",
+                b"e3ab580174750379786871f23380ed0d",
+            ),
+        ],
+    )
+    def test_non_96_bit_nonces(self, nonce, ct, tag, backend):
+        if backend._fips_enabled:
+            # Red Hat disables non-96-bit IV support as part of its FIPS
+            # patches.
+            pytest.skip("Non-96-bit IVs unsupported in FIPS mode.")
+
+        key = binascii.unhexlify(b"feffe9928665731c6d6a8f9467308308")
+        pt = binascii.unhexlify(
+            b"d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d"
+            b"8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657"
+            b"ba637b39"
+        )
+        aad = binascii.unhexlify(b"feedfacedeadbeeffeedfacedeadbeefabaddad2")
+        nonce = binascii.unhexlify(nonce)
+        expected = binascii.unhexlify(ct + tag)
+
+        aesgcm = AESGCM(key)
+        assert aesgcm.encrypt(nonce, pt, aad) == expected
+        assert aesgcm.decrypt(nonce, expected, aad) == pt
+
+    def test_min_nonce_length(self, backend):
+        if backend._fips_enabled:
+            # Red Hat disables non-96-bit IV support as part of its FIPS
+            # patches.
+            pytest.skip("Non-96-bit IVs unsupported in FIPS mode.")
+
+        key = AESGCM.generate_key(128)
+        aesgcm = AESGCM(key, min_nonce_length=12)
+        with pytest.raises(ValueError, match="between 12 and 128"):
+            aesgcm.encrypt(b"\x00" * 8, b"hi", None)
+        with pytest.raises(ValueError, match="between 12 and 128"):
+            aesgcm.decrypt(b"\x00" * 8, b"hi" * 8, None)
+
+        for length in [12, 16]:
+            nonce = b"\x00" * length
+            ct = aesgcm.encrypt(nonce, b"hi", None)
+            assert aesgcm.decrypt(nonce, ct, None) == b"hi"
+
+    @pytest.mark.parametrize("min_nonce_length", [0, 7, 129])
+    def test_invalid_min_nonce_length(self, min_nonce_length, backend):
+        key = AESGCM.generate_key(128)
+        with pytest.raises(ValueError):
+            AESGCM(key, min_nonce_length=min_nonce_length)
+
     def test_bad_key(self, backend):
         with pytest.raises(TypeError):
             AESGCM(object())  # type:ignore[arg-type]
@@ -513,6 +580,102 @@ class TestAESGCM:
         assert computed_pt3 == pt
 
 
+
+class TestAESGCMContext:
+    def test_vectors(self, backend, subtests):
+        vectors = _load_gcm_vectors()
+        for vector in vectors:
+            with subtests.test():
+                nonce = binascii.unhexlify(vector["iv"])
+
+                if backend._fips_enabled and len(nonce) != 12:
+                    # Red Hat disables non-96-bit IV support as part of its
+                    # FIPS patches.
+                    pytest.skip("Non-96-bit IVs unsupported in FIPS mode.")
+
+                key = binascii.unhexlify(vector["key"])
+                aad = binascii.unhexlify(vector["aad"])
+                ct = binascii.unhexlify(vector["ct"])
+                pt = binascii.unhexlify(vector.get("pt", b""))
+                tag = binascii.unhexlify(vector["tag"])
+                ctx = AESGCMContext(key)
+                if vector.get("fail") is True:
+                    with pytest.raises(InvalidTag):
+                        ctx.decrypt(nonce, ct + tag, aad)
+                else:
+                    computed_ct = ctx.encrypt(nonce, pt, aad)
+                    assert computed_ct[:-16] == ct
+                    assert computed_ct[-16:] == tag
+                    computed_pt = ctx.decrypt(nonce, ct + tag, aad)
+                    assert computed_pt == pt
+
+    @pytest.mark.parametrize("key_size", [128, 192, 256])
+    def test_matches_aesgcm(self, key_size, backend):
+        key = AESGCM.generate_key(key_size)
+        aesgcm = AESGCM(key)
+        ctx = AESGCMContext(key)
+        # Reuse the same context for many messages of varying lengths.
+        for i in range(64):
+            nonce = os.urandom(12)
+            data = os.urandom(i * 7)
+            aad = os.urandom(i) if i % 2 else None
+            ct = ctx.encrypt(nonce, data, aad)
+            assert ct == aesgcm.encrypt(nonce, data, aad)
+            assert ctx.decrypt(nonce, ct, aad) == data
+            assert aesgcm.decrypt(nonce, ct, aad) == data
+
+    def test_usable_after_invalid_tag(self, backend):
+        key = AESGCM.generate_key(128)
+        ctx = AESGCMContext(key)
+        nonce = os.urandom(12)
+        ct = ctx.encrypt(nonce, b"some data", b"aad")
+        with pytest.raises(InvalidTag):
+            ctx.decrypt(nonce, ct[:-1] + bytes([ct[-1] ^ 1]), b"aad")
+        with pytest.raises(InvalidTag):
+            ctx.decrypt(nonce, b"short", b"aad")
+        assert ctx.decrypt(nonce, ct, b"aad") == b"some data"
+        nonce2 = os.urandom(12)
+        assert ctx.encrypt(nonce2, b"more", None) == AESGCM(key).encrypt(
+            nonce2, b"more", None
+        )
+
+    def test_min_nonce_length(self, backend):
+        key = AESGCM.generate_key(128)
+        ctx = AESGCMContext(key, min_nonce_length=12)
+        with pytest.raises(ValueError, match="between 12 and 128"):
+            ctx.encrypt(b"\x00" * 8, b"hi", None)
+        with pytest.raises(ValueError, match="between 12 and 128"):
+            ctx.decrypt(b"\x00" * 8, b"hi" * 8, None)
+        with pytest.raises(ValueError):
+            ctx.encrypt(b"\x00" * 129, b"hi", None)
+
+        for min_nonce_length in [0, 7, 129]:
+            with pytest.raises(ValueError):
+                AESGCMContext(key, min_nonce_length=min_nonce_length)
+
+    def test_bad_key(self, backend):
+        with pytest.raises(TypeError):
+            AESGCMContext(object())  # type:ignore[arg-type]
+
+        with pytest.raises(ValueError):
+            AESGCMContext(b"0" * 31)
+
+    def test_buffer_protocol(self, backend):
+        key = AESGCM.generate_key(128)
+        nonce = os.urandom(12)
+        ct = AESGCM(key).encrypt(nonce, b"encrypt me", b"additional")
+        ctx = AESGCMContext(bytearray(key))
+        assert (
+            ctx.encrypt(
+                memoryview(nonce), bytearray(b"encrypt me"), b"additional"
+            )
+            == ct
+        )
+        assert (
+            ctx.decrypt(bytearray(nonce), memoryview(ct), b"additional")
+            == b"encrypt me"
+        )
+
 @pytest.mark.skipif(
     _aead_supported(AESOCB3),
     reason="Requires OpenSSL without AESOCB3 support",
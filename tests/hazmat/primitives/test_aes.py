@@ -12,7 +12,7 @@ from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 from cryptography.hazmat.primitives.ciphers import algorithms, base, modes
 
 from ...doubles import DummyMode
-from ...utils import load_nist_vectors
+from ...utils import load_nist_vectors, load_vectors_from_file
 from .utils import _load_all_params, generate_encrypt_test
 
 
@@ -53,6 +53,27 @@ class TestAESModeXTS:
                 computed_pt = dec.update(ct) + dec.finalize()
                 assert computed_pt == pt
 
+    def test_xts_ieee1619_vectors(self, backend, subtests):
+        vectors = load_vectors_from_file(
+            os.path.join(
+                "ciphers", "AES", "XTS", "ieee1619", "xts-aes-128.txt"
+            ),
+            load_nist_vectors,
+        )
+        for vector in vectors:
+            with subtests.test():
+                key = binascii.unhexlify(vector["key"])
+                tweak = binascii.unhexlify(vector["i"])
+                pt = binascii.unhexlify(vector["pt"])
+                ct = binascii.unhexlify(vector["ct"])
+                cipher = base.Cipher(
+                    algorithms.AES(key), modes.XTS(tweak), backend
+                )
+                enc = cipher.encryptor()
+                assert enc.update(pt) + enc.finalize() == ct
+                dec = cipher.decryptor()
+                assert dec.update(ct) + dec.finalize() == pt
+
     def test_xts_too_short(self, backend):
         key = b"thirty_two_byte_keys_are_great!!"
         tweak = b"\x00" * 16
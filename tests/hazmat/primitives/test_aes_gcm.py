@@ -8,6 +8,7 @@ import os
 
 import pytest
 
+from cryptography.exceptions import AlreadyFinalized, InvalidTag
 from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 from cryptography.hazmat.primitives.ciphers import algorithms, base, modes
 
@@ -219,6 +220,113 @@ class TestAESModeGCM:
         decryptor.finalize_with_tag(tag)
         assert pt == payload
 
+    def test_buffered_decryptor(self, backend):
+        key = os.urandom(16)
+        iv = os.urandom(12)
+        payload = b"buffered plaintext" * 10
+
+        encryptor = base.Cipher(algorithms.AES(key), modes.GCM(iv)).encryptor()
+        encryptor.authenticate_additional_data(b"aad")
+        ct = encryptor.update(payload) + encryptor.finalize()
+        tag = encryptor.tag
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv)
+        ).buffered_decryptor()
+        decryptor.authenticate_additional_data(b"aad")
+        assert decryptor.update(ct[:50]) == b""
+        buf = bytearray(len(ct) + 15)
+        assert decryptor.update_into(ct[50:], buf) == 0
+        assert buf == bytearray(len(buf))
+        assert decryptor.finalize_with_tag(tag) == payload
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv, tag)
+        ).buffered_decryptor()
+        decryptor.authenticate_additional_data(b"aad")
+        assert decryptor.update(ct) == b""
+        assert decryptor.finalize() == payload
+        with pytest.raises(AlreadyFinalized):
+            decryptor.update(ct)
+
+    def test_buffered_decryptor_many_updates(self, backend):
+        key = os.urandom(16)
+        iv = os.urandom(12)
+        payload = os.urandom(1000)
+
+        encryptor = base.Cipher(algorithms.AES(key), modes.GCM(iv)).encryptor()
+        ct = encryptor.update(payload) + encryptor.finalize()
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv)
+        ).buffered_decryptor()
+        for i in range(0, len(ct), 7):
+            assert decryptor.update(ct[i : i + 7]) == b""
+        assert decryptor.finalize_with_tag(encryptor.tag) == payload
+
+    def test_buffered_decryptor_tampered_tag(self, backend):
+        key = os.urandom(16)
+        iv = os.urandom(12)
+        payload = b"buffered plaintext" * 10
+
+        encryptor = base.Cipher(algorithms.AES(key), modes.GCM(iv)).encryptor()
+        ct = encryptor.update(payload) + encryptor.finalize()
+        bad_tag = bytes([encryptor.tag[0] ^ 1]) + encryptor.tag[1:]
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv)
+        ).buffered_decryptor()
+        assert decryptor.update(ct) == b""
+        with pytest.raises(InvalidTag):
+            decryptor.finalize_with_tag(bad_tag)
+        # The buffered plaintext was discarded along with the context.
+        with pytest.raises(AlreadyFinalized):
+            decryptor.finalize_with_tag(encryptor.tag)
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv, bad_tag)
+        ).buffered_decryptor()
+        assert decryptor.update(ct) == b""
+        with pytest.raises(InvalidTag):
+            decryptor.finalize()
+
+    def test_buffered_decryptor_requires_aead_mode(self, backend):
+        cipher = base.Cipher(
+            algorithms.AES(b"\x00" * 16), modes.CBC(b"\x00" * 16)
+        )
+        with pytest.raises(TypeError):
+            cipher.buffered_decryptor()  # type: ignore[misc]
+
+    def test_unsafe_streaming_decryptor(self, backend):
+        key = os.urandom(16)
+        iv = os.urandom(12)
+        payload = b"streamed plaintext" * 10
+
+        encryptor = base.Cipher(algorithms.AES(key), modes.GCM(iv)).encryptor()
+        ct = encryptor.update(payload) + encryptor.finalize()
+        bad_tag = bytes([encryptor.tag[0] ^ 1]) + encryptor.tag[1:]
+
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv)
+        ).unsafe_streaming_decryptor()
+        assert decryptor.update(ct) == payload
+        assert decryptor.finalize_with_tag(encryptor.tag) == b""
+
+        # The plaintext is released before the tag is checked.
+        decryptor = base.Cipher(
+            algorithms.AES(key), modes.GCM(iv)
+        ).unsafe_streaming_decryptor()
+        assert decryptor.update(ct) == payload
+        with pytest.raises(InvalidTag):
+            decryptor.finalize_with_tag(bad_tag)
+
+    def test_unsafe_streaming_decryptor_requires_aead_mode(self, backend):
+        cipher = base.Cipher(
+            algorithms.AES(b"\x00" * 16), modes.CBC(b"\x00" * 16)
+        )
+        with pytest.raises(TypeError):
+            cipher.unsafe_streaming_decryptor()  # type: ignore[misc]
+
     @pytest.mark.parametrize("alg", [algorithms.AES128, algorithms.AES256])
     def test_alternate_aes_classes(self, alg, backend):
         data = bytearray(b"sixteen_byte_msg")
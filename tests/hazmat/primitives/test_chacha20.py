@@ -26,7 +26,11 @@ class TestChaCha20:
         "vector",
         _load_all_params(
             os.path.join("ciphers", "ChaCha20"),
-            ["counter-overflow.txt", "rfc7539.txt"],
+            [
+                "counter-overflow.txt",
+                "rfc7539.txt",
+                "rfc7539-block-function.txt",
+            ],
             load_nist_vectors,
         ),
     )
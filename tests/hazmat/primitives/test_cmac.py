@@ -212,3 +212,9 @@ class TestCMAC:
         assert cmac.finalize() == binascii.unhexlify(
             b"a21e6e647bfeaf5ca0a5e1bcd957dfad"
         )
+
+        cmac = CMAC(AES(key), backend)
+        cmac.update(b"6bc1bee22e409f96e93d7e117393172a")
+        cmac.verify(
+            bytearray(binascii.unhexlify(b"a21e6e647bfeaf5ca0a5e1bcd957dfad"))
+        )
@@ -874,6 +874,47 @@ class TestDHParameterSerialization:
         )
         assert serialized == param_bytes
 
+    def test_load_pkcs3_private_value_length(self, backend):
+        param_bytes = load_vectors_from_file(
+            os.path.join("asymmetric", "DH", "dhp_private_value_length.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        parameters = serialization.load_der_parameters(param_bytes, backend)
+        assert parameters.parameter_numbers().q is None
+
+        expected = load_vectors_from_file(
+            os.path.join("asymmetric", "DH", "dhp.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        assert (
+            parameters.parameter_bytes(
+                serialization.Encoding.DER,
+                serialization.ParameterFormat.PKCS3,
+            )
+            == expected
+        )
+
+    def test_load_x942_validation_params(self, backend):
+        _skip_dhx_unsupported(backend, True)
+        param_bytes = load_vectors_from_file(
+            os.path.join("asymmetric", "DH", "dhp_x942_validation_params.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        parameters = serialization.load_pem_parameters(param_bytes, backend)
+        parameter_numbers = parameters.parameter_numbers()
+        assert parameter_numbers.q == int(
+            "d720c5fda368caeaa33c3e85709fb4f9fd908207", 16
+        )
+
+        serialized = parameters.parameter_bytes(
+            serialization.Encoding.DER, serialization.ParameterFormat.PKCS3
+        )
+        reloaded = serialization.load_der_parameters(serialized, backend)
+        assert reloaded.parameter_numbers() == parameter_numbers
+
     @pytest.mark.parametrize(
         ("param_path", "loader_func", "vec_path"),
         [
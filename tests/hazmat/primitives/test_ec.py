@@ -5,6 +5,7 @@
 import binascii
 import copy
 import itertools
+import mmap
 import os
 import textwrap
 import typing
@@ -22,6 +23,7 @@ from cryptography.hazmat.primitives.asymmetric.ec import (
 )
 from cryptography.hazmat.primitives.asymmetric.utils import (
     Prehashed,
+    decode_dss_signature,
     encode_dss_signature,
 )
 
@@ -36,6 +38,7 @@ from ...utils import (
     raises_unsupported_algorithm,
 )
 from .fixtures_ec import EC_KEY_SECP384R1
+from .fixtures_rsa import RSA_KEY_2048
 from .utils import skip_fips_traditional_openssl
 
 _HASH_TYPES: typing.Dict[str, typing.Type[hashes.HashAlgorithm]] = {
@@ -215,6 +218,53 @@ def test_ec_key_key_size(backend):
     assert key.public_key().key_size == 256
 
 
+@pytest.mark.parametrize(
+    ("curve", "oid"),
+    [
+        (ec.BrainpoolP256R1(), ec.EllipticCurveOID.BRAINPOOLP256R1),
+        (ec.BrainpoolP320R1(), ec.EllipticCurveOID.BRAINPOOLP320R1),
+        (ec.BrainpoolP384R1(), ec.EllipticCurveOID.BRAINPOOLP384R1),
+        (ec.BrainpoolP512R1(), ec.EllipticCurveOID.BRAINPOOLP512R1),
+    ],
+)
+def test_brainpool_curves(backend, curve, oid):
+    _skip_curve_unsupported(backend, curve)
+    assert ec.get_curve_for_oid(oid) is type(curve)
+
+    key = ec.generate_private_key(curve, backend)
+    assert key.key_size == curve.key_size
+    signature = key.sign(b"message", ec.ECDSA(hashes.SHA256()))
+    key.public_key().verify(
+        signature, b"message", ec.ECDSA(hashes.SHA256())
+    )
+
+    loaded_key = serialization.load_der_private_key(
+        key.private_bytes(
+            serialization.Encoding.DER,
+            serialization.PrivateFormat.PKCS8,
+            serialization.NoEncryption(),
+        ),
+        None,
+        backend,
+    )
+    assert isinstance(loaded_key, ec.EllipticCurvePrivateKey)
+    assert type(loaded_key.curve) is type(curve)
+    assert loaded_key.private_numbers() == key.private_numbers()
+
+    loaded_public_key = serialization.load_der_public_key(
+        key.public_key().public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        ),
+        backend,
+    )
+    assert isinstance(loaded_public_key, ec.EllipticCurvePublicKey)
+    assert type(loaded_public_key.curve) is type(curve)
+    loaded_public_key.verify(
+        signature, b"message", ec.ECDSA(hashes.SHA256())
+    )
+
+
 def test_deprecated_generate_private_key_with_curve_class(backend):
     # This test verifies that if you pass a curve _class_ instead of instance,
     # you get a warning and then `key.curve` is still an instance.
@@ -521,6 +571,53 @@ class TestECDSAVectors:
         with pytest.raises(exceptions.UnsupportedAlgorithm):
             ec.ECDSA(hashes.SHA256(), deterministic_signing=True)
 
+    def test_low_s_default(self):
+        assert ec.ECDSA(hashes.SHA256()).low_s is False
+        assert ec.ECDSA(hashes.SHA256(), low_s=True).low_s is True
+
+    @pytest.mark.parametrize(
+        ("curve", "order"),
+        [
+            (
+                ec.SECP256R1(),
+                int(
+                    "FFFFFFFF00000000FFFFFFFFFFFFFFFF"
+                    "BCE6FAADA7179E84F3B9CAC2FC632551",
+                    16,
+                ),
+            ),
+            (
+                ec.SECP384R1(),
+                int(
+                    "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"
+                    "C7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973",
+                    16,
+                ),
+            ),
+            (
+                ec.SECP256K1(),
+                int(
+                    "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE"
+                    "BAAEDCE6AF48A03BBFD25E8CD0364141",
+                    16,
+                ),
+            ),
+        ],
+    )
+    def test_low_s_signing(self, backend, curve, order):
+        _skip_curve_unsupported(backend, curve)
+        key = ec.generate_private_key(curve)
+        algorithm = ec.ECDSA(hashes.SHA256(), low_s=True)
+        # Half of the signatures would have a high S value without
+        # normalization, so this exercises both branches.
+        for i in range(64):
+            data = b"message %d" % i
+            signature = key.sign(data, algorithm)
+            _, s = decode_dss_signature(signature)
+            assert 0 < s <= order // 2
+            key.public_key().verify(signature, data, algorithm)
+            key.public_key().verify(signature, data, ec.ECDSA(hashes.SHA256()))
+
     def test_deterministic_nonce(self, backend, subtests):
         if not backend.ecdsa_deterministic_supported():
             pytest.skip(
@@ -612,6 +709,23 @@ class TestECDSAVectors:
         public_key = private_key.public_key()
         public_key.verify(bytearray(signature), message, algorithm)
 
+    def test_verify_large_memoryview(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP256R1())
+        algorithm = ec.ECDSA(hashes.SHA256())
+        private_key = ec.generate_private_key(ec.SECP256R1(), backend)
+        public_key = private_key.public_key()
+        # Signed payloads are commonly mmapped files, which are verified
+        # through the buffer protocol without being copied into bytes.
+        with mmap.mmap(-1, 16 * 1024 * 1024) as m:
+            with memoryview(m) as message:
+                message[-5:] = b"hello"
+                signature = private_key.sign(message, algorithm)
+                public_key.verify(memoryview(signature), message, algorithm)
+
+                message[-5:] = b"world"
+                with pytest.raises(exceptions.InvalidSignature):
+                    public_key.verify(signature, message, algorithm)
+
     def test_sign_prehashed(self, backend):
         _skip_curve_unsupported(backend, ec.SECP256R1())
         message = b"one little message"
@@ -624,6 +738,24 @@ class TestECDSAVectors:
         public_key = private_key.public_key()
         public_key.verify(signature, message, ec.ECDSA(hashes.SHA256()))
 
+    def test_sign_matches_prehashed(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP256R1())
+        if not backend.ecdsa_deterministic_supported():
+            pytest.skip("ECDSA deterministic signing is not supported")
+        message = b"one little message"
+        h = hashes.Hash(hashes.SHA256(), backend)
+        h.update(message)
+        data = h.finalize()
+        private_key = ec.generate_private_key(ec.SECP256R1(), backend)
+        signature = private_key.sign(
+            message, ec.ECDSA(hashes.SHA256(), deterministic_signing=True)
+        )
+        prehashed_signature = private_key.sign(
+            data,
+            ec.ECDSA(Prehashed(hashes.SHA256()), deterministic_signing=True),
+        )
+        assert signature == prehashed_signature
+
     def test_sign_prehashed_digest_mismatch(self, backend):
         _skip_curve_unsupported(backend, ec.SECP256R1())
         message = b"one little message"
@@ -1213,6 +1345,130 @@ class TestEllipticCurvePEMPublicKeySerialization:
                 serialization.Encoding.PEM, serialization.PublicFormat.PKCS1
             )
 
+    @pytest.mark.supported(
+        only_if=lambda backend: (
+            not rust_openssl.CRYPTOGRAPHY_IS_BORINGSSL
+            and not rust_openssl.CRYPTOGRAPHY_IS_LIBRESSL
+        ),
+        skip_message="Requires OpenSSL for explicit curve parameters",
+    )
+    @pytest.mark.parametrize(
+        "curve", [ec.SECP256R1(), ec.SECP384R1(), ec.SECP521R1()]
+    )
+    @pytest.mark.parametrize(
+        ("encoding", "loader_func"),
+        [
+            (serialization.Encoding.PEM, serialization.load_pem_public_key),
+            (serialization.Encoding.DER, serialization.load_der_public_key),
+        ],
+    )
+    def test_public_bytes_explicit_parameters(
+        self, curve, encoding, loader_func, backend
+    ):
+        _skip_curve_unsupported(backend, curve)
+        key = ec.generate_private_key(curve, backend).public_key()
+        named = key.public_bytes(
+            encoding, serialization.PublicFormat.SubjectPublicKeyInfo
+        )
+        explicit = key.public_bytes(
+            encoding,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+            explicit_parameters=True,
+        )
+        assert explicit != named
+
+        # The usual loaders still reject explicit parameters.
+        with pytest.raises(ValueError, match="explicit parameters"):
+            loader_func(explicit, backend)
+
+        loaded = ec.load_public_key_with_explicit_parameters(
+            explicit, encoding
+        )
+        assert isinstance(loaded, ec.EllipticCurvePublicKey)
+        assert isinstance(loaded.curve, type(curve))
+        assert loaded == key
+        # Re-serializing uses the named curve again.
+        assert (
+            loaded.public_bytes(
+                encoding, serialization.PublicFormat.SubjectPublicKeyInfo
+            )
+            == named
+        )
+        # Keys on a named curve are accepted too.
+        loaded = ec.load_public_key_with_explicit_parameters(named, encoding)
+        assert loaded == key
+
+    @pytest.mark.supported(
+        only_if=lambda backend: (
+            not rust_openssl.CRYPTOGRAPHY_IS_BORINGSSL
+            and not rust_openssl.CRYPTOGRAPHY_IS_LIBRESSL
+        ),
+        skip_message="Requires OpenSSL for explicit curve parameters",
+    )
+    def test_load_explicit_parameters_modified_curve(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP256R1())
+        key = ec.generate_private_key(ec.SECP256R1(), backend).public_key()
+        explicit = key.public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+            explicit_parameters=True,
+        )
+        # Change the curve's b coefficient so that the parameters no longer
+        # describe P-256.
+        b = binascii.unhexlify(
+            "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"
+        )
+        assert explicit.count(b) == 1
+        modified = explicit.replace(b, b[:-1] + b"\x4c")
+        with pytest.raises(ValueError):
+            ec.load_public_key_with_explicit_parameters(
+                modified, serialization.Encoding.DER
+            )
+
+    def test_load_explicit_parameters_invalid(self, backend):
+        key = RSA_KEY_2048.private_key(
+            unsafe_skip_rsa_key_validation=True
+        ).public_key()
+        der = key.public_bytes(
+            serialization.Encoding.DER,
+            serialization.PublicFormat.SubjectPublicKeyInfo,
+        )
+        with pytest.raises(ValueError):
+            ec.load_public_key_with_explicit_parameters(
+                der, serialization.Encoding.DER
+            )
+        with pytest.raises(ValueError):
+            ec.load_public_key_with_explicit_parameters(
+                der, serialization.Encoding.X962
+            )
+        with pytest.raises(ValueError):
+            ec.load_public_key_with_explicit_parameters(
+                b"-----BEGIN RSA PUBLIC KEY-----\n"
+                b"-----END RSA PUBLIC KEY-----",
+                serialization.Encoding.PEM,
+            )
+
+    @pytest.mark.parametrize(
+        ("encoding", "fmt"),
+        [
+            (
+                serialization.Encoding.X962,
+                serialization.PublicFormat.UncompressedPoint,
+            ),
+            (
+                serialization.Encoding.OpenSSH,
+                serialization.PublicFormat.OpenSSH,
+            ),
+        ],
+    )
+    def test_public_bytes_explicit_parameters_invalid_format(
+        self, encoding, fmt, backend
+    ):
+        _skip_curve_unsupported(backend, ec.SECP256R1())
+        key = ec.generate_private_key(ec.SECP256R1(), backend).public_key()
+        with pytest.raises(ValueError):
+            key.public_bytes(encoding, fmt, explicit_parameters=True)
+
     @pytest.mark.parametrize(
         "vector",
         load_vectors_from_file(
@@ -1242,6 +1498,41 @@ class TestEllipticCurvePEMPublicKeySerialization:
                 ec.SECP256R1(), uncompressed_point
             )
 
+    def test_small_subgroup_point(self, backend):
+        _skip_curve_unsupported(backend, ec.SECT163K1())
+        # sect163k1 has a cofactor of 2, and (0, 1) is the point of order 2:
+        # it is on the curve, but not in the prime order subgroup.
+        point = b"\x04" + (0).to_bytes(21, "big") + (1).to_bytes(21, "big")
+        with pytest.raises(ValueError, match="subgroup"):
+            ec.EllipticCurvePublicKey.from_encoded_point(ec.SECT163K1(), point)
+        with pytest.raises(ValueError, match="subgroup"):
+            ec.EllipticCurvePublicNumbers(0, 1, ec.SECT163K1()).public_key()
+
+        spki = bytearray(
+            ec.generate_private_key(ec.SECT163K1())
+            .public_key()
+            .public_bytes(
+                serialization.Encoding.DER,
+                serialization.PublicFormat.SubjectPublicKeyInfo,
+            )
+        )
+        spki[-len(point) :] = point
+        with pytest.raises(ValueError):
+            serialization.load_der_public_key(bytes(spki))
+
+    def test_from_encoded_point_with_cofactor(self, backend):
+        _skip_curve_unsupported(backend, ec.SECT163K1())
+        key = ec.generate_private_key(ec.SECT163K1()).public_key()
+        point = key.public_bytes(
+            serialization.Encoding.X962,
+            serialization.PublicFormat.UncompressedPoint,
+        )
+        assert (
+            ec.EllipticCurvePublicKey.from_encoded_point(ec.SECT163K1(), point)
+            == key
+        )
+        assert key.public_numbers().public_key() == key
+
     def test_from_encoded_point_uncompressed(self):
         uncompressed_point = binascii.unhexlify(
             "047399336a9edf2197c2f8eb3d39aed9c34a66e45d918a07dc7684c42c9b37ac"
@@ -4,6 +4,7 @@
 
 
 import binascii
+import os
 
 import pytest
 
@@ -164,6 +165,56 @@ def test_buffer_protocol_hash(backend):
     )
 
 
+class TestHashFile:
+    @pytest.mark.parametrize(
+        "algorithm",
+        [hashes.SHA256(), hashes.SHA512(), hashes.SHAKE128(digest_size=64)],
+    )
+    def test_large_file(self, backend, algorithm, tmp_path):
+        if not backend.hash_supported(algorithm):
+            pytest.skip(f"{algorithm.name} is not supported")
+        path = tmp_path / "data"
+        # Larger than, and not a multiple of, the internal read buffer.
+        data = os.urandom(1024 * 1024 + 17)
+        path.write_bytes(data)
+
+        h = hashes.Hash(algorithm)
+        with path.open("rb") as f:
+            for chunk in iter(lambda: f.read(8192), b""):
+                h.update(chunk)
+        expected = h.finalize()
+
+        assert hashes.hash_file(algorithm, path) == expected
+        assert hashes.hash_file(algorithm, str(path)) == expected
+
+    def test_empty_file(self, tmp_path):
+        path = tmp_path / "empty"
+        path.write_bytes(b"")
+        assert hashes.hash_file(hashes.SHA256(), path) == binascii.unhexlify(
+            b"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        )
+
+    def test_missing_file(self, tmp_path):
+        with pytest.raises(FileNotFoundError):
+            hashes.hash_file(hashes.SHA256(), tmp_path / "missing")
+
+    def test_directory(self, tmp_path):
+        with pytest.raises(OSError):
+            hashes.hash_file(hashes.SHA256(), tmp_path)
+
+    def test_unsupported_algorithm(self, tmp_path):
+        path = tmp_path / "data"
+        path.write_bytes(b"abc")
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_HASH):
+            hashes.hash_file(DummyHashAlgorithm(), path)
+
+    def test_not_a_hash_algorithm(self, tmp_path):
+        with pytest.raises(TypeError):
+            hashes.hash_file(
+                object(),  # type: ignore[arg-type]
+                tmp_path / "data",
+            )
+
 class TestSHAKE:
     @pytest.mark.parametrize("xof", [hashes.SHAKE128, hashes.SHAKE256])
     def test_invalid_digest_type(self, xof):
@@ -179,6 +230,20 @@ class TestSHAKE:
             xof(digest_size=0)
 
 
+class TestSHA3:
+    @pytest.mark.parametrize(
+        ("algorithm", "block_size"),
+        [
+            (hashes.SHA3_224(), 144),
+            (hashes.SHA3_256(), 136),
+            (hashes.SHA3_384(), 104),
+            (hashes.SHA3_512(), 72),
+        ],
+    )
+    def test_block_size(self, algorithm, block_size):
+        assert algorithm.block_size == block_size
+
+
 @pytest.mark.supported(
     only_if=lambda backend: backend.hash_supported(hashes.SM3()),
     skip_message="Does not support SM3",
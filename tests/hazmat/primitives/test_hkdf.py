@@ -10,7 +10,14 @@ import pytest
 
 from cryptography.exceptions import AlreadyFinalized, InvalidKey
 from cryptography.hazmat.primitives import hashes
-from cryptography.hazmat.primitives.kdf.hkdf import HKDF, HKDFExpand
+from cryptography.hazmat.primitives.asymmetric import x25519
+from cryptography.hazmat.primitives.kdf.hkdf import (
+    HKDF,
+    HKDFExpand,
+    hkdf_expand_label,
+    key_confirmation_tag,
+    verify_key_confirmation_tag,
+)
 
 from ...utils import load_nist_vectors, load_vectors_from_file
 
@@ -207,3 +214,193 @@ class TestHKDFExpand:
 
         with pytest.raises(TypeError):
             hkdf.derive("first")  # type: ignore[arg-type]
+
+
+class TestHKDFExpandLabel:
+    # The key schedule from the "Simple 1-RTT Handshake" trace in RFC 8448,
+    # section 3.
+    def test_derive_secret(self, backend):
+        early_secret = binascii.unhexlify(
+            b"33ad0a1c607ec03b09e6cd9893680ce210adf300aa1f2660e1b22e10f170f92a"
+        )
+        empty_hash = hashes.Hash(hashes.SHA256())
+        derived = hkdf_expand_label(
+            hashes.SHA256(),
+            early_secret,
+            b"derived",
+            empty_hash.finalize(),
+            32,
+        )
+        assert derived == binascii.unhexlify(
+            b"6f2615a108c702c5678f54fc9dbab69716c076189c48250cebeac3576c3611ba"
+        )
+
+    @pytest.mark.parametrize(
+        ("label", "secret"),
+        [
+            (
+                b"c hs traffic",
+                b"b3eddb126e067f35a780b3abf45e2d8f"
+                b"3b1a950738f52e9600746a0e27a55a21",
+            ),
+            (
+                b"s hs traffic",
+                b"b67b7d690cc16c4e75e54213cb2d37b4"
+                b"e9c912bcded9105d42befd59d391ad38",
+            ),
+        ],
+    )
+    def test_handshake_traffic_secrets(self, label, secret, backend):
+        handshake_secret = binascii.unhexlify(
+            b"1dc826e93606aa6fdc0aadc12f741b01046aa6b99f691ed221a9f0ca043fbeac"
+        )
+        transcript_hash = binascii.unhexlify(
+            b"860c06edc07858ee8e78f0e7428c58edd6b43f2ca3e6e95f02ed063cf0e1cad8"
+        )
+        assert hkdf_expand_label(
+            hashes.SHA256(), handshake_secret, label, transcript_hash, 32
+        ) == binascii.unhexlify(secret)
+
+    def test_traffic_key_and_iv(self, backend):
+        server_handshake_traffic_secret = bytearray(
+            binascii.unhexlify(
+                b"b67b7d690cc16c4e75e54213cb2d37b4"
+                b"e9c912bcded9105d42befd59d391ad38"
+            )
+        )
+        key = hkdf_expand_label(
+            hashes.SHA256(), server_handshake_traffic_secret, b"key", b"", 16
+        )
+        iv = hkdf_expand_label(
+            hashes.SHA256(), server_handshake_traffic_secret, b"iv", b"", 12
+        )
+        assert key == binascii.unhexlify(b"3fce516009c21727d0f2e4e86ee403bc")
+        assert iv == binascii.unhexlify(b"5d313eb2671276ee13000b30")
+
+    def test_invalid_lengths(self, backend):
+        secret = b"\x00" * 32
+        with pytest.raises(ValueError):
+            hkdf_expand_label(hashes.SHA256(), secret, b"a" * 250, b"", 32)
+        with pytest.raises(ValueError):
+            hkdf_expand_label(hashes.SHA256(), secret, b"key", b"a" * 256, 32)
+        with pytest.raises(ValueError):
+            hkdf_expand_label(hashes.SHA256(), secret, b"key", b"", 0)
+        with pytest.raises(ValueError):
+            hkdf_expand_label(hashes.SHA512(), secret, b"key", b"", 0x10000)
+        with pytest.raises(ValueError):
+            hkdf_expand_label(hashes.SHA256(), secret, b"key", b"", 255 * 33)
+
+    def test_unicode_typeerror(self, backend):
+        secret = b"\x00" * 32
+        with pytest.raises(TypeError):
+            hkdf_expand_label(
+                hashes.SHA256(),
+                secret,
+                "key",  # type: ignore[arg-type]
+                b"",
+                16,
+            )
+        with pytest.raises(TypeError):
+            hkdf_expand_label(
+                hashes.SHA256(),
+                secret,
+                b"key",
+                "",  # type: ignore[arg-type]
+                16,
+            )
+        with pytest.raises(TypeError):
+            hkdf_expand_label(
+                hashes.SHA256(),
+                "secret",  # type: ignore[arg-type]
+                b"key",
+                b"",
+                16,
+            )
+
+
+class TestKeyConfirmationTag:
+    def test_tag(self, backend):
+        tag = key_confirmation_tag(
+            hashes.SHA256(), b"\x0b" * 22, b"salt", b"KC_1_U"
+        )
+        assert tag == binascii.unhexlify(
+            b"d4472b46d3114de821aa33eb4d9fe2446ebd53c2a05e8c656605ffc3a737dcd6"
+        )
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.x25519_supported(),
+        skip_message="Requires OpenSSL with X25519 support",
+    )
+    def test_matching_derived_keys(self, backend):
+        alice = x25519.X25519PrivateKey.generate()
+        bob = x25519.X25519PrivateKey.generate()
+        alice_secret = alice.exchange(bob.public_key())
+        bob_secret = bob.exchange(alice.public_key())
+
+        tag = key_confirmation_tag(
+            hashes.SHA256(), alice_secret, None, b"KC_1_U alice bob"
+        )
+        verify_key_confirmation_tag(
+            hashes.SHA256(), bob_secret, None, b"KC_1_U alice bob", tag
+        )
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.x25519_supported(),
+        skip_message="Requires OpenSSL with X25519 support",
+    )
+    def test_mismatched_derived_keys(self, backend):
+        alice = x25519.X25519PrivateKey.generate()
+        bob = x25519.X25519PrivateKey.generate()
+        mallory = x25519.X25519PrivateKey.generate()
+        alice_secret = alice.exchange(mallory.public_key())
+        bob_secret = bob.exchange(alice.public_key())
+
+        tag = key_confirmation_tag(
+            hashes.SHA256(), alice_secret, None, b"KC_1_U alice bob"
+        )
+        with pytest.raises(InvalidKey):
+            verify_key_confirmation_tag(
+                hashes.SHA256(), bob_secret, None, b"KC_1_U alice bob", tag
+            )
+
+    def test_mismatched_mac_data(self, backend):
+        secret = os.urandom(32)
+        tag = key_confirmation_tag(hashes.SHA256(), secret, None, b"KC_1_U")
+        with pytest.raises(InvalidKey):
+            verify_key_confirmation_tag(
+                hashes.SHA256(), secret, None, b"KC_1_V", tag
+            )
+
+    def test_mismatched_salt(self, backend):
+        secret = os.urandom(32)
+        tag = key_confirmation_tag(hashes.SHA256(), secret, b"a", b"KC_1_U")
+        with pytest.raises(InvalidKey):
+            verify_key_confirmation_tag(
+                hashes.SHA256(), secret, b"b", b"KC_1_U", tag
+            )
+
+    def test_truncated_tag(self, backend):
+        secret = os.urandom(32)
+        tag = key_confirmation_tag(hashes.SHA256(), secret, None, b"KC_1_U")
+        with pytest.raises(InvalidKey):
+            verify_key_confirmation_tag(
+                hashes.SHA256(), secret, None, b"KC_1_U", tag[:16]
+            )
+
+    def test_unicode_typeerror(self, backend):
+        secret = os.urandom(32)
+        with pytest.raises(TypeError):
+            key_confirmation_tag(
+                hashes.SHA256(),
+                secret,
+                None,
+                "KC_1_U",  # type: ignore[arg-type]
+            )
+        with pytest.raises(TypeError):
+            verify_key_confirmation_tag(
+                hashes.SHA256(),
+                secret,
+                None,
+                b"KC_1_U",
+                "tag",  # type: ignore[arg-type]
+            )
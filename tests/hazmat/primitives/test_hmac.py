@@ -85,6 +85,7 @@ class TestHMAC:
 
         with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_HASH):
             hmac.HMAC(b"key", hashes.SHAKE256(digest_size=256), backend)
+        assert not backend.hmac_supported(hashes.SHAKE256(digest_size=256))
 
     def test_buffer_protocol(self, backend):
         key = bytearray(b"2b7e151628aed2a6abf7158809cf4f3c")
@@ -94,6 +95,13 @@ class TestHMAC:
             b"a1bf7169c56a501c6585190ff4f07cad6e492a3ee187c0372614fb444b9fc3f0"
         )
 
+    def test_verify_buffer_protocol(self, backend):
+        h = hmac.HMAC(b"", hashes.SHA1(), backend=backend)
+        digest = h.finalize()
+
+        h = hmac.HMAC(b"", hashes.SHA1(), backend=backend)
+        h.verify(memoryview(bytearray(digest)))
+
     def test_algorithm(self):
         alg = hashes.SHA256()
         h = hmac.HMAC(b"123456", alg)
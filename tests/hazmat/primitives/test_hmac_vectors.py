@@ -91,6 +91,58 @@ class TestHMACSHA512:
     )
 
 
+@pytest.mark.supported(
+    only_if=lambda backend: backend.hmac_supported(hashes.SHA3_224()),
+    skip_message="Does not support SHA3_224",
+)
+class TestHMACSHA3_224:  # noqa: N801
+    test_hmac_sha3_224 = generate_hmac_test(
+        load_hash_vectors,
+        "HMAC",
+        ["hmac-sha3-224.txt"],
+        hashes.SHA3_224(),
+    )
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.hmac_supported(hashes.SHA3_256()),
+    skip_message="Does not support SHA3_256",
+)
+class TestHMACSHA3_256:  # noqa: N801
+    test_hmac_sha3_256 = generate_hmac_test(
+        load_hash_vectors,
+        "HMAC",
+        ["hmac-sha3-256.txt"],
+        hashes.SHA3_256(),
+    )
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.hmac_supported(hashes.SHA3_384()),
+    skip_message="Does not support SHA3_384",
+)
+class TestHMACSHA3_384:  # noqa: N801
+    test_hmac_sha3_384 = generate_hmac_test(
+        load_hash_vectors,
+        "HMAC",
+        ["hmac-sha3-384.txt"],
+        hashes.SHA3_384(),
+    )
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.hmac_supported(hashes.SHA3_512()),
+    skip_message="Does not support SHA3_512",
+)
+class TestHMACSHA3_512:  # noqa: N801
+    test_hmac_sha3_512 = generate_hmac_test(
+        load_hash_vectors,
+        "HMAC",
+        ["hmac-sha3-512.txt"],
+        hashes.SHA3_512(),
+    )
+
+
 @pytest.mark.supported(
     only_if=lambda backend: backend.hmac_supported(
         hashes.BLAKE2b(digest_size=64)
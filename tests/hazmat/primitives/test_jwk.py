@@ -0,0 +1,273 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+
+import json
+import os
+
+import pytest
+
+from cryptography.exceptions import _Reasons
+from cryptography.hazmat.primitives.asymmetric import (
+    dsa,
+    ec,
+    ed448,
+    ed25519,
+    rsa,
+    x448,
+    x25519,
+)
+from cryptography.hazmat.primitives.serialization import (
+    load_jwk_private_key,
+    load_jwk_public_key,
+    serialize_jwk_private_key,
+    serialize_jwk_public_key,
+)
+
+from ...utils import load_vectors_from_file, raises_unsupported_algorithm
+from .test_ec import _skip_curve_unsupported
+
+_RSA_MEMBERS = ("kty", "n", "e", "d", "p", "q", "dp", "dq", "qi")
+_RSA_PUBLIC_MEMBERS = ("kty", "n", "e")
+
+
+def _load_jwk(*path):
+    return load_vectors_from_file(
+        os.path.join("asymmetric", "JWK", *path), json.load
+    )
+
+
+def _rfc7517_key(kty):
+    keys = _load_jwk("rfc7517-private-keys.json")["keys"]
+    return next(key for key in keys if key["kty"] == kty)
+
+
+def _members(jwk, members):
+    return {member: jwk[member] for member in members}
+
+
+class TestRFC7517Keys:
+    def test_ec_private_key(self, backend):
+        jwk = _rfc7517_key("EC")
+        key = load_jwk_private_key(jwk)
+        assert isinstance(key, ec.EllipticCurvePrivateKey)
+        assert isinstance(key.curve, ec.SECP256R1)
+        assert serialize_jwk_private_key(key) == _members(
+            jwk, ("kty", "crv", "x", "y", "d")
+        )
+
+    def test_ec_public_key(self, backend):
+        jwk = _rfc7517_key("EC")
+        del jwk["d"]
+        key = load_jwk_public_key(jwk)
+        assert isinstance(key, ec.EllipticCurvePublicKey)
+        assert serialize_jwk_public_key(key) == _members(
+            jwk, ("kty", "crv", "x", "y")
+        )
+        assert load_jwk_private_key(_rfc7517_key("EC")).public_key() == key
+
+    def test_rsa_private_key(self, backend):
+        jwk = _rfc7517_key("RSA")
+        key = load_jwk_private_key(jwk)
+        assert isinstance(key, rsa.RSAPrivateKey)
+        assert key.key_size == 2048
+        assert serialize_jwk_private_key(key) == _members(jwk, _RSA_MEMBERS)
+
+    def test_rsa_public_key(self, backend):
+        jwk = _members(_rfc7517_key("RSA"), _RSA_PUBLIC_MEMBERS)
+        key = load_jwk_public_key(jwk)
+        assert isinstance(key, rsa.RSAPublicKey)
+        assert key.public_numbers().e == 65537
+        assert serialize_jwk_public_key(key) == jwk
+
+    def test_rsa_private_key_without_crt_parameters(self, backend):
+        jwk = _rfc7517_key("RSA")
+        expected = _members(jwk, _RSA_MEMBERS)
+        for member in ("p", "q", "dp", "dq", "qi"):
+            del jwk[member]
+        key = load_jwk_private_key(jwk)
+        assert serialize_jwk_private_key(key) == expected
+
+    def test_rsa_partial_crt_parameters(self, backend):
+        jwk = _rfc7517_key("RSA")
+        del jwk["qi"]
+        with pytest.raises(ValueError):
+            load_jwk_private_key(jwk)
+
+    def test_rsa_other_primes(self, backend):
+        jwk = _rfc7517_key("RSA")
+        jwk["oth"] = []
+        with raises_unsupported_algorithm(
+            _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM
+        ):
+            load_jwk_private_key(jwk)
+
+    def test_rsa_mismatched_private_key(self, backend):
+        jwk = _rfc7517_key("RSA")
+        jwk["d"] = jwk["dp"]
+        with pytest.raises(ValueError):
+            load_jwk_private_key(jwk)
+
+    def test_public_jwk_as_private_key(self, backend):
+        jwk = _members(_rfc7517_key("RSA"), _RSA_PUBLIC_MEMBERS)
+        with pytest.raises(ValueError):
+            load_jwk_private_key(jwk)
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.ed25519_supported(),
+    skip_message="Requires OpenSSL with Ed25519 support",
+)
+def test_rfc8037_ed25519(backend):
+    jwk = _load_jwk("rfc8037-ed25519.json")
+    key = load_jwk_private_key(jwk)
+    assert isinstance(key, ed25519.Ed25519PrivateKey)
+    assert serialize_jwk_private_key(key) == {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": jwk["x"],
+        "d": jwk["d"],
+    }
+    public_key = load_jwk_public_key(jwk)
+    assert public_key == key.public_key()
+    assert serialize_jwk_public_key(public_key) == {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": jwk["x"],
+    }
+
+
+class TestJWKRoundTrip:
+    @pytest.mark.parametrize(
+        ("curve", "crv", "length"),
+        [
+            (ec.SECP256R1(), "P-256", 32),
+            (ec.SECP384R1(), "P-384", 48),
+            (ec.SECP521R1(), "P-521", 66),
+            (ec.SECP256K1(), "secp256k1", 32),
+        ],
+    )
+    def test_ec(self, backend, curve, crv, length):
+        _skip_curve_unsupported(backend, curve)
+        # Small private values make leading zero bytes likely to be
+        # required.
+        for private_value in (1, 2**8 + 1, 2**200 + 3):
+            key = ec.derive_private_key(private_value, curve)
+            jwk = serialize_jwk_private_key(key)
+            assert jwk["crv"] == crv
+            for member in ("x", "y", "d"):
+                assert len(jwk[member]) == (length * 4 + 2) // 3
+            assert load_jwk_private_key(jwk).private_numbers() == (
+                key.private_numbers()
+            )
+            assert load_jwk_public_key(jwk) == key.public_key()
+
+    def test_rsa_minimal_integers(self, backend):
+        key = rsa.generate_private_key(65537, 1024)
+        jwk = serialize_jwk_private_key(key)
+        assert jwk["e"] == "AQAB"
+        assert load_jwk_private_key(jwk).private_numbers() == (
+            key.private_numbers()
+        )
+
+    @pytest.mark.parametrize(
+        ("key_type", "crv"),
+        [
+            (ed25519.Ed25519PrivateKey, "Ed25519"),
+            (ed448.Ed448PrivateKey, "Ed448"),
+            (x25519.X25519PrivateKey, "X25519"),
+            (x448.X448PrivateKey, "X448"),
+        ],
+    )
+    def test_okp(self, backend, key_type, crv):
+        if not getattr(backend, f"{crv.lower()}_supported")():
+            pytest.skip(f"Requires OpenSSL with {crv} support")
+        key = key_type.generate()
+        jwk = serialize_jwk_private_key(key)
+        assert jwk["kty"] == "OKP"
+        assert jwk["crv"] == crv
+        loaded = load_jwk_private_key(jwk)
+        assert isinstance(loaded, key_type)
+        assert loaded.private_bytes_raw() == key.private_bytes_raw()
+        assert load_jwk_public_key(jwk) == key.public_key()
+
+
+class TestJWKErrors:
+    def test_unsupported_key_types(self, backend):
+        key = dsa.generate_private_key(1024)
+        with pytest.raises(TypeError):
+            serialize_jwk_private_key(key)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            serialize_jwk_public_key(
+                key.public_key()  # type: ignore[arg-type]
+            )
+
+    def test_unsupported_curve(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP224R1())
+        key = ec.generate_private_key(ec.SECP224R1())
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_ELLIPTIC_CURVE):
+            serialize_jwk_public_key(key.public_key())
+
+    def test_not_a_mapping(self):
+        with pytest.raises(TypeError):
+            load_jwk_public_key([])  # type: ignore[arg-type]
+
+    @pytest.mark.parametrize(
+        "jwk",
+        [
+            {},
+            {"kty": 1},
+            {"kty": "EC", "x": "AA", "y": "AA"},
+            {"kty": "OKP", "crv": "Ed25519"},
+            {"kty": "RSA", "n": "", "e": "AQAB"},
+            {"kty": "RSA", "n": "AQAB", "e": "AQAB="},
+            {"kty": "RSA", "n": "AQ+B", "e": "AQAB"},
+            {"kty": "RSA", "n": "AQABA", "e": "AQAB"},
+        ],
+    )
+    def test_malformed(self, backend, jwk):
+        with pytest.raises(ValueError):
+            load_jwk_public_key(jwk)
+
+    @pytest.mark.parametrize(
+        ("jwk", "reason"),
+        [
+            (
+                {"kty": "oct", "k": "AA"},
+                _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM,
+            ),
+            (
+                {"kty": "EC", "crv": "P-192", "x": "AA", "y": "AA"},
+                _Reasons.UNSUPPORTED_ELLIPTIC_CURVE,
+            ),
+            (
+                {"kty": "OKP", "crv": "Ed1", "x": "AA"},
+                _Reasons.UNSUPPORTED_ELLIPTIC_CURVE,
+            ),
+        ],
+    )
+    def test_unsupported(self, backend, jwk, reason):
+        with raises_unsupported_algorithm(reason):
+            load_jwk_public_key(jwk)
+
+    def test_ec_coordinates_must_be_full_length(self, backend):
+        jwk = _rfc7517_key("EC")
+        # Truncate ``x`` from 32 to 30 bytes.
+        jwk["x"] = jwk["x"][:-3]
+        with pytest.raises(ValueError):
+            load_jwk_public_key(jwk)
+
+    def test_ec_mismatched_private_key(self, backend):
+        jwk = _rfc7517_key("EC")
+        jwk["d"] = jwk["x"]
+        with pytest.raises(ValueError):
+            load_jwk_private_key(jwk)
+
+    def test_okp_mismatched_private_key(self, backend):
+        jwk = serialize_jwk_private_key(x25519.X25519PrivateKey.generate())
+        jwk["x"] = serialize_jwk_public_key(
+            x25519.X25519PrivateKey.generate().public_key()
+        )["x"]
+        with pytest.raises(ValueError):
+            load_jwk_private_key(jwk)
@@ -0,0 +1,89 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+
+import pytest
+
+from cryptography.hazmat.primitives.asymmetric import ec, ed25519
+from cryptography.hazmat.primitives.asymmetric.key_health import (
+    KeyWeakness,
+    check_key_health,
+)
+
+from .fixtures_ec import EC_KEY_SECP256R1
+from .fixtures_rsa import RSA_KEY_512, RSA_KEY_2048
+from .test_ec import _skip_curve_unsupported
+
+
+def test_small_rsa_key(backend):
+    key = RSA_KEY_512.private_key(unsafe_skip_rsa_key_validation=True)
+    assert check_key_health(key) == [KeyWeakness.SMALL_KEY]
+    assert check_key_health(key.public_key()) == [KeyWeakness.SMALL_KEY]
+    assert check_key_health(key, min_rsa_key_size=512) == []
+
+
+def test_rsa_key_ok(backend):
+    key = RSA_KEY_2048.private_key(unsafe_skip_rsa_key_validation=True)
+    assert check_key_health(key) == []
+    assert check_key_health(key, min_rsa_key_size=3072) == [
+        KeyWeakness.SMALL_KEY
+    ]
+
+
+def test_compromised_rsa_key(backend):
+    key = RSA_KEY_2048.private_key(unsafe_skip_rsa_key_validation=True)
+    compromised = {RSA_KEY_2048.public_numbers.n}
+    assert check_key_health(key, compromised_moduli=compromised) == [
+        KeyWeakness.COMPROMISED_KEY
+    ]
+    assert check_key_health(key, compromised_moduli={3}) == []
+
+
+def test_compromised_set_is_pluggable(backend):
+    class FakeBloomFilter:
+        def __init__(self):
+            self.lookups = []
+
+        def __contains__(self, n):
+            self.lookups.append(n)
+            return True
+
+    key = RSA_KEY_512.private_key(unsafe_skip_rsa_key_validation=True)
+    bloom = FakeBloomFilter()
+    assert check_key_health(key, compromised_moduli=bloom) == [
+        KeyWeakness.SMALL_KEY,
+        KeyWeakness.COMPROMISED_KEY,
+    ]
+    assert bloom.lookups == [RSA_KEY_512.public_numbers.n]
+
+
+@pytest.mark.parametrize("curve", [ec.SECP192R1(), ec.SECT163K1()])
+def test_deprecated_curve(backend, curve):
+    _skip_curve_unsupported(backend, curve)
+    key = ec.generate_private_key(curve)
+    assert check_key_health(key) == [KeyWeakness.DEPRECATED_CURVE]
+    assert check_key_health(key.public_key()) == [
+        KeyWeakness.DEPRECATED_CURVE
+    ]
+
+
+def test_ec_key_ok(backend):
+    key = EC_KEY_SECP256R1.private_key()
+    assert check_key_health(key) == []
+
+
+def test_other_key_types(backend):
+    if not backend.ed25519_supported():
+        pytest.skip("Requires OpenSSL with Ed25519 support")
+    key = ed25519.Ed25519PrivateKey.generate()
+    assert check_key_health(key) == []
+
+
+def test_invalid_min_rsa_key_size(backend):
+    key = RSA_KEY_2048.private_key(unsafe_skip_rsa_key_validation=True)
+    with pytest.raises(TypeError):
+        check_key_health(
+            key,
+            min_rsa_key_size="2048",  # type: ignore[arg-type]
+        )
@@ -91,6 +91,24 @@ class TestPKCS12Loading:
     def test_load_pkcs12_ec_keys_rc2(self, filename, password, backend):
         self._test_load_pkcs12_ec_keys(filename, password, backend)
 
+    @pytest.mark.supported(
+        only_if=lambda backend: (
+            rust_openssl.CRYPTOGRAPHY_OPENSSL_300_OR_GREATER
+            and not backend.cipher_supported(RC2(b"0" * 16), CBC(b"0" * 8))
+        ),
+        skip_message="Requires OpenSSL 3.0.0+ without RC2",
+    )
+    def test_load_pkcs12_rc2_unsupported(self, backend):
+        data = load_vectors_from_file(
+            os.path.join("pkcs12", "cert-rc2-key-3des.p12"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        with pytest.raises(UnsupportedAlgorithm):
+            load_key_and_certificates(data, b"cryptography", backend)
+        with pytest.raises(UnsupportedAlgorithm):
+            load_pkcs12(data, b"cryptography", backend)
+
     def test_load_key_and_cert_cert_only(self, backend):
         cert, _ = _load_ca(backend)
         parsed_key, parsed_cert, parsed_more_certs = load_vectors_from_file(
@@ -154,6 +172,65 @@ class TestPKCS12Loading:
                 mode="rb",
             )
 
+    @pytest.mark.parametrize("password", [None, b""])
+    def test_load_empty_password(self, password, backend):
+        # Files exported with an empty password load with either ``None``
+        # or ``b""``.
+        cert, key = _load_ca(backend)
+        p12 = load_vectors_from_file(
+            os.path.join("pkcs12", "no-name-no-pwd.p12"),
+            lambda data: load_pkcs12(data.read(), password, backend),
+            mode="rb",
+        )
+        assert p12.cert is not None
+        assert p12.cert.certificate == cert
+
+    def test_load_no_encryption_no_mac(self, backend):
+        self._test_load_pkcs12_ec_keys(
+            "cert-none-key-none-no-mac.p12", None, backend
+        )
+
+    @pytest.mark.parametrize(
+        ("filename", "password", "match"),
+        [
+            (
+                "cert-none-key-none.p12",
+                b"invalid",
+                "Invalid password: PKCS12 MAC verification failed",
+            ),
+            (
+                "cert-none-key-none.p12",
+                None,
+                "no password was provided",
+            ),
+            (
+                "cert-key-aes256cbc.p12",
+                b"",
+                "no password was provided",
+            ),
+            (
+                "cert-none-key-none-no-mac.p12",
+                b"cryptography",
+                "PKCS12 data has no MAC",
+            ),
+            (
+                "cert-key-aes256cbc-no-mac.p12",
+                None,
+                "Invalid password: PKCS12 data could not be decrypted",
+            ),
+        ],
+    )
+    def test_invalid_password_errors(self, filename, password, match, backend):
+        data = load_vectors_from_file(
+            os.path.join("pkcs12", filename),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        with pytest.raises(ValueError, match=match):
+            load_key_and_certificates(data, password, backend)
+        with pytest.raises(ValueError, match=match):
+            load_pkcs12(data, password, backend)
+
     def test_buffer_protocol(self, backend):
         p12 = load_vectors_from_file(
             os.path.join("pkcs12", "cert-key-aes256cbc.p12"),
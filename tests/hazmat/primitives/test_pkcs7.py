@@ -3,6 +3,8 @@
 # for complete details.
 
 
+import base64
+import datetime
 import email.parser
 import os
 import typing
@@ -10,7 +12,7 @@ import typing
 import pytest
 
 from cryptography import x509
-from cryptography.exceptions import _Reasons
+from cryptography.exceptions import InvalidSignature, _Reasons
 from cryptography.hazmat.bindings._rust import openssl as rust_openssl
 from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import ed25519, padding, rsa
@@ -80,6 +82,20 @@ class TestPKCS7Loading:
             )
         ]
 
+    def test_load_pkcs7_pem_multiple_certificates(self, backend):
+        der = load_vectors_from_file(
+            os.path.join("pkcs7", "amazon-roots.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        pem = serialization.der_to_pem("PKCS7", der)
+        certs = pkcs7.load_pem_pkcs7_certificates(pem)
+        assert certs == pkcs7.load_der_pkcs7_certificates(der)
+        assert [c.subject.rfc4514_string() for c in certs] == [
+            "CN=Amazon Root CA 3,O=Amazon,C=US",
+            "CN=Amazon Root CA 2,O=Amazon,C=US",
+        ]
+
     def test_load_pkcs7_unsupported_type(self, backend):
         with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_SERIALIZATION):
             load_vectors_from_file(
@@ -97,10 +113,155 @@ class TestPKCS7Loading:
             pkcs7.load_der_pkcs7_certificates(der)
 
 
-# We have no public verification API and won't be adding one until we get
-# some requirements from users so this function exists to give us basic
-# verification for the signing tests.
-def _pkcs7_verify(encoding, sig, msg, certs, options, backend):
+def _load_rsa_ca_and_key():
+    cert = load_vectors_from_file(
+        os.path.join("x509", "custom", "ca", "rsa_ca.pem"),
+        loader=lambda pemfile: x509.load_pem_x509_certificate(pemfile.read()),
+        mode="rb",
+    )
+    key = load_vectors_from_file(
+        os.path.join("x509", "custom", "ca", "rsa_key.pem"),
+        lambda pemfile: serialization.load_pem_private_key(
+            pemfile.read(), None, unsafe_skip_rsa_key_validation=True
+        ),
+        mode="rb",
+    )
+    assert isinstance(key, rsa.RSAPrivateKey)
+    return cert, key
+
+
+class TestPKCS7ContentType:
+    def test_content_type_der(self):
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "auth-enveloped-aes-256-gcm.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        assert (
+            pkcs7.pkcs7_content_type_der(data)
+            == pkcs7.PKCS7ContentTypeOID.AUTH_ENVELOPED_DATA
+        )
+
+    @pytest.mark.parametrize(
+        ("filename", "content_type"),
+        [
+            ("isrg.pem", pkcs7.PKCS7ContentTypeOID.SIGNED_DATA),
+            ("enveloped.pem", pkcs7.PKCS7ContentTypeOID.ENVELOPED_DATA),
+            (
+                "auth-enveloped-aes-128-gcm.pem",
+                pkcs7.PKCS7ContentTypeOID.AUTH_ENVELOPED_DATA,
+            ),
+        ],
+    )
+    def test_content_type_pem(self, filename, content_type):
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", filename),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        assert pkcs7.pkcs7_content_type_pem(data) == content_type
+
+    def test_content_type_invalid(self):
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_content_type_der(b"nonsense")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_content_type_pem(b"nonsense")
+
+
+class TestPKCS7Decrypt:
+    def test_decrypt_der(self):
+        cert, key = _load_rsa_ca_and_key()
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "auth-enveloped-aes-256-gcm.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        assert pkcs7.pkcs7_decrypt_der(data, cert, key) == b"Hello world!\n"
+
+    def test_decrypt_pem(self):
+        cert, key = _load_rsa_ca_and_key()
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "auth-enveloped-aes-128-gcm.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        assert pkcs7.pkcs7_decrypt_pem(data, cert, key) == b"Hello world!\n"
+
+    @pytest.mark.parametrize(
+        "offset",
+        [
+            # The last byte of the MAC.
+            -1,
+            # The last byte of the encrypted content.
+            -19,
+            # The last byte of the encrypted content-encryption key.
+            -79,
+        ],
+    )
+    def test_decrypt_tampered(self, offset):
+        cert, key = _load_rsa_ca_and_key()
+        data = bytearray(
+            load_vectors_from_file(
+                os.path.join("pkcs7", "auth-enveloped-aes-256-gcm.der"),
+                lambda derfile: derfile.read(),
+                mode="rb",
+            )
+        )
+        data[offset] ^= 0x01
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_decrypt_der(bytes(data), cert, key)
+
+    def test_decrypt_wrong_recipient(self):
+        _, key = _load_rsa_ca_and_key()
+        cert = load_vectors_from_file(
+            os.path.join("x509", "custom", "ca", "ca.pem"),
+            loader=lambda pemfile: x509.load_pem_x509_certificate(
+                pemfile.read()
+            ),
+            mode="rb",
+        )
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "auth-enveloped-aes-256-gcm.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_decrypt_der(data, cert, key)
+
+    def test_decrypt_not_rsa_key(self):
+        cert, _ = _load_rsa_ca_and_key()
+        key = ed25519.Ed25519PrivateKey.generate()
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "auth-enveloped-aes-256-gcm.der"),
+            lambda derfile: derfile.read(),
+            mode="rb",
+        )
+        with pytest.raises(TypeError):
+            pkcs7.pkcs7_decrypt_der(
+                data,
+                cert,
+                key,  # type: ignore[arg-type]
+            )
+
+    def test_decrypt_unsupported_content_type(self):
+        cert, key = _load_rsa_ca_and_key()
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "enveloped.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_SERIALIZATION):
+            pkcs7.pkcs7_decrypt_pem(data, cert, key)
+
+    def test_decrypt_invalid(self):
+        cert, key = _load_rsa_ca_and_key()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_decrypt_der(b"nonsense", cert, key)
+
+
+# pkcs7_verify_der only checks a single signer's signature, so this function
+# uses OpenSSL to give us full verification for the signing tests.
+def _pkcs7_verify(encoding, sig, msg, certs, options, backend, signers=None):
     sig_bio = backend._bytes_to_bio(sig)
     if encoding is serialization.Encoding.DER:
         p7 = backend._lib.d2i_PKCS7_bio(sig_bio.bio, backend._ffi.NULL)
@@ -129,10 +290,22 @@ def _pkcs7_verify(encoding, sig, msg, certs, options, backend):
         ossl_certs.append(ossl_cert)
         res = backend._lib.X509_STORE_add_cert(store, ossl_cert)
         backend.openssl_assert(res == 1)
+    # Signatures created with NoCerts don't contain the signer certificate,
+    # so it has to be passed to PKCS7_verify separately.
+    ossl_signers = backend._ffi.NULL
+    if signers is not None:
+        ossl_signers = backend._lib.sk_X509_new_null()
+        backend.openssl_assert(ossl_signers != backend._ffi.NULL)
+        ossl_signers = backend._ffi.gc(ossl_signers, backend._lib.sk_X509_free)
+        for cert in signers:
+            ossl_cert = backend._cert2ossl(cert)
+            ossl_certs.append(ossl_cert)
+            res = backend._lib.sk_X509_push(ossl_signers, ossl_cert)
+            backend.openssl_assert(res >= 1)
     if msg is None:
         res = backend._lib.PKCS7_verify(
             p7,
-            backend._ffi.NULL,
+            ossl_signers,
             store,
             backend._ffi.NULL,
             backend._ffi.NULL,
@@ -144,7 +317,7 @@ def _pkcs7_verify(encoding, sig, msg, certs, options, backend):
         # around it for now.
         out_bio = backend._create_mem_bio_gc()
         res = backend._lib.PKCS7_verify(
-            p7, backend._ffi.NULL, store, msg_bio.bio, out_bio, flags
+            p7, ossl_signers, store, msg_bio.bio, out_bio, flags
         )
     backend.openssl_assert(res == 1)
     # OpenSSL 3.0 leaves a random bio error on the stack:
@@ -169,6 +342,545 @@ def _load_cert_key():
     return cert, key
 
 
+_MESSAGE_DIGEST_OID = b"\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x09\x04"
+
+
+def _der_children(data):
+    children = []
+    while data:
+        tag, length, offset = data[0], data[1], 2
+        if length & 0x80:
+            offset += length & 0x7F
+            length = int.from_bytes(data[2:offset], "big")
+        children.append((tag, data[offset : offset + length]))
+        data = data[offset + length :]
+    return children
+
+
+def _der_encode(tag, value):
+    if len(value) < 0x80:
+        length = bytes([len(value)])
+    else:
+        n = (len(value).bit_length() + 7) // 8
+        length = bytes([0x80 | n]) + len(value).to_bytes(n, "big")
+    return bytes([tag]) + length + value
+
+
+def _truncate_message_digests(data):
+    # Re-encodes `data` with the value of every messageDigest attribute
+    # shortened by one byte.
+    out = b""
+    for tag, value in _der_children(data):
+        if tag & 0x20:
+            if value.startswith(_MESSAGE_DIGEST_OID):
+                [(_, digest)] = _der_children(_der_children(value)[1][1])
+                value = _MESSAGE_DIGEST_OID + _der_encode(
+                    0x31, _der_encode(0x04, digest[:-1])
+                )
+            else:
+                value = _truncate_message_digests(value)
+        out += _der_encode(tag, value)
+    return out
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.pkcs7_supported(),
+    skip_message="Requires OpenSSL with PKCS7 support",
+)
+class TestPKCS7Verify:
+    def _sign_detached(self, cert, key, data, options=()):
+        return (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(data)
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(
+                serialization.Encoding.DER,
+                [pkcs7.PKCS7Options.DetachedSignature, *options],
+            )
+        )
+
+    @pytest.mark.parametrize(
+        "load_cert_key", [_load_cert_key, _load_rsa_ca_and_key]
+    )
+    def test_verify_content_digest(self, load_cert_key):
+        cert, key = load_cert_key()
+        data = b"hello world"
+        sig = self._sign_detached(cert, key, data)
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(data)
+        pkcs7.pkcs7_verify_der(sig, cert, content_digest=digest.finalize())
+
+    def test_verify_incorrect_content_digest(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(b"goodbye world")
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(
+                sig, cert, content_digest=digest.finalize()
+            )
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(sig, cert, content_digest=b"\x00" * 32)
+
+    def test_verify_wrong_length_content_digest(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        for content_digest in [b"\x00", b"\x00" * 31, b"\x00" * 64]:
+            with pytest.raises(InvalidSignature):
+                pkcs7.pkcs7_verify_der(
+                    sig, cert, content_digest=content_digest
+                )
+
+    def test_verify_truncated_message_digest(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        truncated = _truncate_message_digests(sig)
+        assert len(truncated) == len(sig) - 1
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(b"hello world")
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(truncated, cert, content=b"hello world")
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(
+                truncated, cert, content_digest=digest.finalize()
+            )
+
+    def test_verify_content_type_mismatch(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        # The first id-data OID is the encapsulated content type, which
+        # precedes the signer's content type attribute. Replace it with
+        # id-digestedData, so that the signed attribute no longer matches.
+        id_data = b"\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x07\x01"
+        id_digested_data = id_data[:-1] + b"\x05"
+        assert sig.count(id_data) == 2
+        tampered = sig.replace(id_data, id_digested_data, 1)
+        pkcs7.pkcs7_verify_der(sig, cert, content=b"hello world")
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(tampered, cert, content=b"hello world")
+
+    def test_verify_content(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        pkcs7.pkcs7_verify_der(sig, cert, content=b"hello world")
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(sig, cert, content=b"goodbye world")
+
+    def test_verify_pem(self):
+        cert, key = _load_rsa_ca_and_key()
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(serialization.Encoding.PEM, [])
+        )
+        pkcs7.pkcs7_verify_pem(sig, cert)
+
+    def test_verify_tampered_signature(self):
+        cert, key = _load_cert_key()
+        sig = bytearray(self._sign_detached(cert, key, b"hello world"))
+        sig[-1] ^= 0x01
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(bytes(sig), cert, content=b"hello world")
+
+    def test_verify_content_digest_without_attributes(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(
+            cert, key, b"hello world", [pkcs7.PKCS7Options.NoAttributes]
+        )
+        pkcs7.pkcs7_verify_der(sig, cert, content=b"hello world")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_der(sig, cert, content_digest=b"\x00" * 32)
+
+    def test_verify_missing_content(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_der(sig, cert)
+
+    def test_verify_content_and_content_digest(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_der(
+                sig, cert, content=b"hello world", content_digest=b"\x00"
+            )
+
+    def test_verify_wrong_signer(self):
+        cert, key = _load_cert_key()
+        other_cert, _ = _load_rsa_ca_and_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_der(sig, other_cert, content=b"hello world")
+
+    def test_verify_unsupported_content_type(self):
+        cert, _ = _load_rsa_ca_and_key()
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "enveloped.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_SERIALIZATION):
+            pkcs7.pkcs7_verify_pem(data, cert)
+
+    def test_verify_invalid(self):
+        cert, _ = _load_cert_key()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_der(b"nonsense", cert)
+
+    def _sign_two_signers(self, encoding=serialization.Encoding.DER):
+        cert, key = _load_cert_key()
+        rsa_cert, rsa_key = _load_rsa_ca_and_key()
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .add_signer(rsa_cert, rsa_key, hashes.SHA256())
+            .sign(encoding, [pkcs7.PKCS7Options.DetachedSignature])
+        )
+        return sig, cert, rsa_cert
+
+    def test_verify_signers(self):
+        sig, cert, rsa_cert = self._sign_two_signers()
+        verified = pkcs7.pkcs7_verify_signers_der(
+            sig, [rsa_cert, cert], content=b"hello world"
+        )
+        assert verified == [cert, rsa_cert]
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_signers_der(
+                sig, [cert, rsa_cert], content=b"goodbye world"
+            )
+
+    def test_verify_signers_pem(self):
+        sig, cert, rsa_cert = self._sign_two_signers(
+            serialization.Encoding.PEM
+        )
+        verified = pkcs7.pkcs7_verify_signers_pem(
+            sig, [cert, rsa_cert], content=b"hello world"
+        )
+        assert verified == [cert, rsa_cert]
+
+    def test_verify_signers_content_digest(self):
+        sig, cert, rsa_cert = self._sign_two_signers()
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(b"hello world")
+        verified = pkcs7.pkcs7_verify_signers_der(
+            sig, [cert, rsa_cert], content_digest=digest.finalize()
+        )
+        assert verified == [cert, rsa_cert]
+
+    def test_verify_signers_mixed_digest_lengths(self):
+        cert, key = _load_cert_key()
+        rsa_cert, rsa_key = _load_rsa_ca_and_key()
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .add_signer(rsa_cert, rsa_key, hashes.SHA512())
+            .sign(
+                serialization.Encoding.DER,
+                [pkcs7.PKCS7Options.DetachedSignature],
+            )
+        )
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(b"hello world")
+        verified = pkcs7.pkcs7_verify_signers_der(
+            sig,
+            [cert, rsa_cert],
+            content_digest=digest.finalize(),
+            require_all=False,
+        )
+        assert verified == [cert]
+
+    def test_verify_signers_one_invalid(self):
+        sig, cert, rsa_cert = self._sign_two_signers()
+        # The RSA signer's signature is the last element of the structure.
+        tampered = bytearray(sig)
+        tampered[-1] ^= 0x01
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_signers_der(
+                bytes(tampered), [cert, rsa_cert], content=b"hello world"
+            )
+        verified = pkcs7.pkcs7_verify_signers_der(
+            bytes(tampered),
+            [cert, rsa_cert],
+            content=b"hello world",
+            require_all=False,
+        )
+        assert verified == [cert]
+
+    def test_verify_signers_one_malformed(self):
+        sig, cert, rsa_cert = self._sign_two_signers()
+        # Renaming the first signer's messageDigest attribute makes it fail
+        # with a ValueError rather than an invalid signature.
+        no_digest_oid = _MESSAGE_DIGEST_OID[:-1] + b"\x7f"
+        malformed = sig.replace(_MESSAGE_DIGEST_OID, no_digest_oid, 1)
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signers_der(
+                malformed, [cert, rsa_cert], content=b"hello world"
+            )
+        verified = pkcs7.pkcs7_verify_signers_der(
+            malformed,
+            [cert, rsa_cert],
+            content=b"hello world",
+            require_all=False,
+        )
+        assert len(verified) == 1
+        # If no signer verifies, the first failure is raised.
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signers_der(
+                malformed,
+                [cert, rsa_cert],
+                content=b"goodbye world",
+                require_all=False,
+            )
+
+    def test_verify_signers_missing_certificate(self):
+        sig, cert, _ = self._sign_two_signers()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_signers_der(
+                sig, [cert], content=b"hello world"
+            )
+        verified = pkcs7.pkcs7_verify_signers_der(
+            sig, [cert], content=b"hello world", require_all=False
+        )
+        assert verified == [cert]
+
+    def test_verify_signers_none_verified(self):
+        sig, cert, rsa_cert = self._sign_two_signers()
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_signers_der(
+                sig,
+                [cert, rsa_cert],
+                content=b"goodbye world",
+                require_all=False,
+            )
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_signers_der(
+                sig, [], content=b"hello world", require_all=False
+            )
+
+    def test_digest_algorithms(self):
+        sig, _, _ = self._sign_two_signers()
+        assert pkcs7.pkcs7_digest_algorithms_der(sig) == [
+            x509.ObjectIdentifier("2.16.840.1.101.3.4.2.1")
+        ]
+        pem, _ = self._load_countersigned()
+        assert pkcs7.pkcs7_digest_algorithms_pem(pem) == [
+            x509.ObjectIdentifier("2.16.840.1.101.3.4.2.1")
+        ]
+
+    def test_digest_algorithms_multiple(self):
+        cert, key = _load_cert_key()
+        rsa_cert, rsa_key = _load_rsa_ca_and_key()
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA384())
+            .add_signer(rsa_cert, rsa_key, hashes.SHA256())
+            .sign(
+                serialization.Encoding.DER,
+                [pkcs7.PKCS7Options.DetachedSignature],
+            )
+        )
+        assert sorted(
+            oid.dotted_string
+            for oid in pkcs7.pkcs7_digest_algorithms_der(sig)
+        ) == ["2.16.840.1.101.3.4.2.1", "2.16.840.1.101.3.4.2.2"]
+        verified = pkcs7.pkcs7_verify_signers_der(
+            sig, [cert, rsa_cert], content=b"hello world"
+        )
+        assert verified == [cert, rsa_cert]
+
+    def test_digest_algorithms_undeclared(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        pkcs7.pkcs7_verify_der(sig, cert, content=b"hello world")
+        # The digestAlgorithms set is encoded before the signer infos, so
+        # this replaces the declared SHA-256 with SHA-512 while leaving the
+        # signer's own digest algorithm, and its signature, unchanged.
+        sha256 = bytes.fromhex("0609608648016503040201")
+        sha512 = bytes.fromhex("0609608648016503040203")
+        assert sig.count(sha256) == 2
+        tampered = sig.replace(sha256, sha512, 1)
+        with pytest.raises(ValueError, match="digestAlgorithms"):
+            pkcs7.pkcs7_digest_algorithms_der(tampered)
+        with pytest.raises(ValueError, match="digestAlgorithms"):
+            pkcs7.pkcs7_verify_der(tampered, cert, content=b"hello world")
+        with pytest.raises(ValueError, match="digestAlgorithms"):
+            pkcs7.pkcs7_verify_signers_der(
+                tampered, [cert], content=b"hello world"
+            )
+
+    def test_digest_algorithms_not_signed_data(self):
+        data = load_vectors_from_file(
+            os.path.join("pkcs7", "enveloped.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_SERIALIZATION):
+            pkcs7.pkcs7_digest_algorithms_pem(data)
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_digest_algorithms_der(b"nonsense")
+
+    def _load_countersigned(self):
+        pem = load_vectors_from_file(
+            os.path.join("pkcs7", "countersigned.pem"),
+            lambda pemfile: pemfile.read(),
+            mode="rb",
+        )
+        der = base64.b64decode(b"".join(pem.splitlines()[1:-1]))
+        return pem, der
+
+    def test_verify_countersignature(self):
+        pem, der = self._load_countersigned()
+        cert, _ = _load_cert_key()
+        rsa_cert, _ = _load_rsa_ca_and_key()
+        pkcs7.pkcs7_verify_pem(pem, cert)
+        pkcs7.pkcs7_verify_countersignature_pem(pem, cert, rsa_cert)
+        pkcs7.pkcs7_verify_countersignature_der(der, cert, rsa_cert)
+
+    def test_verify_tampered_countersignature(self):
+        _, der = self._load_countersigned()
+        cert, _ = _load_cert_key()
+        rsa_cert, _ = _load_rsa_ca_and_key()
+        # The countersignature's signature is the last element of the
+        # structure, so the original signature is unaffected.
+        tampered = bytearray(der)
+        tampered[-1] ^= 0x01
+        pkcs7.pkcs7_verify_der(bytes(tampered), cert)
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_countersignature_der(
+                bytes(tampered), cert, rsa_cert
+            )
+
+    def test_verify_countersignature_wrong_countersigner(self):
+        _, der = self._load_countersigned()
+        cert, _ = _load_cert_key()
+        rsa_cert, _ = _load_rsa_ca_and_key()
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_countersignature_der(der, cert, cert)
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_countersignature_der(der, rsa_cert, rsa_cert)
+
+    def test_verify_countersignature_not_countersigned(self):
+        cert, key = _load_cert_key()
+        rsa_cert, _ = _load_rsa_ca_and_key()
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(serialization.Encoding.DER, [])
+        )
+        with pytest.raises(ValueError):
+            pkcs7.pkcs7_verify_countersignature_der(sig, cert, rsa_cert)
+
+    def _sign_with_email_cert(self, subject, extensions, encoding):
+        _, key = _load_cert_key()
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(subject)
+            .issuer_name(subject)
+            .public_key(key.public_key())
+            .serial_number(x509.random_serial_number())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+        )
+        for extension in extensions:
+            builder = builder.add_extension(extension, critical=False)
+        cert = builder.sign(key, hashes.SHA256())
+        sig = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(b"hello world")
+            .add_signer(cert, key, hashes.SHA256())
+            .sign(encoding, [])
+        )
+        return cert, sig
+
+    def _sign_with_san_cert(self, encoding=serialization.Encoding.DER):
+        return self._sign_with_email_cert(
+            x509.Name([x509.NameAttribute(x509.NameOID.COMMON_NAME, "Alice")]),
+            [
+                x509.SubjectAlternativeName(
+                    [
+                        x509.DNSName("example.com"),
+                        x509.RFC822Name("alice@example.com"),
+                        x509.RFC822Name("bob@mail.example.org"),
+                    ]
+                )
+            ],
+            encoding,
+        )
+
+    @pytest.mark.parametrize(
+        "expected_email",
+        ["alice@example.com", "alice@EXAMPLE.COM", "bob@mail.example.org"],
+    )
+    def test_verify_expected_email_san(self, expected_email):
+        cert, sig = self._sign_with_san_cert()
+        pkcs7.pkcs7_verify_der(sig, cert, expected_email=expected_email)
+        cert, sig = self._sign_with_san_cert(serialization.Encoding.PEM)
+        pkcs7.pkcs7_verify_pem(sig, cert, expected_email=expected_email)
+
+    def test_verify_expected_email_subject(self):
+        cert, sig = self._sign_with_email_cert(
+            x509.Name(
+                [
+                    x509.NameAttribute(x509.NameOID.COMMON_NAME, "Alice"),
+                    x509.NameAttribute(
+                        x509.NameOID.EMAIL_ADDRESS, "alice@example.com"
+                    ),
+                ]
+            ),
+            [],
+            serialization.Encoding.DER,
+        )
+        pkcs7.pkcs7_verify_der(sig, cert, expected_email="alice@Example.com")
+        with pytest.raises(ValueError, match="expected email"):
+            pkcs7.pkcs7_verify_der(
+                sig, cert, expected_email="mallory@example.com"
+            )
+
+    @pytest.mark.parametrize(
+        "expected_email",
+        [
+            "mallory@example.com",
+            # The local part is compared exactly.
+            "Alice@example.com",
+            "alice@example.org",
+            "alice@mail.example.com",
+            "alice",
+            "example.com",
+        ],
+    )
+    def test_verify_mismatched_expected_email(self, expected_email):
+        cert, sig = self._sign_with_san_cert()
+        # The signature itself is fine.
+        pkcs7.pkcs7_verify_der(sig, cert)
+        with pytest.raises(ValueError, match="expected email"):
+            pkcs7.pkcs7_verify_der(sig, cert, expected_email=expected_email)
+
+    def test_verify_expected_email_no_address(self):
+        cert, key = _load_cert_key()
+        sig = self._sign_detached(cert, key, b"hello world")
+        with pytest.raises(ValueError, match="expected email"):
+            pkcs7.pkcs7_verify_der(
+                sig,
+                cert,
+                content=b"hello world",
+                expected_email="alice@example.com",
+            )
+
+    def test_verify_expected_email_invalid_signature(self):
+        cert, sig = self._sign_with_san_cert()
+        tampered = bytearray(sig)
+        tampered[-1] ^= 0x01
+        with pytest.raises(InvalidSignature):
+            pkcs7.pkcs7_verify_der(
+                bytes(tampered), cert, expected_email="alice@example.com"
+            )
+
+
 @pytest.mark.supported(
     only_if=lambda backend: backend.pkcs7_supported(),
     skip_message="Requires OpenSSL with PKCS7 support",
@@ -682,6 +1394,52 @@ class TestPKCS7Builder:
         sig_no = builder.sign(serialization.Encoding.DER, options)
         assert sig_no.count(cert.public_bytes(serialization.Encoding.DER)) == 0
 
+    @pytest.mark.parametrize(
+        ("encoding", "options"),
+        [
+            (serialization.Encoding.DER, []),
+            (serialization.Encoding.PEM, []),
+            (serialization.Encoding.SMIME, []),
+            (
+                serialization.Encoding.DER,
+                [pkcs7.PKCS7Options.DetachedSignature],
+            ),
+            (
+                serialization.Encoding.SMIME,
+                [pkcs7.PKCS7Options.DetachedSignature],
+            ),
+        ],
+    )
+    def test_sign_no_certs_verify_external_cert(
+        self, encoding, options, backend
+    ):
+        data = b"hello world"
+        cert, key = _load_cert_key()
+        other_cert = load_vectors_from_file(
+            os.path.join("x509", "custom", "ca", "rsa_ca.pem"),
+            loader=lambda pemfile: x509.load_pem_x509_certificate(
+                pemfile.read()
+            ),
+            mode="rb",
+        )
+        builder = (
+            pkcs7.PKCS7SignatureBuilder()
+            .set_data(data)
+            .add_signer(cert, key, hashes.SHA256())
+            .add_certificate(other_cert)
+        )
+
+        options = [*options, pkcs7.PKCS7Options.NoCerts]
+        sig = builder.sign(encoding, options)
+        if encoding is serialization.Encoding.DER:
+            for c in [cert, other_cert]:
+                assert c.public_bytes(serialization.Encoding.DER) not in sig
+
+        msg = data if pkcs7.PKCS7Options.DetachedSignature in options else None
+        _pkcs7_verify(
+            encoding, sig, msg, [cert], options, backend, signers=[cert]
+        )
+
     @pytest.mark.parametrize(
         "pad",
         [
@@ -153,3 +153,11 @@ class TestPoly1305:
         assert Poly1305.generate_tag(key, msg) == binascii.unhexlify(
             b"4541669a7eaaee61e708dc7cbcc5eb62"
         )
+
+        tag = memoryview(
+            bytearray(binascii.unhexlify(b"4541669a7eaaee61e708dc7cbcc5eb62"))
+        )
+        Poly1305.verify_tag(key, memoryview(msg), tag)
+        poly = Poly1305(key)
+        poly.update(msg)
+        poly.verify(tag)
@@ -6,6 +6,7 @@
 import binascii
 import copy
 import itertools
+import json
 import os
 
 import pytest
@@ -23,6 +24,7 @@ from cryptography.hazmat.primitives.asymmetric.rsa import (
     RSAPrivateNumbers,
     RSAPublicNumbers,
 )
+from cryptography.hazmat.primitives.kdf.x963kdf import X963KDF
 
 from ...doubles import (
     DummyAsymmetricPadding,
@@ -783,6 +785,25 @@ class TestRSASignature:
         public_key = private_key.public_key()
         public_key.verify(signature, message, pss, hashes.SHA256())
 
+    @pytest.mark.parametrize(
+        "hash_alg",
+        [hashes.SHA224(), hashes.SHA256(), hashes.SHA384(), hashes.SHA512()],
+    )
+    def test_sign_matches_prehashed(
+        self, rsa_key_2048: rsa.RSAPrivateKey, hash_alg, backend
+    ):
+        if not backend.signature_hash_supported(hash_alg):
+            pytest.skip(f"{hash_alg.name} signatures not supported")
+        message = b"one little message"
+        h = hashes.Hash(hash_alg, backend)
+        h.update(message)
+        digest = h.finalize()
+        signature = rsa_key_2048.sign(message, padding.PKCS1v15(), hash_alg)
+        prehashed_signature = rsa_key_2048.sign(
+            digest, padding.PKCS1v15(), asym_utils.Prehashed(hash_alg)
+        )
+        assert signature == prehashed_signature
+
     @pytest.mark.supported(
         only_if=lambda backend: backend.rsa_padding_supported(
             padding.PSS(
@@ -968,6 +989,29 @@ class TestRSAVerification:
                 signature, padding.PKCS1v15(), hashes.SHA512()
             )
 
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.rsa_padding_supported(
+            padding.PKCS1v15()
+        ),
+        skip_message="Does not support PKCS1v1.5.",
+    )
+    def test_recover_data_from_signature_buffer(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        private_key = rsa_key_2048
+        public_key = private_key.public_key()
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(b"sign me")
+        signature = private_key.sign(
+            b"sign me", padding.PKCS1v15(), hashes.SHA256()
+        )
+        recovered = public_key.recover_data_from_signature(
+            memoryview(bytearray(signature)),
+            padding.PKCS1v15(),
+            hashes.SHA256(),
+        )
+        assert recovered == digest.finalize()
+
     def test_invalid_signature_sequence_removed(self, backend):
         """
         This test comes from wycheproof
@@ -2398,6 +2442,633 @@ class TestRSAPrimeFactorRecovery:
             rsa.rsa_recover_prime_factors(34, 3, 7)
 
 
+class TestRSARawOperations:
+    def test_pkcs1v15_signature_vectors(self, backend, subtests):
+        # The PKCS#1 v1.5 SHA-1 DigestInfo prefix from RFC 8017 section 9.2.
+        digest_info_prefix = binascii.unhexlify(
+            "3021300906052b0e03021a05000414"
+        )
+        vectors = _flatten_pkcs1_examples(
+            load_vectors_from_file(
+                os.path.join("asymmetric", "RSA", "pkcs1v15sign-vectors.txt"),
+                load_pkcs1_vectors,
+            )
+        )
+        for private, public, example in vectors:
+            with subtests.test():
+                private_key = rsa.RSAPrivateNumbers(
+                    p=private["p"],
+                    q=private["q"],
+                    d=private["private_exponent"],
+                    dmp1=private["dmp1"],
+                    dmq1=private["dmq1"],
+                    iqmp=private["iqmp"],
+                    public_numbers=rsa.RSAPublicNumbers(
+                        e=private["public_exponent"], n=private["modulus"]
+                    ),
+                ).private_key(backend, unsafe_skip_rsa_key_validation=True)
+                signature = binascii.unhexlify(example["signature"])
+                h = hashes.Hash(hashes.SHA1())
+                h.update(binascii.unhexlify(example["message"]))
+                digest = h.finalize()
+                t = digest_info_prefix + digest
+                encoded = (
+                    b"\x00\x01"
+                    + b"\xff" * (len(signature) - len(t) - 3)
+                    + b"\x00"
+                    + t
+                )
+
+                assert (
+                    rsa.unsafe_raw_public_operation(
+                        private_key.public_key(), signature
+                    )
+                    == encoded
+                )
+                assert (
+                    rsa.unsafe_raw_private_operation(
+                        private_key, bytearray(encoded)
+                    )
+                    == signature
+                )
+
+    def test_round_trip(self, rsa_key_2048: rsa.RSAPrivateKey):
+        data = b"\x00" + os.urandom(255)
+        ciphertext = rsa.unsafe_raw_public_operation(
+            rsa_key_2048.public_key(), data
+        )
+        assert len(ciphertext) == 256
+        assert rsa.unsafe_raw_private_operation(rsa_key_2048, ciphertext) == (
+            data
+        )
+
+    def test_leading_zeros_preserved(self, rsa_key_2048: rsa.RSAPrivateKey):
+        data = b"\x00" * 255 + b"\x01"
+        assert (
+            rsa.unsafe_raw_public_operation(rsa_key_2048.public_key(), data)
+            == data
+        )
+        assert rsa.unsafe_raw_private_operation(rsa_key_2048, data) == data
+
+    @pytest.mark.parametrize("length", [0, 255, 257])
+    def test_invalid_length(self, rsa_key_2048: rsa.RSAPrivateKey, length):
+        data = b"\x01" * length
+        with pytest.raises(ValueError):
+            rsa.unsafe_raw_public_operation(rsa_key_2048.public_key(), data)
+        with pytest.raises(ValueError):
+            rsa.unsafe_raw_private_operation(rsa_key_2048, data)
+
+    def test_not_less_than_modulus(self, rsa_key_2048: rsa.RSAPrivateKey):
+        n = rsa_key_2048.public_key().public_numbers().n
+        for value in [n, 2**2048 - 1]:
+            data = value.to_bytes(256, "big")
+            with pytest.raises(ValueError):
+                rsa.unsafe_raw_public_operation(
+                    rsa_key_2048.public_key(), data
+                )
+            with pytest.raises(ValueError):
+                rsa.unsafe_raw_private_operation(rsa_key_2048, data)
+
+    def test_invalid_key_type(self, rsa_key_2048: rsa.RSAPrivateKey):
+        data = b"\x00" * 256
+        with pytest.raises(TypeError):
+            rsa.unsafe_raw_public_operation(
+                rsa_key_2048,  # type: ignore[arg-type]
+                data,
+            )
+        with pytest.raises(TypeError):
+            rsa.unsafe_raw_private_operation(
+                rsa_key_2048.public_key(),  # type: ignore[arg-type]
+                data,
+            )
+
+
+class TestRSABlindSignatures:
+    def _sign_blinded(self, private_key, message, pss, algorithm):
+        blinded, state = rsa.blind(
+            message, private_key.public_key(), pss, algorithm
+        )
+        blinded_signature = rsa.unsafe_raw_private_operation(
+            private_key, blinded
+        )
+        return rsa.unblind(blinded_signature, state)
+
+    def test_rfc9474_vectors(self, backend, subtests):
+        vectors = load_vectors_from_file(
+            os.path.join("asymmetric", "RSA", "rfc9474", "test-vectors.json"),
+            json.load,
+        )
+        for vector in vectors:
+            with subtests.test():
+                p, q, d, e, n = (
+                    int(vector[name], 16) for name in ("p", "q", "d", "e", "n")
+                )
+                private_key = rsa.RSAPrivateNumbers(
+                    p=p,
+                    q=q,
+                    d=d,
+                    dmp1=rsa.rsa_crt_dmp1(d, p),
+                    dmq1=rsa.rsa_crt_dmq1(d, q),
+                    iqmp=rsa.rsa_crt_iqmp(p, q),
+                    public_numbers=rsa.RSAPublicNumbers(e=e, n=n),
+                ).private_key(unsafe_skip_rsa_key_validation=True)
+                inv = int(vector["inv"], 16)
+                pss = padding.PSS(
+                    mgf=padding.MGF1(hashes.SHA384()),
+                    salt_length=int(vector["sLen"], 16),
+                )
+                # The randomized variants prepend `msg_prefix` to the
+                # message, which is included in `input_msg`.
+                blinded, state = rust_openssl.rsa._blind_with_fixed_randomness(
+                    binascii.unhexlify(vector["input_msg"]),
+                    private_key.public_key(),
+                    pss,
+                    hashes.SHA384(),
+                    binascii.unhexlify(vector["salt"]),
+                    inv.to_bytes((inv.bit_length() + 7) // 8, "big"),
+                )
+                assert blinded == binascii.unhexlify(vector["blinded_msg"])
+
+                blind_sig = rsa.unsafe_raw_private_operation(
+                    private_key, blinded
+                )
+                assert blind_sig == binascii.unhexlify(vector["blind_sig"])
+                signature = rsa.unblind(blind_sig, state)
+                assert signature == binascii.unhexlify(vector["sig"])
+                private_key.public_key().verify(
+                    signature,
+                    binascii.unhexlify(vector["input_msg"]),
+                    pss,
+                    hashes.SHA384(),
+                )
+
+    def test_blind_salt_length_mismatch(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        pss = padding.PSS(mgf=padding.MGF1(hashes.SHA256()), salt_length=32)
+        with pytest.raises(ValueError):
+            rust_openssl.rsa._blind_with_fixed_randomness(
+                b"message",
+                rsa_key_2048.public_key(),
+                pss,
+                hashes.SHA256(),
+                b"\x00" * 16,
+                b"\x01",
+            )
+
+    def test_pss_vectors(self, backend, subtests):
+        for private, public, example in _flatten_pkcs1_examples(
+            load_vectors_from_file(
+                os.path.join(
+                    "asymmetric", "RSA", "pkcs-1v2-1d2-vec", "pss-vect.txt"
+                ),
+                load_pkcs1_vectors,
+            )
+        ):
+            with subtests.test():
+                private_key = rsa.RSAPrivateNumbers(
+                    p=private["p"],
+                    q=private["q"],
+                    d=private["private_exponent"],
+                    dmp1=private["dmp1"],
+                    dmq1=private["dmq1"],
+                    iqmp=private["iqmp"],
+                    public_numbers=rsa.RSAPublicNumbers(
+                        e=private["public_exponent"], n=private["modulus"]
+                    ),
+                ).private_key(backend, unsafe_skip_rsa_key_validation=True)
+                message = binascii.unhexlify(example["message"])
+                pss = padding.PSS(
+                    mgf=padding.MGF1(algorithm=hashes.SHA1()),
+                    salt_length=len(binascii.unhexlify(example["salt"])),
+                )
+                signature = self._sign_blinded(
+                    private_key, message, pss, hashes.SHA1()
+                )
+                assert len(signature) == (private_key.key_size + 7) // 8
+                # The salt is random so the signature won't match the
+                # vector, but it must verify.
+                private_key.public_key().verify(
+                    signature, message, pss, hashes.SHA1()
+                )
+
+    @pytest.mark.parametrize(
+        "key_data",
+        [RSA_KEY_1024, RSA_KEY_1025, RSA_KEY_1031, RSA_KEY_2048],
+    )
+    @pytest.mark.parametrize("algorithm", [hashes.SHA256(), hashes.SHA384()])
+    def test_zero_salt_matches_sign(self, key_data, algorithm, backend):
+        # With an empty salt PSS is deterministic, so the unblinded
+        # signature must be identical to a directly computed one.
+        private_key = key_data.private_key(
+            backend, unsafe_skip_rsa_key_validation=True
+        )
+        pss = padding.PSS(mgf=padding.MGF1(algorithm), salt_length=0)
+        message = b"blind signature test"
+        signature = self._sign_blinded(private_key, message, pss, algorithm)
+        assert signature == private_key.sign(message, pss, algorithm)
+
+    @pytest.mark.parametrize(
+        "salt_length",
+        [padding.PSS.DIGEST_LENGTH, padding.PSS.MAX_LENGTH, 0, 32],
+    )
+    def test_round_trip(self, rsa_key_2048: rsa.RSAPrivateKey, salt_length):
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA384()), salt_length=salt_length
+        )
+        message = b"\x00" * 32 + b"prepared message"
+        signature = self._sign_blinded(
+            rsa_key_2048, message, pss, hashes.SHA384()
+        )
+        rsa_key_2048.public_key().verify(
+            signature, message, pss, hashes.SHA384()
+        )
+
+    def test_prehashed(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA384()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+        message = b"prehashed message"
+        h = hashes.Hash(hashes.SHA384())
+        h.update(message)
+        digest = h.finalize()
+        signature = self._sign_blinded(
+            rsa_key_2048, digest, pss, asym_utils.Prehashed(hashes.SHA384())
+        )
+        rsa_key_2048.public_key().verify(
+            signature, message, pss, hashes.SHA384()
+        )
+
+    def test_blinding_is_randomized(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(mgf=padding.MGF1(hashes.SHA384()), salt_length=0)
+        public_key = rsa_key_2048.public_key()
+        blinded1, _ = rsa.blind(b"message", public_key, pss, hashes.SHA384())
+        blinded2, _ = rsa.blind(b"message", public_key, pss, hashes.SHA384())
+        assert blinded1 != blinded2
+
+    def test_unblind_invalid_signature(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA384()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+        _, state = rsa.blind(
+            b"message", rsa_key_2048.public_key(), pss, hashes.SHA384()
+        )
+        other_blinded, _ = rsa.blind(
+            b"other", rsa_key_2048.public_key(), pss, hashes.SHA384()
+        )
+        blinded_signature = rsa.unsafe_raw_private_operation(
+            rsa_key_2048, other_blinded
+        )
+        with pytest.raises(InvalidSignature):
+            rsa.unblind(blinded_signature, state)
+
+    def test_unblind_invalid_length(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA384()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+        _, state = rsa.blind(
+            b"message", rsa_key_2048.public_key(), pss, hashes.SHA384()
+        )
+        with pytest.raises(ValueError):
+            rsa.unblind(b"\x00" * 255, state)
+        with pytest.raises(ValueError):
+            rsa.unblind(b"\xff" * 256, state)
+
+    def test_unsupported_padding(self, rsa_key_2048: rsa.RSAPrivateKey):
+        public_key = rsa_key_2048.public_key()
+        with pytest.raises(TypeError):
+            rsa.blind(
+                b"message", public_key, padding.PKCS1v15(), hashes.SHA256()
+            )
+        with raises_unsupported_algorithm(_Reasons.UNSUPPORTED_MGF):
+            rsa.blind(
+                b"message",
+                public_key,
+                padding.PSS(
+                    mgf=DummyMGF(),  # type: ignore[arg-type]
+                    salt_length=padding.PSS.DIGEST_LENGTH,
+                ),
+                hashes.SHA256(),
+            )
+        with pytest.raises(ValueError):
+            rsa.blind(
+                b"message",
+                public_key,
+                padding.PSS(
+                    mgf=padding.MGF1(hashes.SHA256()),
+                    salt_length=padding.PSS.AUTO,
+                ),
+                hashes.SHA256(),
+            )
+
+    def test_salt_too_large(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(mgf=padding.MGF1(hashes.SHA384()), salt_length=207)
+        with pytest.raises(ValueError):
+            rsa.blind(
+                b"message", rsa_key_2048.public_key(), pss, hashes.SHA384()
+            )
+
+    def test_invalid_types(self, rsa_key_2048: rsa.RSAPrivateKey):
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA384()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+        with pytest.raises(TypeError):
+            rsa.blind(
+                b"message",
+                rsa_key_2048,  # type: ignore[arg-type]
+                pss,
+                hashes.SHA384(),
+            )
+        with pytest.raises(TypeError):
+            rsa.unblind(b"\x00" * 256, object())  # type: ignore[arg-type]
+
+
+class TestRSAImplicitRejection:
+    @staticmethod
+    def _encrypt_em(public_key: rsa.RSAPublicKey, em: bytes) -> bytes:
+        return rsa.unsafe_raw_public_operation(public_key, em)
+
+    def test_pkcs1v15_vectors(self, backend, subtests):
+        vectors = _flatten_pkcs1_examples(
+            load_vectors_from_file(
+                os.path.join("asymmetric", "RSA", "pkcs1v15crypt-vectors.txt"),
+                load_pkcs1_vectors,
+            )
+        )
+        for private, public, example in vectors:
+            with subtests.test():
+                skey = rsa.RSAPrivateNumbers(
+                    p=private["p"],
+                    q=private["q"],
+                    d=private["private_exponent"],
+                    dmp1=private["dmp1"],
+                    dmq1=private["dmq1"],
+                    iqmp=private["iqmp"],
+                    public_numbers=rsa.RSAPublicNumbers(
+                        e=private["public_exponent"], n=private["modulus"]
+                    ),
+                ).private_key(backend, unsafe_skip_rsa_key_validation=True)
+                ciphertext = binascii.unhexlify(example["encryption"])
+                message = binascii.unhexlify(example["message"])
+                synthetic = os.urandom(len(message))
+                assert (
+                    rsa.decrypt_pkcs1v15_implicit_rejection(
+                        skey, ciphertext, synthetic
+                    )
+                    == message
+                )
+
+    @pytest.mark.parametrize("length", [1, 16, 48, 245])
+    def test_round_trip(self, rsa_key_2048: rsa.RSAPrivateKey, length):
+        message = os.urandom(length)
+        ciphertext = rsa_key_2048.public_key().encrypt(
+            message, padding.PKCS1v15()
+        )
+        synthetic = os.urandom(length)
+        assert (
+            rsa.decrypt_pkcs1v15_implicit_rejection(
+                rsa_key_2048, ciphertext, synthetic
+            )
+            == message
+        )
+
+    def test_wrong_length_returns_synthetic(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        ciphertext = rsa_key_2048.public_key().encrypt(
+            b"\x03" * 32, padding.PKCS1v15()
+        )
+        for length in [31, 33, 48]:
+            synthetic = os.urandom(length)
+            assert (
+                rsa.decrypt_pkcs1v15_implicit_rejection(
+                    rsa_key_2048, ciphertext, synthetic
+                )
+                == synthetic
+            )
+
+    @pytest.mark.parametrize(
+        ("em", "length"),
+        [
+            # Leading byte is not zero.
+            (b"\x01\x02" + b"\x11" * 205 + b"\x00" + b"\x03" * 48, 48),
+            # Block type is not 2.
+            (b"\x00\x01" + b"\xff" * 205 + b"\x00" + b"\x03" * 48, 48),
+            # A zero byte inside the padding string.
+            (
+                b"\x00\x02"
+                + b"\x11" * 100
+                + b"\x00"
+                + b"\x11" * 104
+                + b"\x00"
+                + b"\x03" * 48,
+                48,
+            ),
+            # No zero separator before the message.
+            (b"\x00\x02" + b"\x11" * 206 + b"\x03" * 48, 48),
+            # Valid padding, but for a shorter message.
+            (b"\x00\x02" + b"\x11" * 206 + b"\x00" + b"\x03" * 47, 48),
+            # Valid padding, but for a longer message.
+            (b"\x00\x02" + b"\x11" * 204 + b"\x00" + b"\x03" * 49, 48),
+            # Padding string shorter than 8 bytes.
+            (b"\x00\x02" + b"\x11" * 7 + b"\x00" + b"\x03" * 246, 245),
+            (b"\x00" * 256, 48),
+        ],
+    )
+    def test_invalid_padding_returns_synthetic(
+        self, rsa_key_2048: rsa.RSAPrivateKey, em, length
+    ):
+        assert len(em) == 256
+        ciphertext = self._encrypt_em(rsa_key_2048.public_key(), em)
+        synthetic = os.urandom(length)
+        # No exception is raised; the caller always gets bytes of the
+        # expected length back.
+        assert (
+            rsa.decrypt_pkcs1v15_implicit_rejection(
+                rsa_key_2048, ciphertext, synthetic
+            )
+            == synthetic
+        )
+
+    def test_minimum_padding_string(self, rsa_key_2048: rsa.RSAPrivateKey):
+        em = b"\x00\x02" + b"\x11" * 8 + b"\x00" + b"\x03" * 245
+        ciphertext = self._encrypt_em(rsa_key_2048.public_key(), em)
+        assert (
+            rsa.decrypt_pkcs1v15_implicit_rejection(
+                rsa_key_2048, ciphertext, os.urandom(245)
+            )
+            == b"\x03" * 245
+        )
+
+    def test_ciphertext_not_less_than_modulus(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        n = rsa_key_2048.public_key().public_numbers().n
+        synthetic = os.urandom(48)
+        for ciphertext in [n.to_bytes(256, "big"), b"\xff" * 256]:
+            assert (
+                rsa.decrypt_pkcs1v15_implicit_rejection(
+                    rsa_key_2048, ciphertext, synthetic
+                )
+                == synthetic
+            )
+
+    def test_invalid_ciphertext_length(self, rsa_key_2048: rsa.RSAPrivateKey):
+        for ciphertext in [b"\x00" * 255, b"\x00" * 257]:
+            with pytest.raises(ValueError):
+                rsa.decrypt_pkcs1v15_implicit_rejection(
+                    rsa_key_2048, ciphertext, os.urandom(48)
+                )
+
+    def test_invalid_synthetic_length(self, rsa_key_2048: rsa.RSAPrivateKey):
+        ciphertext = rsa_key_2048.public_key().encrypt(
+            b"\x03" * 48, padding.PKCS1v15()
+        )
+        for synthetic in [b"", os.urandom(246)]:
+            with pytest.raises(ValueError):
+                rsa.decrypt_pkcs1v15_implicit_rejection(
+                    rsa_key_2048, ciphertext, synthetic
+                )
+
+    def test_key_too_small(self):
+        # A 64-bit key, which is too small for the PKCS1v15 padding.
+        p, q, e = 4294967291, 4294967279, 65537
+        d = pow(e, -1, (p - 1) * (q - 1))
+        key = rsa.RSAPrivateNumbers(
+            p=p,
+            q=q,
+            d=d,
+            dmp1=rsa.rsa_crt_dmp1(d, p),
+            dmq1=rsa.rsa_crt_dmq1(d, q),
+            iqmp=rsa.rsa_crt_iqmp(p, q),
+            public_numbers=rsa.RSAPublicNumbers(e=e, n=p * q),
+        ).private_key(unsafe_skip_rsa_key_validation=True)
+        with pytest.raises(ValueError, match="too small"):
+            rsa.decrypt_pkcs1v15_implicit_rejection(key, b"\x01" * 8, b"a")
+
+    def test_invalid_types(self, rsa_key_2048: rsa.RSAPrivateKey):
+        ciphertext = rsa_key_2048.public_key().encrypt(
+            b"\x03" * 48, padding.PKCS1v15()
+        )
+        with pytest.raises(TypeError):
+            rsa.decrypt_pkcs1v15_implicit_rejection(
+                rsa_key_2048.public_key(),  # type: ignore[arg-type]
+                ciphertext,
+                os.urandom(48),
+            )
+        with pytest.raises(TypeError):
+            rsa.decrypt_pkcs1v15_implicit_rejection(
+                rsa_key_2048,
+                "ciphertext",  # type: ignore[arg-type]
+                os.urandom(48),
+            )
+
+
+
+class TestRSAKEM:
+    @staticmethod
+    def _kdf2(algorithm, z: bytes, length: int) -> bytes:
+        # KDF2 is the X9.63 KDF without shared info.
+        return X963KDF(algorithm, length, None).derive(z)
+
+    @pytest.mark.parametrize(
+        ("algorithm", "length"),
+        [
+            (hashes.SHA256(), 16),
+            (hashes.SHA256(), 32),
+            (hashes.SHA256(), 100),
+            (hashes.SHA384(), 48),
+            (hashes.SHA512(), 1),
+        ],
+    )
+    def test_round_trip(
+        self, rsa_key_2048: rsa.RSAPrivateKey, algorithm, length
+    ):
+        ciphertext, shared_secret = rsa.rsa_kem_encapsulate(
+            rsa_key_2048.public_key(), algorithm, length
+        )
+        assert len(ciphertext) == 256
+        assert len(shared_secret) == length
+        assert (
+            rsa.rsa_kem_decapsulate(
+                rsa_key_2048, ciphertext, algorithm, length
+            )
+            == shared_secret
+        )
+        z = rsa.unsafe_raw_private_operation(rsa_key_2048, ciphertext)
+        assert shared_secret == self._kdf2(algorithm, z, length)
+
+    def test_known_integer(self, rsa_key_2048: rsa.RSAPrivateKey):
+        public_numbers = rsa_key_2048.public_key().public_numbers()
+        for z in [0, 1, 2**1000 + 7, public_numbers.n - 1]:
+            ciphertext = pow(z, public_numbers.e, public_numbers.n).to_bytes(
+                256, "big"
+            )
+            assert rsa.rsa_kem_decapsulate(
+                rsa_key_2048, ciphertext, hashes.SHA256(), 32
+            ) == self._kdf2(hashes.SHA256(), z.to_bytes(256, "big"), 32)
+
+    def test_encapsulate_is_randomized(self, rsa_key_2048: rsa.RSAPrivateKey):
+        public_key = rsa_key_2048.public_key()
+        c1, k1 = rsa.rsa_kem_encapsulate(public_key, hashes.SHA256(), 32)
+        c2, k2 = rsa.rsa_kem_encapsulate(public_key, hashes.SHA256(), 32)
+        assert c1 != c2
+        assert k1 != k2
+
+    def test_invalid_ciphertext_length(self, rsa_key_2048: rsa.RSAPrivateKey):
+        for ciphertext in [b"", b"\x00" * 255, b"\x00" * 257]:
+            with pytest.raises(ValueError):
+                rsa.rsa_kem_decapsulate(
+                    rsa_key_2048, ciphertext, hashes.SHA256(), 32
+                )
+
+    def test_ciphertext_not_less_than_modulus(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        n = rsa_key_2048.public_key().public_numbers().n
+        for ciphertext in [n.to_bytes(256, "big"), b"\xff" * 256]:
+            with pytest.raises(ValueError):
+                rsa.rsa_kem_decapsulate(
+                    rsa_key_2048, ciphertext, hashes.SHA256(), 32
+                )
+
+    def test_invalid_length(self, rsa_key_2048: rsa.RSAPrivateKey):
+        public_key = rsa_key_2048.public_key()
+        with pytest.raises(ValueError):
+            rsa.rsa_kem_encapsulate(public_key, hashes.SHA256(), 0)
+        ciphertext, _ = rsa.rsa_kem_encapsulate(
+            public_key, hashes.SHA256(), 32
+        )
+        with pytest.raises(ValueError):
+            rsa.rsa_kem_decapsulate(
+                rsa_key_2048, ciphertext, hashes.SHA256(), 0
+            )
+
+    def test_invalid_types(self, rsa_key_2048: rsa.RSAPrivateKey):
+        with pytest.raises(TypeError):
+            rsa.rsa_kem_encapsulate(
+                rsa_key_2048,  # type: ignore[arg-type]
+                hashes.SHA256(),
+                32,
+            )
+        with pytest.raises(TypeError):
+            rsa.rsa_kem_decapsulate(
+                rsa_key_2048.public_key(),  # type: ignore[arg-type]
+                b"\x00" * 256,
+                hashes.SHA256(),
+                32,
+            )
+        with pytest.raises(TypeError):
+            rsa.rsa_kem_encapsulate(
+                rsa_key_2048.public_key(),
+                object(),  # type: ignore[arg-type]
+                32,
+            )
+
 class TestRSAPrivateKeySerialization:
     @pytest.mark.parametrize(
         ("fmt", "password"),
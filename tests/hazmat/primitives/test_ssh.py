@@ -689,6 +689,30 @@ class TestOpenSSHSerialization:
 
             assert original_public_key == decoded_public_key
 
+    @pytest.mark.parametrize(
+        ("private_value", "padding"),
+        [
+            # The encoded secrets are 121 bytes long.
+            (1, b"\x01\x02\x03\x04\x05\x06\x07"),
+            # The encoded secrets are 152 bytes long, a multiple of the
+            # block size, so there is no padding.
+            (2**254 + 1, b""),
+        ],
+    )
+    def test_serialize_ssh_private_key_padding(
+        self, private_value, padding, backend
+    ):
+        key = ec.derive_private_key(private_value, ec.SECP256R1())
+        encoded = key.private_bytes(
+            Encoding.PEM, PrivateFormat.OpenSSH, NoEncryption()
+        )
+        data = base64.b64decode(b"".join(encoded.splitlines()[1:-1]))
+        # The secrets end with an empty comment, followed by the padding.
+        assert data.endswith(b"\x00\x00\x00\x00" + padding)
+        loaded = load_ssh_private_key(encoded, None)
+        assert isinstance(loaded, ec.EllipticCurvePrivateKey)
+        assert loaded.private_numbers() == key.private_numbers()
+
     @pytest.mark.supported(
         only_if=lambda backend: backend.dsa_supported(),
         skip_message="Does not support DSA.",
@@ -10,11 +10,12 @@ import os
 import pytest
 
 from cryptography.exceptions import _Reasons
-from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric.x25519 import (
     X25519PrivateKey,
     X25519PublicKey,
 )
+from cryptography.hazmat.primitives.kdf.hkdf import HKDF
 
 from ...doubles import DummyKeySerializationEncryption
 from ...utils import (
@@ -182,6 +183,99 @@ class TestX25519Exchange:
         with pytest.raises(TypeError):
             key.exchange(object())  # type: ignore[arg-type]
 
+    @pytest.mark.parametrize(
+        ("algorithm", "length", "salt", "info"),
+        [
+            (hashes.SHA256(), 32, None, None),
+            (hashes.SHA256(), 32, b"salt", b"info"),
+            (hashes.SHA512(), 100, b"salt", None),
+            (hashes.SHA256(), 255 * 32, None, b"info"),
+        ],
+    )
+    def test_exchange_hkdf(self, algorithm, length, salt, info, backend):
+        key = X25519PrivateKey.generate()
+        peer = X25519PrivateKey.generate()
+        derived = key.exchange_hkdf(
+            peer.public_key(), algorithm, length, salt, info
+        )
+
+        shared_key = key.exchange(peer.public_key())
+        hkdf = HKDF(algorithm, length, salt, info)
+        assert derived == hkdf.derive(shared_key)
+        assert derived == peer.exchange_hkdf(
+            key.public_key(), algorithm, length, salt, info
+        )
+
+    # The keys are from RFC 7748, the expected output was computed with an
+    # independent HKDF implementation.
+    @pytest.mark.parametrize(
+        ("salt", "info", "length", "expected"),
+        [
+            (
+                None,
+                None,
+                32,
+                b"ea1d8a20f476d1e1ec952ca42708b8f7"
+                b"161ce7c81eadf97e520e2b40333decd5",
+            ),
+            (
+                b"salt",
+                b"info",
+                42,
+                b"dfdf3ce5d8a064972e2c7033de0f29798e773efba52a80c0"
+                b"7250c458c7c315a6d41aa94b006e18a15fc7",
+            ),
+        ],
+    )
+    def test_exchange_hkdf_vectors(self, salt, info, length, expected):
+        private_key = X25519PrivateKey.from_private_bytes(
+            binascii.unhexlify(
+                b"77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba"
+                b"51db92c2a"
+            )
+        )
+        public_key = X25519PublicKey.from_public_bytes(
+            binascii.unhexlify(
+                b"de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e1"
+                b"46f882b4f"
+            )
+        )
+        derived = private_key.exchange_hkdf(
+            public_key, hashes.SHA256(), length, salt, info
+        )
+        assert derived == binascii.unhexlify(expected)
+
+    def test_exchange_hkdf_invalid(self, backend):
+        key = X25519PrivateKey.generate()
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                object(),  # type: ignore[arg-type]
+                hashes.SHA256(),
+                32,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                key.public_key(),
+                object(),  # type: ignore[arg-type]
+                32,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                key.public_key(),
+                hashes.SHA256(),
+                32,
+                "salt",  # type: ignore[arg-type]
+                None,
+            )
+        with pytest.raises(ValueError):
+            key.exchange_hkdf(
+                key.public_key(), hashes.SHA256(), 255 * 32 + 1, None, None
+            )
+
     def test_invalid_length_from_public_bytes(self, backend):
         with pytest.raises(ValueError):
             X25519PublicKey.from_public_bytes(b"a" * 31)
@@ -10,11 +10,12 @@ import os
 import pytest
 
 from cryptography.exceptions import _Reasons
-from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric.x448 import (
     X448PrivateKey,
     X448PublicKey,
 )
+from cryptography.hazmat.primitives.kdf.hkdf import HKDF
 
 from ...doubles import DummyKeySerializationEncryption
 from ...utils import (
@@ -186,6 +187,99 @@ class TestX448Exchange:
         with pytest.raises(TypeError):
             key.exchange(object())  # type: ignore[arg-type]
 
+    @pytest.mark.parametrize(
+        ("algorithm", "length", "salt", "info"),
+        [
+            (hashes.SHA256(), 32, None, None),
+            (hashes.SHA256(), 32, b"salt", b"info"),
+            (hashes.SHA512(), 100, b"salt", None),
+            (hashes.SHA256(), 255 * 32, None, b"info"),
+        ],
+    )
+    def test_exchange_hkdf(self, algorithm, length, salt, info, backend):
+        key = X448PrivateKey.generate()
+        peer = X448PrivateKey.generate()
+        derived = key.exchange_hkdf(
+            peer.public_key(), algorithm, length, salt, info
+        )
+
+        shared_key = key.exchange(peer.public_key())
+        hkdf = HKDF(algorithm, length, salt, info)
+        assert derived == hkdf.derive(shared_key)
+        assert derived == peer.exchange_hkdf(
+            key.public_key(), algorithm, length, salt, info
+        )
+
+    # The keys are from RFC 7748, the expected output was computed with an
+    # independent HKDF implementation.
+    @pytest.mark.parametrize(
+        ("salt", "info", "length", "expected"),
+        [
+            (
+                None,
+                None,
+                32,
+                b"a6b244f4344d4873caf1e8df49dca57b"
+                b"a9fcb8b2ed9cbb1876ef1ae18748403a",
+            ),
+            (
+                b"salt",
+                b"info",
+                42,
+                b"e00f104880704ddf53ed7577a59b9f06b22c428680403380"
+                b"e0a07d0b8680b8085f66365bd42480f15fe3",
+            ),
+        ],
+    )
+    def test_exchange_hkdf_vectors(self, salt, info, length, expected):
+        private_key = X448PrivateKey.from_private_bytes(
+            binascii.unhexlify(
+                b"9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28d"
+                b"d9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b"
+            )
+        )
+        public_key = X448PublicKey.from_public_bytes(
+            binascii.unhexlify(
+                b"3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b430"
+                b"27d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609"
+            )
+        )
+        derived = private_key.exchange_hkdf(
+            public_key, hashes.SHA256(), length, salt, info
+        )
+        assert derived == binascii.unhexlify(expected)
+
+    def test_exchange_hkdf_invalid(self, backend):
+        key = X448PrivateKey.generate()
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                object(),  # type: ignore[arg-type]
+                hashes.SHA256(),
+                32,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                key.public_key(),
+                object(),  # type: ignore[arg-type]
+                32,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            key.exchange_hkdf(
+                key.public_key(),
+                hashes.SHA256(),
+                32,
+                "salt",  # type: ignore[arg-type]
+                None,
+            )
+        with pytest.raises(ValueError):
+            key.exchange_hkdf(
+                key.public_key(), hashes.SHA256(), 255 * 32 + 1, None, None
+            )
+
     def test_invalid_length_from_public_bytes(self, backend):
         with pytest.raises(ValueError):
             X448PublicKey.from_public_bytes(b"a" * 55)
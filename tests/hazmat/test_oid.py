@@ -6,7 +6,16 @@ import copy
 
 import pytest
 
-from cryptography.hazmat._oid import ObjectIdentifier
+from cryptography.hazmat._oid import (
+    ExtendedKeyUsageOID,
+    ExtensionOID,
+    NameOID,
+    ObjectIdentifier,
+    PublicKeyAlgorithmOID,
+    SignatureAlgorithmOID,
+    name_to_oid,
+    oid_to_name,
+)
 
 
 def test_basic_oid():
@@ -46,3 +55,43 @@ def test_oid_constraint():
     # negative oid
     with pytest.raises(ValueError):
         ObjectIdentifier("1.2.-3.-4")
+
+
+@pytest.mark.parametrize(
+    ("oid", "name"),
+    [
+        (NameOID.COMMON_NAME, "commonName"),
+        (ExtensionOID.SUBJECT_ALTERNATIVE_NAME, "subjectAltName"),
+        (ExtendedKeyUsageOID.SERVER_AUTH, "serverAuth"),
+        (SignatureAlgorithmOID.RSA_WITH_SHA256, "sha256WithRSAEncryption"),
+        (SignatureAlgorithmOID.ECDSA_WITH_SHA384, "ecdsa-with-SHA384"),
+        (PublicKeyAlgorithmOID.X25519, "X25519"),
+    ],
+)
+def test_oid_name_lookup(oid, name):
+    assert oid_to_name(oid) == name
+    assert oid_to_name(oid.dotted_string) == name
+    assert name_to_oid(name) == oid
+
+
+def test_oid_name_lookup_shared_name():
+    name = "signedCertificateTimestampList"
+    assert oid_to_name(ExtensionOID.SIGNED_CERTIFICATE_TIMESTAMPS) == name
+    assert (
+        name_to_oid(name) == ExtensionOID.PRECERT_SIGNED_CERTIFICATE_TIMESTAMPS
+    )
+
+
+def test_oid_name_lookup_unknown():
+    assert oid_to_name(ObjectIdentifier("1.2.3.4")) is None
+    assert oid_to_name("1.2.3.4") is None
+    assert name_to_oid("notARealName") is None
+
+
+def test_oid_name_lookup_invalid_types():
+    with pytest.raises(TypeError):
+        oid_to_name(1234)  # type: ignore[arg-type]
+    with pytest.raises(TypeError):
+        name_to_oid(ObjectIdentifier("2.5.4.3"))  # type: ignore[arg-type]
+    with pytest.raises(ValueError):
+        oid_to_name("not.an.oid")
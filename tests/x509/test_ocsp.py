@@ -16,6 +16,8 @@ from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import ec, ed448, ed25519, rsa
 from cryptography.hazmat.primitives.asymmetric.padding import PKCS1v15
 from cryptography.x509 import ocsp
+from cryptography.x509.name import _ASN1Type
+from cryptography.x509.oid import OCSPExtensionOID
 
 from ..hazmat.primitives.fixtures_ec import EC_KEY_SECP256R1
 from ..utils import load_vectors_from_file, raises_unsupported_algorithm
@@ -272,6 +274,48 @@ class TestOCSPRequestBuilder:
         with pytest.raises(ValueError):
             builder.add_extension(x509.OCSPNonce(b"123"), False)
 
+    @pytest.mark.parametrize("length", [1, 16, 32])
+    def test_add_nonce(self, length):
+        cert, issuer = _cert_and_issuer()
+        nonce = os.urandom(length)
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, issuer, hashes.SHA256())
+            .add_nonce(nonce)
+            .build()
+        )
+        ext = req.extensions.get_extension_for_class(x509.OCSPNonce)
+        assert ext.critical is False
+        assert ext.value == x509.OCSPNonce(nonce)
+
+    def test_add_nonce_default(self):
+        cert, issuer = _cert_and_issuer()
+        builder = ocsp.OCSPRequestBuilder().add_certificate(
+            cert, issuer, hashes.SHA256()
+        )
+        nonce1 = (
+            builder.add_nonce()
+            .build()
+            .extensions.get_extension_for_class(x509.OCSPNonce)
+            .value.nonce
+        )
+        nonce2 = (
+            builder.add_nonce()
+            .build()
+            .extensions.get_extension_for_class(x509.OCSPNonce)
+            .value.nonce
+        )
+        assert len(nonce1) == 32
+        assert nonce1 != nonce2
+
+    @pytest.mark.parametrize("length", [0, 33, 64])
+    def test_invalid_nonce_length(self, length):
+        builder = ocsp.OCSPRequestBuilder()
+        with pytest.raises(ValueError):
+            builder.add_extension(x509.OCSPNonce(b"0" * length), False)
+        with pytest.raises(ValueError):
+            builder.add_nonce(b"0" * length)
+
     def test_add_invalid_extension(self):
         builder = ocsp.OCSPRequestBuilder()
         with pytest.raises(TypeError):
@@ -360,6 +404,144 @@ class TestOCSPRequestBuilder:
         )
 
 
+class TestOCSPCertID:
+    def test_compute_cert_id(self):
+        cert, issuer = _cert_and_issuer()
+        cert_id = ocsp.compute_cert_id(cert, issuer, hashes.SHA1())
+        assert isinstance(cert_id, ocsp.OCSPCertID)
+        assert cert_id.issuer_name_hash == (
+            b"@\x0bFz\xf1\xe6\xb2\xd3\t\x83\xba\r`~~Y7H$\xc4"
+        )
+        assert cert_id.issuer_key_hash == (
+            b"\xc3\x9c\xf3\xfc\xd3F\x084\xbb\xceF\x7f\xa0|[\xf3\xe2\x08\xcbY"
+        )
+        assert isinstance(cert_id.hash_algorithm, hashes.SHA1)
+        assert cert_id.serial_number == cert.serial_number
+        assert cert_id.public_bytes(
+            serialization.Encoding.DER
+        ) == base64.b64decode(
+            b"MDswCQYFKw4DAhoFAAQUQAtGevHmstMJg7oNYH5+WTdIJMQEFMOc8/zTRgg0u85G"
+            b"f6B8W/PiCMtZAgI/IA=="
+        )
+
+    def test_matches_request(self):
+        cert, issuer = _cert_and_issuer()
+        cert_id = ocsp.compute_cert_id(cert, issuer, hashes.SHA256())
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, issuer, hashes.SHA256())
+            .build()
+        )
+        assert cert_id.issuer_name_hash == req.issuer_name_hash
+        assert cert_id.issuer_key_hash == req.issuer_key_hash
+        assert cert_id.serial_number == req.serial_number
+        assert isinstance(cert_id.hash_algorithm, hashes.SHA256)
+
+    def test_issuer_name_hash_uses_issuer_subject(self):
+        cert, issuer = _cert_and_issuer()
+        cert_id = ocsp.compute_cert_id(cert, issuer, hashes.SHA256())
+        h = hashes.Hash(hashes.SHA256())
+        h.update(issuer.subject.public_bytes())
+        assert cert_id.issuer_name_hash == h.finalize()
+
+    def test_builders_hash_issuer_subject(self):
+        issuer, private_key = _generate_root()
+        # The certificate names its issuer with a PrintableString common
+        # name, while the issuer's subject uses a UTF8String, so the two
+        # encodings hash differently.
+        issuer_name = x509.Name(
+            [
+                x509.NameAttribute(x509.NameOID.COUNTRY_NAME, "US"),
+                x509.NameAttribute(
+                    x509.NameOID.COMMON_NAME,
+                    "Cryptography CA",
+                    _ASN1Type.PrintableString,
+                ),
+            ]
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .serial_number(1)
+            .issuer_name(issuer_name)
+            .subject_name(
+                x509.Name(
+                    [x509.NameAttribute(x509.NameOID.COMMON_NAME, "leaf")]
+                )
+            )
+            .public_key(private_key.public_key())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(private_key, hashes.SHA256())
+        )
+        assert cert.issuer == issuer.subject
+        assert cert.issuer.public_bytes() != issuer.subject.public_bytes()
+        h = hashes.Hash(hashes.SHA1())
+        h.update(issuer.subject.public_bytes())
+        expected = h.finalize()
+
+        req = (
+            ocsp.OCSPRequestBuilder()
+            .add_certificate(cert, issuer, hashes.SHA1())
+            .build()
+        )
+        assert req.issuer_name_hash == expected
+
+        time = datetime.datetime(2024, 1, 1)
+        resp = (
+            ocsp.OCSPResponseBuilder()
+            .responder_id(ocsp.OCSPResponderEncoding.HASH, issuer)
+            .add_response(
+                cert,
+                issuer,
+                hashes.SHA1(),
+                ocsp.OCSPCertStatus.GOOD,
+                time,
+                None,
+                None,
+                None,
+            )
+            .sign(private_key, hashes.SHA256())
+        )
+        assert resp.issuer_name_hash == expected
+
+    def test_eq_hash(self):
+        cert, issuer = _cert_and_issuer()
+        cert_id = ocsp.compute_cert_id(cert, issuer, hashes.SHA1())
+        same = ocsp.compute_cert_id(cert, issuer, hashes.SHA1())
+        other = ocsp.compute_cert_id(cert, issuer, hashes.SHA256())
+        assert cert_id == same
+        assert cert_id != other
+        assert cert_id != object()
+        assert hash(cert_id) == hash(same)
+        assert {cert_id: 1}[same] == 1
+
+    def test_invalid_hash_algorithm(self):
+        cert, issuer = _cert_and_issuer()
+        with pytest.raises(ValueError):
+            ocsp.compute_cert_id(cert, issuer, hashes.MD5())
+
+    def test_not_a_cert(self):
+        cert, issuer = _cert_and_issuer()
+        with pytest.raises(TypeError):
+            ocsp.compute_cert_id(
+                "notacert",  # type: ignore[arg-type]
+                issuer,
+                hashes.SHA1(),
+            )
+        with pytest.raises(TypeError):
+            ocsp.compute_cert_id(
+                cert,
+                "notacert",  # type: ignore[arg-type]
+                hashes.SHA1(),
+            )
+
+    def test_invalid_encoding(self):
+        cert, issuer = _cert_and_issuer()
+        cert_id = ocsp.compute_cert_id(cert, issuer, hashes.SHA1())
+        with pytest.raises(ValueError):
+            cert_id.public_bytes(serialization.Encoding.PEM)
+
+
 class TestOCSPResponseBuilder:
     def test_add_response_twice(self):
         cert, issuer = _cert_and_issuer()
@@ -955,6 +1137,43 @@ class TestOCSPResponseBuilder:
             resp.signature, resp.tbs_response_bytes, ec.ECDSA(hashes.SHA256())
         )
 
+    def test_sign_with_invalid_nonce_length(self):
+        builder = ocsp.OCSPResponseBuilder()
+        with pytest.raises(ValueError):
+            builder.add_extension(x509.OCSPNonce(b"0" * 33), False)
+
+    @pytest.mark.parametrize("length", [0, 33])
+    def test_load_response_invalid_nonce_length(self, length):
+        builder = ocsp.OCSPResponseBuilder()
+        cert, issuer = _cert_and_issuer()
+        root_cert, private_key = _generate_root()
+        this_update = datetime.datetime(2024, 1, 1)
+        builder = (
+            builder.responder_id(ocsp.OCSPResponderEncoding.HASH, root_cert)
+            .add_response(
+                cert,
+                issuer,
+                hashes.SHA1(),
+                ocsp.OCSPCertStatus.GOOD,
+                this_update,
+                None,
+                None,
+                None,
+            )
+            # The nonce builder checks are bypassed by encoding it as an
+            # unrecognized extension.
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    OCSPExtensionOID.NONCE,
+                    b"\x04" + bytes([length]) + b"0" * length,
+                ),
+                False,
+            )
+        )
+        resp = builder.sign(private_key, hashes.SHA256())
+        with pytest.raises(ValueError):
+            resp.extensions
+
     @pytest.mark.parametrize(
         ("status", "der"),
         [
@@ -1437,6 +1656,54 @@ class TestOCSPResponse:
             b'5\x957\x9fa\x03\x83\x87\x89rW\x8f\xae\x99\xf7"'
         )
 
+    def test_produced_at_fractional_seconds(self):
+        resp_bytes = load_vectors_from_file(
+            os.path.join("x509", "ocsp", "resp-produced-at-fractional.der"),
+            lambda data: data.read(),
+            mode="rb",
+        )
+        resp = ocsp.load_der_ocsp_response(resp_bytes)
+        assert resp.produced_at_utc == datetime.datetime(
+            2024, 1, 2, 3, 4, 5, 250000, tzinfo=datetime.timezone.utc
+        )
+        with pytest.warns(utils.DeprecatedIn43):
+            assert resp.produced_at == datetime.datetime(
+                2024, 1, 2, 3, 4, 5, 250000
+            )
+        assert resp.this_update_utc == datetime.datetime(
+            2024, 1, 2, 3, 4, 5, tzinfo=datetime.timezone.utc
+        )
+        # The signed bytes are re-encoded from the parsed response, so this
+        # checks that the fraction survives the round trip unchanged.
+        assert b"20240102030405.25Z" in resp.tbs_response_bytes
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "ca", "ca.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        public_key = issuer.public_key()
+        assert isinstance(public_key, ec.EllipticCurvePublicKey)
+        public_key.verify(
+            resp.signature,
+            resp.tbs_response_bytes,
+            ec.ECDSA(hashes.SHA256()),
+        )
+        assert resp.public_bytes(serialization.Encoding.DER) == resp_bytes
+
+    @pytest.mark.parametrize("fraction", [b".20Z", b".2Z0", b".2+Z"])
+    def test_produced_at_invalid_fractional_seconds(self, fraction):
+        resp_bytes = load_vectors_from_file(
+            os.path.join("x509", "ocsp", "resp-produced-at-fractional.der"),
+            lambda data: data.read(),
+            mode="rb",
+        )
+        # A trailing zero isn't valid DER, and the fraction must be digits
+        # followed by "Z".
+        assert resp_bytes.count(b".25Z") == 1
+        with pytest.raises(ValueError):
+            ocsp.load_der_ocsp_response(
+                resp_bytes.replace(b".25Z", fraction)
+            )
+
     def test_response_unknown_extension(self):
         resp = _load_data(
             os.path.join("x509", "ocsp", "resp-unknown-extension.der"),
@@ -1496,6 +1763,33 @@ class TestOCSPResponse:
         assert ext.oid == x509.CRLReason.oid
         assert ext.value == x509.CRLReason(x509.ReasonFlags.unspecified)
 
+    def test_single_extensions_archive_cutoff(self, backend):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-archive-cutoff.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        ext = resp.single_extensions.get_extension_for_class(
+            x509.OCSPArchiveCutoff
+        )
+        assert ext.oid == x509.OCSPArchiveCutoff.oid
+        assert ext.critical is False
+        assert ext.value == x509.OCSPArchiveCutoff(
+            datetime.datetime(2023, 1, 1)
+        )
+        assert ext.value.archive_cutoff_utc == datetime.datetime(
+            2023, 1, 1, tzinfo=datetime.timezone.utc
+        )
+
+    def test_single_extensions_no_archive_cutoff(self, backend):
+        resp = _load_data(
+            os.path.join("x509", "ocsp", "resp-single-extension-reason.der"),
+            ocsp.load_der_ocsp_response,
+        )
+        with pytest.raises(x509.ExtensionNotFound):
+            resp.single_extensions.get_extension_for_class(
+                x509.OCSPArchiveCutoff
+            )
+
     def test_unknown_response_type(self):
         with pytest.raises(ValueError):
             _load_data(
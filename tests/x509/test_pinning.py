@@ -0,0 +1,57 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+
+import os
+
+import pytest
+
+from cryptography import x509
+from cryptography.x509.pinning import matches_pins, spki_pin
+
+from ..utils import load_vectors_from_file
+
+# Computed with:
+#   openssl x509 -pubkey -noout | openssl pkey -pubin -outform der |
+#       openssl dgst -sha256 -binary | base64
+_LEAF_PIN = "jeHmKR1BO+YKvR3Re25kVbbBci7g3TE513U0i1o2l8I="
+_INTERMEDIATE_PIN = "6X0iNAQtPIjXKEVcqZBwyMcRwq1yW60549axatu3oDE="
+
+
+def _chain():
+    return load_vectors_from_file(
+        os.path.join("x509", "cryptography.io.chain.pem"),
+        lambda pemfile: x509.load_pem_x509_certificates(pemfile.read()),
+        mode="rb",
+    )
+
+
+def test_spki_pin():
+    leaf, intermediate = _chain()
+    assert spki_pin(leaf) == _LEAF_PIN
+    assert spki_pin(leaf.public_key()) == _LEAF_PIN
+    assert spki_pin(intermediate) == _INTERMEDIATE_PIN
+
+
+def test_spki_pin_invalid_type():
+    with pytest.raises(TypeError):
+        spki_pin(object())  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("pins", "expected"),
+    [
+        ([_LEAF_PIN], True),
+        ([_INTERMEDIATE_PIN], True),
+        ({"AAAA", _INTERMEDIATE_PIN}, True),
+        (["AAAA"], False),
+        ([], False),
+    ],
+)
+def test_matches_pins(pins, expected):
+    assert matches_pins(_chain(), pins) is expected
+
+
+def test_matches_pins_empty_chain():
+    assert matches_pins([], [_LEAF_PIN]) is False
@@ -9,11 +9,16 @@ import datetime
 import ipaddress
 import os
 import typing
+import warnings
 
 import pytest
 
 from cryptography import utils, x509
-from cryptography.exceptions import InvalidSignature, UnsupportedAlgorithm
+from cryptography.exceptions import (
+    InvalidSignature,
+    UnsupportedAlgorithm,
+    _Reasons,
+)
 from cryptography.hazmat.bindings._rust import asn1
 from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import (
@@ -430,6 +435,34 @@ class TestCertificateRevocationList:
             [x509.UniformResourceIdentifier("https://cryptography.io")]
         )
 
+    def test_crl_number_and_authority_key_identifier(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_ian_aia_aki.pem"),
+            x509.load_pem_x509_crl,
+        )
+        assert crl.crl_number == 1
+        assert crl.authority_key_identifier == x509.AuthorityKeyIdentifier(
+            key_identifier=(b"yu\xbb\x84:\xcb,\xdez\t\xbe1\x1bC\xbc\x1c*MSX"),
+            authority_cert_issuer=None,
+            authority_cert_serial_number=None,
+        )
+        assert crl.crl_number == (
+            crl.extensions.get_extension_for_class(x509.CRLNumber).value
+        ).crl_number
+        assert crl.authority_key_identifier == (
+            crl.extensions.get_extension_for_class(
+                x509.AuthorityKeyIdentifier
+            ).value
+        )
+
+    def test_crl_number_and_authority_key_identifier_absent(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_all_reasons.pem"),
+            x509.load_pem_x509_crl,
+        )
+        assert crl.crl_number is None
+        assert crl.authority_key_identifier is None
+
     def test_delta_crl_indicator(self, backend):
         crl = _load_cert(
             os.path.join("x509", "custom", "crl_delta_crl_indicator.pem"),
@@ -603,6 +636,143 @@ class TestCertificateRevocationList:
         with pytest.raises(TypeError):
             crl.is_signature_valid(object)  # type: ignore[arg-type]
 
+    def test_verify_directly_issued_by_rsa(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "valid_signature_crl.pem"),
+            x509.load_pem_x509_crl,
+        )
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "valid_signature_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        crl.verify_directly_issued_by(issuer)
+
+    def test_verify_directly_issued_by_rsa_bad_sig(self, backend):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "invalid_signature_crl.pem"),
+            x509.load_pem_x509_crl,
+        )
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "invalid_signature_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(InvalidSignature):
+            crl.verify_directly_issued_by(issuer)
+
+    def test_verify_directly_issued_by_mismatched_inner_out_oid(
+        self, backend
+    ):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "crl_inner_outer_mismatch.der"),
+            x509.load_der_x509_crl,
+        )
+        issuer = _load_cert(
+            os.path.join("x509", "custom", "valid_signature_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(ValueError, match="Inner and outer signature"):
+            crl.verify_directly_issued_by(issuer)
+
+    def test_verify_directly_issued_by_subject_issuer_mismatch(
+        self, backend
+    ):
+        crl = _load_cert(
+            os.path.join("x509", "custom", "valid_signature_crl.pem"),
+            x509.load_pem_x509_crl,
+        )
+        issuer = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(ValueError, match="does not match CRL issuer"):
+            crl.verify_directly_issued_by(issuer)
+
+    @staticmethod
+    def _issuer_and_crl(private_key, algorithm, key_usage=None):
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CRL CA")])
+        builder = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+        )
+        if key_usage is not None:
+            builder = builder.add_extension(key_usage, critical=True)
+        issuer = builder.sign(private_key, algorithm)
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(name)
+            .last_update(datetime.datetime(2024, 1, 1))
+            .next_update(datetime.datetime(2024, 2, 1))
+            .sign(private_key, algorithm)
+        )
+        return issuer, crl
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support",
+    )
+    @pytest.mark.parametrize(
+        ("private_key", "algorithm"),
+        [
+            (EC_KEY_SECP256R1.private_key(), hashes.SHA256()),
+            (ed25519.Ed25519PrivateKey.generate, None),
+        ],
+    )
+    def test_verify_directly_issued_by_key_types(
+        self, backend, private_key, algorithm
+    ):
+        if callable(private_key):
+            private_key = private_key()
+        issuer, crl = self._issuer_and_crl(private_key, algorithm)
+        crl.verify_directly_issued_by(issuer)
+
+        # Corrupt the last byte of the signature.
+        der = bytearray(crl.public_bytes(serialization.Encoding.DER))
+        der[-1] ^= 0x01
+        tampered = x509.load_der_x509_crl(bytes(der))
+        with pytest.raises(InvalidSignature):
+            tampered.verify_directly_issued_by(issuer)
+
+    def test_verify_directly_issued_by_key_usage(self, backend):
+        private_key = EC_KEY_SECP256R1.private_key()
+        key_usage = x509.KeyUsage(
+            digital_signature=True,
+            content_commitment=False,
+            key_encipherment=False,
+            data_encipherment=False,
+            key_agreement=False,
+            key_cert_sign=True,
+            crl_sign=False,
+            encipher_only=False,
+            decipher_only=False,
+        )
+        issuer, crl = self._issuer_and_crl(
+            private_key, hashes.SHA256(), key_usage
+        )
+        with pytest.raises(ValueError, match="does not permit CRL signing"):
+            crl.verify_directly_issued_by(issuer)
+
+        issuer, crl = self._issuer_and_crl(
+            private_key,
+            hashes.SHA256(),
+            x509.KeyUsage(
+                digital_signature=False,
+                content_commitment=False,
+                key_encipherment=False,
+                data_encipherment=False,
+                key_agreement=False,
+                key_cert_sign=True,
+                crl_sign=True,
+                encipher_only=False,
+                decipher_only=False,
+            ),
+        )
+        crl.verify_directly_issued_by(issuer)
+
 
 class TestRevokedCertificate:
     def test_revoked_basics(self, backend):
@@ -689,6 +859,120 @@ class TestRevokedCertificate:
 
         assert len(flags) == 0
 
+    def test_certificate_issuer(self, backend):
+        private_key = EC_KEY_SECP256R1.private_key()
+        crl_issuer = x509.Name(
+            [x509.NameAttribute(NameOID.COMMON_NAME, "CRL Distributor")]
+        )
+        issuer_a = [x509.DNSName("a.example.com")]
+        issuer_b = [
+            x509.DirectoryName(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CA B")])
+            )
+        ]
+
+        def revoked(serial, issuer=None):
+            builder = (
+                x509.RevokedCertificateBuilder()
+                .serial_number(serial)
+                .revocation_date(datetime.datetime(2024, 1, 1))
+            )
+            if issuer is not None:
+                builder = builder.add_extension(
+                    x509.CertificateIssuer(issuer), critical=True
+                )
+            return builder.build()
+
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(crl_issuer)
+            .last_update(datetime.datetime(2024, 1, 1))
+            .next_update(datetime.datetime(2024, 2, 1))
+            .add_extension(
+                x509.IssuingDistributionPoint(
+                    full_name=None,
+                    relative_name=None,
+                    only_contains_user_certs=False,
+                    only_contains_ca_certs=False,
+                    only_some_reasons=None,
+                    indirect_crl=True,
+                    only_contains_attribute_certs=False,
+                ),
+                critical=True,
+            )
+        )
+        for r in [
+            revoked(1),
+            revoked(2, issuer_a),
+            revoked(3),
+            revoked(4, issuer_b),
+            revoked(5),
+        ]:
+            builder = builder.add_revoked_certificate(r)
+        crl = builder.sign(private_key, hashes.SHA256())
+
+        expected = [
+            [x509.DirectoryName(crl_issuer)],
+            issuer_a,
+            issuer_a,
+            issuer_b,
+            issuer_b,
+        ]
+        assert [r.certificate_issuer for r in crl] == expected
+        assert [crl[i].certificate_issuer for i in range(5)] == expected
+        assert [r.certificate_issuer for r in crl[1:4]] == expected[1:4]
+        for serial, issuer in zip(range(1, 6), expected):
+            r = crl.get_revoked_certificate_by_serial_number(serial)
+            assert r is not None
+            assert r.certificate_issuer == issuer
+
+        assert revoked(1).certificate_issuer is None
+        assert revoked(2, issuer_a).certificate_issuer == issuer_a
+
+    @pytest.mark.parametrize("indirect_crl", [None, False])
+    def test_certificate_issuer_not_indirect(self, indirect_crl, backend):
+        private_key = EC_KEY_SECP256R1.private_key()
+        crl_issuer = x509.Name(
+            [x509.NameAttribute(NameOID.COMMON_NAME, "CRL Issuer")]
+        )
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(crl_issuer)
+            .last_update(datetime.datetime(2024, 1, 1))
+            .next_update(datetime.datetime(2024, 2, 1))
+        )
+        if indirect_crl is not None:
+            builder = builder.add_extension(
+                x509.IssuingDistributionPoint(
+                    full_name=None,
+                    relative_name=None,
+                    only_contains_user_certs=True,
+                    only_contains_ca_certs=False,
+                    only_some_reasons=None,
+                    indirect_crl=indirect_crl,
+                    only_contains_attribute_certs=False,
+                ),
+                critical=True,
+            )
+        for serial in [1, 2]:
+            revoked = (
+                x509.RevokedCertificateBuilder()
+                .serial_number(serial)
+                .revocation_date(datetime.datetime(2024, 1, 1))
+            )
+            if serial == 2:
+                revoked = revoked.add_extension(
+                    x509.CertificateIssuer([x509.DNSName("a.example.com")]),
+                    critical=True,
+                )
+            builder = builder.add_revoked_certificate(revoked.build())
+        crl = builder.sign(private_key, hashes.SHA256())
+
+        # Without the indirectCRL flag, a certificateIssuer entry is invalid.
+        assert crl[0].certificate_issuer == [x509.DirectoryName(crl_issuer)]
+        with pytest.raises(ValueError):
+            crl[1].certificate_issuer
+
     def test_no_revoked_certs(self, backend):
         crl = _load_cert(
             os.path.join("x509", "custom", "crl_empty.pem"),
@@ -920,6 +1204,93 @@ class TestRSAPSSCertificate:
         with pytest.raises(ValueError):
             cert.signature_hash_algorithm
 
+    def test_unrestricted_public_key(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "rsa_pss_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.public_key_algorithm_parameters is None
+        assert cert.public_key_hash_algorithm is None
+
+    @pytest.mark.parametrize(
+        ("path", "loader_func"),
+        [
+            [
+                os.path.join("x509", "custom", "rsa_pss_restricted_cert.pem"),
+                x509.load_pem_x509_certificate,
+            ],
+            [
+                os.path.join("x509", "requests", "rsa_pss_restricted.pem"),
+                x509.load_pem_x509_csr,
+            ],
+        ],
+    )
+    def test_restricted_public_key(self, path, loader_func, backend):
+        cert = _load_cert(path, loader_func)
+        assert (
+            cert.public_key_algorithm_oid == PublicKeyAlgorithmOID.RSASSA_PSS
+        )
+        pss = cert.public_key_algorithm_parameters
+        assert isinstance(pss, padding.PSS)
+        assert isinstance(pss._mgf, padding.MGF1)
+        assert isinstance(pss._mgf._algorithm, hashes.SHA256)
+        assert pss._salt_length == 32
+        assert isinstance(cert.public_key_hash_algorithm, hashes.SHA256)
+        # The restrictions are usable to verify the self-signature.
+        public_key = cert.public_key()
+        assert isinstance(public_key, rsa.RSAPublicKey)
+        public_key.verify(
+            cert.signature,
+            cert.tbs_certificate_bytes
+            if isinstance(cert, x509.Certificate)
+            else cert.tbs_certrequest_bytes,
+            pss,
+            cert.public_key_hash_algorithm,
+        )
+
+    def test_load_pss_csr(self, backend):
+        csr = _load_cert(
+            os.path.join("x509", "requests", "rsa_pss_restricted.pem"),
+            x509.load_pem_x509_csr,
+        )
+        assert csr.signature_algorithm_oid == SignatureAlgorithmOID.RSASSA_PSS
+        assert isinstance(csr.signature_hash_algorithm, hashes.SHA256)
+        pss = csr.signature_algorithm_parameters
+        assert isinstance(pss, padding.PSS)
+        assert isinstance(pss._mgf, padding.MGF1)
+        assert isinstance(pss._mgf._algorithm, hashes.SHA256)
+        assert pss._salt_length == 32
+        assert csr.is_signature_valid
+
+    def test_pss_csr_bad_signature(self, backend):
+        csr = _load_cert(
+            os.path.join("x509", "requests", "rsa_pss_restricted.pem"),
+            x509.load_pem_x509_csr,
+        )
+        der = bytearray(csr.public_bytes(serialization.Encoding.DER))
+        # The signature is the last field of the CSR.
+        der[-1] ^= 1
+        tampered = x509.load_der_x509_csr(bytes(der))
+        assert not tampered.is_signature_valid
+
+
+class TestRSAOAEPPublicKey:
+    def test_restricted_public_key(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "rsa_oaep_restricted_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert (
+            cert.public_key_algorithm_oid == PublicKeyAlgorithmOID.RSAES_OAEP
+        )
+        oaep = cert.public_key_algorithm_parameters
+        assert isinstance(oaep, padding.OAEP)
+        assert isinstance(oaep.mgf, padding.MGF1)
+        assert isinstance(oaep.mgf._algorithm, hashes.SHA256)
+        assert isinstance(oaep.algorithm, hashes.SHA256)
+        assert oaep._label == b"label"
+        assert isinstance(cert.public_key_hash_algorithm, hashes.SHA256)
+
 
 class TestRSACertificate:
     def test_load_pem_cert(self, backend):
@@ -1008,6 +1379,75 @@ class TestRSACertificate:
 
         with pytest.warns(utils.DeprecatedIn36):
             assert cert.serial_number == -18008675309
+        assert cert.serial_number_is_negative is True
+
+    def test_serial_number_is_negative(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.serial_number > 0
+        assert cert.serial_number_is_negative is False
+
+    @pytest.mark.parametrize(
+        ("serial", "expected_raw"),
+        [
+            (2, b"\x02"),
+            (0x7F, b"\x7f"),
+            (0x80, b"\x00\x80"),
+            # The largest serial number RFC 5280 allows, 20 octets with the
+            # high bit of the first octet clear.
+            ((1 << 159) - 1, b"\x7f" + b"\xff" * 19),
+        ],
+    )
+    def test_serial_number_raw(
+        self, rsa_key_2048: rsa.RSAPrivateKey, serial, expected_raw, backend
+    ):
+        cert = (
+            x509.CertificateBuilder()
+            .serial_number(serial)
+            .issuer_name(x509.Name([]))
+            .subject_name(x509.Name([]))
+            .public_key(rsa_key_2048.public_key())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2038, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        assert cert.serial_number_raw == (expected_raw, serial)
+        assert cert.serial_number == serial
+
+    def test_serial_number_raw_leading_zero(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "post2000utctime.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        raw, value = cert.serial_number_raw
+        assert raw[0] == 0
+        assert raw[1] & 0x80
+        assert int.from_bytes(raw, "big") == value == cert.serial_number
+
+    def test_serial_number_raw_negative(self, backend):
+        with pytest.warns(utils.DeprecatedIn36):
+            cert = _load_cert(
+                os.path.join("x509", "custom", "negative_serial.pem"),
+                x509.load_pem_x509_certificate,
+            )
+        with pytest.warns(utils.DeprecatedIn36):
+            raw, value = cert.serial_number_raw
+        assert value == -18008675309
+        assert int.from_bytes(raw, "big", signed=True) == value
+
+    def test_negative_serial_number_round_trip(self, backend):
+        with pytest.warns(utils.DeprecatedIn36):
+            cert = _load_cert(
+                os.path.join("x509", "custom", "negative_serial.pem"),
+                x509.load_pem_x509_certificate,
+            )
+        der = cert.public_bytes(serialization.Encoding.DER)
+        with pytest.warns(utils.DeprecatedIn36):
+            reloaded = x509.load_der_x509_certificate(der)
+        assert reloaded.serial_number_is_negative
+        assert reloaded == cert
 
     def test_country_jurisdiction_country_too_long(self, backend):
         cert = _load_cert(
@@ -1163,6 +1603,60 @@ class TestRSACertificate:
         ):
             cert.tbs_precertificate_bytes
 
+    def test_raw_extensions(self, rsa_key_2048: rsa.RSAPrivateKey, backend):
+        unknown = x509.UnrecognizedExtension(
+            x509.ObjectIdentifier("1.2.3.4"), b"\x05\x00"
+        )
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "test")])
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(x509.BasicConstraints(True, None), critical=True)
+            .add_extension(unknown, critical=True)
+            .add_extension(x509.OCSPNoCheck(), critical=False)
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        assert cert.raw_extensions == [
+            (
+                ExtensionOID.BASIC_CONSTRAINTS,
+                True,
+                b"0\x03\x01\x01\xff",
+                False,
+            ),
+            (x509.ObjectIdentifier("1.2.3.4"), True, b"\x05\x00", False),
+            (ExtensionOID.OCSP_NO_CHECK, False, b"\x05\x00", False),
+        ]
+
+    def test_raw_extensions_duplicates(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "two_basic_constraints.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(x509.DuplicateExtension):
+            cert.extensions
+
+        assert cert.raw_extensions == [
+            (ExtensionOID.BASIC_CONSTRAINTS, True, b"0\x03\x01\x01\xff", True),
+            (
+                ExtensionOID.BASIC_CONSTRAINTS,
+                True,
+                b"0\x06\x01\x01\xff\x02\x01\x00",
+                True,
+            ),
+        ]
+
+    def test_raw_extensions_none(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "v1_cert.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        assert cert.raw_extensions == []
+
     def test_tbs_precertificate_bytes_missing_extension_raises(self, backend):
         cert = _load_cert(
             os.path.join("x509", "cryptography.io.pem"),
@@ -1470,6 +1964,107 @@ class TestRSACertificate:
         )
         assert cert.version is x509.Version.v3
 
+    def test_validity_status(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "ValidGeneralizedTimenotAfterDateTest8EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        utc = datetime.timezone.utc
+        not_before = datetime.datetime(2010, 1, 1, 8, 30, tzinfo=utc)
+        not_after = datetime.datetime(2050, 1, 1, 12, 1, tzinfo=utc)
+
+        def seconds_until_expiry(time):
+            return int((not_after - time).total_seconds())
+
+        for time, status in [
+            (
+                datetime.datetime(2020, 6, 15, tzinfo=utc),
+                x509.CertificateValidityStatus.VALID,
+            ),
+            (not_before, x509.CertificateValidityStatus.VALID),
+            (not_after, x509.CertificateValidityStatus.VALID),
+            (
+                not_after + datetime.timedelta(seconds=1),
+                x509.CertificateValidityStatus.EXPIRED,
+            ),
+            (
+                datetime.datetime(2051, 1, 1, tzinfo=utc),
+                x509.CertificateValidityStatus.EXPIRED,
+            ),
+            (
+                not_before - datetime.timedelta(seconds=1),
+                x509.CertificateValidityStatus.NOT_YET_VALID,
+            ),
+            (
+                datetime.datetime(1960, 2, 29, tzinfo=utc),
+                x509.CertificateValidityStatus.NOT_YET_VALID,
+            ),
+        ]:
+            assert cert.validity_status(time) == (
+                status,
+                seconds_until_expiry(time),
+            )
+
+    def test_validity_status_utc_time(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "post2000utctime.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        time = cert.not_valid_after_utc + datetime.timedelta(days=1)
+        assert cert.validity_status(time) == (
+            x509.CertificateValidityStatus.EXPIRED,
+            -86400,
+        )
+
+    def test_validity_status_naive_and_aware(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "ValidGeneralizedTimenotAfterDateTest8EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        naive = datetime.datetime(2050, 1, 1, 12, 0)
+        aware = datetime.datetime(
+            2050,
+            1,
+            1,
+            7,
+            0,
+            tzinfo=datetime.timezone(datetime.timedelta(hours=-5)),
+        )
+        expected = (x509.CertificateValidityStatus.VALID, 60)
+        assert cert.validity_status(naive) == expected
+        assert cert.validity_status(aware) == expected
+
+    def test_validity_status_now(self, backend):
+        cert = _load_cert(
+            os.path.join(
+                "x509",
+                "PKITS_data",
+                "certs",
+                "ValidGeneralizedTimenotAfterDateTest8EE.crt",
+            ),
+            x509.load_der_x509_certificate,
+        )
+        before = datetime.datetime.now(datetime.timezone.utc)
+        status, seconds = cert.validity_status()
+        after = datetime.datetime.now(datetime.timezone.utc)
+        assert status is x509.CertificateValidityStatus.VALID
+        not_after = cert.not_valid_after_utc
+        assert (
+            int((not_after - after).total_seconds()) - 1
+            <= seconds
+            <= int((not_after - before).total_seconds()) + 1
+        )
+
     def test_invalid_version_cert(self, backend):
         with pytest.raises(x509.InvalidVersion) as exc:
             _load_cert(
@@ -1702,8 +2297,62 @@ class TestRSACertificate:
             [x509.TLSFeatureType.status_request]
         )
 
+    def test_build_must_staple(self, rsa_key_2048: rsa.RSAPrivateKey):
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+            )
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+            )
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .serial_number(1)
+            .public_key(rsa_key_2048.public_key())
+            .add_extension(
+                x509.TLSFeature([x509.TLSFeatureType.status_request]),
+                critical=False,
+            )
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        ext = cert.extensions.get_extension_for_class(x509.TLSFeature)
+        assert ext.critical is False
+        assert ext.value == x509.TLSFeature(
+            [x509.TLSFeatureType.status_request]
+        )
+        assert ext.value.public_bytes() == b"\x30\x03\x02\x01\x05"
+
+    def test_parse_unknown_tls_feature(self, rsa_key_2048: rsa.RSAPrivateKey):
+        # A feature list containing a type (99) that has no TLSFeatureType
+        # member is surfaced as an unrecognized extension.
+        unrecognized = x509.UnrecognizedExtension(
+            ExtensionOID.TLS_FEATURE, b"\x30\x06\x02\x01\x05\x02\x01\x63"
+        )
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+            )
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+            )
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .serial_number(1)
+            .public_key(rsa_key_2048.public_key())
+            .add_extension(unrecognized, critical=False)
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        ext = cert.extensions.get_extension_for_oid(ExtensionOID.TLS_FEATURE)
+        assert ext.value == unrecognized
+
+    @pytest.mark.parametrize(
+        "mgf_alg",
+        [hashes.SHA256(), hashes.SHA1()],
+    )
     def test_verify_directly_issued_by_rsa_pss(
-        self, rsa_key_2048: rsa.RSAPrivateKey
+        self, rsa_key_2048: rsa.RSAPrivateKey, mgf_alg
     ):
         subject_private_key = RSA_KEY_2048_ALT.private_key(
             unsafe_skip_rsa_key_validation=True
@@ -1740,7 +2389,7 @@ class TestRSACertificate:
             rsa_key_2048,
             hashes.SHA256(),
             rsa_padding=padding.PSS(
-                padding.MGF1(hashes.SHA256()),
+                padding.MGF1(mgf_alg),
                 salt_length=padding.PSS.DIGEST_LENGTH,
             ),
         )
@@ -1888,6 +2537,8 @@ class TestRSACertificateRequest:
             request.public_key_algorithm_oid
             == PublicKeyAlgorithmOID.RSAES_PKCS1_v1_5
         )
+        assert request.public_key_algorithm_parameters is None
+        assert request.public_key_hash_algorithm is None
         subject = request.subject
         assert isinstance(subject, x509.Name)
         assert list(subject) == [
@@ -2144,6 +2795,16 @@ class TestRSACertificateRequest:
         )
         assert not request.is_signature_valid
 
+    def test_signature_valid_unsupported_algorithm(self, backend):
+        # Only an invalid signature makes this False; being unable to check
+        # the signature at all is an error.
+        request = _load_cert(
+            os.path.join("x509", "requests", "rsa_md4.pem"),
+            x509.load_pem_x509_csr,
+        )
+        with pytest.raises(ValueError):
+            request.is_signature_valid
+
     def test_signature_valid(self, backend):
         request = _load_cert(
             os.path.join("x509", "requests", "rsa_sha256.pem"),
@@ -2151,6 +2812,48 @@ class TestRSACertificateRequest:
         )
         assert request.is_signature_valid
 
+    @pytest.mark.parametrize(
+        "path",
+        [
+            os.path.join("x509", "requests", "rsa_sha256.pem"),
+            os.path.join("x509", "requests", "ec_sha256.pem"),
+        ],
+    )
+    def test_verify_signature(self, backend, path):
+        request = _load_cert(path, x509.load_pem_x509_csr)
+        request.verify_signature()
+
+    def test_verify_signature_invalid(self, backend):
+        request = _load_cert(
+            os.path.join("x509", "requests", "invalid_signature.pem"),
+            x509.load_pem_x509_csr,
+        )
+        with pytest.raises(InvalidSignature):
+            request.verify_signature()
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support",
+    )
+    def test_verify_signature_ed25519(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        request = (
+            x509.CertificateSigningRequestBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COUNTRY_NAME, "US")])
+            )
+            .sign(private_key, None)
+        )
+        request.verify_signature()
+
+        # The signature is the final element of the CSR, so flipping the
+        # last byte corrupts it while leaving the structure intact.
+        der = bytearray(request.public_bytes(serialization.Encoding.DER))
+        der[-1] ^= 0x01
+        tampered = x509.load_der_x509_csr(bytes(der))
+        with pytest.raises(InvalidSignature):
+            tampered.verify_signature()
+
     @pytest.mark.parametrize(
         ("request_path", "loader_func", "encoding"),
         [
@@ -2664,9 +3367,75 @@ class TestCertificateBuilder:
             NameOID.SURNAME, "RDNs"
         )
 
+    def test_multivalue_rdn_round_trip(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        name = x509.Name(
+            [
+                x509.RelativeDistinguishedName(
+                    [x509.NameAttribute(NameOID.COUNTRY_NAME, "US")]
+                ),
+                x509.RelativeDistinguishedName(
+                    [
+                        x509.NameAttribute(NameOID.COMMON_NAME, "Test"),
+                        x509.NameAttribute(NameOID.USER_ID, "jdoe"),
+                    ]
+                ),
+            ]
+        )
+
+        cert = (
+            x509.CertificateBuilder()
+            .serial_number(1)
+            .issuer_name(name)
+            .subject_name(name)
+            .public_key(rsa_key_2048.public_key())
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2038, 1, 1))
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        der = cert.public_bytes(serialization.Encoding.DER)
+        loaded_cert = x509.load_der_x509_certificate(der)
+        assert loaded_cert.subject == name
+        assert loaded_cert.issuer == name
+        assert len(loaded_cert.subject.rdns) == 2
+        assert len(loaded_cert.subject.rdns[1]) == 2
+        assert loaded_cert.subject.rfc4514_string() == "CN=Test+UID=jdoe,C=US"
+        assert loaded_cert.subject.get_attributes_for_oid(NameOID.USER_ID) == [
+            x509.NameAttribute(NameOID.USER_ID, "jdoe")
+        ]
+
+        parsed = asn1.test_parse_certificate(der)
+        # Every attribute of the multi-valued RDN is reported.
+        assert parsed.subject_value_tags == [0x13, 0x0C, 0x0C]
+        assert parsed.issuer_value_tags == [0x13, 0x0C, 0x0C]
+
+    def test_multivalue_rdn_from_rfc4514_string(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        name = x509.Name.from_rfc4514_string("CN=Test+UID=jdoe,C=US")
+        assert len(name.rdns) == 2
+        assert name.rdns[1] == x509.RelativeDistinguishedName(
+            [
+                x509.NameAttribute(NameOID.COMMON_NAME, "Test"),
+                x509.NameAttribute(NameOID.USER_ID, "jdoe"),
+            ]
+        )
+
+        csr = (
+            x509.CertificateSigningRequestBuilder()
+            .subject_name(name)
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        loaded_csr = x509.load_der_x509_csr(
+            csr.public_bytes(serialization.Encoding.DER)
+        )
+        assert loaded_csr.subject == name
+
     @pytest.mark.parametrize(
         ("alg", "mgf_alg"),
         [
+            (hashes.SHA256(), hashes.SHA1()),
             (hashes.SHA512(), hashes.SHA256()),
             (hashes.SHA3_512(), hashes.SHA3_256()),
         ],
@@ -3095,6 +3864,48 @@ class TestCertificateBuilder:
         assert parsed.not_before_tag == 0x17
         assert parsed.not_after_tag == 0x17
 
+    @pytest.mark.parametrize(
+        ("time", "tag"),
+        [
+            # RFC 5280 section 4.1.2.5: UTCTime through 2049, GeneralizedTime
+            # from 2050 onwards.
+            (datetime.datetime(2049, 12, 31, 23, 59, 59), 0x17),
+            (datetime.datetime(2050, 1, 1, 0, 0, 0), 0x18),
+            (
+                datetime.datetime(
+                    2050, 1, 1, 0, 0, 0, tzinfo=datetime.timezone.utc
+                ),
+                0x18,
+            ),
+        ],
+    )
+    def test_time_type_2050_boundary(
+        self, rsa_key_2048: rsa.RSAPrivateKey, time, tag, backend
+    ):
+        private_key = rsa_key_2048
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COUNTRY_NAME, "US")])
+            )
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COUNTRY_NAME, "US")])
+            )
+            .serial_number(1)
+            .public_key(private_key.public_key())
+            .not_valid_before(time)
+            .not_valid_after(time)
+            .sign(private_key, hashes.SHA256(), backend)
+        )
+        parsed = asn1.test_parse_certificate(
+            cert.public_bytes(serialization.Encoding.DER)
+        )
+        assert parsed.not_before_tag == tag
+        assert parsed.not_after_tag == tag
+        assert cert.not_valid_before_utc == time.replace(
+            tzinfo=datetime.timezone.utc
+        )
+
     def test_invalid_not_valid_after(self):
         with pytest.raises(TypeError):
             x509.CertificateBuilder().not_valid_after(
@@ -3950,6 +4761,18 @@ class TestCertificateBuilder:
             x509.PolicyConstraints(
                 require_explicit_policy=0, inhibit_policy_mapping=None
             ),
+            x509.PolicyMappings(
+                [
+                    x509.PolicyMapping(
+                        x509.ObjectIdentifier("1.2.3"),
+                        x509.ObjectIdentifier("1.2.4"),
+                    ),
+                    x509.PolicyMapping(
+                        x509.ObjectIdentifier("1.2.5"),
+                        x509.ObjectIdentifier("1.2.6"),
+                    ),
+                ]
+            ),
             x509.CRLDistributionPoints(
                 [
                     x509.DistributionPoint(
@@ -4366,6 +5189,182 @@ class TestCertificateBuilder:
         assert ext.value == unrecognized
 
 
+class TestCreateSelfSignedCertificate:
+    _NAME = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "Root")])
+    _NOT_BEFORE = datetime.datetime(2002, 1, 1, 12, 1)
+    _NOT_AFTER = datetime.datetime(2030, 12, 31, 8, 30)
+
+    def test_ec(self, backend):
+        _skip_curve_unsupported(backend, ec.SECP256R1())
+        private_key = ec.generate_private_key(ec.SECP256R1(), backend)
+        basic_constraints = x509.Extension(
+            ExtensionOID.BASIC_CONSTRAINTS,
+            True,
+            x509.BasicConstraints(ca=True, path_length=None),
+        )
+        cert = x509.create_self_signed_certificate(
+            self._NAME,
+            private_key,
+            self._NOT_BEFORE,
+            self._NOT_AFTER,
+            hashes.SHA256(),
+            extensions=[basic_constraints],
+            serial_number=123,
+        )
+
+        assert cert.version is x509.Version.v3
+        assert cert.serial_number == 123
+        assert cert.subject == self._NAME
+        assert cert.issuer == self._NAME
+        assert cert.not_valid_before_utc == self._NOT_BEFORE.replace(
+            tzinfo=datetime.timezone.utc
+        )
+        assert cert.not_valid_after_utc == self._NOT_AFTER.replace(
+            tzinfo=datetime.timezone.utc
+        )
+        assert cert.public_key() == private_key.public_key()
+        assert isinstance(cert.signature_hash_algorithm, hashes.SHA256)
+        cert.verify_directly_issued_by(cert)
+
+        ski = x509.SubjectKeyIdentifier.from_public_key(
+            private_key.public_key()
+        )
+        assert list(cert.extensions) == [
+            x509.Extension(ExtensionOID.SUBJECT_KEY_IDENTIFIER, False, ski),
+            x509.Extension(
+                ExtensionOID.AUTHORITY_KEY_IDENTIFIER,
+                False,
+                x509.AuthorityKeyIdentifier.from_issuer_subject_key_identifier(
+                    ski
+                ),
+            ),
+            basic_constraints,
+        ]
+
+    def test_matches_builder(self, rsa_key_2048: rsa.RSAPrivateKey, backend):
+        private_key = rsa_key_2048
+        ski = x509.SubjectKeyIdentifier.from_public_key(
+            private_key.public_key()
+        )
+        built = (
+            x509.CertificateBuilder()
+            .subject_name(self._NAME)
+            .issuer_name(self._NAME)
+            .public_key(private_key.public_key())
+            .serial_number(123)
+            .not_valid_before(self._NOT_BEFORE)
+            .not_valid_after(self._NOT_AFTER)
+            .add_extension(ski, critical=False)
+            .add_extension(
+                x509.AuthorityKeyIdentifier.from_issuer_subject_key_identifier(
+                    ski
+                ),
+                critical=False,
+            )
+            .sign(private_key, hashes.SHA256(), backend)
+        )
+        cert = x509.create_self_signed_certificate(
+            self._NAME,
+            private_key,
+            self._NOT_BEFORE,
+            self._NOT_AFTER,
+            hashes.SHA256(),
+            serial_number=123,
+        )
+        # PKCS#1 v1.5 signatures are deterministic, so the certificates are
+        # byte for byte identical.
+        assert cert == built
+
+    def test_random_serial_number(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        cert = x509.create_self_signed_certificate(
+            self._NAME, private_key, self._NOT_BEFORE, self._NOT_AFTER, None
+        )
+        assert 0 < cert.serial_number < 2**159
+        cert.verify_directly_issued_by(cert)
+
+    def test_key_identifiers_not_allowed(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        ski = x509.SubjectKeyIdentifier.from_public_key(
+            private_key.public_key()
+        )
+        with pytest.raises(ValueError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                self._NOT_BEFORE,
+                self._NOT_AFTER,
+                None,
+                extensions=[x509.Extension(ski.oid, False, ski)],
+            )
+
+    def test_invalid_arguments(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        with pytest.raises(TypeError):
+            x509.create_self_signed_certificate(
+                "notaname",  # type: ignore[arg-type]
+                private_key,
+                self._NOT_BEFORE,
+                self._NOT_AFTER,
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                "notadate",  # type: ignore[arg-type]
+                self._NOT_AFTER,
+                None,
+            )
+        with pytest.raises(ValueError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                self._NOT_AFTER,
+                self._NOT_BEFORE,
+                None,
+            )
+        with pytest.raises(ValueError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                datetime.datetime(1940, 1, 1),
+                self._NOT_AFTER,
+                None,
+            )
+        with pytest.raises(ValueError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                self._NOT_BEFORE,
+                self._NOT_AFTER,
+                None,
+                serial_number=0,
+            )
+        with pytest.raises(TypeError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                self._NOT_BEFORE,
+                self._NOT_AFTER,
+                None,
+                extensions=[
+                    x509.BasicConstraints(  # type: ignore[list-item]
+                        ca=True, path_length=None
+                    )
+                ],
+            )
+        with pytest.raises(TypeError):
+            x509.create_self_signed_certificate(
+                self._NAME,
+                private_key,
+                self._NOT_BEFORE,
+                self._NOT_AFTER,
+                None,
+                rsa_padding=padding.PKCS1v15(),
+            )
+
+
 class TestCertificateSigningRequestBuilder:
     def test_sign_invalid_hash_algorithm(
         self, rsa_key_2048: rsa.RSAPrivateKey, backend
@@ -5008,6 +6007,7 @@ class TestCertificateSigningRequestBuilder:
                         type_id=x509.ObjectIdentifier("1.2.3.3.3.3"),
                         value=b"0\x03\x02\x01\x05",
                     ),
+                    x509.X400Address(b"0\x03\x13\x01a"),
                     x509.RFC822Name("test@example.com"),
                     x509.RFC822Name("email"),
                     x509.RFC822Name("email@xn--eml-vla4c.com"),
@@ -5076,6 +6076,31 @@ class TestCertificateSigningRequestBuilder:
         with pytest.raises(ValueError):
             builder.sign(private_key, hashes.SHA256(), backend)
 
+    @pytest.mark.parametrize(
+        "value",
+        [
+            # Not a SEQUENCE
+            b"\x13\x01a",
+            # Invalid length
+            b"0\x05\x13\x01a",
+        ],
+    )
+    def test_invalid_asn1_x400address(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend, value
+    ):
+        builder = (
+            x509.CertificateSigningRequestBuilder()
+            .subject_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "SAN")])
+            )
+            .add_extension(
+                x509.SubjectAlternativeName([x509.X400Address(value)]),
+                critical=False,
+            )
+        )
+        with pytest.raises(ValueError):
+            builder.sign(rsa_key_2048, hashes.SHA256(), backend)
+
     def test_subject_alt_name_unsupported_general_name(
         self, rsa_key_2048: rsa.RSAPrivateKey, backend
     ):
@@ -5108,6 +6133,7 @@ class TestCertificateSigningRequestBuilder:
     @pytest.mark.parametrize(
         ("alg", "mgf_alg"),
         [
+            (hashes.SHA256(), hashes.SHA1()),
             (hashes.SHA512(), hashes.SHA256()),
             (hashes.SHA3_512(), hashes.SHA3_256()),
         ],
@@ -5138,6 +6164,7 @@ class TestCertificateSigningRequestBuilder:
             cert_params,
             alg,
         )
+        assert csr.is_signature_valid
 
     @pytest.mark.parametrize(
         ("padding_len", "computed_len"),
@@ -5464,6 +6491,63 @@ class TestGOSTCertificate:
             == "007710474375"
         )
 
+    def test_load_gost_cert_metadata(self):
+        cert = _load_cert(
+            os.path.join("x509", "e-trust.ru.der"),
+            x509.load_der_x509_certificate,
+        )
+        assert (
+            cert.signature_algorithm_oid
+            == SignatureAlgorithmOID.GOSTR3411_94_WITH_3410_2001
+        )
+        assert (
+            cert.public_key_algorithm_oid
+            == PublicKeyAlgorithmOID.GOSTR3410_2001
+        )
+        assert cert.signature_hash_algorithm is None
+        assert cert.signature_algorithm_parameters is None
+        assert cert.issuer == cert.subject
+        assert cert.serial_number > 0
+
+    def test_gost_public_key_unsupported(self):
+        cert = _load_cert(
+            os.path.join("x509", "e-trust.ru.der"),
+            x509.load_der_x509_certificate,
+        )
+        with raises_unsupported_algorithm(
+            _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM
+        ) as exc:
+            cert.public_key()
+        assert "GOST R 34.10-2001" in str(exc.value)
+
+        with raises_unsupported_algorithm(
+            _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM
+        ) as exc:
+            cert.verify_directly_issued_by(cert)
+        assert "GOST R 34.10-2001" in str(exc.value)
+
+    def test_gost_signature_unsupported(self):
+        cert = _load_cert(
+            os.path.join("x509", "e-trust.ru.der"),
+            x509.load_der_x509_certificate,
+        )
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        issuer = (
+            x509.CertificateBuilder()
+            .subject_name(cert.issuer)
+            .issuer_name(cert.issuer)
+            .public_key(private_key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .sign(private_key, hashes.SHA256())
+        )
+        with raises_unsupported_algorithm(
+            _Reasons.UNSUPPORTED_PUBLIC_KEY_ALGORITHM
+        ) as exc:
+            cert.verify_directly_issued_by(issuer)
+        assert "GOST R 34.11-94 with GOST R 34.10-2001" in str(exc.value)
+
 
 class TestECDSACertificate:
     def test_load_ecdsa_cert(self, backend):
@@ -5560,6 +6644,17 @@ class TestECDSACertificate:
             minor_version=None,
         )
 
+    def test_ms_certificate_template_name(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "ms-certificate-template-name.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        ext = cert.extensions.get_extension_for_class(
+            x509.MSCertificateTemplateName
+        )
+        assert ext.critical is False
+        assert ext.value == x509.MSCertificateTemplateName("DomainController")
+
     def test_signature(self, backend):
         cert = _load_cert(
             os.path.join("x509", "ecdsa_root.pem"),
@@ -5645,6 +6740,28 @@ class TestECDSACertificate:
         with pytest.raises(InvalidSignature):
             cert_bad_sig.verify_directly_issued_by(ca)
 
+    def test_verify_directly_issued_by_ec_non_issuer(
+        self, rsa_key_2048: rsa.RSAPrivateKey
+    ):
+        issuer_private_key = ec.generate_private_key(ec.SECP256R1())
+        subject_private_key = ec.generate_private_key(ec.SECP256R1())
+        _, cert = _generate_ca_and_leaf(
+            issuer_private_key, subject_private_key
+        )
+        # CAs with the right name but a different key didn't sign the
+        # certificate.
+        other_ca, _ = _generate_ca_and_leaf(
+            ec.generate_private_key(ec.SECP256R1()), subject_private_key
+        )
+        with pytest.raises(InvalidSignature):
+            cert.verify_directly_issued_by(other_ca)
+        rsa_ca, _ = _generate_ca_and_leaf(rsa_key_2048, subject_private_key)
+        with pytest.raises(ValueError, match="does not match issuer key"):
+            cert.verify_directly_issued_by(rsa_ca)
+        # The leaf's subject doesn't match its own issuer name.
+        with pytest.raises(ValueError, match="subject does not match"):
+            cert.verify_directly_issued_by(cert)
+
 
 class TestECDSACertificateRequest:
     @pytest.mark.parametrize(
@@ -5734,6 +6851,91 @@ class TestOtherCertificate:
             )
 
 
+class TestCertificateView:
+    @pytest.mark.parametrize(
+        "filename",
+        [
+            "cryptography.io.pem",
+            os.path.join("custom", "post2000utctime.pem"),
+            "v1_cert.pem",
+            "unique_identifier.pem",
+            os.path.join("custom", "utf8_common_name.pem"),
+        ],
+    )
+    def test_matches_certificate(self, filename, backend):
+        cert = _load_cert(
+            os.path.join("x509", filename),
+            x509.load_pem_x509_certificate,
+        )
+        view = x509.load_der_x509_certificate_view(
+            cert.public_bytes(serialization.Encoding.DER)
+        )
+        assert isinstance(view, x509.CertificateView)
+        assert view.serial_number == cert.serial_number
+        assert view.issuer == cert.issuer
+        assert view.subject == cert.subject
+        assert view.issuer_bytes == cert.issuer.public_bytes()
+        assert view.subject_bytes == cert.subject.public_bytes()
+        assert view.not_valid_before_utc == cert.not_valid_before_utc
+        assert view.not_valid_after_utc == cert.not_valid_after_utc
+
+    def test_outlives_caller_reference(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        data = cert.public_bytes(serialization.Encoding.DER)
+        view = x509.load_der_x509_certificate_view(data)
+        del data
+        assert view.subject == cert.subject
+
+    def test_negative_serial_does_not_warn(self, backend):
+        with pytest.warns(utils.DeprecatedIn36):
+            cert = _load_cert(
+                os.path.join("x509", "custom", "negative_serial.pem"),
+                x509.load_pem_x509_certificate,
+            )
+        der = cert.public_bytes(serialization.Encoding.DER)
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            view = x509.load_der_x509_certificate_view(der)
+            assert view.serial_number == -18008675309
+
+    def test_extensions_not_decoded(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "two_basic_constraints.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        view = x509.load_der_x509_certificate_view(
+            cert.public_bytes(serialization.Encoding.DER)
+        )
+        assert view.subject == cert.subject
+
+    def test_invalid_version(self, backend):
+        der = load_vectors_from_file(
+            os.path.join("x509", "PKITS_data", "certs", "GoodCACert.crt"),
+            lambda f: f.read(),
+            mode="rb",
+        )
+        version = b"\xa0\x03\x02\x01\x02"
+        assert version in der
+        der = der.replace(version, b"\xa0\x03\x02\x01\x07", 1)
+        with pytest.raises(x509.InvalidVersion) as exc:
+            x509.load_der_x509_certificate_view(der)
+        assert exc.value.parsed_version == 7
+
+    def test_malformed(self, backend):
+        der = load_vectors_from_file(
+            os.path.join("x509", "PKITS_data", "certs", "GoodCACert.crt"),
+            lambda f: f.read(),
+            mode="rb",
+        )
+        with pytest.raises(ValueError):
+            x509.load_der_x509_certificate_view(der[:-1])
+        with pytest.raises(ValueError):
+            x509.load_der_x509_certificate_view(b"not a certificate")
+
+
 class TestNameAttribute:
     EXPECTED_TYPES: typing.ClassVar[
         typing.List[typing.Tuple[x509.ObjectIdentifier, _ASN1Type]]
@@ -6288,6 +7490,92 @@ class TestEd25519Certificate:
         with pytest.raises(InvalidSignature):
             cert_bad_sig.verify_directly_issued_by(ca)
 
+    def test_sign_algorithm_identifier_has_no_parameters(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+        # RFC 8410 section 3: the parameters MUST be absent (not NULL).
+        algorithm_identifier = b"\x30\x05\x06\x03\x2b\x65\x70"
+
+        ca, cert = _generate_ca_and_leaf(private_key, private_key)
+        csr = (
+            x509.CertificateSigningRequestBuilder()
+            .subject_name(name)
+            .sign(private_key, None)
+        )
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(name)
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .sign(private_key, None)
+        )
+
+        cert.verify_directly_issued_by(ca)
+        assert csr.is_signature_valid
+        assert crl.is_signature_valid(private_key.public_key())
+        for obj, tbs in [
+            (cert, cert.tbs_certificate_bytes),
+            (csr, csr.tbs_certrequest_bytes),
+            (crl, crl.tbs_certlist_bytes),
+        ]:
+            assert obj.signature_algorithm_oid == SignatureAlgorithmOID.ED25519
+            assert obj.signature_algorithm_parameters is None
+            assert obj.signature_hash_algorithm is None
+            der = obj.public_bytes(serialization.Encoding.DER)
+            offset = der.index(tbs) + len(tbs)
+            assert der[offset : offset + 7] == algorithm_identifier
+
+    def test_sign_rejects_hash_algorithm(self, backend):
+        private_key = ed25519.Ed25519PrivateKey.generate()
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "PyCA")])
+        csr_builder = x509.CertificateSigningRequestBuilder().subject_name(
+            name
+        )
+        crl_builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(name)
+            .last_update(datetime.datetime(2020, 1, 1))
+            .next_update(datetime.datetime(2030, 1, 1))
+        )
+        with pytest.raises(ValueError):
+            csr_builder.sign(private_key, hashes.SHA256())
+        with pytest.raises(ValueError):
+            crl_builder.sign(private_key, hashes.SHA256())
+        with pytest.raises(TypeError):
+            csr_builder.sign(private_key, None, rsa_padding=padding.PKCS1v15())
+
+    def test_load_pem_csr(self, backend):
+        request = _load_cert(
+            os.path.join("x509", "requests", "ed25519.pem"),
+            x509.load_pem_x509_csr,
+        )
+        public_key = request.public_key()
+        assert isinstance(public_key, ed25519.Ed25519PublicKey)
+        assert (
+            request.public_key_algorithm_oid == PublicKeyAlgorithmOID.ED25519
+        )
+        expected_public_key = _load_cert(
+            os.path.join("asymmetric", "Ed25519", "ed25519-pub.pem"),
+            serialization.load_pem_public_key,
+        )
+        assert public_key == expected_public_key
+        public_key.verify(request.signature, request.tbs_certrequest_bytes)
+        assert request.is_signature_valid
+
+    def test_csr_bad_signature(self, backend):
+        request = _load_cert(
+            os.path.join("x509", "requests", "ed25519.pem"),
+            x509.load_pem_x509_csr,
+        )
+        assert request.signature_algorithm_oid == SignatureAlgorithmOID.ED25519
+        assert request.signature_algorithm_parameters is None
+        assert request.signature_hash_algorithm is None
+        der = bytearray(request.public_bytes(serialization.Encoding.DER))
+        # The signature is the last field of the CSR.
+        der[-1] ^= 1
+        tampered = x509.load_der_x509_csr(bytes(der))
+        assert not tampered.is_signature_valid
+
 
 @pytest.mark.supported(
     only_if=lambda backend: backend.ed448_supported(),
@@ -6699,3 +7987,72 @@ def test_load_pem_x509_certificates():
     assert len(certs) == 2
     assert certs[0].serial_number == 16160
     assert certs[1].serial_number == 146039
+
+
+def test_load_der_x509_certificates():
+    with pytest.raises(ValueError):
+        x509.load_der_x509_certificates(b"")
+
+    chain = load_vectors_from_file(
+        filename=os.path.join("x509", "cryptography.io.chain.pem"),
+        loader=lambda pemfile: x509.load_pem_x509_certificates(pemfile.read()),
+        mode="rb",
+    )
+    leaf = _load_cert(
+        os.path.join("x509", "cryptography.io.pem"),
+        x509.load_pem_x509_certificate,
+    )
+    expected = [leaf, *chain]
+    data = b"".join(
+        c.public_bytes(serialization.Encoding.DER) for c in expected
+    )
+
+    certs = x509.load_der_x509_certificates(data)
+    assert certs == expected
+    assert [c.serial_number for c in certs] == [
+        leaf.serial_number,
+        16160,
+        146039,
+    ]
+
+    # A truncated certificate.
+    with pytest.raises(ValueError):
+        x509.load_der_x509_certificates(data[:-1])
+    # Trailing data that isn't a complete TLV.
+    with pytest.raises(ValueError):
+        x509.load_der_x509_certificates(data + b"\x30\x03\x02")
+    # Trailing data that is a TLV, but not a certificate.
+    with pytest.raises(ValueError):
+        x509.load_der_x509_certificates(data + b"\x05\x00")
+
+
+@pytest.mark.supported(
+    only_if=lambda backend: backend.ed25519_supported(),
+    skip_message="Requires OpenSSL with Ed25519 support",
+)
+def test_generate_deterministic_certificate(backend):
+    cert = asn1.test_generate_certificate(b"seed")
+    der = cert.public_bytes(serialization.Encoding.DER)
+    # Every call with the same seed produces the same certificate, while a
+    # different seed produces a different key.
+    for _ in range(3):
+        assert (
+            asn1.test_generate_certificate(b"seed").public_bytes(
+                serialization.Encoding.DER
+            )
+            == der
+        )
+    other = asn1.test_generate_certificate(b"other seed")
+    assert other.public_key() != cert.public_key()
+
+    assert cert.serial_number == 1
+    assert cert.subject == cert.issuer
+    assert cert.subject.rfc4514_string() == "CN=cryptography.io"
+    assert cert.not_valid_before_utc == datetime.datetime(
+        2020, 1, 1, tzinfo=datetime.timezone.utc
+    )
+    assert cert.not_valid_after_utc == datetime.datetime(
+        2070, 1, 1, tzinfo=datetime.timezone.utc
+    )
+    assert isinstance(cert.public_key(), ed25519.Ed25519PublicKey)
+    cert.verify_directly_issued_by(cert)
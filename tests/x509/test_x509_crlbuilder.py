@@ -9,7 +9,7 @@ import pytest
 
 from cryptography import utils, x509
 from cryptography.exceptions import UnsupportedAlgorithm
-from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import (
     ec,
     ed448,
@@ -19,6 +19,7 @@ from cryptography.hazmat.primitives.asymmetric import (
 )
 from cryptography.x509.oid import (
     AuthorityInformationAccessOID,
+    HoldInstructionOID,
     NameOID,
     SignatureAlgorithmOID,
 )
@@ -273,6 +274,39 @@ class TestCertificateRevocationListBuilder:
             tzinfo=datetime.timezone.utc
         )
 
+    def test_time_type_2050_boundary(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        last_update = datetime.datetime(2049, 12, 31, 23, 59, 59)
+        next_update = datetime.datetime(2050, 1, 1, 0, 0, 0)
+        revoked_cert = (
+            x509.RevokedCertificateBuilder()
+            .serial_number(2)
+            .revocation_date(next_update)
+            .build(backend)
+        )
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CA")])
+            )
+            .last_update(last_update)
+            .next_update(next_update)
+            .add_revoked_certificate(revoked_cert)
+            .sign(rsa_key_2048, hashes.SHA256(), backend)
+        )
+        # RFC 5280 sections 5.1.2.4 to 5.1.2.6: UTCTime through 2049,
+        # GeneralizedTime from 2050 onwards.
+        tbs = crl.tbs_certlist_bytes
+        assert tbs.count(b"\x17\x0d491231235959Z") == 1
+        assert tbs.count(b"\x18\x0f20500101000000Z") == 2
+        assert crl.last_update_utc == last_update.replace(
+            tzinfo=datetime.timezone.utc
+        )
+        assert crl[0].revocation_date_utc == next_update.replace(
+            tzinfo=datetime.timezone.utc
+        )
+
     def test_sign_pss(self, rsa_key_2048: rsa.RSAPrivateKey, backend):
         private_key = rsa_key_2048
         last_update = datetime.datetime(2002, 1, 1, 12, 1)
@@ -307,6 +341,36 @@ class TestCertificateRevocationListBuilder:
             hashes.SHA256(),
         )
 
+    def test_sign_pss_mgf1_hash_differs(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        builder = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name(
+                    [
+                        x509.NameAttribute(
+                            NameOID.COMMON_NAME, "cryptography.io CA"
+                        )
+                    ]
+                )
+            )
+            .last_update(datetime.datetime(2002, 1, 1, 12, 1))
+            .next_update(datetime.datetime(2030, 1, 1, 12, 1))
+        )
+
+        pss = padding.PSS(
+            mgf=padding.MGF1(hashes.SHA1()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+        crl = builder.sign(rsa_key_2048, hashes.SHA256(), rsa_padding=pss)
+        params = crl.signature_algorithm_parameters
+        assert isinstance(params, padding.PSS)
+        assert isinstance(params._mgf, padding.MGF1)
+        assert isinstance(params._mgf._algorithm, hashes.SHA1)
+        assert isinstance(crl.signature_hash_algorithm, hashes.SHA256)
+        assert crl.is_signature_valid(rsa_key_2048.public_key())
+
     @pytest.mark.parametrize(
         "extension",
         [
@@ -968,3 +1032,60 @@ class TestCertificateRevocationListBuilder:
             .value
             == ci
         )
+
+    def test_sign_with_held_certificate(self, backend):
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        # The key was suspected to be compromised before the CA processed
+        # the revocation.
+        revocation_date = datetime.datetime(2012, 1, 1, 1, 1)
+        invalidity_date = x509.InvalidityDate(
+            datetime.datetime(2011, 12, 24, 18, 30)
+        )
+        hold_instruction = x509.HoldInstructionCode(
+            HoldInstructionOID.CALL_ISSUER
+        )
+        revoked_cert = (
+            x509.RevokedCertificateBuilder()
+            .serial_number(2)
+            .revocation_date(revocation_date)
+            .add_extension(
+                x509.CRLReason(x509.ReasonFlags.certificate_hold), False
+            )
+            .add_extension(invalidity_date, False)
+            .add_extension(hold_instruction, False)
+            .build(backend)
+        )
+        crl = (
+            x509.CertificateRevocationListBuilder()
+            .issuer_name(
+                x509.Name(
+                    [
+                        x509.NameAttribute(
+                            NameOID.COMMON_NAME, "cryptography.io CA"
+                        )
+                    ]
+                )
+            )
+            .last_update(datetime.datetime(2012, 1, 2))
+            .next_update(datetime.datetime(2030, 1, 1))
+            .add_revoked_certificate(revoked_cert)
+            .sign(private_key, hashes.SHA256(), backend)
+        )
+        crl = x509.load_der_x509_crl(
+            crl.public_bytes(serialization.Encoding.DER)
+        )
+
+        entry = crl.get_revoked_certificate_by_serial_number(2)
+        assert entry is not None
+        assert len(entry.extensions) == 3
+        assert entry.extensions.get_extension_for_class(
+            x509.CRLReason
+        ).value == x509.CRLReason(x509.ReasonFlags.certificate_hold)
+        ext = entry.extensions.get_extension_for_class(x509.InvalidityDate)
+        assert ext.value == invalidity_date
+        assert ext.value.invalidity_date_utc < entry.revocation_date_utc
+        ext = entry.extensions.get_extension_for_oid(
+            x509.CRLEntryExtensionOID.HOLD_INSTRUCTION_CODE
+        )
+        assert ext.critical is False
+        assert ext.value == hold_instruction
@@ -13,9 +13,10 @@ import pretend
 import pytest
 
 from cryptography import x509
+from cryptography.exceptions import InvalidSignature
 from cryptography.hazmat._oid import _OID_NAMES
 from cryptography.hazmat.bindings._rust import x509 as rust_x509
-from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives import hashes, serialization
 from cryptography.hazmat.primitives.asymmetric import ec, rsa
 from cryptography.x509 import (
     DNSName,
@@ -29,8 +30,10 @@ from cryptography.x509.extensions import (
 )
 from cryptography.x509.oid import (
     AuthorityInformationAccessOID,
+    CertificatePoliciesOID,
     ExtendedKeyUsageOID,
     ExtensionOID,
+    HoldInstructionOID,
     NameOID,
     ObjectIdentifier,
     SubjectInformationAccessOID,
@@ -465,6 +468,41 @@ class TestInvalidityDate:
         )
 
 
+class TestHoldInstructionCode:
+    def test_invalid_hold_instruction(self):
+        with pytest.raises(TypeError):
+            x509.HoldInstructionCode("1.2.3")  # type:ignore[arg-type]
+
+    def test_eq(self):
+        hold1 = x509.HoldInstructionCode(HoldInstructionOID.CALL_ISSUER)
+        hold2 = x509.HoldInstructionCode(HoldInstructionOID.CALL_ISSUER)
+        assert hold1 == hold2
+
+    def test_ne(self):
+        hold1 = x509.HoldInstructionCode(HoldInstructionOID.CALL_ISSUER)
+        hold2 = x509.HoldInstructionCode(HoldInstructionOID.REJECT)
+        assert hold1 != hold2
+        assert hold1 != object()
+
+    def test_repr(self):
+        hold = x509.HoldInstructionCode(HoldInstructionOID.NONE)
+        assert repr(hold) == (
+            "<HoldInstructionCode(hold_instruction=<ObjectIdentifier(oid=1.2."
+            "840.10040.2.1, name=holdInstructionNone)>)>"
+        )
+
+    def test_hash(self):
+        hold1 = x509.HoldInstructionCode(HoldInstructionOID.CALL_ISSUER)
+        hold2 = x509.HoldInstructionCode(HoldInstructionOID.CALL_ISSUER)
+        hold3 = x509.HoldInstructionCode(HoldInstructionOID.REJECT)
+        assert hash(hold1) == hash(hold2)
+        assert hash(hold1) != hash(hold3)
+
+    def test_public_bytes(self):
+        ext = x509.HoldInstructionCode(HoldInstructionOID.REJECT)
+        assert ext.public_bytes() == b"\x06\x07*\x86H\xce8\x02\x03"
+
+
 class TestNoticeReference:
     def test_notice_numbers_not_all_int(self):
         with pytest.raises(TypeError):
@@ -2168,6 +2206,35 @@ class TestOtherName:
         assert hash(gn) != hash(gn3)
 
 
+class TestX400Address:
+    def test_invalid_args(self):
+        with pytest.raises(TypeError):
+            x509.X400Address("notderdata")  # type:ignore[arg-type]
+
+    def test_repr(self):
+        gn = x509.X400Address(b"0\x03\x13\x01a")
+        assert repr(gn) == "<X400Address(value=b'0\\x03\\x13\\x01a')>"
+
+    def test_eq(self):
+        gn = x509.X400Address(b"0\x03\x13\x01a")
+        gn2 = x509.X400Address(b"0\x03\x13\x01a")
+        assert gn == gn2
+
+    def test_ne(self):
+        gn = x509.X400Address(b"0\x03\x13\x01a")
+        assert gn != object()
+
+        gn2 = x509.X400Address(b"0\x03\x13\x01b")
+        assert gn != gn2
+
+    def test_hash(self):
+        gn = x509.X400Address(b"0\x03\x13\x01a")
+        gn2 = x509.X400Address(b"0\x03\x13\x01a")
+        gn3 = x509.X400Address(b"0\x03\x13\x01b")
+        assert hash(gn) == hash(gn2)
+        assert hash(gn) != hash(gn3)
+
+
 class TestGeneralNames:
     def test_get_values_for_type(self):
         gns = x509.GeneralNames([x509.DNSName("cryptography.io")])
@@ -2486,11 +2553,24 @@ class TestRSASubjectAlternativeNameExtension:
         dns = ext.value.get_values_for_type(x509.DNSName)
         assert dns == ["*.xn--80ato2c.cryptography"]
 
-    def test_unsupported_gn(self, backend):
+    def test_x400_address(self, backend):
         cert = _load_cert(
             os.path.join("x509", "san_x400address.der"),
             x509.load_der_x509_certificate,
         )
+        ext = cert.extensions.get_extension_for_class(
+            x509.SubjectAlternativeName
+        )
+        # The ORAddress is returned as a standalone DER SEQUENCE.
+        assert ext.value.get_values_for_type(x509.X400Address) == [
+            b"0\x03\x13\x01a"
+        ]
+
+    def test_unsupported_gn(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "san_edipartyname.der"),
+            x509.load_der_x509_certificate,
+        )
         with pytest.raises(x509.UnsupportedGeneralNameType):
             cert.extensions
 
@@ -2894,6 +2974,192 @@ class TestPolicyConstraintsExtension:
         assert ext.public_bytes() == b"\x30\x03\x81\x01\x00"
 
 
+class TestPolicyMapping:
+    def test_invalid_policies(self):
+        with pytest.raises(TypeError):
+            x509.PolicyMapping(
+                "1.2.3",  # type:ignore[arg-type]
+                ObjectIdentifier("1.2.4"),
+            )
+        with pytest.raises(TypeError):
+            x509.PolicyMapping(
+                ObjectIdentifier("1.2.3"),
+                "1.2.4",  # type:ignore[arg-type]
+            )
+
+    def test_any_policy(self):
+        with pytest.raises(ValueError):
+            x509.PolicyMapping(
+                CertificatePoliciesOID.ANY_POLICY, ObjectIdentifier("1.2.4")
+            )
+        with pytest.raises(ValueError):
+            x509.PolicyMapping(
+                ObjectIdentifier("1.2.3"), CertificatePoliciesOID.ANY_POLICY
+            )
+
+    def test_repr(self):
+        pm = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        assert repr(pm) == (
+            "<PolicyMapping(issuer_domain_policy=<ObjectIdentifier(oid=1.2.3"
+            ", name=Unknown OID)>, subject_domain_policy=<ObjectIdentifier(o"
+            "id=1.2.4, name=Unknown OID)>)>"
+        )
+
+    def test_eq(self):
+        pm = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        assert pm == pm2
+
+    def test_ne(self):
+        pm = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.4"), ObjectIdentifier("1.2.3")
+        )
+        assert pm != pm2
+        assert pm != object()
+
+    def test_hash(self):
+        pm = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm3 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.4"), ObjectIdentifier("1.2.3")
+        )
+        assert hash(pm) == hash(pm2)
+        assert hash(pm) != hash(pm3)
+
+
+class TestPolicyMappings:
+    def test_invalid_mappings(self):
+        with pytest.raises(TypeError):
+            x509.PolicyMappings(
+                ["notapolicymapping"]  # type:ignore[list-item]
+            )
+
+    def test_iter_len(self):
+        pm1 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.5"), ObjectIdentifier("1.2.6")
+        )
+        pms = x509.PolicyMappings([pm1, pm2])
+        assert len(pms) == 2
+        assert list(pms) == [pm1, pm2]
+        assert pms[-1] == pm2
+
+    def test_repr(self):
+        pms = x509.PolicyMappings(
+            [
+                x509.PolicyMapping(
+                    ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+                )
+            ]
+        )
+        assert repr(pms) == (
+            "<PolicyMappings([<PolicyMapping(issuer_domain_policy=<ObjectIde"
+            "ntifier(oid=1.2.3, name=Unknown OID)>, subject_domain_policy=<O"
+            "bjectIdentifier(oid=1.2.4, name=Unknown OID)>)>])>"
+        )
+
+    def test_eq(self):
+        pm = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        assert x509.PolicyMappings([pm]) == x509.PolicyMappings([pm])
+
+    def test_ne(self):
+        pm1 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.5"), ObjectIdentifier("1.2.6")
+        )
+        pms = x509.PolicyMappings([pm1, pm2])
+        assert pms != x509.PolicyMappings([pm2, pm1])
+        assert pms != x509.PolicyMappings([pm1])
+        assert pms != object()
+
+    def test_hash(self):
+        pm1 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+        )
+        pm2 = x509.PolicyMapping(
+            ObjectIdentifier("1.2.5"), ObjectIdentifier("1.2.6")
+        )
+        pms = x509.PolicyMappings([pm1, pm2])
+        pms2 = x509.PolicyMappings([pm1, pm2])
+        pms3 = x509.PolicyMappings([pm1])
+        assert hash(pms) == hash(pms2)
+        assert hash(pms) != hash(pms3)
+
+    def test_public_bytes(self):
+        ext = x509.PolicyMappings(
+            [
+                x509.PolicyMapping(
+                    ObjectIdentifier("1.2.3"), ObjectIdentifier("1.2.4")
+                )
+            ]
+        )
+        assert ext.public_bytes() == b"0\n0\x08\x06\x02*\x03\x06\x02*\x04"
+
+
+class TestPolicyMappingsExtension:
+    def test_two_mappings(self, backend):
+        mappings = x509.PolicyMappings(
+            [
+                x509.PolicyMapping(
+                    ObjectIdentifier("2.16.840.1.101.3.2.1.48.1"),
+                    ObjectIdentifier("1.3.6.1.4.1.11129.2.5.1"),
+                ),
+                x509.PolicyMapping(
+                    ObjectIdentifier("2.16.840.1.101.3.2.1.48.2"),
+                    ObjectIdentifier("1.3.6.1.4.1.11129.2.5.2"),
+                ),
+            ]
+        )
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        cert = (
+            _make_certbuilder(private_key)
+            .add_extension(mappings, critical=True)
+            .sign(private_key, hashes.SHA256(), backend)
+        )
+        ext = cert.extensions.get_extension_for_oid(
+            ExtensionOID.POLICY_MAPPINGS
+        )
+        assert ext.critical is True
+        assert ext.value == mappings
+        assert ext.value.public_bytes() == mappings.public_bytes()
+
+    def test_any_policy(self, backend):
+        # A mapping from anyPolicy, which can't be built through the
+        # PolicyMapping API.
+        value = b"0\r0\x0b\x06\x04U\x1d \x00\x06\x03*\x03\x04"
+        private_key = ec.generate_private_key(ec.SECP256R1())
+        cert = (
+            _make_certbuilder(private_key)
+            .add_extension(
+                x509.UnrecognizedExtension(
+                    ExtensionOID.POLICY_MAPPINGS, value
+                ),
+                critical=True,
+            )
+            .sign(private_key, hashes.SHA256(), backend)
+        )
+        with pytest.raises(ValueError):
+            cert.extensions
+
 class TestAuthorityInformationAccess:
     def test_invalid_descriptions(self):
         with pytest.raises(TypeError):
@@ -3326,6 +3592,46 @@ class TestSubjectInformationAccessExtension:
             ]
         )
 
+    def test_sia_ca_repository_time_stamping(self, backend):
+        private_key = ec.generate_private_key(ec.SECP256R1(), backend)
+        sia = x509.SubjectInformationAccess(
+            [
+                x509.AccessDescription(
+                    SubjectInformationAccessOID.CA_REPOSITORY,
+                    x509.UniformResourceIdentifier("http://ca.example.com/"),
+                ),
+                x509.AccessDescription(
+                    SubjectInformationAccessOID.TIME_STAMPING,
+                    x509.UniformResourceIdentifier("http://tsa.example.com/"),
+                ),
+            ]
+        )
+        cert = x509.create_self_signed_certificate(
+            x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "CA")]),
+            private_key,
+            datetime.datetime(2020, 1, 1),
+            datetime.datetime(2030, 1, 1),
+            hashes.SHA256(),
+            extensions=[
+                x509.Extension(
+                    ExtensionOID.BASIC_CONSTRAINTS,
+                    True,
+                    x509.BasicConstraints(ca=True, path_length=None),
+                ),
+                x509.Extension(sia.oid, False, sia),
+            ],
+        )
+
+        ext = cert.extensions.get_extension_for_class(
+            x509.SubjectInformationAccess
+        )
+        assert ext.critical is False
+        assert ext.value == sia
+        assert [_OID_NAMES[ad.access_method] for ad in ext.value] == [
+            "caRepository",
+            "timeStamping",
+        ]
+
 
 class TestAuthorityInformationAccessExtension:
     def test_aia_ocsp_ca_issuers(self, backend):
@@ -3902,6 +4208,68 @@ class TestNameConstraintsExtension:
         ]
         assert result == permitted
 
+    def test_certbuilder_structured_subtrees(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        nc = x509.NameConstraints(
+            permitted_subtrees=[
+                x509.DNSName(".example.com"),
+                x509.IPAddress(ipaddress.IPv4Network("10.0.0.0/8")),
+                x509.IPAddress(ipaddress.IPv6Network("2001:db8::/32")),
+            ],
+            excluded_subtrees=[
+                x509.DirectoryName(
+                    x509.Name(
+                        [x509.NameAttribute(NameOID.ORGANIZATION_NAME, "Evil")]
+                    )
+                ),
+                x509.DNSName("bad.example.com"),
+            ],
+        )
+        builder = _make_certbuilder(rsa_key_2048).add_extension(nc, True)
+        cert = builder.sign(rsa_key_2048, hashes.SHA256(), backend)
+
+        parsed = cert.extensions.get_extension_for_class(
+            x509.NameConstraints
+        ).value
+        assert parsed == nc
+        assert parsed.permitted_subtrees is not None
+        assert parsed.excluded_subtrees is not None
+        assert [type(n) for n in parsed.permitted_subtrees] == [
+            x509.DNSName,
+            x509.IPAddress,
+            x509.IPAddress,
+        ]
+        assert [type(n) for n in parsed.excluded_subtrees] == [
+            x509.DirectoryName,
+            x509.DNSName,
+        ]
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            # permittedSubtrees with a dNSName and maximum=1
+            b"\x30\x0e\xa0\x0c\x30\x0a\x82\x05a.com\x81\x01\x01",
+            # excludedSubtrees with a dNSName and minimum=1
+            b"\x30\x0e\xa1\x0c\x30\x0a\x82\x05a.com\x80\x01\x01",
+        ],
+    )
+    def test_nonstandard_subtree_bounds(
+        self, rsa_key_2048: rsa.RSAPrivateKey, value, backend
+    ):
+        # Bounds other than minimum=0 and no maximum can't be represented by
+        # NameConstraints, so the extension is left unrecognized rather than
+        # having them silently dropped.
+        ext = x509.UnrecognizedExtension(ExtensionOID.NAME_CONSTRAINTS, value)
+        builder = _make_certbuilder(rsa_key_2048).add_extension(ext, True)
+        cert = builder.sign(rsa_key_2048, hashes.SHA256(), backend)
+
+        parsed = cert.extensions.get_extension_for_oid(
+            ExtensionOID.NAME_CONSTRAINTS
+        )
+        assert parsed.critical is True
+        assert parsed.value == ext
+
     def test_public_bytes(self):
         ext = x509.NameConstraints(
             permitted_subtrees=[x509.DNSName("zombo.local")],
@@ -5827,6 +6195,178 @@ class TestSignedCertificateTimestamps:
         assert hash(sct) == hash(sct2)
         assert hash(sct) != hash(sct3)
 
+    def _load_mammoth_sct(self):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography-scts.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        issuer = _load_cert(
+            os.path.join("x509", "letsencryptx3.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        log_key = load_vectors_from_file(
+            os.path.join("x509", "mammoth-ct-log.pem"),
+            lambda f: serialization.load_pem_public_key(f.read()),
+            mode="rb",
+        )
+        scts = cert.extensions.get_extension_for_class(
+            x509.PrecertificateSignedCertificateTimestamps
+        ).value
+        return scts, cert, issuer, log_key
+
+    def test_verify(self, backend):
+        scts, cert, issuer, log_key = self._load_mammoth_sct()
+        scts[1].verify(log_key, cert, issuer)
+
+    def test_verify_wrong_issuer(self, backend):
+        scts, cert, _, log_key = self._load_mammoth_sct()
+        issuer = _load_cert(
+            os.path.join("x509", "rapidssl_sha256_ca_g3.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(InvalidSignature):
+            scts[1].verify(log_key, cert, issuer)
+
+    def test_verify_wrong_certificate(self, backend):
+        scts, _, issuer, log_key = self._load_mammoth_sct()
+        cert = _load_cert(
+            os.path.join("x509", "badssl-sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(InvalidSignature):
+            scts[1].verify(log_key, cert, issuer)
+
+    def test_verify_wrong_log(self, backend):
+        scts, cert, issuer, log_key = self._load_mammoth_sct()
+        with pytest.raises(ValueError, match="log ID"):
+            scts[0].verify(log_key, cert, issuer)
+
+    def test_verify_x509_entry(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography-scts.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        cert_der = cert.public_bytes(serialization.Encoding.DER)
+        log_key = ec.generate_private_key(ec.SECP256R1())
+        digest = hashes.Hash(hashes.SHA256())
+        digest.update(
+            log_key.public_key().public_bytes(
+                serialization.Encoding.DER,
+                serialization.PublicFormat.SubjectPublicKeyInfo,
+            )
+        )
+        log_id = digest.finalize()
+        timestamp = (1700000000000).to_bytes(8, "big")
+
+        # The digitally-signed struct for an x509_entry (RFC 6962 3.2),
+        # which covers the whole certificate.
+        signature = log_key.sign(
+            b"\x00\x00"
+            + timestamp
+            + b"\x00\x00"
+            + len(cert_der).to_bytes(3, "big")
+            + cert_der
+            + b"\x00\x00",
+            ec.ECDSA(hashes.SHA256()),
+        )
+        sct = (
+            b"\x00"
+            + log_id
+            + timestamp
+            + b"\x00\x00\x04\x03"
+            + len(signature).to_bytes(2, "big")
+            + signature
+        )
+        sct_entry = len(sct).to_bytes(2, "big") + sct
+        sct_list = len(sct_entry).to_bytes(2, "big") + sct_entry
+
+        # SCTs for x509_entry types are only carried in OCSP responses, so
+        # a minimal (unsigned) one is assembled around them.
+        def der(tag, *values):
+            value = b"".join(values)
+            if len(value) < 0x80:
+                return bytes([tag, len(value)]) + value
+            n = (len(value).bit_length() + 7) // 8
+            length = len(value).to_bytes(n, "big")
+            return bytes([tag, 0x80 | n]) + length + value
+
+        sha1_alg = der(
+            0x30, der(0x06, bytes.fromhex("2b0e03021a")), b"\x05\x00"
+        )
+        single_response = der(
+            0x30,
+            der(
+                0x30,
+                sha1_alg,
+                der(0x04, b"\x00" * 20),
+                der(0x04, b"\x00" * 20),
+                der(0x02, b"\x01"),
+            ),
+            b"\x80\x00",
+            der(0x18, b"20240101000000Z"),
+            der(
+                0xA1,
+                der(
+                    0x30,
+                    der(
+                        0x30,
+                        # 1.3.6.1.4.1.11129.2.4.5, SignedCertificateTimestamps
+                        der(0x06, bytes.fromhex("2b06010401d679020405")),
+                        der(0x04, der(0x04, sct_list)),
+                    ),
+                ),
+            ),
+        )
+        basic_response = der(
+            0x30,
+            der(
+                0x30,
+                der(0xA1, cert.subject.public_bytes()),
+                der(0x18, b"20240101000000Z"),
+                der(0x30, single_response),
+            ),
+            # ecdsa-with-SHA256
+            der(0x30, der(0x06, bytes.fromhex("2a8648ce3d040302"))),
+            der(0x03, b"\x00"),
+        )
+        response = ocsp.load_der_ocsp_response(
+            der(
+                0x30,
+                der(0x0A, b"\x00"),
+                der(
+                    0xA0,
+                    der(
+                        0x30,
+                        # 1.3.6.1.5.5.7.48.1.1, id-pkix-ocsp-basic
+                        der(0x06, bytes.fromhex("2b0601050507300101")),
+                        der(0x04, basic_response),
+                    ),
+                ),
+            )
+        )
+        [sct_obj] = response.single_extensions.get_extension_for_class(
+            x509.SignedCertificateTimestamps
+        ).value
+        assert (
+            sct_obj.entry_type
+            == x509.certificate_transparency.LogEntryType.X509_CERTIFICATE
+        )
+
+        sct_obj.verify(log_key.public_key(), cert, cert)
+        other = _load_cert(
+            os.path.join("x509", "badssl-sct.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(InvalidSignature):
+            sct_obj.verify(log_key.public_key(), other, cert)
+
+    def test_verify_key_type_mismatch(
+        self, rsa_key_2048: rsa.RSAPrivateKey, backend
+    ):
+        scts, cert, issuer, _ = self._load_mammoth_sct()
+        with pytest.raises(ValueError, match="key type"):
+            scts[1].verify(rsa_key_2048.public_key(), cert, issuer)
+
     def test_public_bytes(self, backend):
         ext = (
             load_vectors_from_file(
@@ -6227,6 +6767,54 @@ class TestOCSPAcceptableResponses:
         )
 
 
+class TestOCSPArchiveCutoff:
+    def test_invalid_archive_cutoff(self):
+        with pytest.raises(TypeError):
+            x509.OCSPArchiveCutoff("notadate")  # type:ignore[arg-type]
+
+    def test_eq(self):
+        cutoff1 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        cutoff2 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        assert cutoff1 == cutoff2
+
+    def test_ne(self):
+        cutoff1 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        cutoff2 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 2))
+        assert cutoff1 != cutoff2
+        assert cutoff1 != object()
+
+    def test_repr(self):
+        cutoff = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        assert repr(cutoff) == (
+            "<OCSPArchiveCutoff(archive_cutoff=2015-01-01 01:01:00)>"
+        )
+
+    def test_hash(self):
+        cutoff1 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        cutoff2 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        cutoff3 = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 2))
+        assert hash(cutoff1) == hash(cutoff2)
+        assert hash(cutoff1) != hash(cutoff3)
+
+    def test_public_bytes(self):
+        ext = x509.OCSPArchiveCutoff(datetime.datetime(2015, 1, 1, 1, 1))
+        assert ext.public_bytes() == b"\x18\x0f20150101010100Z"
+
+    def test_timezone_aware_api(self):
+        tz_aware_date = datetime.datetime(
+            2015,
+            1,
+            1,
+            1,
+            1,
+            tzinfo=datetime.timezone(datetime.timedelta(hours=-8)),
+        )
+        ext = x509.OCSPArchiveCutoff(tz_aware_date)
+        assert ext.archive_cutoff_utc == datetime.datetime(
+            2015, 1, 1, 9, 1, tzinfo=datetime.timezone.utc
+        )
+
+
 class TestMSCertificateTemplate:
     def test_invalid_type(self):
         with pytest.raises(TypeError):
@@ -6316,15 +6904,428 @@ class TestMSCertificateTemplate:
         )
 
 
-def test_all_extension_oid_members_have_names_defined():
-    for oid in dir(ExtensionOID):
-        if oid.startswith("__"):
-            continue
-        assert getattr(ExtensionOID, oid) in _OID_NAMES
+class TestMSCertificateTemplateName:
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            x509.MSCertificateTemplateName(b"User")  # type:ignore[arg-type]
 
+    def test_eq(self):
+        assert x509.MSCertificateTemplateName(
+            "User"
+        ) == x509.MSCertificateTemplateName("User")
 
-def test_unknown_extension():
-    class MyExtension(ExtensionType):
+    def test_ne(self):
+        name = x509.MSCertificateTemplateName("User")
+        assert name != x509.MSCertificateTemplateName("Machine")
+        assert name != object()
+
+    def test_repr(self):
+        name = x509.MSCertificateTemplateName("User")
+        assert repr(name) == "<MSCertificateTemplateName(name='User')>"
+
+    def test_hash(self):
+        name1 = x509.MSCertificateTemplateName("User")
+        name2 = x509.MSCertificateTemplateName("User")
+        name3 = x509.MSCertificateTemplateName("Machine")
+        assert hash(name1) == hash(name2)
+        assert hash(name1) != hash(name3)
+
+    def test_public_bytes(self):
+        ext = x509.MSCertificateTemplateName("User")
+        assert ext.public_bytes() == b"\x1e\x08\x00U\x00s\x00e\x00r"
+
+
+class TestMSApplicationPolicies:
+    def test_invalid_policies(self):
+        with pytest.raises(TypeError):
+            x509.MSApplicationPolicies(
+                [ExtendedKeyUsageOID.CLIENT_AUTH]  # type:ignore[list-item]
+            )
+
+    def test_iter_len_getitem(self):
+        pi1 = x509.PolicyInformation(ExtendedKeyUsageOID.CLIENT_AUTH, None)
+        pi2 = x509.PolicyInformation(ExtendedKeyUsageOID.SMARTCARD_LOGON, None)
+        ap = x509.MSApplicationPolicies([pi1, pi2])
+        assert len(ap) == 2
+        assert list(ap) == [pi1, pi2]
+        assert ap[0] == pi1
+        assert ap[-1] == pi2
+        assert ap[:1] == [pi1]
+
+    def test_repr(self):
+        pi = x509.PolicyInformation(ObjectIdentifier("1.2.3"), None)
+        ap = x509.MSApplicationPolicies([pi])
+        assert repr(ap) == (
+            "<MSApplicationPolicies([<PolicyInformation(policy_identifier=<O"
+            "bjectIdentifier(oid=1.2.3, name=Unknown OID)>, policy_qualifier"
+            "s=None)>])>"
+        )
+
+    def test_eq(self):
+        pi = x509.PolicyInformation(ObjectIdentifier("1.2.3"), None)
+        pi2 = x509.PolicyInformation(ObjectIdentifier("1.2.3"), None)
+        assert x509.MSApplicationPolicies([pi]) == x509.MSApplicationPolicies(
+            [pi2]
+        )
+
+    def test_ne(self):
+        pi = x509.PolicyInformation(ObjectIdentifier("1.2.3"), None)
+        pi2 = x509.PolicyInformation(ObjectIdentifier("1.2.4"), None)
+        ap = x509.MSApplicationPolicies([pi])
+        assert ap != x509.MSApplicationPolicies([pi2])
+        assert ap != x509.CertificatePolicies([pi])
+        assert ap != object()
+
+    def test_hash(self):
+        pi = x509.PolicyInformation(ObjectIdentifier("1.2.3"), None)
+        pi2 = x509.PolicyInformation(ObjectIdentifier("1.2.4"), None)
+        ap = x509.MSApplicationPolicies([pi])
+        assert hash(ap) == hash(x509.MSApplicationPolicies([pi]))
+        assert hash(ap) != hash(x509.MSApplicationPolicies([pi2]))
+
+    def test_public_bytes(self):
+        ext = x509.MSApplicationPolicies(
+            [x509.PolicyInformation(ExtendedKeyUsageOID.CLIENT_AUTH, None)]
+        )
+        assert ext.public_bytes() == (
+            b"0\x0c0\n\x06\x08+\x06\x01\x05\x05\x07\x03\x02"
+        )
+
+    def test_parse_certificate(self, rsa_key_2048: rsa.RSAPrivateKey):
+        ext = x509.MSApplicationPolicies(
+            [
+                x509.PolicyInformation(ExtendedKeyUsageOID.CLIENT_AUTH, None),
+                x509.PolicyInformation(
+                    ExtendedKeyUsageOID.SMARTCARD_LOGON, None
+                ),
+            ]
+        )
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "test")])
+        cert = (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(rsa_key_2048.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(ext, critical=False)
+            .sign(rsa_key_2048, hashes.SHA256())
+        )
+        parsed = cert.extensions.get_extension_for_class(
+            x509.MSApplicationPolicies
+        )
+        assert parsed.oid == ExtensionOID.MS_APPLICATION_POLICIES
+        assert parsed.critical is False
+        assert parsed.value == ext
+
+
+class TestNamingAuthority:
+    def test_invalid_init(self):
+        with pytest.raises(TypeError):
+            x509.NamingAuthority(
+                "1.2.3",  # type:ignore[arg-type]
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.NamingAuthority(None, 1, None)  # type:ignore[arg-type]
+        with pytest.raises(TypeError):
+            x509.NamingAuthority(None, None, 1)  # type:ignore[arg-type]
+
+    def test_repr(self):
+        authority = x509.NamingAuthority(
+            ObjectIdentifier("1.2.3"), "https://example.com", "text"
+        )
+        assert repr(authority) == (
+            "<NamingAuthority(id=<ObjectIdentifier(oid=1.2.3, name=Unknown "
+            "OID)>, url='https://example.com', text='text')>"
+        )
+
+    def test_eq_hash(self):
+        authority = x509.NamingAuthority(None, "https://example.com", None)
+        authority2 = x509.NamingAuthority(None, "https://example.com", None)
+        authority3 = x509.NamingAuthority(None, None, "text")
+        assert authority == authority2
+        assert hash(authority) == hash(authority2)
+        assert authority != authority3
+        assert hash(authority) != hash(authority3)
+        assert authority != object()
+
+
+class TestProfessionInfo:
+    def test_invalid_init(self):
+        with pytest.raises(TypeError):
+            x509.ProfessionInfo(
+                "authority",  # type:ignore[arg-type]
+                [],
+                None,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.ProfessionInfo(
+                None,
+                [1],  # type:ignore[list-item]
+                None,
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.ProfessionInfo(
+                None,
+                [],
+                ["1.2.3"],  # type:ignore[list-item]
+                None,
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.ProfessionInfo(
+                None,
+                [],
+                None,
+                1,  # type:ignore[arg-type]
+                None,
+            )
+        with pytest.raises(TypeError):
+            x509.ProfessionInfo(
+                None,
+                [],
+                None,
+                None,
+                "info",  # type:ignore[arg-type]
+            )
+
+    def test_repr(self):
+        info = x509.ProfessionInfo(None, ["item"], None, "123", b"\x00")
+        assert repr(info) == (
+            "<ProfessionInfo(naming_authority=None, profession_items=['item']"
+            ", profession_oids=None, registration_number='123', "
+            "add_profession_info=b'\\x00')>"
+        )
+
+    def test_eq_hash(self):
+        info = x509.ProfessionInfo(
+            None, ["item"], [ObjectIdentifier("1.2.3")], None, None
+        )
+        info2 = x509.ProfessionInfo(
+            None, ["item"], [ObjectIdentifier("1.2.3")], None, None
+        )
+        info3 = x509.ProfessionInfo(None, ["item"], None, None, None)
+        assert info == info2
+        assert hash(info) == hash(info2)
+        assert info != info3
+        assert hash(info) != hash(info3)
+        assert info != object()
+
+
+class TestAdmissions:
+    # An AdmissionSyntax with an authority, a naming authority, and a
+    # profession info using UTF8String and BMPString profession items, a
+    # profession OID, a registration number and additional profession info.
+    sample_der = bytes.fromhex(
+        "30819b861868747470733a2f2f7777772e6578616d706c652e636f6d2f307f307d"
+        "a019861768747470733a2f2f63612e6578616d706c652e636f6d2fa12d302b0607"
+        "2a8214004c0436161768747470733a2f2f7777772e67656d6174696b2e64652f0c"
+        "0767656d6174696b3031302f30140c0841706f7468656b651e0800410072007a00"
+        "74300906072a8214004c043613063132333435360404deadbeef"
+    )
+    sample = x509.Admissions(
+        x509.UniformResourceIdentifier("https://www.example.com/"),
+        [
+            x509.Admission(
+                x509.UniformResourceIdentifier("https://ca.example.com/"),
+                x509.NamingAuthority(
+                    ObjectIdentifier("1.2.276.0.76.4.54"),
+                    "https://www.gematik.de/",
+                    "gematik",
+                ),
+                [
+                    x509.ProfessionInfo(
+                        None,
+                        ["Apotheke", "Arzt"],
+                        [ObjectIdentifier("1.2.276.0.76.4.54")],
+                        "123456",
+                        b"\xde\xad\xbe\xef",
+                    )
+                ],
+            )
+        ],
+    )
+
+    def test_invalid_init(self):
+        with pytest.raises(TypeError):
+            x509.Admissions(
+                "authority",  # type:ignore[arg-type]
+                [],
+            )
+        with pytest.raises(TypeError):
+            x509.Admissions(None, [object()])  # type:ignore[list-item]
+        with pytest.raises(TypeError):
+            x509.Admission(
+                "authority",  # type:ignore[arg-type]
+                None,
+                [],
+            )
+        with pytest.raises(TypeError):
+            x509.Admission(
+                None,
+                "authority",  # type:ignore[arg-type]
+                [],
+            )
+        with pytest.raises(TypeError):
+            x509.Admission(None, None, [object()])  # type:ignore[list-item]
+
+    def test_iter_len_getitem(self):
+        admission = self.sample[0]
+        assert len(self.sample) == 1
+        assert list(self.sample) == [admission]
+        assert self.sample[-1] == admission
+        assert self.sample[:1] == [admission]
+
+    def test_repr(self):
+        ext = x509.Admissions(None, [x509.Admission(None, None, [])])
+        assert repr(ext) == (
+            "<Admissions(authority=None, admissions=[<Admission(admission_aut"
+            "hority=None, naming_authority=None, profession_infos=[])>])>"
+        )
+
+    def test_eq_hash(self):
+        ext = x509.Admissions(None, [x509.Admission(None, None, [])])
+        ext2 = x509.Admissions(None, [x509.Admission(None, None, [])])
+        assert ext == ext2
+        assert hash(ext) == hash(ext2)
+        assert ext != self.sample
+        assert hash(ext) != hash(self.sample)
+        assert ext != object()
+        assert ext.oid == ExtensionOID.ADMISSIONS
+
+    def test_public_bytes(self):
+        ext = x509.Admissions(
+            None,
+            [
+                x509.Admission(
+                    None,
+                    None,
+                    [x509.ProfessionInfo(None, ["Arzt"], None, None, None)],
+                )
+            ],
+        )
+        assert ext.public_bytes() == (
+            b"0\x100\x0e0\x0c0\n0\x080\x06\x0c\x04Arzt"
+        )
+
+    def test_public_bytes_invalid(self):
+        ext = x509.Admissions(
+            None,
+            [
+                x509.Admission(
+                    None,
+                    x509.NamingAuthority(None, "\u2603", None),
+                    [],
+                )
+            ],
+        )
+        with pytest.raises(ValueError):
+            ext.public_bytes()
+        ext = x509.Admissions(
+            None,
+            [
+                x509.Admission(
+                    None,
+                    None,
+                    [x509.ProfessionInfo(None, [], None, "a@b", None)],
+                )
+            ],
+        )
+        with pytest.raises(ValueError):
+            ext.public_bytes()
+
+    def _cert_with_extension(self, ext, key):
+        name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "test")])
+        return (
+            x509.CertificateBuilder()
+            .subject_name(name)
+            .issuer_name(name)
+            .public_key(key.public_key())
+            .serial_number(1)
+            .not_valid_before(datetime.datetime(2020, 1, 1))
+            .not_valid_after(datetime.datetime(2030, 1, 1))
+            .add_extension(ext, critical=False)
+            .sign(key, hashes.SHA256())
+        )
+
+    def test_parse_sample(self, rsa_key_2048: rsa.RSAPrivateKey):
+        cert = self._cert_with_extension(
+            x509.UnrecognizedExtension(
+                ExtensionOID.ADMISSIONS, self.sample_der
+            ),
+            rsa_key_2048,
+        )
+        parsed = cert.extensions.get_extension_for_class(x509.Admissions)
+        assert parsed.value == self.sample
+        assert parsed.value.authority == x509.UniformResourceIdentifier(
+            "https://www.example.com/"
+        )
+        info = parsed.value[0].profession_infos[0]
+        assert info.add_profession_info == b"\xde\xad\xbe\xef"
+
+    @pytest.mark.parametrize(
+        "item",
+        [
+            b"\x0c\x04Arzt",
+            b"\x13\x04Arzt",
+            b"\x14\x04Arzt",
+            b"\x1c\x10" + "Arzt".encode("utf_32_be"),
+            b"\x1e\x08" + "Arzt".encode("utf_16_be"),
+        ],
+    )
+    def test_parse_directory_strings(
+        self, rsa_key_2048: rsa.RSAPrivateKey, item
+    ):
+        def tlv(tag, *values):
+            value = b"".join(values)
+            return bytes([tag, len(value)]) + value
+
+        # The naming authority's text and the profession items are
+        # DirectoryStrings, which may use any of the string types.
+        naming_authority = tlv(0xA1, tlv(0x30, item))
+        profession_info = tlv(0x30, tlv(0x30, item))
+        der = tlv(
+            0x30,
+            tlv(
+                0x30,
+                tlv(0x30, naming_authority, tlv(0x30, profession_info)),
+            ),
+        )
+        cert = self._cert_with_extension(
+            x509.UnrecognizedExtension(ExtensionOID.ADMISSIONS, der),
+            rsa_key_2048,
+        )
+        parsed = cert.extensions.get_extension_for_class(x509.Admissions)
+        [admission] = parsed.value
+        assert admission.naming_authority == x509.NamingAuthority(
+            None, None, "Arzt"
+        )
+        [info] = admission.profession_infos
+        assert info.profession_items == ["Arzt"]
+
+    def test_round_trip(self, rsa_key_2048: rsa.RSAPrivateKey):
+        cert = self._cert_with_extension(self.sample, rsa_key_2048)
+        parsed = cert.extensions.get_extension_for_class(x509.Admissions)
+        assert parsed.oid == ExtensionOID.ADMISSIONS
+        assert parsed.value == self.sample
+
+
+def test_all_extension_oid_members_have_names_defined():
+    for oid in dir(ExtensionOID):
+        if oid.startswith("__"):
+            continue
+        assert getattr(ExtensionOID, oid) in _OID_NAMES
+
+
+def test_unknown_extension():
+    class MyExtension(ExtensionType):
         oid = x509.ObjectIdentifier("1.2.3.4")
 
     with pytest.raises(NotImplementedError):
@@ -6332,3 +7333,241 @@ def test_unknown_extension():
 
     with pytest.raises(NotImplementedError):
         rust_x509.encode_extension_value(MyExtension())
+
+
+class TestNetscapeCertType:
+    def test_eq(self):
+        ct1 = x509.NetscapeCertType(
+            True, True, False, False, False, False, False
+        )
+        ct2 = x509.NetscapeCertType(
+            True, True, False, False, False, False, False
+        )
+        assert ct1 == ct2
+
+    def test_ne(self):
+        ct = x509.NetscapeCertType(
+            True, True, False, False, False, False, False
+        )
+        assert ct != x509.NetscapeCertType(
+            True, False, False, False, False, False, False
+        )
+        assert ct != object()
+
+    def test_repr(self):
+        ct = x509.NetscapeCertType(
+            False, True, False, False, True, False, False
+        )
+        assert repr(ct) == (
+            "<NetscapeCertType(ssl_client=False, ssl_server=True, "
+            "smime=False, object_signing=False, ssl_ca=True, "
+            "smime_ca=False, object_signing_ca=False)>"
+        )
+
+    def test_hash(self):
+        ct1 = x509.NetscapeCertType(
+            True, True, False, False, False, False, False
+        )
+        ct2 = x509.NetscapeCertType(
+            True, True, False, False, False, False, False
+        )
+        ct3 = x509.NetscapeCertType(
+            False, False, False, False, False, False, True
+        )
+        assert hash(ct1) == hash(ct2)
+        assert hash(ct1) != hash(ct3)
+
+    @pytest.mark.parametrize(
+        ("ext", "serialized"),
+        [
+            (
+                x509.NetscapeCertType(
+                    False, True, False, False, False, False, False
+                ),
+                b"\x03\x02\x06\x40",
+            ),
+            (
+                x509.NetscapeCertType(
+                    True, True, True, True, True, True, True
+                ),
+                b"\x03\x02\x00\xf7",
+            ),
+            (
+                x509.NetscapeCertType(
+                    False, False, False, False, False, False, False
+                ),
+                b"\x03\x01\x00",
+            ),
+        ],
+    )
+    def test_public_bytes(self, ext, serialized):
+        assert ext.public_bytes() == serialized
+
+
+class TestNetscapeCertTypeExtension:
+    @pytest.mark.parametrize(
+        ("filename", "expected"),
+        [
+            (
+                os.path.join("custom", "cdp_empty_hostname.pem"),
+                x509.NetscapeCertType(
+                    False, True, False, False, False, False, False
+                ),
+            ),
+            (
+                "belgian-eid-invalid-visiblestring.pem",
+                x509.NetscapeCertType(
+                    False, False, True, False, False, False, False
+                ),
+            ),
+        ],
+    )
+    def test_netscape_cert_type(self, filename, expected, backend):
+        cert = _load_cert(
+            os.path.join("x509", filename),
+            x509.load_pem_x509_certificate,
+        )
+        ext = cert.extensions.get_extension_for_oid(
+            ExtensionOID.NETSCAPE_CERT_TYPE
+        )
+        assert ext.critical is False
+        assert ext.value == expected
+
+    def test_netscape_comment(self, backend):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "cdp_empty_hostname.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        comment = cert.extensions.get_extension_for_class(
+            x509.NetscapeComment
+        ).value
+        assert comment == x509.NetscapeComment(
+            "OpenSSL Generated Server Certificate"
+        )
+
+
+class TestNetscapeComment:
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            x509.NetscapeComment(b"comment")  # type:ignore[arg-type]
+
+    def test_eq(self):
+        assert x509.NetscapeComment("comment") == x509.NetscapeComment(
+            "comment"
+        )
+
+    def test_ne(self):
+        comment = x509.NetscapeComment("comment")
+        assert comment != x509.NetscapeComment("other")
+        assert comment != object()
+
+    def test_repr(self):
+        comment = x509.NetscapeComment("comment")
+        assert repr(comment) == "<NetscapeComment(comment='comment')>"
+
+    def test_hash(self):
+        comment1 = x509.NetscapeComment("comment")
+        comment2 = x509.NetscapeComment("comment")
+        comment3 = x509.NetscapeComment("other")
+        assert hash(comment1) == hash(comment2)
+        assert hash(comment1) != hash(comment3)
+
+    def test_public_bytes(self):
+        ext = x509.NetscapeComment("comment")
+        assert ext.public_bytes() == b"\x16\x07comment"
+
+    def test_public_bytes_non_ascii(self):
+        ext = x509.NetscapeComment("café")
+        with pytest.raises(ValueError):
+            ext.public_bytes()
+
+
+class TestEntrustVersionInfo:
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            x509.EntrustVersionInfo(
+                b"V8.1",  # type:ignore[arg-type]
+                False,
+                False,
+                False,
+            )
+
+    def test_eq(self):
+        assert x509.EntrustVersionInfo(
+            "V8.1", True, False, False
+        ) == x509.EntrustVersionInfo("V8.1", True, False, False)
+
+    def test_ne(self):
+        info = x509.EntrustVersionInfo("V8.1", True, False, False)
+        assert info != x509.EntrustVersionInfo("V8.1", False, False, False)
+        assert info != x509.EntrustVersionInfo("V7.1", True, False, False)
+        assert info != object()
+
+    def test_repr(self):
+        info = x509.EntrustVersionInfo("V8.1", True, False, True)
+        assert repr(info) == (
+            "<EntrustVersionInfo(version='V8.1', key_update_allowed=True, "
+            "new_extensions=False, pkix_certificate=True)>"
+        )
+
+    def test_hash(self):
+        info1 = x509.EntrustVersionInfo("V8.1", True, False, False)
+        info2 = x509.EntrustVersionInfo("V8.1", True, False, False)
+        info3 = x509.EntrustVersionInfo("V8.1", False, True, False)
+        assert hash(info1) == hash(info2)
+        assert hash(info1) != hash(info3)
+
+    @pytest.mark.parametrize(
+        ("ext", "serialized"),
+        [
+            (
+                x509.EntrustVersionInfo("V8.1:4.0", True, False, True),
+                b"\x30\x0e\x1b\x08V8.1:4.0\x03\x02\x05\xa0",
+            ),
+            (
+                x509.EntrustVersionInfo("V8.1", False, False, False),
+                b"\x30\x06\x1b\x04V8.1",
+            ),
+        ],
+    )
+    def test_public_bytes(self, ext, serialized):
+        assert ext.public_bytes() == serialized
+
+    @pytest.mark.parametrize(
+        ("der", "expected"),
+        [
+            (
+                # Entrust certificates commonly set bits outside of the
+                # three that are defined, which are ignored.
+                b"\x30\x0c\x1b\x06V8.1:4\x03\x02\x04\x90",
+                x509.EntrustVersionInfo("V8.1:4", True, False, False),
+            ),
+            (
+                b"\x30\x06\x1b\x04V8.1",
+                x509.EntrustVersionInfo("V8.1", False, False, False),
+            ),
+        ],
+    )
+    def test_parse(
+        self, rsa_key_2048: rsa.RSAPrivateKey, der, expected, backend
+    ):
+        cert = x509.create_self_signed_certificate(
+            x509.Name([]),
+            rsa_key_2048,
+            datetime.datetime(2020, 1, 1),
+            datetime.datetime(2030, 1, 1),
+            hashes.SHA256(),
+            extensions=[
+                x509.Extension(
+                    ExtensionOID.ENTRUST_VERSION_INFO,
+                    False,
+                    x509.UnrecognizedExtension(
+                        ExtensionOID.ENTRUST_VERSION_INFO, der
+                    ),
+                )
+            ],
+        )
+        info = cert.extensions.get_extension_for_class(
+            x509.EntrustVersionInfo
+        ).value
+        assert info == expected
@@ -8,6 +8,7 @@ import datetime
 import pytest
 
 from cryptography import utils, x509
+from cryptography.x509.oid import HoldInstructionOID
 
 
 class TestRevokedCertificateBuilder:
@@ -149,6 +150,7 @@ class TestRevokedCertificateBuilder:
             x509.InvalidityDate(datetime.datetime(2015, 1, 1, 0, 0)),
             x509.CRLReason(x509.ReasonFlags.ca_compromise),
             x509.CertificateIssuer([x509.DNSName("cryptography.io")]),
+            x509.HoldInstructionCode(HoldInstructionOID.REJECT),
         ],
     )
     def test_add_extensions(self, backend, extension):
@@ -39,16 +39,17 @@ LIMBO_UNSUPPORTED_FEATURES = {
     # incompatible ways. Our validator always tries (by default) to comply
     # closer to CABF, so we skip these.
     "rfc5280-incompatible-with-webpki",
-    # We do not support policy constraints.
+    # We process certificate policies, policy constraints and
+    # inhibitAnyPolicy, but these testcases also cover policy mappings and
+    # user-supplied initial policy sets, which we do not support.
     "has-policy-constraints",
 }
 
 LIMBO_SKIP_TESTCASES = {
-    # We unconditionally count intermediate certificates for pathlen and max
-    # depth constraint purposes, even when self-issued.
+    # We unconditionally count intermediate certificates for max depth
+    # constraint purposes, even when self-issued.
     # This is a violation of RFC 5280, but is consistent with Go's crypto/x509
     # and Rust's webpki crate do.
-    "pathlen::self-issued-certs-pathlen",
     "pathlen::max-chain-depth-1-self-issued",
     # We allow certificates with serial numbers of zero. This is
     # invalid under RFC 5280 but is widely violated by certs in common
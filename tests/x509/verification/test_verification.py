@@ -4,19 +4,50 @@
 
 import datetime
 import os
+import typing
 from functools import lru_cache
 from ipaddress import IPv4Address
 
 import pytest
 
 from cryptography import x509
-from cryptography.x509.general_name import DNSName, IPAddress
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec, ed25519, x25519
+from cryptography.hazmat.primitives.asymmetric.types import (
+    CertificatePublicKeyTypes,
+)
+from cryptography.hazmat.primitives.serialization import Encoding
+from cryptography.x509.general_name import (
+    DirectoryName,
+    DNSName,
+    IPAddress,
+    UniformResourceIdentifier,
+)
+from cryptography.x509.name import _ASN1Type
+from cryptography.x509.oid import (
+    AuthorityInformationAccessOID,
+    CertificatePoliciesOID,
+    ExtendedKeyUsageOID,
+    ExtensionOID,
+    NameOID,
+)
 from cryptography.x509.verification import (
     PolicyBuilder,
     Store,
+    TrustAnchor,
     VerificationError,
+    authority_key_identifier_matches,
+    chain_pem,
 )
 from tests.x509.test_x509 import _load_cert
+from tests.x509.verification.utils import (
+    VALIDATION_TIME,
+    Extensions,
+    issue_cert,
+    make_ca,
+    make_leaf,
+    make_name,
+)
 
 
 @lru_cache(maxsize=1)
@@ -203,3 +234,1758 @@ class TestServerVerifier:
                 match="cert is not valid at validation time",
             ):
                 verifier.verify(leaf, [])
+
+
+class TestChainBuilding:
+    def test_many_decoy_intermediates(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), int_key, root.subject, root_key
+        )
+        leaf = make_leaf("example.com", intermediate.subject, int_key)
+
+        # Decoys whose subject doesn't match the leaf's issuer...
+        decoy_key = ec.generate_private_key(ec.SECP256R1())
+        decoys = [
+            make_ca(make_name(f"decoy {i}"), decoy_key, root.subject, root_key)
+            for i in range(200)
+        ]
+        # ...and decoys whose subject matches but which didn't sign the leaf.
+        decoys += [
+            make_ca(intermediate.subject, decoy_key, root.subject, root_key)
+            for _ in range(5)
+        ]
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        chain = verifier.verify(leaf, [*decoys, intermediate])
+        assert chain == [leaf, intermediate, root]
+
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, decoys)
+
+    def test_names_compared_canonically(self):
+        def name(value: str, asn1_type: _ASN1Type) -> x509.Name:
+            return x509.Name(
+                [x509.NameAttribute(NameOID.COMMON_NAME, value, asn1_type)]
+            )
+
+        # Issuer names only match their issuer's subject after case folding
+        # and whitespace handling, as in RFC 5280 7.1.
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(
+            name("Example Root", _ASN1Type.PrintableString), root_key
+        )
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            name("Example Intermediate", _ASN1Type.PrintableString),
+            int_key,
+            name(" example  ROOT", _ASN1Type.UTF8String),
+            root_key,
+        )
+        leaf = make_leaf(
+            "example.com",
+            name("example intermediate ", _ASN1Type.UTF8String),
+            int_key,
+        )
+
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        chain = verifier.verify(leaf, [intermediate])
+        assert chain == [leaf, intermediate, root]
+
+        other = make_leaf(
+            "example.com",
+            name("example intermediates", _ASN1Type.UTF8String),
+            int_key,
+        )
+        with pytest.raises(VerificationError):
+            verifier.verify(other, [intermediate])
+
+    def _hierarchy(self):
+        # root -> first -> second -> third -> leaf
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        issuer, issuer_key = root, root_key
+        intermediates = []
+        for name in ("first", "second", "third"):
+            key = ec.generate_private_key(ec.SECP256R1())
+            issuer = make_ca(make_name(name), key, issuer.subject, issuer_key)
+            issuer_key = key
+            intermediates.append(issuer)
+        leaf = make_leaf("example.com", issuer.subject, issuer_key)
+        return root, intermediates, leaf
+
+    def _verifier(self, store):
+        return (
+            PolicyBuilder()
+            .store(store)
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+
+    def test_unordered_intermediates(self):
+        root, (first, second, third), leaf = self._hierarchy()
+        verifier = self._verifier(Store([root]))
+        # Out of order, with duplicates and the trust anchor itself.
+        chain = verifier.verify(
+            leaf, [second, root, first, third, second, first, third]
+        )
+        assert chain == [leaf, third, second, first, root]
+
+    def test_partial_intermediates(self):
+        root, (first, second, third), leaf = self._hierarchy()
+        # The intermediate that's missing from the peer's set is a trust
+        # anchor, so the chain is completed from the store.
+        verifier = self._verifier(Store([first]))
+        chain = verifier.verify(leaf, [third, second, third])
+        assert chain == [leaf, third, second, first]
+
+        # Otherwise the chain can't be completed.
+        verifier = self._verifier(Store([root]))
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [third, first, third])
+
+    def test_many_duplicate_intermediates(self):
+        _, (first, second, third), leaf = self._hierarchy()
+        # A root with the right name, but the wrong key.
+        other_root = make_ca(
+            make_name("root"), ec.generate_private_key(ec.SECP256R1())
+        )
+        verifier = self._verifier(Store([other_root]))
+        # Without deduplication, every combination of copies would be tried
+        # before giving up.
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [third] * 50 + [second] * 50 + [first] * 50)
+
+    def _same_name_issuers(self):
+        # Two intermediates with the same subject and key, differing only in
+        # their SubjectKeyIdentifier: both verify the leaf's signature, so
+        # only the leaf's AuthorityKeyIdentifier can tell them apart.
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        first, second = (
+            make_ca(
+                make_name("intermediate"),
+                int_key,
+                root.subject,
+                root_key,
+                [(x509.SubjectKeyIdentifier(ski), False)],
+            )
+            for ski in (b"\x01" * 20, b"\x02" * 20)
+        )
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier, root, int_key, first, second
+
+    def test_aki_disambiguates_same_name_issuers(self):
+        verifier, root, int_key, first, second = self._same_name_issuers()
+        for issuer in (first, second):
+            ski = issuer.extensions.get_extension_for_class(
+                x509.SubjectKeyIdentifier
+            ).value.digest
+            leaf = make_leaf(
+                "example.com",
+                issuer.subject,
+                int_key,
+                [
+                    (
+                        x509.AuthorityKeyIdentifier(ski, None, None),
+                        False,
+                    )
+                ],
+            )
+            for intermediates in ([first, second], [second, first]):
+                chain = verifier.verify(leaf, intermediates)
+                assert chain == [leaf, issuer, root]
+
+    def test_aki_without_matching_ski_falls_back(self):
+        verifier, root, int_key, first, second = self._same_name_issuers()
+        leaf = make_leaf(
+            "example.com",
+            first.subject,
+            int_key,
+            [(x509.AuthorityKeyIdentifier(b"\x03" * 20, None, None), False)],
+        )
+        chain = verifier.verify(leaf, [second, first])
+        assert chain == [leaf, second, root]
+
+    def test_aki_issuer_and_serial_disambiguates_same_name_issuers(self):
+        verifier, root, int_key, first, second = self._same_name_issuers()
+        for issuer in (first, second):
+            leaf = make_leaf(
+                "example.com",
+                issuer.subject,
+                int_key,
+                [
+                    (
+                        x509.AuthorityKeyIdentifier(
+                            None,
+                            [DirectoryName(issuer.issuer)],
+                            issuer.serial_number,
+                        ),
+                        False,
+                    )
+                ],
+            )
+            for intermediates in ([first, second], [second, first]):
+                chain = verifier.verify(leaf, intermediates)
+                assert chain == [leaf, issuer, root]
+
+    def _cross_signed_cycle(self):
+        # Two CAs which have cross-signed each other. The leaf's issuer is
+        # also signed by the root, but only after the cycle in input order.
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        a_key = ec.generate_private_key(ec.SECP256R1())
+        b_key = ec.generate_private_key(ec.SECP256R1())
+        a_by_b = make_ca(make_name("a"), a_key, make_name("b"), b_key)
+        b_by_a = make_ca(make_name("b"), b_key, make_name("a"), a_key)
+        a_by_root = make_ca(make_name("a"), a_key, root.subject, root_key)
+        leaf = make_leaf("example.com", make_name("a"), a_key)
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .max_chain_depth(255)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier, root, leaf, a_by_b, b_by_a, a_by_root
+
+    def test_cross_signed_cycle(self):
+        verifier, root, leaf, a_by_b, b_by_a, a_by_root = (
+            self._cross_signed_cycle()
+        )
+        chain = verifier.verify(leaf, [a_by_b, b_by_a, a_by_root])
+        assert chain == [leaf, a_by_root, root]
+
+    def test_cross_signed_cycle_without_path(self):
+        verifier, _, leaf, a_by_b, b_by_a, _ = self._cross_signed_cycle()
+        with pytest.raises(VerificationError, match="loop"):
+            verifier.verify(leaf, [a_by_b, b_by_a])
+
+
+class TestSignatureHashAlgorithms:
+    def _verify_sha1_chain(
+        self, builder: PolicyBuilder, intermediate: str
+    ) -> typing.List[x509.Certificate]:
+        # The leaf is signed with SHA-256 and both intermediates share its
+        # key, so only the intermediate's and root's algorithms vary.
+        root, intermediate_cert, leaf = (
+            _load_cert(
+                os.path.join("x509", "custom", "sha1_chain", filename),
+                x509.load_pem_x509_certificate,
+            )
+            for filename in ["root.pem", intermediate, "leaf.pem"]
+        )
+        verifier = (
+            builder.store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier.verify(leaf, [intermediate_cert])
+
+    def test_already_set(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().signature_hash_algorithms(
+                [hashes.SHA256()]
+            ).signature_hash_algorithms([hashes.SHA256()])
+
+    def test_invalid_algorithms(self):
+        with pytest.raises(ValueError):
+            PolicyBuilder().signature_hash_algorithms([hashes.SHA1()])
+        with pytest.raises(TypeError):
+            PolicyBuilder().signature_hash_algorithms(
+                ["sha256"]  # type: ignore[list-item]
+            )
+
+    def test_sha256_rejected_by_allow_list(self):
+        # The same chain is accepted by default, so it's only the configured
+        # algorithms that reject its SHA-256 signatures.
+        chain = self._verify_sha1_chain(
+            PolicyBuilder(), "intermediate_sha256.pem"
+        )
+        assert len(chain) == 3
+        builder = PolicyBuilder().signature_hash_algorithms(
+            [hashes.SHA384(), hashes.SHA512()]
+        )
+        with pytest.raises(
+            VerificationError, match="Forbidden signature algorithm"
+        ):
+            self._verify_sha1_chain(builder, "intermediate_sha256.pem")
+
+    def test_sha1_intermediate_rejected_by_default(self):
+        # The chain only differs from the one below in the intermediate's
+        # signature, so the policy rejects it for being signed with SHA-1.
+        chain = self._verify_sha1_chain(
+            PolicyBuilder(), "intermediate_sha256.pem"
+        )
+        assert len(chain) == 3
+        with pytest.raises(
+            VerificationError, match="Forbidden signature algorithm"
+        ):
+            self._verify_sha1_chain(PolicyBuilder(), "intermediate_sha1.pem")
+
+    def test_sha1_root_exempt_by_default(self):
+        # root.pem's self-signature uses SHA-1, which isn't relied upon.
+        chain = self._verify_sha1_chain(
+            PolicyBuilder(), "intermediate_sha256.pem"
+        )
+        assert isinstance(chain[-1].signature_hash_algorithm, hashes.SHA1)
+
+    def test_sha1_intermediate_rejected(self):
+        # Restricting the algorithms doesn't bring SHA-1 back.
+        builder = PolicyBuilder().signature_hash_algorithms([hashes.SHA256()])
+        with pytest.raises(
+            VerificationError, match="Forbidden signature algorithm"
+        ):
+            self._verify_sha1_chain(builder, "intermediate_sha1.pem")
+
+    def test_sha1_root_allowed(self):
+        builder = PolicyBuilder().signature_hash_algorithms([hashes.SHA256()])
+        chain = self._verify_sha1_chain(builder, "intermediate_sha256.pem")
+        assert len(chain) == 3
+
+    def test_sha1_root_rejected_with_check_trust_anchor(self):
+        builder = PolicyBuilder().signature_hash_algorithms(
+            [hashes.SHA256()], check_trust_anchor=True
+        )
+        with pytest.raises(
+            VerificationError,
+            match="Forbidden trust anchor signature algorithm",
+        ):
+            self._verify_sha1_chain(builder, "intermediate_sha256.pem")
+
+    def test_restricts_default_algorithms(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"),
+            int_key,
+            root.subject,
+            root_key,
+            algorithm=hashes.SHA384(),
+        )
+        sha256_leaf = make_leaf("example.com", intermediate.subject, int_key)
+        sha384_leaf = make_leaf(
+            "example.com",
+            intermediate.subject,
+            int_key,
+            algorithm=hashes.SHA384(),
+        )
+        verifier = (
+            PolicyBuilder()
+            .signature_hash_algorithms([hashes.SHA384(), hashes.SHA512()])
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+
+        with pytest.raises(
+            VerificationError, match="Forbidden signature algorithm"
+        ):
+            verifier.verify(sha256_leaf, [intermediate])
+
+        # The root's own SHA-256 self-signature is exempt by default.
+        chain = verifier.verify(sha384_leaf, [intermediate])
+        assert len(chain) == 3
+
+
+class TestTrustAnchor:
+    def test_invalid_purposes(self):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        with pytest.raises(ValueError):
+            TrustAnchor(cert, [])
+        with pytest.raises(TypeError):
+            TrustAnchor(cert, ["not an oid"])  # type: ignore[list-item]
+
+    def test_invalid_store_entry(self):
+        with pytest.raises(TypeError):
+            Store([object()])  # type: ignore[list-item]
+
+    def test_attributes(self):
+        cert = _load_cert(
+            os.path.join("x509", "cryptography.io.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        anchor = TrustAnchor(cert, [ExtendedKeyUsageOID.EMAIL_PROTECTION])
+        assert anchor.certificate == cert
+        assert anchor.purposes == [ExtendedKeyUsageOID.EMAIL_PROTECTION]
+
+    def _verify(
+        self, root_key: ec.EllipticCurvePrivateKey, store: Store
+    ) -> typing.List[x509.Certificate]:
+        leaf = make_leaf("example.com", make_name("root"), root_key)
+        verifier = (
+            PolicyBuilder()
+            .store(store)
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier.verify(leaf, [])
+
+    def test_anchor_trusted_for_purpose(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        anchor = TrustAnchor(
+            root,
+            [
+                ExtendedKeyUsageOID.EMAIL_PROTECTION,
+                ExtendedKeyUsageOID.SERVER_AUTH,
+            ],
+        )
+        assert self._verify(root_key, Store([anchor]))[-1] == root
+
+    def test_anchor_not_trusted_for_purpose(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        anchor = TrustAnchor(root, [ExtendedKeyUsageOID.EMAIL_PROTECTION])
+        with pytest.raises(
+            VerificationError, match="not trusted for the policy's"
+        ):
+            self._verify(root_key, Store([anchor]))
+
+    def test_other_anchor_trusted_for_purpose(self):
+        # The same root, listed a second time without purpose restrictions.
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        anchor = TrustAnchor(root, [ExtendedKeyUsageOID.EMAIL_PROTECTION])
+        assert self._verify(root_key, Store([anchor, root]))[-1] == root
+
+
+class TestSatisfiedExtendedKeyUsages:
+    def _chain(self, leaf_ekus, intermediate_ekus):
+        def eku_ext(ekus):
+            if ekus is None:
+                return []
+            return [(x509.ExtendedKeyUsage(ekus), False)]
+
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"),
+            int_key,
+            root.subject,
+            root_key,
+            eku_ext(intermediate_ekus),
+        )
+        leaf = make_leaf(
+            "example.com", intermediate.subject, int_key, eku_ext(leaf_ekus)
+        )
+        return root, intermediate, leaf
+
+    def _server_verifier(self, store):
+        return (
+            PolicyBuilder()
+            .store(store)
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+
+    def test_leaf_ekus(self):
+        ekus = [
+            ExtendedKeyUsageOID.SERVER_AUTH,
+            ExtendedKeyUsageOID.CLIENT_AUTH,
+        ]
+        root, intermediate, leaf = self._chain(ekus, None)
+        verifier = self._server_verifier(Store([root]))
+        satisfied = verifier.satisfied_extended_key_usages(
+            leaf, [intermediate]
+        )
+        assert satisfied == ekus
+
+    def test_intermediate_narrows(self):
+        root, intermediate, leaf = self._chain(
+            [
+                ExtendedKeyUsageOID.SERVER_AUTH,
+                ExtendedKeyUsageOID.CLIENT_AUTH,
+                ExtendedKeyUsageOID.CODE_SIGNING,
+            ],
+            [
+                ExtendedKeyUsageOID.EMAIL_PROTECTION,
+                ExtendedKeyUsageOID.SERVER_AUTH,
+                ExtendedKeyUsageOID.CODE_SIGNING,
+            ],
+        )
+        verifier = self._server_verifier(Store([root]))
+        satisfied = verifier.satisfied_extended_key_usages(
+            leaf, [intermediate]
+        )
+        assert satisfied == [
+            ExtendedKeyUsageOID.SERVER_AUTH,
+            ExtendedKeyUsageOID.CODE_SIGNING,
+        ]
+
+    def test_any_extended_key_usage(self):
+        root, intermediate, leaf = self._chain(
+            None, [ExtendedKeyUsageOID.ANY_EXTENDED_KEY_USAGE]
+        )
+        verifier = self._server_verifier(Store([root]))
+        satisfied = verifier.satisfied_extended_key_usages(
+            leaf, [intermediate]
+        )
+        assert satisfied == [ExtendedKeyUsageOID.ANY_EXTENDED_KEY_USAGE]
+
+    def test_trust_anchor_narrows(self):
+        root, intermediate, leaf = self._chain(None, None)
+        anchor = TrustAnchor(
+            root,
+            [
+                ExtendedKeyUsageOID.SERVER_AUTH,
+                ExtendedKeyUsageOID.CLIENT_AUTH,
+            ],
+        )
+        verifier = self._server_verifier(Store([anchor]))
+        satisfied = verifier.satisfied_extended_key_usages(
+            leaf, [intermediate]
+        )
+        assert satisfied == [
+            ExtendedKeyUsageOID.SERVER_AUTH,
+            ExtendedKeyUsageOID.CLIENT_AUTH,
+        ]
+
+    def test_client_verifier(self):
+        root, intermediate, leaf = self._chain(
+            [
+                ExtendedKeyUsageOID.SERVER_AUTH,
+                ExtendedKeyUsageOID.CLIENT_AUTH,
+            ],
+            [ExtendedKeyUsageOID.CLIENT_AUTH],
+        )
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_client_verifier()
+        )
+        satisfied = verifier.satisfied_extended_key_usages(
+            leaf, [intermediate]
+        )
+        assert satisfied == [ExtendedKeyUsageOID.CLIENT_AUTH]
+
+    def test_verification_failure(self):
+        root, intermediate, leaf = self._chain(
+            None, [ExtendedKeyUsageOID.CLIENT_AUTH]
+        )
+        verifier = self._server_verifier(Store([root]))
+        with pytest.raises(VerificationError):
+            verifier.satisfied_extended_key_usages(leaf, [intermediate])
+
+
+_POLICY = x509.ObjectIdentifier("1.2.3.4")
+
+
+def _policies(*oids: x509.ObjectIdentifier) -> Extensions:
+    return [
+        (
+            x509.CertificatePolicies(
+                [x509.PolicyInformation(oid, None) for oid in oids]
+            ),
+            False,
+        )
+    ]
+
+
+class TestCertificatePolicies:
+    def _verify(
+        self,
+        first_extensions: Extensions,
+        second_extensions: Extensions,
+        leaf_extensions: Extensions,
+    ):
+        # root -> first -> second -> leaf
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        first_key = ec.generate_private_key(ec.SECP256R1())
+        first = make_ca(
+            make_name("first"),
+            first_key,
+            root.subject,
+            root_key,
+            first_extensions,
+        )
+        second_key = ec.generate_private_key(ec.SECP256R1())
+        second = make_ca(
+            make_name("second"),
+            second_key,
+            first.subject,
+            first_key,
+            second_extensions,
+        )
+        leaf = make_leaf(
+            "example.com", second.subject, second_key, leaf_extensions
+        )
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier.verify(leaf, [first, second])
+
+    def _constraints(
+        self,
+        require_explicit_policy: typing.Optional[int],
+        inhibit_any_policy: typing.Optional[int] = None,
+    ) -> Extensions:
+        extensions: typing.List[typing.Tuple[x509.ExtensionType, bool]] = [
+            *_policies(CertificatePoliciesOID.ANY_POLICY)
+        ]
+        if require_explicit_policy is not None:
+            extensions.append(
+                (x509.PolicyConstraints(require_explicit_policy, None), True)
+            )
+        if inhibit_any_policy is not None:
+            extensions.append(
+                (x509.InhibitAnyPolicy(inhibit_any_policy), True)
+            )
+        return extensions
+
+    def test_any_policy(self):
+        chain = self._verify(
+            self._constraints(0),
+            _policies(CertificatePoliciesOID.ANY_POLICY),
+            _policies(_POLICY),
+        )
+        assert len(chain) == 4
+
+    def test_inhibit_any_policy(self):
+        with pytest.raises(
+            VerificationError, match="no certificate policy is valid"
+        ):
+            self._verify(
+                self._constraints(0, 0),
+                _policies(CertificatePoliciesOID.ANY_POLICY),
+                _policies(_POLICY),
+            )
+
+    def test_inhibit_any_policy_without_explicit_policy(self):
+        # Without requireExplicitPolicy, an empty set of valid policies is
+        # acceptable.
+        chain = self._verify(
+            self._constraints(None, 0),
+            _policies(CertificatePoliciesOID.ANY_POLICY),
+            _policies(_POLICY),
+        )
+        assert len(chain) == 4
+
+    def test_inhibit_any_policy_skip_certs(self):
+        # skipCerts=1 lets the next certificate still use anyPolicy, but
+        # not the one after it.
+        chain = self._verify(
+            self._constraints(0, 1),
+            _policies(CertificatePoliciesOID.ANY_POLICY),
+            _policies(_POLICY),
+        )
+        assert len(chain) == 4
+
+        with pytest.raises(VerificationError):
+            self._verify(
+                self._constraints(0, 1),
+                _policies(CertificatePoliciesOID.ANY_POLICY),
+                _policies(CertificatePoliciesOID.ANY_POLICY),
+            )
+
+    def test_explicit_policy_mismatch(self):
+        with pytest.raises(VerificationError):
+            self._verify(
+                [
+                    *_policies(_POLICY),
+                    (x509.PolicyConstraints(0, None), True),
+                ],
+                _policies(CertificatePoliciesOID.ANY_POLICY),
+                _policies(x509.ObjectIdentifier("1.2.3.5")),
+            )
+
+    @pytest.mark.parametrize(
+        ("require_explicit_policy", "valid"),
+        [(1, False), (2, False), (3, True)],
+    )
+    def test_require_explicit_policy_skip_certs(
+        self, require_explicit_policy, valid
+    ):
+        # Neither `second` nor the leaf assert any policies, so the chain is
+        # only valid if the explicit policy requirement kicks in after the
+        # leaf.
+        extensions = self._constraints(require_explicit_policy)
+        if valid:
+            assert len(self._verify(extensions, [], [])) == 4
+        else:
+            with pytest.raises(VerificationError):
+                self._verify(extensions, [], [])
+
+    def test_cross_signed_policy_fallback(self):
+        # The intermediate is cross-signed by two roots. Only the path
+        # through the second cross-signature satisfies the policy, so path
+        # building has to move on from the first instead of failing.
+        root_keys = [ec.generate_private_key(ec.SECP256R1()) for _ in "ab"]
+        roots = [
+            make_ca(make_name(f"root {i}"), key)
+            for i, key in enumerate(root_keys)
+        ]
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        unsatisfiable = make_ca(
+            make_name("intermediate"),
+            int_key,
+            roots[0].subject,
+            root_keys[0],
+            [
+                *_policies(x509.ObjectIdentifier("1.2.3.5")),
+                (x509.PolicyConstraints(0, None), True),
+            ],
+        )
+        satisfiable = make_ca(
+            make_name("intermediate"),
+            int_key,
+            roots[1].subject,
+            root_keys[1],
+            _policies(_POLICY),
+        )
+        leaf = make_leaf(
+            "example.com", satisfiable.subject, int_key, _policies(_POLICY)
+        )
+        verifier = (
+            PolicyBuilder()
+            .store(Store(roots))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+
+        chain = verifier.verify(leaf, [unsatisfiable, satisfiable])
+        assert chain == [leaf, satisfiable, roots[1]]
+
+        with pytest.raises(
+            VerificationError, match="no certificate policy is valid"
+        ):
+            verifier.verify(leaf, [unsatisfiable])
+
+    def test_policy_mappings(self):
+        mappings: Extensions = [
+            (
+                x509.PolicyMappings(
+                    [
+                        x509.PolicyMapping(
+                            _POLICY, x509.ObjectIdentifier("1.2.3.5")
+                        )
+                    ]
+                ),
+                False,
+            )
+        ]
+        # Policy mappings aren't processed. That's only harmless if no
+        # explicit policy is required, since then the valid policies don't
+        # matter.
+        chain = self._verify(
+            self._constraints(None),
+            [*_policies(_POLICY), *mappings],
+            _policies(_POLICY),
+        )
+        assert len(chain) == 4
+
+        with pytest.raises(
+            VerificationError, match="policy mappings are not supported"
+        ):
+            self._verify(
+                self._constraints(0),
+                [*_policies(_POLICY), *mappings],
+                _policies(_POLICY),
+            )
+
+        # Mappings in a critical extension are rejected outright.
+        with pytest.raises(VerificationError, match="2.5.29.33"):
+            self._verify(
+                self._constraints(None),
+                [*_policies(_POLICY), (mappings[0][0], True)],
+                _policies(_POLICY),
+            )
+
+
+def _key_usage(**usages: bool) -> x509.KeyUsage:
+    fields = {
+        "digital_signature": False,
+        "content_commitment": False,
+        "key_encipherment": False,
+        "data_encipherment": False,
+        "key_agreement": False,
+        "key_cert_sign": False,
+        "crl_sign": False,
+        "encipher_only": False,
+        "decipher_only": False,
+    }
+    fields.update(usages)
+    return x509.KeyUsage(**fields)
+
+
+class TestKeyUsage:
+    def _verify(
+        self,
+        public_key: CertificatePublicKeyTypes,
+        key_usage: typing.Optional[x509.ExtensionType],
+        require_key_establishment: bool = False,
+    ):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        extensions: Extensions = [
+            (x509.SubjectAlternativeName([DNSName("example.com")]), False),
+        ]
+        if key_usage is not None:
+            extensions.append((key_usage, True))
+        leaf = issue_cert(
+            make_name("example.com"),
+            public_key,
+            root.subject,
+            root_key,
+            extensions,
+        )
+        builder = PolicyBuilder().store(Store([root])).time(VALIDATION_TIME)
+        if require_key_establishment:
+            builder = builder.require_key_establishment()
+        verifier = builder.build_server_verifier(DNSName("example.com"))
+        return verifier.verify(leaf, [])
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            # digitalSignature and encipherOnly
+            b"\x03\x02\x00\x81",
+            # digitalSignature and decipherOnly
+            b"\x03\x03\x07\x80\x80",
+        ],
+    )
+    def test_encipher_decipher_only_requires_key_agreement(self, value):
+        # KeyUsage refuses to construct these, so they're encoded by hand.
+        key_usage = x509.UnrecognizedExtension(ExtensionOID.KEY_USAGE, value)
+        key = ec.generate_private_key(ec.SECP256R1()).public_key()
+        with pytest.raises(VerificationError, match="keyAgreement"):
+            self._verify(key, key_usage)
+
+    @pytest.mark.parametrize(
+        "key_usage",
+        [
+            _key_usage(key_agreement=True),
+            _key_usage(key_agreement=True, encipher_only=True),
+            _key_usage(key_agreement=True, decipher_only=True),
+        ],
+    )
+    def test_key_agreement(self, key_usage):
+        key = ec.generate_private_key(ec.SECP256R1()).public_key()
+        assert len(self._verify(key, key_usage)) == 2
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.x25519_supported(),
+        skip_message="Requires OpenSSL with X25519 support",
+    )
+    def test_ecdh_key_requires_key_agreement(self, backend):
+        key = x25519.X25519PrivateKey.generate().public_key()
+        with pytest.raises(VerificationError, match="keyAgreement"):
+            self._verify(key, _key_usage(digital_signature=True))
+
+        assert len(self._verify(key, _key_usage(key_agreement=True))) == 2
+
+    @pytest.mark.parametrize(
+        "key_usage",
+        [
+            _key_usage(digital_signature=True),
+            _key_usage(data_encipherment=True),
+            _key_usage(digital_signature=True, key_agreement=True),
+        ],
+    )
+    def test_rsa_key_transport_requires_key_encipherment(
+        self, rsa_key_2048, key_usage
+    ):
+        key = rsa_key_2048.public_key()
+        # Without the requirement, a signing-only certificate is fine.
+        assert len(self._verify(key, key_usage)) == 2
+        with pytest.raises(VerificationError, match="keyEncipherment"):
+            self._verify(key, key_usage, require_key_establishment=True)
+
+    @pytest.mark.parametrize(
+        "key_usage",
+        [None, _key_usage(digital_signature=True, key_encipherment=True)],
+    )
+    def test_rsa_key_transport(self, rsa_key_2048, key_usage):
+        key = rsa_key_2048.public_key()
+        chain = self._verify(key, key_usage, require_key_establishment=True)
+        assert len(chain) == 2
+
+    @pytest.mark.parametrize(
+        "key_usage",
+        [
+            _key_usage(digital_signature=True),
+            _key_usage(key_encipherment=True),
+            _key_usage(data_encipherment=True),
+        ],
+    )
+    def test_ec_key_establishment_requires_key_agreement(self, key_usage):
+        key = ec.generate_private_key(ec.SECP256R1()).public_key()
+        with pytest.raises(VerificationError, match="keyAgreement"):
+            self._verify(key, key_usage, require_key_establishment=True)
+
+        key_usage = _key_usage(key_agreement=True)
+        chain = self._verify(key, key_usage, require_key_establishment=True)
+        assert len(chain) == 2
+
+    @pytest.mark.supported(
+        only_if=lambda backend: backend.ed25519_supported(),
+        skip_message="Requires OpenSSL with Ed25519 support",
+    )
+    def test_signing_key_cannot_establish_keys(self, backend):
+        key = ed25519.Ed25519PrivateKey.generate().public_key()
+        with pytest.raises(VerificationError, match="key establishment"):
+            self._verify(key, None, require_key_establishment=True)
+
+
+class TestPrecertificates:
+    _POISON = (x509.PrecertPoison(), True)
+
+    def _signer(self, root, root_key, ekus):
+        key = ec.generate_private_key(ec.SECP256R1())
+        signer = make_ca(
+            make_name("precert signer"),
+            key,
+            root.subject,
+            root_key,
+            [(x509.ExtendedKeyUsage(ekus), False)],
+        )
+        return signer, key
+
+    def _verifier(self, store, permit=True):
+        builder = PolicyBuilder().store(store).time(VALIDATION_TIME)
+        if permit:
+            builder = builder.permit_precertificates()
+        return builder.build_server_verifier(DNSName("example.com"))
+
+    def test_precertificate_rejected_by_default(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        precert = make_leaf(
+            "example.com", root.subject, root_key, [self._POISON]
+        )
+        verifier = self._verifier(Store([root]), permit=False)
+        with pytest.raises(VerificationError, match="precertificates"):
+            verifier.verify(precert, [])
+
+    def test_precertificate_issued_by_ca(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        precert = make_leaf(
+            "example.com", root.subject, root_key, [self._POISON]
+        )
+        verifier = self._verifier(Store([root]))
+        assert verifier.verify(precert, []) == [precert, root]
+
+    def test_precertificate_signing_certificate(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        signer, signer_key = self._signer(
+            root, root_key, [ExtendedKeyUsageOID.CERTIFICATE_TRANSPARENCY]
+        )
+        precert = make_leaf(
+            "example.com",
+            signer.subject,
+            signer_key,
+            [
+                self._POISON,
+                (
+                    x509.ExtendedKeyUsage([ExtendedKeyUsageOID.SERVER_AUTH]),
+                    False,
+                ),
+            ],
+        )
+        verifier = self._verifier(Store([root]))
+        assert verifier.verify(precert, [signer]) == [precert, signer, root]
+        # The signer issues on behalf of the root, so it doesn't narrow the
+        # chain's extended key usages to Certificate Transparency.
+        assert verifier.satisfied_extended_key_usages(precert, [signer]) == [
+            ExtendedKeyUsageOID.SERVER_AUTH
+        ]
+
+        with pytest.raises(VerificationError):
+            self._verifier(Store([root]), permit=False).verify(
+                precert, [signer]
+            )
+
+    def test_precertificate_signer_cannot_issue_certificates(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        signer, signer_key = self._signer(
+            root, root_key, [ExtendedKeyUsageOID.CERTIFICATE_TRANSPARENCY]
+        )
+        leaf = make_leaf("example.com", signer.subject, signer_key)
+        verifier = self._verifier(Store([root]))
+        with pytest.raises(
+            VerificationError, match="may only issue precertificates"
+        ):
+            verifier.verify(leaf, [signer])
+
+    def test_precertificate_signer_cannot_issue_cas(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        signer, signer_key = self._signer(
+            root, root_key, [ExtendedKeyUsageOID.CERTIFICATE_TRANSPARENCY]
+        )
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), int_key, signer.subject, signer_key
+        )
+        precert = make_leaf(
+            "example.com", intermediate.subject, int_key, [self._POISON]
+        )
+        verifier = self._verifier(Store([root]))
+        with pytest.raises(VerificationError):
+            verifier.verify(precert, [intermediate, signer])
+
+    def test_signer_with_other_ekus_is_not_precertificate_signer(self):
+        # A CA that is also valid for the verifier's EKU is an ordinary CA,
+        # and may issue ordinary certificates.
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        signer, signer_key = self._signer(
+            root,
+            root_key,
+            [
+                ExtendedKeyUsageOID.CERTIFICATE_TRANSPARENCY,
+                ExtendedKeyUsageOID.SERVER_AUTH,
+            ],
+        )
+        leaf = make_leaf("example.com", signer.subject, signer_key)
+        verifier = self._verifier(Store([root]))
+        assert verifier.verify(leaf, [signer]) == [leaf, signer, root]
+
+    def test_poison_rejected_in_ca(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"),
+            int_key,
+            root.subject,
+            root_key,
+            [self._POISON],
+        )
+        precert = make_leaf(
+            "example.com", intermediate.subject, int_key, [self._POISON]
+        )
+        verifier = self._verifier(Store([root]))
+        with pytest.raises(VerificationError):
+            verifier.verify(precert, [intermediate])
+
+
+class TestHandledCriticalExtensions:
+    _OID = x509.ObjectIdentifier("1.2.3.4.5")
+
+    def _extension(self, critical=True):
+        return (x509.UnrecognizedExtension(self._OID, b"\x05\x00"), critical)
+
+    def _verifier(self, store, handled=None):
+        builder = PolicyBuilder().store(store).time(VALIDATION_TIME)
+        if handled is not None:
+            builder = builder.handled_critical_extensions(handled)
+        return builder.build_server_verifier(DNSName("example.com"))
+
+    def test_rejected_by_default(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        leaf = make_leaf(
+            "example.com", root.subject, root_key, [self._extension()]
+        )
+        with pytest.raises(
+            VerificationError, match="unaccounted-for critical extensions"
+        ):
+            self._verifier(Store([root])).verify(leaf, [])
+
+    def test_handled_in_leaf(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        leaf = make_leaf(
+            "example.com", root.subject, root_key, [self._extension()]
+        )
+        verifier = self._verifier(Store([root]), [self._OID])
+        assert verifier.verify(leaf, []) == [leaf, root]
+
+    def test_handled_in_intermediate(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"),
+            int_key,
+            root.subject,
+            root_key,
+            [self._extension()],
+        )
+        leaf = make_leaf("example.com", intermediate.subject, int_key)
+        with pytest.raises(VerificationError):
+            self._verifier(Store([root])).verify(leaf, [intermediate])
+        verifier = self._verifier(Store([root]), [self._OID])
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+
+    def test_other_extension_still_rejected(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        leaf = make_leaf(
+            "example.com", root.subject, root_key, [self._extension()]
+        )
+        verifier = self._verifier(
+            Store([root]), [x509.ObjectIdentifier("1.2.3.4.6")]
+        )
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [])
+
+    def test_client_verifier(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        leaf = make_leaf(
+            "example.com",
+            root.subject,
+            root_key,
+            [
+                self._extension(),
+                (
+                    x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]),
+                    False,
+                ),
+            ],
+        )
+        builder = PolicyBuilder().store(Store([root])).time(VALIDATION_TIME)
+        with pytest.raises(VerificationError):
+            builder.build_client_verifier().verify(leaf, [])
+        verifier = builder.handled_critical_extensions(
+            [self._OID]
+        ).build_client_verifier()
+        assert verifier.verify(leaf, []).chain == [leaf, root]
+
+    def test_set_twice(self):
+        builder = PolicyBuilder().handled_critical_extensions([self._OID])
+        with pytest.raises(ValueError):
+            builder.handled_critical_extensions([self._OID])
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            PolicyBuilder().handled_critical_extensions(
+                ["1.2.3.4.5"]  # type: ignore[list-item]
+            )
+
+
+class TestCaIssuersFetcher:
+    _URL = "http://example.com/intermediate.der"
+
+    def _aia(self, url):
+        return (
+            x509.AuthorityInformationAccess(
+                [
+                    x509.AccessDescription(
+                        AuthorityInformationAccessOID.CA_ISSUERS,
+                        UniformResourceIdentifier(url),
+                    )
+                ]
+            ),
+            False,
+        )
+
+    def _chain(self, extensions: Extensions = ()):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), int_key, root.subject, root_key
+        )
+        leaf = make_leaf(
+            "example.com",
+            intermediate.subject,
+            int_key,
+            [self._aia(self._URL), *extensions],
+        )
+        return root, intermediate, leaf
+
+    def _verifier(self, store, fetcher=None, max_aia_fetches=None):
+        builder = PolicyBuilder().store(store).time(VALIDATION_TIME)
+        if fetcher is not None:
+            builder = builder.ca_issuers_fetcher(fetcher)
+        if max_aia_fetches is not None:
+            builder = builder.max_aia_fetches(max_aia_fetches)
+        return builder.build_server_verifier(DNSName("example.com"))
+
+    def test_incomplete_chain_without_fetcher(self):
+        root, _, leaf = self._chain()
+        with pytest.raises(VerificationError):
+            self._verifier(Store([root])).verify(leaf, [])
+
+    def test_fetches_missing_intermediate(self):
+        root, intermediate, leaf = self._chain()
+        urls = []
+
+        def fetcher(url):
+            urls.append(url)
+            return [intermediate.public_bytes(Encoding.DER)]
+
+        verifier = self._verifier(Store([root]), fetcher)
+        assert verifier.verify(leaf, []) == [leaf, intermediate, root]
+        assert urls == [self._URL]
+
+    def test_not_called_for_complete_chain(self):
+        root, intermediate, leaf = self._chain()
+
+        def fetcher(url):
+            raise AssertionError("unexpected fetch")
+
+        verifier = self._verifier(Store([root]), fetcher)
+        assert verifier.verify(leaf, [intermediate]) == [
+            leaf,
+            intermediate,
+            root,
+        ]
+
+    def test_fetched_subject_matched_as_name(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), int_key, root.subject, root_key
+        )
+        # The leaf names its issuer with a different string type and case,
+        # which still matches under RFC 5280 7.1.
+        issuer = x509.Name(
+            [
+                x509.NameAttribute(
+                    NameOID.COMMON_NAME,
+                    "Intermediate",
+                    _ASN1Type.PrintableString,
+                )
+            ]
+        )
+        leaf = make_leaf(
+            "example.com", issuer, int_key, [self._aia(self._URL)]
+        )
+
+        def fetcher(url):
+            return [intermediate.public_bytes(Encoding.DER)]
+
+        verifier = self._verifier(Store([root]), fetcher)
+        assert verifier.verify(leaf, []) == [leaf, intermediate, root]
+
+    def test_follows_multiple_levels(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int1_key = ec.generate_private_key(ec.SECP256R1())
+        int1 = make_ca(make_name("int1"), int1_key, root.subject, root_key)
+        int2_key = ec.generate_private_key(ec.SECP256R1())
+        int2 = make_ca(
+            make_name("int2"),
+            int2_key,
+            int1.subject,
+            int1_key,
+            [self._aia("http://example.com/int1.der")],
+        )
+        leaf = make_leaf(
+            "example.com",
+            int2.subject,
+            int2_key,
+            [self._aia("http://example.com/int2.der")],
+        )
+        responses = {
+            "http://example.com/int1.der": int1,
+            "http://example.com/int2.der": int2,
+        }
+
+        def fetcher(url):
+            return [responses[url].public_bytes(Encoding.DER)]
+
+        verifier = self._verifier(Store([root]), fetcher)
+        assert verifier.verify(leaf, []) == [leaf, int2, int1, root]
+
+        # Reaching int1 takes a second fetch.
+        verifier = self._verifier(Store([root]), fetcher, max_aia_fetches=1)
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [])
+
+    def test_max_aia_fetches_zero(self):
+        root, intermediate, leaf = self._chain()
+
+        def fetcher(url):
+            raise AssertionError("unexpected fetch")
+
+        verifier = self._verifier(Store([root]), fetcher, max_aia_fetches=0)
+        with pytest.raises(VerificationError):
+            verifier.verify(leaf, [])
+
+    def test_ignores_unusable_responses(self):
+        root, intermediate, leaf = self._chain()
+
+        def fetcher(url):
+            return [
+                b"not a certificate",
+                root.public_bytes(Encoding.DER),
+                intermediate.public_bytes(Encoding.DER),
+            ]
+
+        verifier = self._verifier(Store([root]), fetcher)
+        assert verifier.verify(leaf, []) == [leaf, intermediate, root]
+
+    def test_fetched_certificate_is_validated(self):
+        root, intermediate, leaf = self._chain()
+        other_key = ec.generate_private_key(ec.SECP256R1())
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        impostor = make_ca(
+            intermediate.subject, other_key, root.subject, root_key
+        )
+
+        def fetcher(url):
+            return [impostor.public_bytes(Encoding.DER)]
+
+        with pytest.raises(VerificationError):
+            self._verifier(Store([root]), fetcher).verify(leaf, [])
+
+    def test_fetcher_error_propagates(self):
+        root, _, leaf = self._chain()
+
+        def fetcher(url):
+            raise RuntimeError("network unreachable")
+
+        with pytest.raises(RuntimeError, match="network unreachable"):
+            self._verifier(Store([root]), fetcher).verify(leaf, [])
+
+    def test_client_verifier(self):
+        root, intermediate, leaf = self._chain(
+            [(x509.ExtendedKeyUsage([ExtendedKeyUsageOID.CLIENT_AUTH]), False)]
+        )
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .ca_issuers_fetcher(
+                lambda url: [intermediate.public_bytes(Encoding.DER)]
+            )
+            .build_client_verifier()
+        )
+        assert verifier.verify(leaf, []).chain == [leaf, intermediate, root]
+
+    def test_set_twice(self):
+        builder = PolicyBuilder().ca_issuers_fetcher(lambda url: [])
+        with pytest.raises(ValueError):
+            builder.ca_issuers_fetcher(lambda url: [])
+
+    def test_max_aia_fetches_set_twice(self):
+        builder = PolicyBuilder().max_aia_fetches(1)
+        with pytest.raises(ValueError):
+            builder.max_aia_fetches(2)
+
+    def test_not_callable(self):
+        with pytest.raises(TypeError):
+            PolicyBuilder().ca_issuers_fetcher(
+                "http://example.com"  # type: ignore[arg-type]
+            )
+
+
+class TestPathLength:
+    def _verify(self, root, intermediates, leaf):
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier.verify(leaf, intermediates)
+
+    def _root(self, path_length):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key, path_length=path_length)
+        return root, root_key
+
+    def test_pathlen_zero_allows_leaf(self):
+        root, root_key = self._root(0)
+        leaf = make_leaf("example.com", root.subject, root_key)
+        assert len(self._verify(root, [], leaf)) == 2
+
+    def test_pathlen_zero_forbids_intermediate(self):
+        root, root_key = self._root(0)
+        key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), key, root.subject, root_key
+        )
+        leaf = make_leaf("example.com", intermediate.subject, key)
+        with pytest.raises(
+            VerificationError, match="path length constraint violated"
+        ):
+            self._verify(root, [intermediate], leaf)
+
+    def test_pathlen_is_enforced_chain_wide(self):
+        # root -> first -> second (pathlen=0) -> third -> leaf: the root
+        # is unconstrained, but `second` doesn't permit `third`.
+        root, root_key = self._root(None)
+        first_key = ec.generate_private_key(ec.SECP256R1())
+        first = make_ca(make_name("first"), first_key, root.subject, root_key)
+        second_key = ec.generate_private_key(ec.SECP256R1())
+        second = make_ca(
+            make_name("second"),
+            second_key,
+            first.subject,
+            first_key,
+            path_length=0,
+        )
+        third_key = ec.generate_private_key(ec.SECP256R1())
+        third = make_ca(
+            make_name("third"), third_key, second.subject, second_key
+        )
+        leaf = make_leaf("example.com", third.subject, third_key)
+        with pytest.raises(
+            VerificationError, match="path length constraint violated"
+        ):
+            self._verify(root, [first, second, third], leaf)
+
+        leaf = make_leaf("example.com", second.subject, second_key)
+        assert len(self._verify(root, [first, second], leaf)) == 4
+
+    def test_self_issued_intermediate_not_counted(self):
+        # A key rollover certificate has the same subject as its issuer,
+        # and so doesn't count towards the root's pathLenConstraint.
+        root, root_key = self._root(0)
+        rollover_key = ec.generate_private_key(ec.SECP256R1())
+        rollover = make_ca(root.subject, rollover_key, root.subject, root_key)
+        leaf = make_leaf("example.com", rollover.subject, rollover_key)
+        assert self._verify(root, [rollover], leaf) == [leaf, rollover, root]
+
+    def test_self_issued_intermediate_does_not_hide_others(self):
+        # root (pathlen=1) -> first -> first (self-issued) -> second -> leaf
+        # has two non-self-issued intermediates.
+        root, root_key = self._root(1)
+        first_key = ec.generate_private_key(ec.SECP256R1())
+        first = make_ca(make_name("first"), first_key, root.subject, root_key)
+        rollover_key = ec.generate_private_key(ec.SECP256R1())
+        rollover = make_ca(
+            first.subject, rollover_key, first.subject, first_key
+        )
+        second_key = ec.generate_private_key(ec.SECP256R1())
+        second = make_ca(
+            make_name("second"), second_key, rollover.subject, rollover_key
+        )
+        leaf = make_leaf("example.com", second.subject, second_key)
+        with pytest.raises(VerificationError):
+            self._verify(root, [first, rollover, second], leaf)
+
+        leaf = make_leaf("example.com", rollover.subject, rollover_key)
+        assert len(self._verify(root, [first, rollover], leaf)) == 4
+
+
+class TestBasicConstraintsAbsent:
+    _KEY_USAGE = (
+        x509.KeyUsage(
+            digital_signature=False,
+            content_commitment=False,
+            key_encipherment=False,
+            data_encipherment=False,
+            key_agreement=False,
+            key_cert_sign=True,
+            crl_sign=True,
+            encipher_only=False,
+            decipher_only=False,
+        ),
+        True,
+    )
+
+    def _verify(self, root, root_key, intermediate_extensions):
+        key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = issue_cert(
+            make_name("intermediate"),
+            key.public_key(),
+            root.subject,
+            root_key,
+            intermediate_extensions,
+        )
+        leaf = make_leaf("example.com", intermediate.subject, key)
+        verifier = (
+            PolicyBuilder()
+            .store(Store([root]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        return verifier.verify(leaf, [intermediate])
+
+    def test_issuer_without_basic_constraints_rejected(self):
+        # Without basicConstraints a certificate is an end-entity, even if
+        # its key usage permits certificate signing (RFC 5280 4.2.1.9).
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        with pytest.raises(
+            VerificationError, match="missing required extension"
+        ):
+            self._verify(root, root_key, [self._KEY_USAGE])
+
+    def test_issuer_with_ca_false_rejected(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        with pytest.raises(
+            VerificationError, match="basicConstraints.cA must be asserted"
+        ):
+            self._verify(
+                root,
+                root_key,
+                [
+                    (x509.BasicConstraints(ca=False, path_length=None), True),
+                    self._KEY_USAGE,
+                ],
+            )
+
+    def test_issuer_with_basic_constraints_accepted(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        chain = self._verify(
+            root,
+            root_key,
+            [
+                (x509.BasicConstraints(ca=True, path_length=None), True),
+                self._KEY_USAGE,
+            ],
+        )
+        assert len(chain) == 3
+
+
+
+class TestNestedValidity:
+    _DAY = datetime.timedelta(days=1)
+
+    def _verify(self, validities, require_nested_validity):
+        # validities are for (root, intermediate, leaf)
+        root_validity, int_validity, leaf_validity = validities
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key, validity=root_validity)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"),
+            int_key,
+            root.subject,
+            root_key,
+            validity=int_validity,
+        )
+        leaf = make_leaf(
+            "example.com",
+            intermediate.subject,
+            int_key,
+            validity=leaf_validity,
+        )
+        builder = PolicyBuilder().store(Store([root])).time(VALIDATION_TIME)
+        if require_nested_validity:
+            builder = builder.require_nested_validity()
+        verifier = builder.build_server_verifier(DNSName("example.com"))
+        return verifier.verify(leaf, [intermediate])
+
+    def _validity(self, before, after):
+        return (
+            VALIDATION_TIME - before * self._DAY,
+            VALIDATION_TIME + after * self._DAY,
+        )
+
+    @pytest.mark.parametrize(
+        "validities",
+        [
+            # All identical.
+            ((1, 1), (1, 1), (1, 1)),
+            # Strictly nested.
+            ((3, 3), (2, 2), (1, 1)),
+        ],
+    )
+    def test_nested(self, validities):
+        validities = [self._validity(*v) for v in validities]
+        chain = self._verify(validities, True)
+        assert len(chain) == 3
+
+    @pytest.mark.parametrize(
+        "validities",
+        [
+            # The leaf outlives its issuer.
+            ((3, 3), (2, 2), (1, 3)),
+            # The leaf predates its issuer.
+            ((3, 3), (2, 2), (3, 1)),
+            # The intermediate outlives the root.
+            ((3, 3), (2, 4), (1, 1)),
+        ],
+    )
+    def test_not_nested(self, validities):
+        validities = [self._validity(*v) for v in validities]
+        # Not checked by default.
+        assert len(self._verify(validities, False)) == 3
+        with pytest.raises(VerificationError, match="not within its issuer's"):
+            self._verify(validities, True)
+
+class TestAuthorityKeyIdentifierMatches:
+    def _issuer(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        key = ec.generate_private_key(ec.SECP256R1())
+        issuer = make_ca(
+            make_name("intermediate"), key, root.subject, root_key
+        )
+        return issuer, key
+
+    def test_key_identifier(self):
+        issuer, key = self._issuer()
+        other_key = ec.generate_private_key(ec.SECP256R1())
+        aki = x509.AuthorityKeyIdentifier.from_issuer_public_key(
+            key.public_key()
+        )
+        other_aki = x509.AuthorityKeyIdentifier.from_issuer_public_key(
+            other_key.public_key()
+        )
+        assert authority_key_identifier_matches(aki, issuer)
+        assert not authority_key_identifier_matches(other_aki, issuer)
+
+    def test_issuer_and_serial(self):
+        issuer, _ = self._issuer()
+        aki = x509.AuthorityKeyIdentifier(
+            None, [DirectoryName(issuer.issuer)], issuer.serial_number
+        )
+        assert authority_key_identifier_matches(aki, issuer)
+
+        wrong_serial = x509.AuthorityKeyIdentifier(
+            None, [DirectoryName(issuer.issuer)], issuer.serial_number + 1
+        )
+        assert not authority_key_identifier_matches(wrong_serial, issuer)
+
+        wrong_name = x509.AuthorityKeyIdentifier(
+            None, [DirectoryName(issuer.subject)], issuer.serial_number
+        )
+        assert not authority_key_identifier_matches(wrong_name, issuer)
+
+        not_a_directory_name = x509.AuthorityKeyIdentifier(
+            None, [DNSName("root")], issuer.serial_number
+        )
+        assert not authority_key_identifier_matches(
+            not_a_directory_name, issuer
+        )
+
+    def test_all_fields(self):
+        issuer, key = self._issuer()
+        key_identifier = x509.SubjectKeyIdentifier.from_public_key(
+            key.public_key()
+        ).digest
+        aki = x509.AuthorityKeyIdentifier(
+            key_identifier,
+            [DNSName("root"), DirectoryName(issuer.issuer)],
+            issuer.serial_number,
+        )
+        assert authority_key_identifier_matches(aki, issuer)
+
+        # All present fields must match.
+        aki = x509.AuthorityKeyIdentifier(
+            b"\x00" * 20,
+            [DirectoryName(issuer.issuer)],
+            issuer.serial_number,
+        )
+        assert not authority_key_identifier_matches(aki, issuer)
+
+    def test_no_fields(self):
+        issuer, _ = self._issuer()
+        aki = x509.AuthorityKeyIdentifier(None, None, None)
+        assert authority_key_identifier_matches(aki, issuer)
+
+    def test_issuer_without_subject_key_identifier(self):
+        cert = _load_cert(
+            os.path.join("x509", "custom", "ca", "ca.pem"),
+            x509.load_pem_x509_certificate,
+        )
+        aki = x509.AuthorityKeyIdentifier(b"\x00" * 20, None, None)
+        assert not authority_key_identifier_matches(aki, cert)
+
+    def test_invalid_types(self):
+        issuer, key = self._issuer()
+        aki = x509.AuthorityKeyIdentifier.from_issuer_public_key(
+            key.public_key()
+        )
+        with pytest.raises(TypeError):
+            authority_key_identifier_matches(
+                object(),  # type: ignore[arg-type]
+                issuer,
+            )
+        with pytest.raises(TypeError):
+            authority_key_identifier_matches(
+                aki,
+                object(),  # type: ignore[arg-type]
+            )
+
+
+class TestChainPem:
+    def _chain(self):
+        root_key = ec.generate_private_key(ec.SECP256R1())
+        root = make_ca(make_name("root"), root_key)
+        int_key = ec.generate_private_key(ec.SECP256R1())
+        intermediate = make_ca(
+            make_name("intermediate"), int_key, root.subject, root_key
+        )
+        leaf = make_leaf("example.com", intermediate.subject, int_key)
+        builder = PolicyBuilder().store(Store([root])).time(VALIDATION_TIME)
+        return builder, leaf, intermediate, root
+
+    def test_server_chain(self):
+        builder, leaf, intermediate, root = self._chain()
+        verifier = builder.build_server_verifier(DNSName("example.com"))
+        chain = verifier.verify(leaf, [intermediate])
+
+        bundle = chain_pem(chain)
+        assert bundle == b"".join(
+            c.public_bytes(Encoding.PEM) for c in [leaf, intermediate, root]
+        )
+        assert chain_pem(chain, include_root=True) == bundle
+
+        bundle = chain_pem(chain, include_root=False)
+        assert x509.load_pem_x509_certificates(bundle) == [leaf, intermediate]
+
+        # The bundle, as a server would present it, verifies again.
+        peer, *untrusted_intermediates = x509.load_pem_x509_certificates(
+            bundle
+        )
+        assert verifier.verify(peer, untrusted_intermediates) == chain
+
+    def test_verified_client(self):
+        builder, leaf, intermediate, root = self._chain()
+        verified_client = builder.build_client_verifier().verify(
+            leaf, [intermediate]
+        )
+        assert verified_client.chain_pem() == chain_pem(verified_client.chain)
+        assert x509.load_pem_x509_certificates(
+            verified_client.chain_pem(include_root=False)
+        ) == [leaf, intermediate]
+
+    def test_leaf_is_trust_anchor(self):
+        builder, leaf, _, _ = self._chain()
+        verifier = (
+            PolicyBuilder()
+            .store(Store([leaf]))
+            .time(VALIDATION_TIME)
+            .build_server_verifier(DNSName("example.com"))
+        )
+        chain = verifier.verify(leaf, [])
+        expected = leaf.public_bytes(Encoding.PEM)
+        assert chain_pem(chain) == expected
+        assert chain_pem(chain, include_root=False) == expected
+
+    def test_empty_chain(self):
+        assert chain_pem([]) == b""
+        assert chain_pem([], include_root=False) == b""
+
+    def test_invalid_types(self):
+        with pytest.raises(TypeError):
+            chain_pem([object()])  # type: ignore[list-item]
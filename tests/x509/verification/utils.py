@@ -0,0 +1,126 @@
+# This file is dual licensed under the terms of the Apache License, Version
+# 2.0, and the BSD License. See the LICENSE file in the root of this repository
+# for complete details.
+
+import datetime
+import typing
+
+from cryptography import x509
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec
+from cryptography.hazmat.primitives.asymmetric.types import (
+    CertificatePublicKeyTypes,
+)
+from cryptography.x509.general_name import DNSName
+from cryptography.x509.oid import NameOID
+
+VALIDATION_TIME = datetime.datetime(2024, 1, 1)
+
+Extensions = typing.Sequence[typing.Tuple[x509.ExtensionType, bool]]
+Validity = typing.Tuple[datetime.datetime, datetime.datetime]
+
+
+def make_name(common_name: str) -> x509.Name:
+    return x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, common_name)])
+
+
+def issue_cert(
+    subject: x509.Name,
+    public_key: CertificatePublicKeyTypes,
+    issuer: x509.Name,
+    issuer_key: ec.EllipticCurvePrivateKey,
+    extensions: Extensions,
+    algorithm: hashes.HashAlgorithm = hashes.SHA256(),
+    validity: typing.Optional[Validity] = None,
+) -> x509.Certificate:
+    not_before, not_after = validity or (
+        VALIDATION_TIME - datetime.timedelta(days=1),
+        VALIDATION_TIME + datetime.timedelta(days=1),
+    )
+    builder = (
+        x509.CertificateBuilder()
+        .subject_name(subject)
+        .issuer_name(issuer)
+        .public_key(public_key)
+        .serial_number(x509.random_serial_number())
+        .not_valid_before(not_before)
+        .not_valid_after(not_after)
+    )
+    # Key identifiers are derived from the keys unless explicitly given.
+    given = {type(ext) for ext, _ in extensions}
+    if x509.SubjectKeyIdentifier not in given:
+        builder = builder.add_extension(
+            x509.SubjectKeyIdentifier.from_public_key(public_key),
+            critical=False,
+        )
+    if x509.AuthorityKeyIdentifier not in given:
+        builder = builder.add_extension(
+            x509.AuthorityKeyIdentifier.from_issuer_public_key(
+                issuer_key.public_key()
+            ),
+            critical=False,
+        )
+    for ext, critical in extensions:
+        builder = builder.add_extension(ext, critical=critical)
+    return builder.sign(issuer_key, algorithm)
+
+
+def make_ca(
+    subject: x509.Name,
+    key: ec.EllipticCurvePrivateKey,
+    issuer: typing.Optional[x509.Name] = None,
+    issuer_key: typing.Optional[ec.EllipticCurvePrivateKey] = None,
+    extensions: Extensions = (),
+    algorithm: hashes.HashAlgorithm = hashes.SHA256(),
+    path_length: typing.Optional[int] = None,
+    validity: typing.Optional[Validity] = None,
+) -> x509.Certificate:
+    return issue_cert(
+        subject,
+        key.public_key(),
+        issuer or subject,
+        issuer_key or key,
+        [
+            (x509.BasicConstraints(ca=True, path_length=path_length), True),
+            (
+                x509.KeyUsage(
+                    digital_signature=False,
+                    content_commitment=False,
+                    key_encipherment=False,
+                    data_encipherment=False,
+                    key_agreement=False,
+                    key_cert_sign=True,
+                    crl_sign=True,
+                    encipher_only=False,
+                    decipher_only=False,
+                ),
+                True,
+            ),
+            *extensions,
+        ],
+        algorithm,
+        validity,
+    )
+
+
+def make_leaf(
+    dns_name: str,
+    issuer: x509.Name,
+    issuer_key: ec.EllipticCurvePrivateKey,
+    extensions: Extensions = (),
+    algorithm: hashes.HashAlgorithm = hashes.SHA256(),
+    validity: typing.Optional[Validity] = None,
+) -> x509.Certificate:
+    key = ec.generate_private_key(ec.SECP256R1())
+    return issue_cert(
+        make_name(dns_name),
+        key.public_key(),
+        issuer,
+        issuer_key,
+        [
+            (x509.SubjectAlternativeName([DNSName(dns_name)]), False),
+            *extensions,
+        ],
+        algorithm,
+        validity,
+    )